@@ -0,0 +1,295 @@
+//! # Color functions
+//! Color functions are used to convert and adjust colors stored in a vector.
+//! This has a similar purpose to `XMColor*` in the `DirectXMath` library.
+//!
+//! The `x`, `y`, `z` elements of a vector are the color channels and the `w` element is alpha.
+//! All functions keep the alpha unless otherwise noted.
+//!
+
+use crate::{ Vector, Float4 };
+
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+
+
+
+/// Luminance coefficients of the `Rec. 709` (sRGB) primaries.
+const REC709: [f32; 4] = [0.2126, 0.7152, 0.0722, 0.0];
+
+/// Luminance coefficients of the `Rec. 2020` primaries.
+const REC2020: [f32; 4] = [0.2627, 0.6780, 0.0593, 0.0];
+
+
+
+/// Converts a color from the sRGB transfer curve to linear.
+///
+/// ※ Uses the exact piecewise curve of the sRGB specification, not a gamma approximation. </br>
+///
+#[inline]
+#[must_use]
+pub fn srgb_to_linear(color: Vector) -> Vector {
+    let low = color / 12.92;
+    let high = (((color + Vector::fill(0.055)) / 1.055).log2() * 2.4).exp2();
+    let mut linear = Vector::select(color.le(Vector::fill(0.04045)), low, high);
+    linear.set_w(color.get_w());
+    linear
+}
+
+/// Converts a color from linear to the sRGB transfer curve.
+///
+/// ※ Uses the exact piecewise curve of the sRGB specification, not a gamma approximation. </br>
+///
+#[inline]
+#[must_use]
+pub fn linear_to_srgb(color: Vector) -> Vector {
+    let low = color * 12.92;
+    let high = (color.log2() * (1.0 / 2.4)).exp2().mul_add(Vector::fill(1.055), Vector::fill(-0.055));
+    let mut srgb = Vector::select(color.le(Vector::fill(0.0031308)), low, high);
+    srgb.set_w(color.get_w());
+    srgb
+}
+
+/// Converts a color from `RGB` to `HSV`. (hue, saturation, value)
+///
+/// ※ All elements of the result are in the range `[0, 1]` if the color is in the range `[0, 1]`. </br>
+/// ※ The hue of a gray color is zero. </br>
+///
+#[inline]
+#[must_use]
+pub fn rgb_to_hsv(color: Vector) -> Vector {
+    let [r, g, b, a] = color.into_array();
+    let max = r.max(g).max(b);
+    let delta = max - r.min(g).min(b);
+    let s = if max > 0.0 { delta / max } else { 0.0 };
+    Vector::new(hue(r, g, b, max, delta), s, max, a)
+}
+
+/// Converts a color from `HSV` to `RGB`. (hue, saturation, value)
+///
+/// ※ The hue wraps around, so zero and one are both red. </br>
+///
+#[inline]
+#[must_use]
+pub fn hsv_to_rgb(color: Vector) -> Vector {
+    let [h, s, v, a] = color.into_array();
+    let h = (h - h.floor()) * 6.0;
+    let f = h - h.floor();
+    let p = v * (1.0 - s);
+    let q = v * (1.0 - s * f);
+    let t = v * (1.0 - s * (1.0 - f));
+    match h as u32 % 6 {
+        0 => Vector::new(v, t, p, a),
+        1 => Vector::new(q, v, p, a),
+        2 => Vector::new(p, v, t, a),
+        3 => Vector::new(p, q, v, a),
+        4 => Vector::new(t, p, v, a),
+        _ => Vector::new(v, p, q, a),
+    }
+}
+
+/// Converts a color from `RGB` to `HSL`. (hue, saturation, lightness)
+///
+/// ※ All elements of the result are in the range `[0, 1]` if the color is in the range `[0, 1]`. </br>
+/// ※ The hue of a gray color is zero. </br>
+///
+#[inline]
+#[must_use]
+pub fn rgb_to_hsl(color: Vector) -> Vector {
+    let [r, g, b, a] = color.into_array();
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let delta = max - min;
+    let l = (max + min) * 0.5;
+    let s = match delta > 0.0 {
+        true if l <= 0.5 => delta / (max + min),
+        true => delta / (2.0 - max - min),
+        false => 0.0,
+    };
+    Vector::new(hue(r, g, b, max, delta), s, l, a)
+}
+
+/// Converts a color from `HSL` to `RGB`. (hue, saturation, lightness)
+///
+/// ※ The hue wraps around, so zero and one are both red. </br>
+///
+#[inline]
+#[must_use]
+pub fn hsl_to_rgb(color: Vector) -> Vector {
+    let [h, s, l, a] = color.into_array();
+    let v = l + s * l.min(1.0 - l);
+    let s = if v > 0.0 { 2.0 * (1.0 - l / v) } else { 0.0 };
+    hsv_to_rgb(Vector::new(h, s, v, a))
+}
+
+/// Returns the relative luminance of a linear color with the `Rec. 709` (sRGB) primaries.
+#[inline]
+#[must_use]
+pub fn luminance_rec709(color: Vector) -> f32 {
+    color.vec3_dot_into(Vector::from_array(REC709))
+}
+
+/// Returns the relative luminance of a linear color with the `Rec. 2020` primaries.
+#[inline]
+#[must_use]
+pub fn luminance_rec2020(color: Vector) -> f32 {
+    color.vec3_dot_into(Vector::from_array(REC2020))
+}
+
+/// Multiplies the color channels by alpha.
+#[inline]
+#[must_use]
+pub fn premultiply_alpha(color: Vector) -> Vector {
+    let mut result = color * color.splat_w();
+    result.set_w(color.get_w());
+    result
+}
+
+/// Divides the color channels by alpha.
+///
+/// ※ If alpha is zero, the color channels become zero. </br>
+///
+#[inline]
+#[must_use]
+pub fn unpremultiply_alpha(color: Vector) -> Vector {
+    let alpha = color.splat_w();
+    let mut result = Vector::select(alpha.gt(Vector::ZERO), color / alpha, Vector::ZERO);
+    result.set_w(color.get_w());
+    result
+}
+
+/// Adjusts the saturation of a linear color.
+///
+/// ※ The color is interpolated from the gray of the same `Rec. 709` luminance. </br>
+/// ※ Zero gives the gray, one gives the original color, and values greater than one oversaturate. </br>
+///
+#[inline]
+#[must_use]
+pub fn adjust_saturation(color: Vector, saturation: f32) -> Vector {
+    let gray = color.vec3_dot(Vector::from_array(REC709));
+    let mut result = (color - gray).mul_add(Vector::fill(saturation), gray);
+    result.set_w(color.get_w());
+    result
+}
+
+/// Adjusts the contrast of a color.
+///
+/// ※ The color is interpolated from the middle gray (`0.5`). </br>
+/// ※ Zero gives the middle gray, one gives the original color, and values greater than one increase the contrast. </br>
+///
+#[inline]
+#[must_use]
+pub fn adjust_contrast(color: Vector, contrast: f32) -> Vector {
+    let gray = Vector::fill(0.5);
+    let mut result = (color - gray).mul_add(Vector::fill(contrast), gray);
+    result.set_w(color.get_w());
+    result
+}
+
+/// Converts the colors of the `src` slice from the sRGB transfer curve to linear and stores them in the `dst` slice.
+///
+/// ※ If the lengths are different, only the shorter length is converted. </br>
+///
+/// # Panics
+/// When the `use-assertion` feature is enabled, it will [`panic!`]
+/// if the `src` and `dst` slices have different lengths.
+///
+#[inline]
+pub fn srgb_to_linear_slice(src: &[Float4], dst: &mut [Float4]) {
+    map_slice(src, dst, srgb_to_linear)
+}
+
+/// Converts the colors of the `src` slice from linear to the sRGB transfer curve and stores them in the `dst` slice.
+///
+/// ※ If the lengths are different, only the shorter length is converted. </br>
+///
+/// # Panics
+/// When the `use-assertion` feature is enabled, it will [`panic!`]
+/// if the `src` and `dst` slices have different lengths.
+///
+#[inline]
+pub fn linear_to_srgb_slice(src: &[Float4], dst: &mut [Float4]) {
+    map_slice(src, dst, linear_to_srgb)
+}
+
+/// Converts the colors of the `src` slice from `RGB` to `HSV` and stores them in the `dst` slice.
+///
+/// ※ If the lengths are different, only the shorter length is converted. </br>
+///
+/// # Panics
+/// When the `use-assertion` feature is enabled, it will [`panic!`]
+/// if the `src` and `dst` slices have different lengths.
+///
+#[inline]
+pub fn rgb_to_hsv_slice(src: &[Float4], dst: &mut [Float4]) {
+    map_slice(src, dst, rgb_to_hsv)
+}
+
+/// Converts the colors of the `src` slice from `HSV` to `RGB` and stores them in the `dst` slice.
+///
+/// ※ If the lengths are different, only the shorter length is converted. </br>
+///
+/// # Panics
+/// When the `use-assertion` feature is enabled, it will [`panic!`]
+/// if the `src` and `dst` slices have different lengths.
+///
+#[inline]
+pub fn hsv_to_rgb_slice(src: &[Float4], dst: &mut [Float4]) {
+    map_slice(src, dst, hsv_to_rgb)
+}
+
+/// Multiplies the color channels of the `src` slice by alpha and stores them in the `dst` slice.
+///
+/// ※ If the lengths are different, only the shorter length is converted. </br>
+///
+/// # Panics
+/// When the `use-assertion` feature is enabled, it will [`panic!`]
+/// if the `src` and `dst` slices have different lengths.
+///
+#[inline]
+pub fn premultiply_alpha_slice(src: &[Float4], dst: &mut [Float4]) {
+    map_slice(src, dst, premultiply_alpha)
+}
+
+/// Divides the color channels of the `src` slice by alpha and stores them in the `dst` slice.
+///
+/// ※ If the lengths are different, only the shorter length is converted. </br>
+///
+/// # Panics
+/// When the `use-assertion` feature is enabled, it will [`panic!`]
+/// if the `src` and `dst` slices have different lengths.
+///
+#[inline]
+pub fn unpremultiply_alpha_slice(src: &[Float4], dst: &mut [Float4]) {
+    map_slice(src, dst, unpremultiply_alpha)
+}
+
+
+
+/// Returns the hue in the range `[0, 1]` from the color channels, the largest channel and the chroma.
+#[inline]
+fn hue(r: f32, g: f32, b: f32, max: f32, delta: f32) -> f32 {
+    if delta <= 0.0 {
+        return 0.0;
+    }
+
+    let h = if max == r {
+        (g - b) / delta
+    } else if max == g {
+        (b - r) / delta + 2.0
+    } else {
+        (r - g) / delta + 4.0
+    };
+    let h = h / 6.0;
+    if h < 0.0 { h + 1.0 } else { h }
+}
+
+/// Applies the given function to the colors of the `src` slice and stores the results in the `dst` slice.
+#[inline]
+fn map_slice<F: Fn(Vector) -> Vector>(src: &[Float4], dst: &mut [Float4], f: F) {
+    #[cfg(feature = "use-assertion")]
+    assert!(src.len() == dst.len(), "The lengths of the `src` and `dst` slices are different!");
+
+    for (s, d) in src.iter().zip(dst.iter_mut()) {
+        *d = f(Vector::load_float4(*s)).store_float4();
+    }
+}
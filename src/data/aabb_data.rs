@@ -0,0 +1,27 @@
+use super::float3::Float3;
+
+
+
+/// A structure that stores axis-aligned bounding box data.
+///
+/// ※ Use [`Aabb::load_data`](crate::Aabb::load_data) and [`Aabb::store_data`](crate::Aabb::store_data) to convert with the geometry type. </br>
+///
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Default, PartialEq, Debug)]
+pub struct AabbData {
+    /// The center of the box.
+    pub center: Float3,
+
+    /// The half-lengths of the box along each axis.
+    pub extents: Float3,
+}
+
+impl AabbData {
+    /// Creates with the given center and extents.
+    #[inline]
+    #[must_use]
+    pub const fn new(center: Float3, extents: Float3) -> Self {
+        Self { center, extents }
+    }
+}
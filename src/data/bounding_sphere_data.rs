@@ -0,0 +1,27 @@
+use super::float3::Float3;
+
+
+
+/// A structure that stores bounding sphere data.
+///
+/// ※ Use [`BoundingSphere::load_data`](crate::BoundingSphere::load_data) and [`BoundingSphere::store_data`](crate::BoundingSphere::store_data) to convert with the geometry type. </br>
+///
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Default, PartialEq, Debug)]
+pub struct BoundingSphereData {
+    /// The center of the sphere.
+    pub center: Float3,
+
+    /// The radius of the sphere.
+    pub radius: f32,
+}
+
+impl BoundingSphereData {
+    /// Creates with the given center and radius.
+    #[inline]
+    #[must_use]
+    pub const fn new(center: Float3, radius: f32) -> Self {
+        Self { center, radius }
+    }
+}
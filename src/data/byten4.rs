@@ -0,0 +1,74 @@
+use crate::macros::impl_element4;
+use super::float4::Float4;
+
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+
+
+
+/// A structure that stores four-dimensional signed normalized 8-bit integer data.
+/// 
+/// ※ Each element maps the range `[-1, 1]` to the full range of the integer. </br>
+/// ※ Use [`Vector::load_byten4`](crate::Vector::load_byten4) and [`Vector::store_byten4`](crate::Vector::store_byten4) to convert with the vector type. </br>
+/// 
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub struct ByteN4 {
+    pub x: i8,
+    pub y: i8,
+    pub z: i8,
+    pub w: i8,
+}
+
+impl ByteN4 {
+    /// All elements are zero.
+    pub const ZERO: Self = Self::fill(0);
+
+    /// All elements are one. (`i8::MAX`)
+    pub const ONE: Self = Self::fill(i8::MAX);
+
+    /// All elements are negative one. (`-i8::MAX`)
+    pub const NEG_ONE: Self = Self::fill(-i8::MAX);
+}
+
+impl_element4!(i8, ByteN4);
+
+impl ByteN4 {
+    /// Converts from a single-precision vector.
+    /// 
+    /// ※ Each element is saturated to the range `[-1, 1]` and rounded to the nearest integer. (NaN becomes zero) </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn from_float4(val: Float4) -> Self {
+        Self {
+            x: (val.x.clamp(-1.0, 1.0) * 127.0).round() as i8, 
+            y: (val.y.clamp(-1.0, 1.0) * 127.0).round() as i8, 
+            z: (val.z.clamp(-1.0, 1.0) * 127.0).round() as i8, 
+            w: (val.w.clamp(-1.0, 1.0) * 127.0).round() as i8 
+        }
+    }
+
+    /// Converts to a single-precision vector.
+    /// 
+    /// ※ Both `i8::MIN` and `-i8::MAX` become negative one. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn to_float4(self) -> Float4 {
+        Float4 {
+            x: (self.x as f32 / 127.0).max(-1.0), 
+            y: (self.y as f32 / 127.0).max(-1.0), 
+            z: (self.z as f32 / 127.0).max(-1.0), 
+            w: (self.w as f32 / 127.0).max(-1.0) 
+        }
+    }
+}
+
+impl From<ByteN4> for Float4 {
+    #[inline]
+    fn from(value: ByteN4) -> Self {
+        value.to_float4()
+    }
+}
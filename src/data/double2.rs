@@ -0,0 +1,294 @@
+use crate::macros::impl_element2;
+use crate::macros::impl_element2_op;
+use crate::near_eq;
+use super::bool2::Boolean2;
+use super::double3::Double3;
+use super::double4::Double4;
+use super::float2::Float2;
+
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+
+
+
+
+
+/// A structure that stores double-precision two-dimensional vector data.
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, PartialEq)]
+pub struct Double2 {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl Double2 {
+    /// All elements are zero.
+    pub const ZERO: Self = Self::fill(0.0);
+
+    /// All elements are one.
+    pub const ONE: Self = Self::fill(1.0);
+
+    /// All elements are negative one.
+    pub const NEG_ONE: Self = Self::fill(-1.0);
+    
+    /// positive unit vector on x-axis.
+    pub const X: Self = Self::new(1.0, 0.0);
+    
+    /// positive unit vector on y-axis.
+    pub const Y: Self = Self::new(0.0, 1.0);
+
+    /// negative unit vector on x-axis.
+    pub const NEG_X: Self = Self::new(-1.0, 0.0);
+
+    /// negative unit vector on y-axis.
+    pub const NEG_Y: Self = Self::new(0.0, -1.0);
+
+    /// All elements are [`f64::MIN`].
+    pub const MIN: Self = Self::fill(f64::MIN);
+
+    /// All elements are [`f64::MAX`].
+    pub const MAX: Self = Self::fill(f64::MAX);
+
+    /// All elements are [`f64::NAN`].
+    pub const NAN: Self = Self::fill(f64::NAN);
+
+    /// All elements are [`f64::INFINITY`].
+    pub const INFINITY: Self = Self::fill(f64::INFINITY);
+
+    /// All elements are [`f64::NEG_INFINITY`].
+    pub const NEG_INFINITY: Self = Self::fill(f64::NEG_INFINITY);
+
+    /// Returns `true` if at least one element of the vector is [`f64::NAN`].
+    #[inline]
+    pub fn is_nan(&self) -> bool {
+        Boolean2 {
+            x: self.x.is_nan(),
+            y: self.y.is_nan(),
+        }.any()
+    }
+
+    /// Returns `true` if at least one element of the vector is [`f64::INFINITY`].
+    #[inline]
+    pub fn is_infinite(&self) -> bool {
+        Boolean2 {
+            x: self.x.is_infinite(),
+            y: self.y.is_infinite(),
+        }.any()
+    }
+
+    /// Returns `true` if the absolute difference of all elements is less than or equal to `max_abs_diff`.
+    #[inline]
+    #[must_use]
+    pub fn abs_diff_eq(self, rhs: Self, max_abs_diff: f64) -> bool {
+        near_eq::abs_diff_eq(&self.to_array(), &rhs.to_array(), max_abs_diff)
+    }
+
+    /// Returns `true` if all elements are equal within `max_abs_diff` or the relative tolerance `max_relative`.
+    /// 
+    /// ※ The relative tolerance is scaled by the larger absolute value of the two elements. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn relative_eq(self, rhs: Self, max_abs_diff: f64, max_relative: f64) -> bool {
+        near_eq::relative_eq(&self.to_array(), &rhs.to_array(), max_abs_diff, max_relative)
+    }
+
+    /// Returns `true` if all elements are equal within `max_abs_diff` or `max_ulps` representable values.
+    /// 
+    /// ※ Elements with different signs are equal only within `max_abs_diff`. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn ulps_eq(self, rhs: Self, max_abs_diff: f64, max_ulps: u32) -> bool {
+        near_eq::ulps_eq(&self.to_array(), &rhs.to_array(), max_abs_diff, max_ulps)
+    }
+}
+
+// Vector swizzle code implementation.
+impl Double2 {
+    #[inline]
+    pub const fn xx(self) -> Double2 {
+        Double2 { x: self.x, y: self.x }
+    }
+
+    #[inline]
+    pub const fn xy(self) -> Double2 {
+        Double2 { x: self.x, y: self.y }
+    }
+
+    #[inline]
+    pub const fn yx(self) -> Double2 {
+        Double2 { x: self.y, y: self.x }
+    }
+
+    #[inline]
+    pub const fn yy(self) -> Double2 {
+        Double2 { x: self.y, y: self.y }
+    }
+
+    #[inline]
+    pub const fn xxx(self) -> Double3 {
+        Double3 { x: self.x, y: self.x, z: self.x }
+    }
+
+    #[inline]
+    pub const fn xxy(self) -> Double3 {
+        Double3 { x: self.x, y: self.x, z: self.y }
+    }
+
+    #[inline]
+    pub const fn xyx(self) -> Double3 {
+        Double3 { x: self.x, y: self.y, z: self.x }
+    }
+
+    #[inline]
+    pub const fn xyy(self) -> Double3 {
+        Double3 { x: self.x, y: self.y, z: self.y }
+    }
+
+    #[inline]
+    pub const fn yxx(self) -> Double3 {
+        Double3 { x: self.y, y: self.x, z: self.x }
+    }
+
+    #[inline]
+    pub const fn yxy(self) -> Double3 {
+        Double3 { x: self.y, y: self.x, z: self.y }
+    }
+
+    #[inline]
+    pub const fn yyx(self) -> Double3 {
+        Double3 { x: self.y, y: self.y, z: self.x }
+    }
+
+    #[inline]
+    pub const fn yyy(self) -> Double3 {
+        Double3 { x: self.y, y: self.y, z: self.y }
+    }
+
+    #[inline]
+    pub const fn xxxx(self) -> Double4 {
+        Double4 { x: self.x, y: self.x, z: self.x, w: self.x }
+    }
+
+    #[inline]
+    pub const fn xxxy(self) -> Double4 {
+        Double4 { x: self.x, y: self.x, z: self.x, w: self.y }
+    }
+
+    #[inline]
+    pub const fn xxyx(self) -> Double4 {
+        Double4 { x: self.x, y: self.x, z: self.y, w: self.x }
+    }
+
+    #[inline]
+    pub const fn xxyy(self) -> Double4 {
+        Double4 { x: self.x, y: self.x, z: self.y, w: self.y }
+    }
+
+    #[inline]
+    pub const fn xyxx(self) -> Double4 {
+        Double4 { x: self.x, y: self.y, z: self.x, w: self.x }
+    }
+
+    #[inline]
+    pub const fn xyxy(self) -> Double4 {
+        Double4 { x: self.x, y: self.y, z: self.x, w: self.y }
+    }
+
+    #[inline]
+    pub const fn xyyx(self) -> Double4 {
+        Double4 { x: self.x, y: self.y, z: self.y, w: self.x }
+    }
+
+    #[inline]
+    pub const fn xyyy(self) -> Double4 {
+        Double4 { x: self.x, y: self.y, z: self.y, w: self.y }
+    }
+
+    #[inline]
+    pub const fn yxxx(self) -> Double4 {
+        Double4 { x: self.y, y: self.x, z: self.x, w: self.x }
+    }
+
+    #[inline]
+    pub const fn yxxy(self) -> Double4 {
+        Double4 { x: self.y, y: self.x, z: self.x, w: self.y }
+    }
+
+    #[inline]
+    pub const fn yxyx(self) -> Double4 {
+        Double4 { x: self.y, y: self.x, z: self.y, w: self.x }
+    }
+
+    #[inline]
+    pub const fn yxyy(self) -> Double4 {
+        Double4 { x: self.y, y: self.x, z: self.y, w: self.y }
+    }
+
+    #[inline]
+    pub const fn yyxx(self) -> Double4 {
+        Double4 { x: self.y, y: self.y, z: self.x, w: self.x }
+    }
+
+    #[inline]
+    pub const fn yyxy(self) -> Double4 {
+        Double4 { x: self.y, y: self.y, z: self.x, w: self.y }
+    }
+
+    #[inline]
+    pub const fn yyyx(self) -> Double4 {
+        Double4 { x: self.y, y: self.y, z: self.y, w: self.x }
+    }
+
+    #[inline]
+    pub const fn yyyy(self) -> Double4 {
+        Double4 { x: self.y, y: self.y, z: self.y, w: self.y }
+    }
+}
+
+impl_element2!(f64, Double2, float);
+
+impl_element2_op!(f64, Double2);
+
+impl Double2 {
+    /// Converts to a single-precision vector.
+    #[inline]
+    #[must_use]
+    pub const fn as_f32(self) -> Float2 {
+        Float2 { x: self.x as f32, y: self.y as f32 }
+    }
+}
+
+impl Default for Double2 {
+    #[inline]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl From<Double3> for Double2 {
+    #[inline]
+    fn from(value: Double3) -> Self {
+        Double2 { x: value.x, y: value.y }
+    }
+}
+
+impl From<Double4> for Double2 {
+    #[inline]
+    fn from(value: Double4) -> Self {
+        Double2 { x: value.x, y: value.y }
+    }
+}
+
+impl core::ops::Neg for Double2 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self::Output {
+            x: -self.x, 
+            y: -self.y, 
+        }
+    }
+}
@@ -0,0 +1,742 @@
+use crate::macros::impl_element3;
+use crate::macros::impl_element3_op;
+use crate::near_eq;
+use super::bool3::Boolean3;
+use super::double2::Double2;
+use super::double4::Double4;
+use super::float3::Float3;
+
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+
+
+
+/// A structure that stores double-precision three-dimensional vector data.
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, PartialEq)]
+pub struct Double3 {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl Double3 {
+    /// All elements are zero.
+    pub const ZERO: Self = Self::fill(0.0);
+
+    /// All elements are one.
+    pub const ONE: Self = Self::fill(1.0);
+
+    /// All elements are negative one.
+    pub const NEG_ONE: Self = Self::fill(-1.0);
+
+    /// positive unit vector on x-axis.
+    pub const X: Self = Self::new(1.0, 0.0, 0.0);
+
+    /// positive unit vector on y-axis.
+    pub const Y: Self = Self::new(0.0, 1.0, 0.0);
+
+    /// positive unit vector on z-axis.
+    pub const Z: Self = Self::new(0.0, 0.0, 1.0);
+
+    /// negative unit vector on x-axis.
+    pub const NEG_X: Self = Self::new(-1.0, 0.0, 0.0);
+
+    /// negative unit vector on y-axis.
+    pub const NEG_Y: Self = Self::new(0.0, -1.0, 0.0);
+
+    /// negative unit vector on z-axis.
+    pub const NEG_Z: Self = Self::new(0.0, 0.0, -1.0);
+
+    /// All elements are [`f64::MIN`].
+    pub const MIN: Self = Self::fill(f64::MIN);
+
+    /// All elements are [`f64::MAX`].
+    pub const MAX: Self = Self::fill(f64::MAX);
+
+    /// All elements are [`f64::NAN`].
+    pub const NAN: Self = Self::fill(f64::NAN);
+
+    /// All elements are [`f64::INFINITY`].
+    pub const INFINITY: Self = Self::fill(f64::INFINITY);
+
+    /// All elements are [`f64::NEG_INFINITY`].
+    pub const NEG_INFINITY: Self = Self::fill(f64::NEG_INFINITY);
+
+    /// Returns `true` if at least one element of the vector is [`f64::NAN`].
+    #[inline]
+    pub fn is_nan(&self) -> bool {
+        Boolean3 {
+            x: self.x.is_nan(),
+            y: self.y.is_nan(),
+            z: self.z.is_nan(),
+        }.any()
+    }
+
+    /// Returns `true` if at least one element of the vector is [`f64::INFINITY`].
+    #[inline]
+    pub fn is_infinite(&self) -> bool {
+        Boolean3 {
+            x: self.x.is_infinite(),
+            y: self.y.is_infinite(),
+            z: self.z.is_infinite(),
+        }.any()
+    }
+
+    /// Returns `true` if the absolute difference of all elements is less than or equal to `max_abs_diff`.
+    #[inline]
+    #[must_use]
+    pub fn abs_diff_eq(self, rhs: Self, max_abs_diff: f64) -> bool {
+        near_eq::abs_diff_eq(&self.to_array(), &rhs.to_array(), max_abs_diff)
+    }
+
+    /// Returns `true` if all elements are equal within `max_abs_diff` or the relative tolerance `max_relative`.
+    /// 
+    /// ※ The relative tolerance is scaled by the larger absolute value of the two elements. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn relative_eq(self, rhs: Self, max_abs_diff: f64, max_relative: f64) -> bool {
+        near_eq::relative_eq(&self.to_array(), &rhs.to_array(), max_abs_diff, max_relative)
+    }
+
+    /// Returns `true` if all elements are equal within `max_abs_diff` or `max_ulps` representable values.
+    /// 
+    /// ※ Elements with different signs are equal only within `max_abs_diff`. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn ulps_eq(self, rhs: Self, max_abs_diff: f64, max_ulps: u32) -> bool {
+        near_eq::ulps_eq(&self.to_array(), &rhs.to_array(), max_abs_diff, max_ulps)
+    }
+}
+
+// Vector swizzle code implementation.
+impl Double3 {
+    #[inline]
+    pub const fn xx(self) -> Double2 {
+        Double2 { x: self.x, y: self.x }
+    }
+
+    #[inline]
+    pub const fn xy(self) -> Double2 {
+        Double2 { x: self.x, y: self.y }
+    }
+
+    #[inline]
+    pub const fn xz(self) -> Double2 {
+        Double2 { x: self.x, y: self.z }
+    }
+
+    #[inline]
+    pub const fn yx(self) -> Double2 {
+        Double2 { x: self.y, y: self.x }
+    }
+
+    #[inline]
+    pub const fn yy(self) -> Double2 {
+        Double2 { x: self.y, y: self.y }
+    }
+
+    #[inline]
+    pub const fn yz(self) -> Double2 {
+        Double2 { x: self.y, y: self.z }
+    }
+
+    #[inline]
+    pub const fn zx(self) -> Double2 {
+        Double2 { x: self.z, y: self.x }
+    }
+
+    #[inline]
+    pub const fn zy(self) -> Double2 {
+        Double2 { x: self.z, y: self.y }
+    }
+
+    #[inline]
+    pub const fn zz(self) -> Double2 {
+        Double2 { x: self.z, y: self.z }
+    }
+
+    #[inline]
+    pub const fn xxx(self) -> Double3 {
+        Double3 { x: self.x, y: self.x, z: self.x }
+    }
+
+    #[inline]
+    pub const fn xxy(self) -> Double3 {
+        Double3 { x: self.x, y: self.x, z: self.y }
+    }
+
+    #[inline]
+    pub const fn xxz(self) -> Double3 {
+        Double3 { x: self.x, y: self.x, z: self.z }
+    }
+
+    #[inline]
+    pub const fn xyx(self) -> Double3 {
+        Double3 { x: self.x, y: self.y, z: self.x }
+    }
+
+    #[inline]
+    pub const fn xyy(self) -> Double3 {
+        Double3 { x: self.x, y: self.y, z: self.y }
+    }
+
+    #[inline]
+    pub const fn xyz(self) -> Double3 {
+        Double3 { x: self.x, y: self.y, z: self.z }
+    }
+
+    #[inline]
+    pub const fn xzx(self) -> Double3 {
+        Double3 { x: self.x, y: self.z, z: self.x }
+    }
+
+    #[inline]
+    pub const fn xzy(self) -> Double3 {
+        Double3 { x: self.x, y: self.z, z: self.y }
+    }
+
+    #[inline]
+    pub const fn xzz(self) -> Double3 {
+        Double3 { x: self.x, y: self.z, z: self.z }
+    }
+
+    #[inline]
+    pub const fn yxx(self) -> Double3 {
+        Double3 { x: self.y, y: self.x, z: self.x }
+    }
+
+    #[inline]
+    pub const fn yxy(self) -> Double3 {
+        Double3 { x: self.y, y: self.x, z: self.y }
+    }
+
+    #[inline]
+    pub const fn yxz(self) -> Double3 {
+        Double3 { x: self.y, y: self.x, z: self.z }
+    }
+
+    #[inline]
+    pub const fn yyx(self) -> Double3 {
+        Double3 { x: self.y, y: self.y, z: self.x }
+    }
+
+    #[inline]
+    pub const fn yyy(self) -> Double3 {
+        Double3 { x: self.y, y: self.y, z: self.y }
+    }
+
+    #[inline]
+    pub const fn yyz(self) -> Double3 {
+        Double3 { x: self.y, y: self.y, z: self.z }
+    }
+
+    #[inline]
+    pub const fn yzx(self) -> Double3 {
+        Double3 { x: self.y, y: self.z, z: self.x }
+    }
+
+    #[inline]
+    pub const fn yzy(self) -> Double3 {
+        Double3 { x: self.y, y: self.z, z: self.y }
+    }
+
+    #[inline]
+    pub const fn yzz(self) -> Double3 {
+        Double3 { x: self.y, y: self.z, z: self.z }
+    }
+
+    #[inline]
+    pub const fn zxx(self) -> Double3 {
+        Double3 { x: self.z, y: self.x, z: self.x }
+    }
+
+    #[inline]
+    pub const fn zxy(self) -> Double3 {
+        Double3 { x: self.z, y: self.x, z: self.y }
+    }
+
+    #[inline]
+    pub const fn zxz(self) -> Double3 {
+        Double3 { x: self.z, y: self.x, z: self.z }
+    }
+
+    #[inline]
+    pub const fn zyx(self) -> Double3 {
+        Double3 { x: self.z, y: self.y, z: self.x }
+    }
+
+    #[inline]
+    pub const fn zyy(self) -> Double3 {
+        Double3 { x: self.z, y: self.y, z: self.y }
+    }
+
+    #[inline]
+    pub const fn zyz(self) -> Double3 {
+        Double3 { x: self.z, y: self.y, z: self.z }
+    }
+
+    #[inline]
+    pub const fn zzx(self) -> Double3 {
+        Double3 { x: self.z, y: self.z, z: self.x }
+    }
+
+    #[inline]
+    pub const fn zzy(self) -> Double3 {
+        Double3 { x: self.z, y: self.z, z: self.y }
+    }
+
+    #[inline]
+    pub const fn zzz(self) -> Double3 {
+        Double3 { x: self.z, y: self.z, z: self.z }
+    }
+
+    #[inline]
+    pub const fn xxxx(self) -> Double4 {
+        Double4 { x: self.x, y: self.x, z: self.x, w: self.x }
+    }
+
+    #[inline]
+    pub const fn xxxy(self) -> Double4 {
+        Double4 { x: self.x, y: self.x, z: self.x, w: self.y }
+    }
+
+    #[inline]
+    pub const fn xxxz(self) -> Double4 {
+        Double4 { x: self.x, y: self.x, z: self.x, w: self.z }
+    }
+
+    #[inline]
+    pub const fn xxyx(self) -> Double4 {
+        Double4 { x: self.x, y: self.x, z: self.y, w: self.x }
+    }
+
+    #[inline]
+    pub const fn xxyy(self) -> Double4 {
+        Double4 { x: self.x, y: self.x, z: self.y, w: self.y }
+    }
+
+    #[inline]
+    pub const fn xxyz(self) -> Double4 {
+        Double4 { x: self.x, y: self.x, z: self.y, w: self.z }
+    }
+
+    #[inline]
+    pub const fn xxzx(self) -> Double4 {
+        Double4 { x: self.x, y: self.x, z: self.z, w: self.x }
+    }
+
+    #[inline]
+    pub const fn xxzy(self) -> Double4 {
+        Double4 { x: self.x, y: self.x, z: self.z, w: self.y }
+    }
+
+    #[inline]
+    pub const fn xxzz(self) -> Double4 {
+        Double4 { x: self.x, y: self.x, z: self.z, w: self.z }
+    }
+    
+    #[inline]
+    pub const fn xyxx(self) -> Double4 {
+        Double4 { x: self.x, y: self.y, z: self.x, w: self.x }
+    }
+
+    #[inline]
+    pub const fn xyxy(self) -> Double4 {
+        Double4 { x: self.x, y: self.y, z: self.x, w: self.y }
+    }
+
+    #[inline]
+    pub const fn xyxz(self) -> Double4 {
+        Double4 { x: self.x, y: self.y, z: self.x, w: self.z }
+    }
+
+    #[inline]
+    pub const fn xyyx(self) -> Double4 {
+        Double4 { x: self.x, y: self.y, z: self.y, w: self.x }
+    }
+
+    #[inline]
+    pub const fn xyyy(self) -> Double4 {
+        Double4 { x: self.x, y: self.y, z: self.y, w: self.y }
+    }
+
+    #[inline]
+    pub const fn xyyz(self) -> Double4 {
+        Double4 { x: self.x, y: self.y, z: self.y, w: self.z }
+    }
+
+    #[inline]
+    pub const fn xyzx(self) -> Double4 {
+        Double4 { x: self.x, y: self.y, z: self.z, w: self.x }
+    }
+
+    #[inline]
+    pub const fn xyzy(self) -> Double4 {
+        Double4 { x: self.x, y: self.y, z: self.z, w: self.y }
+    }
+
+    #[inline]
+    pub const fn xyzz(self) -> Double4 {
+        Double4 { x: self.x, y: self.y, z: self.z, w: self.z }
+    }
+
+    #[inline]
+    pub const fn xzxx(self) -> Double4 {
+        Double4 { x: self.x, y: self.z, z: self.x, w: self.x }
+    }
+
+    #[inline]
+    pub const fn xzxy(self) -> Double4 {
+        Double4 { x: self.x, y: self.z, z: self.x, w: self.y }
+    }
+
+    #[inline]
+    pub const fn xzxz(self) -> Double4 {
+        Double4 { x: self.x, y: self.z, z: self.x, w: self.z }
+    }
+
+    #[inline]
+    pub const fn xzyx(self) -> Double4 {
+        Double4 { x: self.x, y: self.z, z: self.y, w: self.x }
+    }
+
+    #[inline]
+    pub const fn xzyy(self) -> Double4 {
+        Double4 { x: self.x, y: self.z, z: self.y, w: self.y }
+    }
+
+    #[inline]
+    pub const fn xzyz(self) -> Double4 {
+        Double4 { x: self.x, y: self.z, z: self.y, w: self.z }
+    }
+
+    #[inline]
+    pub const fn xzzx(self) -> Double4 {
+        Double4 { x: self.x, y: self.z, z: self.z, w: self.x }
+    }
+
+    #[inline]
+    pub const fn xzzy(self) -> Double4 {
+        Double4 { x: self.x, y: self.z, z: self.z, w: self.y }
+    }
+
+    #[inline]
+    pub const fn xzzz(self) -> Double4 {
+        Double4 { x: self.x, y: self.z, z: self.z, w: self.z }
+    }
+
+    #[inline]
+    pub const fn yxxx(self) -> Double4 {
+        Double4 { x: self.y, y: self.x, z: self.x, w: self.x }
+    }
+
+    #[inline]
+    pub const fn yxxy(self) -> Double4 {
+        Double4 { x: self.y, y: self.x, z: self.x, w: self.y }
+    }
+
+    #[inline]
+    pub const fn yxxz(self) -> Double4 {
+        Double4 { x: self.y, y: self.x, z: self.x, w: self.z }
+    }
+
+    #[inline]
+    pub const fn yxyx(self) -> Double4 {
+        Double4 { x: self.y, y: self.x, z: self.y, w: self.x }
+    }
+
+    #[inline]
+    pub const fn yxyy(self) -> Double4 {
+        Double4 { x: self.y, y: self.x, z: self.y, w: self.y }
+    }
+
+    #[inline]
+    pub const fn yxzx(self) -> Double4 {
+        Double4 { x: self.y, y: self.x, z: self.z, w: self.x }
+    }
+
+    #[inline]
+    pub const fn yxzy(self) -> Double4 {
+        Double4 { x: self.y, y: self.x, z: self.z, w: self.y }
+    }
+
+    #[inline]
+    pub const fn yxzz(self) -> Double4 {
+        Double4 { x: self.y, y: self.x, z: self.z, w: self.z }
+    }
+
+    #[inline]
+    pub const fn yyxx(self) -> Double4 {
+        Double4 { x: self.y, y: self.y, z: self.x, w: self.x }
+    }
+
+    #[inline]
+    pub const fn yyxy(self) -> Double4 {
+        Double4 { x: self.y, y: self.y, z: self.x, w: self.y }
+    }
+
+    #[inline]
+    pub const fn yyxz(self) -> Double4 {
+        Double4 { x: self.y, y: self.y, z: self.x, w: self.z }
+    }
+
+    #[inline]
+    pub const fn yyyx(self) -> Double4 {
+        Double4 { x: self.y, y: self.y, z: self.y, w: self.x }
+    }
+
+    #[inline]
+    pub const fn yyyy(self) -> Double4 {
+        Double4 { x: self.y, y: self.y, z: self.y, w: self.y }
+    }
+
+    #[inline]
+    pub const fn yyyz(self) -> Double4 {
+        Double4 { x: self.y, y: self.y, z: self.y, w: self.z }
+    }
+
+    #[inline]
+    pub const fn yyzx(self) -> Double4 {
+        Double4 { x: self.y, y: self.y, z: self.z, w: self.x }
+    }
+
+    #[inline]
+    pub const fn yyzy(self) -> Double4 {
+        Double4 { x: self.y, y: self.y, z: self.z, w: self.y }
+    }
+
+    #[inline]
+    pub const fn yyzz(self) -> Double4 {
+        Double4 { x: self.y, y: self.y, z: self.z, w: self.z }
+    }
+
+    #[inline]
+    pub const fn yzxx(self) -> Double4 {
+        Double4 { x: self.y, y: self.z, z: self.x, w: self.x }
+    }
+
+    #[inline]
+    pub const fn yzxy(self) -> Double4 {
+        Double4 { x: self.y, y: self.z, z: self.x, w: self.y }
+    }
+
+    #[inline]
+    pub const fn yzxz(self) -> Double4 {
+        Double4 { x: self.y, y: self.z, z: self.x, w: self.z }
+    }
+
+    #[inline]
+    pub const fn yzyx(self) -> Double4 {
+        Double4 { x: self.y, y: self.z, z: self.y, w: self.x }
+    }
+
+    #[inline]
+    pub const fn yzyy(self) -> Double4 {
+        Double4 { x: self.y, y: self.z, z: self.y, w: self.y }
+    }
+
+    #[inline]
+    pub const fn yzyz(self) -> Double4 {
+        Double4 { x: self.y, y: self.z, z: self.y, w: self.z }
+    }
+
+    #[inline]
+    pub const fn yzzx(self) -> Double4 {
+        Double4 { x: self.y, y: self.z, z: self.z, w: self.x }
+    }
+
+    #[inline]
+    pub const fn yzzy(self) -> Double4 {
+        Double4 { x: self.y, y: self.z, z: self.z, w: self.y }
+    }
+
+    #[inline]
+    pub const fn yzzz(self) -> Double4 {
+        Double4 { x: self.y, y: self.z, z: self.z, w: self.z }
+    }
+
+    #[inline]
+    pub const fn zxxx(self) -> Double4 {
+        Double4 { x: self.z, y: self.x, z: self.x, w: self.x }
+    }
+
+    #[inline]
+    pub const fn zxxy(self) -> Double4 {
+        Double4 { x: self.z, y: self.x, z: self.x, w: self.y }
+    }
+
+    #[inline]
+    pub const fn zxxz(self) -> Double4 {
+        Double4 { x: self.z, y: self.x, z: self.x, w: self.z }
+    }
+
+    #[inline]
+    pub const fn zxyx(self) -> Double4 {
+        Double4 { x: self.z, y: self.x, z: self.y, w: self.x }
+    }
+
+    #[inline]
+    pub const fn zxyy(self) -> Double4 {
+        Double4 { x: self.z, y: self.x, z: self.y, w: self.y }
+    }
+
+    #[inline]
+    pub const fn zxyz(self) -> Double4 {
+        Double4 { x: self.z, y: self.x, z: self.y, w: self.z }
+    }
+
+    #[inline]
+    pub const fn zxzx(self) -> Double4 {
+        Double4 { x: self.z, y: self.x, z: self.z, w: self.x }
+    }
+
+    #[inline]
+    pub const fn zxzy(self) -> Double4 {
+        Double4 { x: self.z, y: self.x, z: self.z, w: self.y }
+    }
+
+    #[inline]
+    pub const fn zxzz(self) -> Double4 {
+        Double4 { x: self.z, y: self.x, z: self.z, w: self.z }
+    }
+
+    #[inline]
+    pub const fn zyxx(self) -> Double4 {
+        Double4 { x: self.z, y: self.y, z: self.x, w: self.x }
+    }
+
+    #[inline]
+    pub const fn zyxy(self) -> Double4 {
+        Double4 { x: self.z, y: self.y, z: self.x, w: self.y }
+    }
+
+    #[inline]
+    pub const fn zyxz(self) -> Double4 {
+        Double4 { x: self.z, y: self.y, z: self.x, w: self.z }
+    }
+
+    #[inline]
+    pub const fn zyyx(self) -> Double4 {
+        Double4 { x: self.z, y: self.y, z: self.y, w: self.x }
+    }
+
+    #[inline]
+    pub const fn zyyy(self) -> Double4 {
+        Double4 { x: self.z, y: self.y, z: self.y, w: self.y }
+    }
+
+    #[inline]
+    pub const fn zyyz(self) -> Double4 {
+        Double4 { x: self.z, y: self.y, z: self.y, w: self.z }
+    }
+
+    #[inline]
+    pub const fn zyzx(self) -> Double4 {
+        Double4 { x: self.z, y: self.y, z: self.z, w: self.x }
+    }
+
+    #[inline]
+    pub const fn zyzy(self) -> Double4 {
+        Double4 { x: self.z, y: self.y, z: self.z, w: self.y }
+    }
+
+    #[inline]
+    pub const fn zyzz(self) -> Double4 {
+        Double4 { x: self.z, y: self.y, z: self.z, w: self.z }
+    }
+
+    #[inline]
+    pub const fn zzxx(self) -> Double4 {
+        Double4 { x: self.z, y: self.z, z: self.x, w: self.x }
+    }
+
+    #[inline]
+    pub const fn zzxy(self) -> Double4 {
+        Double4 { x: self.z, y: self.z, z: self.x, w: self.y }
+    }
+
+    #[inline]
+    pub const fn zzxz(self) -> Double4 {
+        Double4 { x: self.z, y: self.z, z: self.x, w: self.z }
+    }
+
+    #[inline]
+    pub const fn zzyx(self) -> Double4 {
+        Double4 { x: self.z, y: self.z, z: self.y, w: self.x }
+    }
+
+    #[inline]
+    pub const fn zzyy(self) -> Double4 {
+        Double4 { x: self.z, y: self.z, z: self.y, w: self.y }
+    }
+
+    #[inline]
+    pub const fn zzyz(self) -> Double4 {
+        Double4 { x: self.z, y: self.z, z: self.y, w: self.z }
+    }
+
+    #[inline]
+    pub const fn zzzx(self) -> Double4 {
+        Double4 { x: self.z, y: self.z, z: self.z, w: self.x }
+    }
+
+    #[inline]
+    pub const fn zzzy(self) -> Double4 {
+        Double4 { x: self.z, y: self.z, z: self.z, w: self.y }
+    }
+    
+    #[inline]
+    pub const fn zzzz(self) -> Double4 {
+        Double4 { x: self.z, y: self.z, z: self.z, w: self.z }
+    }
+}
+
+impl_element3!(f64, Double3, float);
+
+impl_element3_op!(f64, Double3);
+
+impl Double3 {
+    /// Converts to a single-precision vector.
+    #[inline]
+    #[must_use]
+    pub const fn as_f32(self) -> Float3 {
+        Float3 { x: self.x as f32, y: self.y as f32, z: self.z as f32 }
+    }
+}
+
+impl Default for Double3 {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl From<Double2> for Double3 {
+    #[inline]
+    fn from(value: Double2) -> Self {
+        Double3 { x: value.x, y: value.y, z: 0.0 }
+    }
+}
+
+impl From<Double4> for Double3 {
+    #[inline]
+    fn from(value: Double4) -> Self {
+        Double3 { x: value.x, y: value.y, z: value.z }
+    }
+}
+
+impl core::ops::Neg for Double3 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self::Output {
+            x: -self.x, 
+            y: -self.y, 
+            z: -self.z, 
+        }
+    }
+}
@@ -0,0 +1,1853 @@
+use crate::macros::impl_element4;
+use crate::macros::impl_element4_op;
+use crate::near_eq;
+use super::bool4::Boolean4;
+use super::double2::Double2;
+use super::double3::Double3;
+use super::float4::Float4;
+
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+
+
+
+/// A structure that stores double-precision four-dimensional vector data.
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, PartialEq)]
+pub struct Double4 {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub w: f64,
+}
+
+impl Double4 {
+    /// All elements are zero.
+    pub const ZERO: Self = Self::fill(0.0);
+
+    /// All elements are one.
+    pub const ONE: Self = Self::fill(1.0);
+
+    /// All elements are negative one.
+    pub const NEG_ONE: Self = Self::fill(-1.0);
+
+    /// positive unit vector on x-axis.
+    pub const X: Self = Self::new(1.0, 0.0, 0.0, 0.0);
+
+    /// positive unit vector on y-axis.
+    pub const Y: Self = Self::new(0.0, 1.0, 0.0, 0.0);
+
+    /// positive unit vector on z-axis.
+    pub const Z: Self = Self::new(0.0, 0.0, 1.0, 0.0);
+
+    /// positive unit vector on w-axis.
+    pub const W: Self = Self::new(0.0, 0.0, 0.0, 1.0);
+
+    /// negative unit vector on x-axis.
+    pub const NEG_X: Self = Self::new(-1.0, 0.0, 0.0, 0.0);
+
+    /// negative unit vector on y-axis.
+    pub const NEG_Y: Self = Self::new(0.0, -1.0, 0.0, 0.0);
+
+    /// negative unit vector on z-axis.
+    pub const NEG_Z: Self = Self::new(0.0, 0.0, -1.0, 0.0);
+
+    /// negative unit vector on w-axis.
+    pub const NEG_W: Self = Self::new(0.0, 0.0, 0.0, -1.0);
+
+    /// All elements are [`f64::MIN`].
+    pub const MIN: Self = Self::fill(f64::MIN);
+
+    /// All elements are [`f64::MAX`].
+    pub const MAX: Self = Self::fill(f64::MAX);
+
+    /// All elements are [`f64::NAN`].
+    pub const NAN: Self = Self::fill(f64::NAN);
+
+    /// All elements are [`f64::INFINITY`].
+    pub const INFINITY: Self = Self::fill(f64::INFINITY);
+
+    /// All elements are [`f64::NEG_INFINITY`].
+    pub const NEG_INFINITY: Self = Self::fill(f64::NEG_INFINITY);
+
+    /// Returns `true` if at least one element of the vector is [`f64::NAN`].
+    #[inline]
+    pub fn is_nan(&self) -> bool {
+        Boolean4 {
+            x: self.x.is_nan(),
+            y: self.y.is_nan(),
+            z: self.z.is_nan(),
+            w: self.w.is_nan(),
+        }.any()
+    }
+
+    /// Returns `true` if at least one element of the vector is [`f64::INFINITY`].
+    #[inline]
+    pub fn is_infinite(&self) -> bool {
+        Boolean4 {
+            x: self.x.is_infinite(),
+            y: self.y.is_infinite(),
+            z: self.z.is_infinite(),
+            w: self.w.is_infinite(),
+        }.any()
+    }
+
+    /// Returns `true` if the absolute difference of all elements is less than or equal to `max_abs_diff`.
+    #[inline]
+    #[must_use]
+    pub fn abs_diff_eq(self, rhs: Self, max_abs_diff: f64) -> bool {
+        near_eq::abs_diff_eq(&self.to_array(), &rhs.to_array(), max_abs_diff)
+    }
+
+    /// Returns `true` if all elements are equal within `max_abs_diff` or the relative tolerance `max_relative`.
+    /// 
+    /// ※ The relative tolerance is scaled by the larger absolute value of the two elements. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn relative_eq(self, rhs: Self, max_abs_diff: f64, max_relative: f64) -> bool {
+        near_eq::relative_eq(&self.to_array(), &rhs.to_array(), max_abs_diff, max_relative)
+    }
+
+    /// Returns `true` if all elements are equal within `max_abs_diff` or `max_ulps` representable values.
+    /// 
+    /// ※ Elements with different signs are equal only within `max_abs_diff`. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn ulps_eq(self, rhs: Self, max_abs_diff: f64, max_ulps: u32) -> bool {
+        near_eq::ulps_eq(&self.to_array(), &rhs.to_array(), max_abs_diff, max_ulps)
+    }
+}
+
+// Vector swizzle code implementation.
+impl Double4 {
+    #[inline]
+    pub const fn xx(self) -> Double2 {
+        Double2 { x: self.x, y: self.x }
+    }
+
+    #[inline]
+    pub const fn xy(self) -> Double2 {
+        Double2 { x: self.x, y: self.y }
+    }
+
+    #[inline]
+    pub const fn xz(self) -> Double2 {
+        Double2 { x: self.x, y: self.z }
+    }
+
+    #[inline]
+    pub const fn xw(self) -> Double2 {
+        Double2 { x: self.x, y: self.w }
+    }
+
+    #[inline]
+    pub const fn yx(self) -> Double2 {
+        Double2 { x: self.y, y: self.x }
+    }
+
+    #[inline]
+    pub const fn yy(self) -> Double2 {
+        Double2 { x: self.y, y: self.y }
+    }
+
+    #[inline]
+    pub const fn yz(self) -> Double2 {
+        Double2 { x: self.y, y: self.z }
+    }
+
+    #[inline]
+    pub const fn yw(self) -> Double2 {
+        Double2 { x: self.y, y: self.w }
+    }
+
+    #[inline]
+    pub const fn zx(self) -> Double2 {
+        Double2 { x: self.z, y: self.x }
+    }
+
+    #[inline]
+    pub const fn zy(self) -> Double2 {
+        Double2 { x: self.z, y: self.x }
+    }
+
+    #[inline]
+    pub const fn zz(self) -> Double2 {
+        Double2 { x: self.z, y: self.z }
+    }
+
+    #[inline]
+    pub const fn zw(self) -> Double2 {
+        Double2 { x: self.z, y: self.w }
+    }
+
+    #[inline]
+    pub const fn wx(self) -> Double2 {
+        Double2 { x: self.w, y: self.x }
+    }
+
+    #[inline]
+    pub const fn wy(self) -> Double2 {
+        Double2 { x: self.w, y: self.y }
+    }
+
+    #[inline]
+    pub const fn wz(self) -> Double2 {
+        Double2 { x: self.w, y: self.z }
+    }
+
+    #[inline]
+    pub const fn ww(self) -> Double2 {
+        Double2 { x: self.w, y: self.w }
+    }
+
+    #[inline]
+    pub const fn xxx(self) -> Double3 {
+        Double3 { x: self.x, y: self.x, z: self.x }
+    }
+
+    #[inline]
+    pub const fn xxy(self) -> Double3 {
+        Double3 { x: self.x, y: self.x, z: self.y }
+    }
+
+    #[inline]
+    pub const fn xxz(self) -> Double3 {
+        Double3 { x: self.x, y: self.x, z: self.z }
+    }
+
+    #[inline]
+    pub const fn xxw(self) -> Double3 {
+        Double3 { x: self.x, y: self.x, z: self.w }
+    }
+
+    #[inline]
+    pub const fn xyx(self) -> Double3 {
+        Double3 { x: self.x, y: self.y, z: self.x }
+    }
+
+    #[inline]
+    pub const fn xyy(self) -> Double3 {
+        Double3 { x: self.x, y: self.y, z: self.y }
+    }
+
+    #[inline]
+    pub const fn xyz(self) -> Double3 {
+        Double3 { x: self.x, y: self.y, z: self.z }
+    }
+
+    #[inline]
+    pub const fn xyw(self) -> Double3 {
+        Double3 { x: self.x, y: self.y, z: self.w }
+    }
+
+    #[inline]
+    pub const fn xzx(self) -> Double3 {
+        Double3 { x: self.x, y: self.z, z: self.x }
+    }
+
+    #[inline]
+    pub const fn xzy(self) -> Double3 {
+        Double3 { x: self.x, y: self.z, z: self.y }
+    }
+
+    #[inline]
+    pub const fn xzz(self) -> Double3 {
+        Double3 { x: self.x, y: self.z, z: self.z }
+    }
+
+    #[inline]
+    pub const fn xzw(self) -> Double3 {
+        Double3 { x: self.x, y: self.z, z: self.w }
+    }
+
+    #[inline]
+    pub const fn xwx(self) -> Double3 {
+        Double3 { x: self.x, y: self.w, z: self.x }
+    }
+
+    #[inline]
+    pub const fn xwy(self) -> Double3 {
+        Double3 { x: self.x, y: self.w, z: self.y }
+    }
+
+    #[inline]
+    pub const fn xwz(self) -> Double3 {
+        Double3 { x: self.x, y: self.w, z: self.z }
+    }
+
+    #[inline]
+    pub const fn xww(self) -> Double3 {
+        Double3 { x: self.x, y: self.w, z: self.w }
+    }
+
+    #[inline]
+    pub const fn yxx(self) -> Double3 {
+        Double3 { x: self.y, y: self.x, z: self.x }
+    }
+
+    #[inline]
+    pub const fn yxy(self) -> Double3 {
+        Double3 { x: self.y, y: self.x, z: self.y }
+    }
+
+    #[inline]
+    pub const fn yxz(self) -> Double3 {
+        Double3 { x: self.y, y: self.x, z: self.z }
+    }
+
+    #[inline]
+    pub const fn yxw(self) -> Double3 {
+        Double3 { x: self.y, y: self.x, z: self.w }
+    }
+
+    #[inline]
+    pub const fn yyx(self) -> Double3 {
+        Double3 { x: self.y, y: self.y, z: self.x }
+    }
+
+    #[inline]
+    pub const fn yyy(self) -> Double3 {
+        Double3 { x: self.y, y: self.y, z: self.y }
+    }
+
+    #[inline]
+    pub const fn yyz(self) -> Double3 {
+        Double3 { x: self.y, y: self.y, z: self.z }
+    }
+
+    #[inline]
+    pub const fn yyw(self) -> Double3 {
+        Double3 { x: self.y, y: self.y, z: self.w }
+    }
+
+    #[inline]
+    pub const fn yzx(self) -> Double3 {
+        Double3 { x: self.y, y: self.z, z: self.x }
+    }
+
+    #[inline]
+    pub const fn yzy(self) -> Double3 {
+        Double3 { x: self.y, y: self.z, z: self.y }
+    }
+
+    #[inline]
+    pub const fn yzz(self) -> Double3 {
+        Double3 { x: self.y, y: self.z, z: self.z }
+    }
+
+    #[inline]
+    pub const fn yzw(self) -> Double3 {
+        Double3 { x: self.y, y: self.z, z: self.w }
+    }
+
+    #[inline]
+    pub const fn ywx(self) -> Double3 {
+        Double3 { x: self.y, y: self.w, z: self.x }
+    }
+
+    #[inline]
+    pub const fn ywy(self) -> Double3 {
+        Double3 { x: self.y, y: self.w, z: self.y }
+    }
+
+    #[inline]
+    pub const fn ywz(self) -> Double3 {
+        Double3 { x: self.y, y: self.w, z: self.z }
+    }
+
+    #[inline]
+    pub const fn yww(self) -> Double3 {
+        Double3 { x: self.y, y: self.w, z: self.w }
+    }
+
+    #[inline]
+    pub const fn zxx(self) -> Double3 {
+        Double3 { x: self.z, y: self.x, z: self.x }
+    }
+
+    #[inline]
+    pub const fn zxy(self) -> Double3 {
+        Double3 { x: self.z, y: self.x, z: self.y }
+    }
+
+    #[inline]
+    pub const fn zxz(self) -> Double3 {
+        Double3 { x: self.z, y: self.x, z: self.z }
+    }
+
+    #[inline]
+    pub const fn zxw(self) -> Double3 {
+        Double3 { x: self.z, y: self.x, z: self.w }
+    }
+
+    #[inline]
+    pub const fn zyx(self) -> Double3 {
+        Double3 { x: self.z, y: self.y, z: self.x }
+    }
+
+    #[inline]
+    pub const fn zyy(self) -> Double3 {
+        Double3 { x: self.z, y: self.y, z: self.y }
+    }
+
+    #[inline]
+    pub const fn zyz(self) -> Double3 {
+        Double3 { x: self.z, y: self.y, z: self.z }
+    }
+
+    #[inline]
+    pub const fn zyw(self) -> Double3 {
+        Double3 { x: self.z, y: self.y, z: self.w }
+    }
+
+    #[inline]
+    pub const fn zzx(self) -> Double3 {
+        Double3 { x: self.z, y: self.z, z: self.x }
+    }
+
+    #[inline]
+    pub const fn zzy(self) -> Double3 {
+        Double3 { x: self.z, y: self.z, z: self.y }
+    }
+
+    #[inline]
+    pub const fn zzz(self) -> Double3 {
+        Double3 { x: self.z, y: self.z, z: self.z }
+    }
+
+    #[inline]
+    pub const fn zzw(self) -> Double3 {
+        Double3 { x: self.z, y: self.z, z: self.w }
+    }
+
+    #[inline]
+    pub const fn zwx(self) -> Double3 {
+        Double3 { x: self.z, y: self.w, z: self.x }
+    }
+
+    #[inline]
+    pub const fn zwy(self) -> Double3 {
+        Double3 { x: self.z, y: self.w, z: self.y }
+    }
+
+    #[inline]
+    pub const fn zwz(self) -> Double3 {
+        Double3 { x: self.z, y: self.w, z: self.z }
+    }
+
+    #[inline]
+    pub const fn zww(self) -> Double3 {
+        Double3 { x: self.z, y: self.w, z: self.w }
+    }
+
+    #[inline]
+    pub const fn wxx(self) -> Double3 {
+        Double3 { x: self.w, y: self.x, z: self.x }
+    }
+
+    #[inline]
+    pub const fn wxy(self) -> Double3 {
+        Double3 { x: self.w, y: self.x, z: self.y }
+    }
+
+    #[inline]
+    pub const fn wxz(self) -> Double3 {
+        Double3 { x: self.w, y: self.x, z: self.z }
+    }
+
+    #[inline]
+    pub const fn wxw(self) -> Double3 {
+        Double3 { x: self.w, y: self.x, z: self.w }
+    }
+
+    #[inline]
+    pub const fn wyx(self) -> Double3 {
+        Double3 { x: self.w, y: self.y, z: self.x }
+    }
+
+    #[inline]
+    pub const fn wyy(self) -> Double3 {
+        Double3 { x: self.w, y: self.y, z: self.y }
+    }
+
+    #[inline]
+    pub const fn wyz(self) -> Double3 {
+        Double3 { x: self.w, y: self.y, z: self.z }
+    }
+
+    #[inline]
+    pub const fn wyw(self) -> Double3 {
+        Double3 { x: self.w, y: self.y, z: self.w }
+    }
+
+    #[inline]
+    pub const fn wzx(self) -> Double3 {
+        Double3 { x: self.w, y: self.z, z: self.x }
+    }
+
+    #[inline]
+    pub const fn wzy(self) -> Double3 {
+        Double3 { x: self.w, y: self.z, z: self.y }
+    }
+
+    #[inline]
+    pub const fn wzz(self) -> Double3 {
+        Double3 { x: self.w, y: self.z, z: self.z }
+    }
+
+    #[inline]
+    pub const fn wzw(self) -> Double3 {
+        Double3 { x: self.w, y: self.z, z: self.w }
+    }
+
+    #[inline]
+    pub const fn wwx(self) -> Double3 {
+        Double3 { x: self.w, y: self.w, z: self.x }
+    }
+
+    #[inline]
+    pub const fn wwy(self) -> Double3 {
+        Double3 { x: self.w, y: self.w, z: self.y }
+    }
+
+    #[inline]
+    pub const fn wwz(self) -> Double3 {
+        Double3 { x: self.w, y: self.w, z: self.z }
+    }
+
+    #[inline]
+    pub const fn www(self) -> Double3 {
+        Double3 { x: self.w, y: self.w, z: self.w }
+    }
+
+    #[inline]
+    pub const fn xxxx(self) -> Double4 {
+        Double4 { x: self.x, y: self.x, z: self.x, w: self.x }
+    }
+
+    #[inline]
+    pub const fn xxxy(self) -> Double4 {
+        Double4 { x: self.x, y: self.x, z: self.x, w: self.y }
+    }
+
+    #[inline]
+    pub const fn xxxz(self) -> Double4 {
+        Double4 { x: self.x, y: self.x, z: self.x, w: self.z }
+    }
+
+    #[inline]
+    pub const fn xxxw(self) -> Double4 {
+        Double4 { x: self.x, y: self.x, z: self.x, w: self.w }
+    }
+
+    #[inline]
+    pub const fn xxyx(self) -> Double4 {
+        Double4 { x: self.x, y: self.x, z: self.y, w: self.x }
+    }
+
+    #[inline]
+    pub const fn xxyy(self) -> Double4 {
+        Double4 { x: self.x, y: self.x, z: self.y, w: self.y }
+    }
+
+    #[inline]
+    pub const fn xxyz(self) -> Double4 {
+        Double4 { x: self.x, y: self.x, z: self.y, w: self.z }
+    }
+
+    #[inline]
+    pub const fn xxyw(self) -> Double4 {
+        Double4 { x: self.x, y: self.x, z: self.y, w: self.w }
+    }
+
+    #[inline]
+    pub const fn xxzx(self) -> Double4 {
+        Double4 { x: self.x, y: self.x, z: self.z, w: self.x }
+    }
+
+    #[inline]
+    pub const fn xxzy(self) -> Double4 {
+        Double4 { x: self.x, y: self.x, z: self.z, w: self.y }
+    }
+
+    #[inline]
+    pub const fn xxzz(self) -> Double4 {
+        Double4 { x: self.x, y: self.x, z: self.z, w: self.z }
+    }
+
+    #[inline]
+    pub const fn xxzw(self) -> Double4 {
+        Double4 { x: self.x, y: self.x, z: self.z, w: self.w }
+    }
+
+    #[inline]
+    pub const fn xxwx(self) -> Double4 {
+        Double4 { x: self.x, y: self.x, z: self.w, w: self.x }
+    }
+
+    #[inline]
+    pub const fn xxwy(self) -> Double4 {
+        Double4 { x: self.x, y: self.x, z: self.w, w: self.y }
+    }
+
+    #[inline]
+    pub const fn xxwz(self) -> Double4 {
+        Double4 { x: self.x, y: self.x, z: self.w, w: self.z }
+    }
+
+    #[inline]
+    pub const fn xxww(self) -> Double4 {
+        Double4 { x: self.x, y: self.x, z: self.w, w: self.w }
+    }
+
+    #[inline]
+    pub const fn xyxx(self) -> Double4 {
+        Double4 { x: self.x, y: self.y, z: self.x, w: self.x }
+    }
+
+    #[inline]
+    pub const fn xyxy(self) -> Double4 {
+        Double4 { x: self.x, y: self.y, z: self.x, w: self.y }
+    }
+
+    #[inline]
+    pub const fn xyxz(self) -> Double4 {
+        Double4 { x: self.x, y: self.y, z: self.x, w: self.z }
+    }
+
+    #[inline]
+    pub const fn xyxw(self) -> Double4 {
+        Double4 { x: self.x, y: self.y, z: self.x, w: self.w }
+    }
+
+    #[inline]
+    pub const fn xyyx(self) -> Double4 {
+        Double4 { x: self.x, y: self.y, z: self.y, w: self.x }
+    }
+
+    #[inline]
+    pub const fn xyyy(self) -> Double4 {
+        Double4 { x: self.x, y: self.y, z: self.y, w: self.y }
+    }
+
+    #[inline]
+    pub const fn xyyz(self) -> Double4 {
+        Double4 { x: self.x, y: self.y, z: self.y, w: self.z }
+    }
+
+    #[inline]
+    pub const fn xyyw(self) -> Double4 {
+        Double4 { x: self.x, y: self.y, z: self.y, w: self.w }
+    }
+
+    #[inline]
+    pub const fn xyzx(self) -> Double4 {
+        Double4 { x: self.x, y: self.y, z: self.z, w: self.x }
+    }
+
+    #[inline]
+    pub const fn xyzy(self) -> Double4 {
+        Double4 { x: self.x, y: self.y, z: self.z, w: self.y }
+    }
+
+    #[inline]
+    pub const fn xyzz(self) -> Double4 {
+        Double4 { x: self.x, y: self.y, z: self.z, w: self.z }
+    }
+
+    #[inline]
+    pub const fn xyzw(self) -> Double4 {
+        Double4 { x: self.x, y: self.y, z: self.z, w: self.w }
+    }
+
+    #[inline]
+    pub const fn xywx(self) -> Double4 {
+        Double4 { x: self.x, y: self.y, z: self.w, w: self.x }
+    }
+
+    #[inline]
+    pub const fn xywy(self) -> Double4 {
+        Double4 { x: self.x, y: self.y, z: self.w, w: self.y }
+    }
+
+    #[inline]
+    pub const fn xywz(self) -> Double4 {
+        Double4 { x: self.x, y: self.y, z: self.w, w: self.z }
+    }
+
+    #[inline]
+    pub const fn xyww(self) -> Double4 {
+        Double4 { x: self.x, y: self.y, z: self.w, w: self.w }
+    }
+
+    #[inline]
+    pub const fn xzxx(self) -> Double4 {
+        Double4 { x: self.x, y: self.z, z: self.x, w: self.x }
+    }
+
+    #[inline]
+    pub const fn xzxy(self) -> Double4 {
+        Double4 { x: self.x, y: self.z, z: self.x, w: self.y }
+    }
+
+    #[inline]
+    pub const fn xzxz(self) -> Double4 {
+        Double4 { x: self.x, y: self.z, z: self.x, w: self.z }
+    }
+
+    #[inline]
+    pub const fn xzxw(self) -> Double4 {
+        Double4 { x: self.x, y: self.z, z: self.x, w: self.w }
+    }
+
+    #[inline]
+    pub const fn xzyx(self) -> Double4 {
+        Double4 { x: self.x, y: self.z, z: self.y, w: self.x }
+    }
+
+    #[inline]
+    pub const fn xzyy(self) -> Double4 {
+        Double4 { x: self.x, y: self.z, z: self.y, w: self.y }
+    }
+
+    #[inline]
+    pub const fn xzyz(self) -> Double4 {
+        Double4 { x: self.x, y: self.z, z: self.y, w: self.z }
+    }
+
+    #[inline]
+    pub const fn xzyw(self) -> Double4 {
+        Double4 { x: self.x, y: self.z, z: self.y, w: self.w }
+    }
+
+    #[inline]
+    pub const fn xzzx(self) -> Double4 {
+        Double4 { x: self.x, y: self.z, z: self.z, w: self.x }
+    }
+
+    #[inline]
+    pub const fn xzzy(self) -> Double4 {
+        Double4 { x: self.x, y: self.z, z: self.z, w: self.y }
+    }
+
+    #[inline]
+    pub const fn xzzz(self) -> Double4 {
+        Double4 { x: self.x, y: self.z, z: self.z, w: self.z }
+    }
+
+    #[inline]
+    pub const fn xzzw(self) -> Double4 {
+        Double4 { x: self.x, y: self.z, z: self.z, w: self.w }
+    }
+
+    #[inline]
+    pub const fn xzwx(self) -> Double4 {
+        Double4 { x: self.x, y: self.z, z: self.w, w: self.x }
+    }
+
+    #[inline]
+    pub const fn xzwy(self) -> Double4 {
+        Double4 { x: self.x, y: self.z, z: self.w, w: self.y }
+    }
+
+    #[inline]
+    pub const fn xzwz(self) -> Double4 {
+        Double4 { x: self.x, y: self.z, z: self.w, w: self.z }
+    }
+
+    #[inline]
+    pub const fn xzww(self) -> Double4 {
+        Double4 { x: self.x, y: self.z, z: self.w, w: self.w }
+    }
+    
+    #[inline]
+    pub const fn xwxx(self) -> Double4 {
+        Double4 { x: self.x, y: self.w, z: self.x, w: self.x }
+    }
+
+    #[inline]
+    pub const fn xwxy(self) -> Double4 {
+        Double4 { x: self.x, y: self.w, z: self.x, w: self.y }
+    }
+
+    #[inline]
+    pub const fn xwxz(self) -> Double4 {
+        Double4 { x: self.x, y: self.w, z: self.x, w: self.z }
+    }
+
+    #[inline]
+    pub const fn xwxw(self) -> Double4 {
+        Double4 { x: self.x, y: self.w, z: self.x, w: self.w }
+    }
+
+    #[inline]
+    pub const fn xwyx(self) -> Double4 {
+        Double4 { x: self.x, y: self.w, z: self.y, w: self.x }
+    }
+
+    #[inline]
+    pub const fn xwyy(self) -> Double4 {
+        Double4 { x: self.x, y: self.w, z: self.y, w: self.y }
+    }
+
+    #[inline]
+    pub const fn xwyz(self) -> Double4 {
+        Double4 { x: self.x, y: self.w, z: self.y, w: self.z }
+    }
+
+    #[inline]
+    pub const fn xwyw(self) -> Double4 {
+        Double4 { x: self.x, y: self.w, z: self.y, w: self.w }
+    }
+
+    #[inline]
+    pub const fn xwzx(self) -> Double4 {
+        Double4 { x: self.x, y: self.w, z: self.z, w: self.x }
+    }
+
+    #[inline]
+    pub const fn xwzy(self) -> Double4 {
+        Double4 { x: self.x, y: self.w, z: self.z, w: self.y }
+    }
+
+    #[inline]
+    pub const fn xwzz(self) -> Double4 {
+        Double4 { x: self.x, y: self.w, z: self.z, w: self.z }
+    }
+
+    #[inline]
+    pub const fn xwzw(self) -> Double4 {
+        Double4 { x: self.x, y: self.w, z: self.z, w: self.w }
+    }
+
+    #[inline]
+    pub const fn xwwx(self) -> Double4 {
+        Double4 { x: self.x, y: self.w, z: self.w, w: self.x }
+    }
+
+    #[inline]
+    pub const fn xwwy(self) -> Double4 {
+        Double4 { x: self.x, y: self.w, z: self.w, w: self.y }
+    }
+
+    #[inline]
+    pub const fn xwwz(self) -> Double4 {
+        Double4 { x: self.x, y: self.w, z: self.w, w: self.z }
+    }
+
+    #[inline]
+    pub const fn xwww(self) -> Double4 {
+        Double4 { x: self.x, y: self.w, z: self.w, w: self.w }
+    }
+
+    #[inline]
+    pub const fn yxxx(self) -> Double4 {
+        Double4 { x: self.y, y: self.x, z: self.x, w: self.x }
+    }
+
+    #[inline]
+    pub const fn yxxy(self) -> Double4 {
+        Double4 { x: self.y, y: self.x, z: self.x, w: self.y }
+    }
+
+    #[inline]
+    pub const fn yxxz(self) -> Double4 {
+        Double4 { x: self.y, y: self.x, z: self.x, w: self.z }
+    }
+
+    #[inline]
+    pub const fn yxxw(self) -> Double4 {
+        Double4 { x: self.y, y: self.x, z: self.x, w: self.w }
+    }
+
+    #[inline]
+    pub const fn yxyx(self) -> Double4 {
+        Double4 { x: self.y, y: self.x, z: self.y, w: self.x }
+    }
+
+    #[inline]
+    pub const fn yxyy(self) -> Double4 {
+        Double4 { x: self.y, y: self.x, z: self.y, w: self.y }
+    }
+
+    #[inline]
+    pub const fn yxyz(self) -> Double4 {
+        Double4 { x: self.y, y: self.x, z: self.y, w: self.z }
+    }
+
+    #[inline]
+    pub const fn yxyw(self) -> Double4 {
+        Double4 { x: self.y, y: self.x, z: self.y, w: self.w }
+    }
+
+    #[inline]
+    pub const fn yxzx(self) -> Double4 {
+        Double4 { x: self.y, y: self.x, z: self.z, w: self.x }
+    }
+
+    #[inline]
+    pub const fn yxzy(self) -> Double4 {
+        Double4 { x: self.y, y: self.x, z: self.z, w: self.y }
+    }
+
+    #[inline]
+    pub const fn yxzz(self) -> Double4 {
+        Double4 { x: self.y, y: self.x, z: self.z, w: self.z }
+    }
+
+    #[inline]
+    pub const fn yxzw(self) -> Double4 {
+        Double4 { x: self.y, y: self.x, z: self.z, w: self.w }
+    }
+
+    #[inline]
+    pub const fn yxwx(self) -> Double4 {
+        Double4 { x: self.y, y: self.x, z: self.w, w: self.x }
+    }
+
+    #[inline]
+    pub const fn yxwy(self) -> Double4 {
+        Double4 { x: self.y, y: self.x, z: self.w, w: self.y }
+    }
+
+    #[inline]
+    pub const fn yxwz(self) -> Double4 {
+        Double4 { x: self.y, y: self.x, z: self.w, w: self.z }
+    }
+
+    #[inline]
+    pub const fn yxww(self) -> Double4 {
+        Double4 { x: self.y, y: self.x, z: self.w, w: self.w }
+    }
+
+    #[inline]
+    pub const fn yyxx(self) -> Double4 {
+        Double4 { x: self.y, y: self.y, z: self.x, w: self.x }
+    }
+
+    #[inline]
+    pub const fn yyxy(self) -> Double4 {
+        Double4 { x: self.y, y: self.y, z: self.x, w: self.y }
+    }
+
+    #[inline]
+    pub const fn yyxz(self) -> Double4 {
+        Double4 { x: self.y, y: self.y, z: self.x, w: self.z }
+    }
+
+    #[inline]
+    pub const fn yyxw(self) -> Double4 {
+        Double4 { x: self.y, y: self.y, z: self.x, w: self.w }
+    }
+
+    #[inline]
+    pub const fn yyyx(self) -> Double4 {
+        Double4 { x: self.y, y: self.y, z: self.y, w: self.x }
+    }
+
+    #[inline]
+    pub const fn yyyy(self) -> Double4 {
+        Double4 { x: self.y, y: self.y, z: self.y, w: self.y }
+    }
+
+    #[inline]
+    pub const fn yyyz(self) -> Double4 {
+        Double4 { x: self.y, y: self.y, z: self.y, w: self.z }
+    }
+
+    #[inline]
+    pub const fn yyyw(self) -> Double4 {
+        Double4 { x: self.y, y: self.y, z: self.y, w: self.w }
+    }
+
+    #[inline]
+    pub const fn yyzx(self) -> Double4 {
+        Double4 { x: self.y, y: self.y, z: self.z, w: self.x }
+    }
+
+    #[inline]
+    pub const fn yyzy(self) -> Double4 {
+        Double4 { x: self.y, y: self.y, z: self.z, w: self.y }
+    }
+
+    #[inline]
+    pub const fn yyzz(self) -> Double4 {
+        Double4 { x: self.y, y: self.y, z: self.z, w: self.z }
+    }
+
+    #[inline]
+    pub const fn yyzw(self) -> Double4 {
+        Double4 { x: self.y, y: self.y, z: self.z, w: self.w }
+    }
+
+    #[inline]
+    pub const fn yywx(self) -> Double4 {
+        Double4 { x: self.y, y: self.y, z: self.w, w: self.x }
+    }
+
+    #[inline]
+    pub const fn yywy(self) -> Double4 {
+        Double4 { x: self.y, y: self.y, z: self.w, w: self.y }
+    }
+
+    #[inline]
+    pub const fn yywz(self) -> Double4 {
+        Double4 { x: self.y, y: self.y, z: self.w, w: self.z }
+    }
+
+    #[inline]
+    pub const fn yyww(self) -> Double4 {
+        Double4 { x: self.y, y: self.y, z: self.w, w: self.w }
+    }
+
+    #[inline]
+    pub const fn yzxx(self) -> Double4 {
+        Double4 { x: self.y, y: self.z, z: self.x, w: self.x }
+    }
+
+    #[inline]
+    pub const fn yzxy(self) -> Double4 {
+        Double4 { x: self.y, y: self.z, z: self.x, w: self.y }
+    }
+
+    #[inline]
+    pub const fn yzxz(self) -> Double4 {
+        Double4 { x: self.y, y: self.z, z: self.x, w: self.z }
+    }
+
+    #[inline]
+    pub const fn yzxw(self) -> Double4 {
+        Double4 { x: self.y, y: self.z, z: self.x, w: self.w }
+    }
+
+    #[inline]
+    pub const fn yzyx(self) -> Double4 {
+        Double4 { x: self.y, y: self.z, z: self.y, w: self.x }
+    }
+
+    #[inline]
+    pub const fn yzyy(self) -> Double4 {
+        Double4 { x: self.y, y: self.z, z: self.y, w: self.y }
+    }
+
+    #[inline]
+    pub const fn yzyz(self) -> Double4 {
+        Double4 { x: self.y, y: self.z, z: self.y, w: self.z }
+    }
+
+    #[inline]
+    pub const fn yzyw(self) -> Double4 {
+        Double4 { x: self.y, y: self.z, z: self.y, w: self.w }
+    }
+
+    #[inline]
+    pub const fn yzzx(self) -> Double4 {
+        Double4 { x: self.y, y: self.z, z: self.z, w: self.x }
+    }
+
+    #[inline]
+    pub const fn yzzy(self) -> Double4 {
+        Double4 { x: self.y, y: self.z, z: self.z, w: self.y }
+    }
+
+    #[inline]
+    pub const fn yzzz(self) -> Double4 {
+        Double4 { x: self.y, y: self.z, z: self.z, w: self.z }
+    }
+
+    #[inline]
+    pub const fn yzzw(self) -> Double4 {
+        Double4 { x: self.y, y: self.z, z: self.z, w: self.w }
+    }
+
+    #[inline]
+    pub const fn yzwx(self) -> Double4 {
+        Double4 { x: self.y, y: self.z, z: self.w, w: self.x }
+    }
+
+    #[inline]
+    pub const fn yzwy(self) -> Double4 {
+        Double4 { x: self.y, y: self.z, z: self.w, w: self.y }
+    }
+
+    #[inline]
+    pub const fn yzwz(self) -> Double4 {
+        Double4 { x: self.y, y: self.z, z: self.w, w: self.z }
+    }
+
+    #[inline]
+    pub const fn yzww(self) -> Double4 {
+        Double4 { x: self.y, y: self.z, z: self.w, w: self.w }
+    }
+    
+    #[inline]
+    pub const fn ywxx(self) -> Double4 {
+        Double4 { x: self.y, y: self.w, z: self.x, w: self.x }
+    }
+
+    #[inline]
+    pub const fn ywxy(self) -> Double4 {
+        Double4 { x: self.y, y: self.w, z: self.x, w: self.y }
+    }
+
+    #[inline]
+    pub const fn ywxz(self) -> Double4 {
+        Double4 { x: self.y, y: self.w, z: self.x, w: self.z }
+    }
+
+    #[inline]
+    pub const fn ywxw(self) -> Double4 {
+        Double4 { x: self.y, y: self.w, z: self.x, w: self.w }
+    }
+
+    #[inline]
+    pub const fn ywyx(self) -> Double4 {
+        Double4 { x: self.y, y: self.w, z: self.y, w: self.x }
+    }
+
+    #[inline]
+    pub const fn ywyy(self) -> Double4 {
+        Double4 { x: self.y, y: self.w, z: self.y, w: self.y }
+    }
+
+    #[inline]
+    pub const fn ywyz(self) -> Double4 {
+        Double4 { x: self.y, y: self.w, z: self.y, w: self.z }
+    }
+
+    #[inline]
+    pub const fn ywyw(self) -> Double4 {
+        Double4 { x: self.y, y: self.w, z: self.y, w: self.w }
+    }
+
+    #[inline]
+    pub const fn ywzx(self) -> Double4 {
+        Double4 { x: self.y, y: self.w, z: self.z, w: self.x }
+    }
+
+    #[inline]
+    pub const fn ywzy(self) -> Double4 {
+        Double4 { x: self.y, y: self.w, z: self.z, w: self.y }
+    }
+
+    #[inline]
+    pub const fn ywzz(self) -> Double4 {
+        Double4 { x: self.y, y: self.w, z: self.z, w: self.z }
+    }
+
+    #[inline]
+    pub const fn ywzw(self) -> Double4 {
+        Double4 { x: self.y, y: self.w, z: self.z, w: self.w }
+    }
+
+    #[inline]
+    pub const fn ywwx(self) -> Double4 {
+        Double4 { x: self.y, y: self.w, z: self.w, w: self.x }
+    }
+
+    #[inline]
+    pub const fn ywwy(self) -> Double4 {
+        Double4 { x: self.y, y: self.w, z: self.w, w: self.y }
+    }
+
+    #[inline]
+    pub const fn ywwz(self) -> Double4 {
+        Double4 { x: self.y, y: self.w, z: self.w, w: self.z }
+    }
+
+    #[inline]
+    pub const fn ywww(self) -> Double4 {
+        Double4 { x: self.y, y: self.w, z: self.w, w: self.w }
+    }
+    
+
+    #[inline]
+    pub const fn zxxx(self) -> Double4 {
+        Double4 { x: self.z, y: self.x, z: self.x, w: self.x }
+    }
+
+    #[inline]
+    pub const fn zxxy(self) -> Double4 {
+        Double4 { x: self.z, y: self.x, z: self.x, w: self.y }
+    }
+
+    #[inline]
+    pub const fn zxxz(self) -> Double4 {
+        Double4 { x: self.z, y: self.x, z: self.x, w: self.z }
+    }
+
+    #[inline]
+    pub const fn zxxw(self) -> Double4 {
+        Double4 { x: self.z, y: self.x, z: self.x, w: self.w }
+    }
+
+    #[inline]
+    pub const fn zxyx(self) -> Double4 {
+        Double4 { x: self.z, y: self.x, z: self.y, w: self.x }
+    }
+
+    #[inline]
+    pub const fn zxyy(self) -> Double4 {
+        Double4 { x: self.z, y: self.x, z: self.y, w: self.y }
+    }
+
+    #[inline]
+    pub const fn zxyz(self) -> Double4 {
+        Double4 { x: self.z, y: self.x, z: self.y, w: self.z }
+    }
+
+    #[inline]
+    pub const fn zxyw(self) -> Double4 {
+        Double4 { x: self.z, y: self.x, z: self.y, w: self.w }
+    }
+
+    #[inline]
+    pub const fn zxzx(self) -> Double4 {
+        Double4 { x: self.z, y: self.x, z: self.z, w: self.x }
+    }
+
+    #[inline]
+    pub const fn zxzy(self) -> Double4 {
+        Double4 { x: self.z, y: self.x, z: self.z, w: self.y }
+    }
+
+    #[inline]
+    pub const fn zxzz(self) -> Double4 {
+        Double4 { x: self.z, y: self.x, z: self.z, w: self.z }
+    }
+
+    #[inline]
+    pub const fn zxzw(self) -> Double4 {
+        Double4 { x: self.z, y: self.x, z: self.z, w: self.w }
+    }
+
+    #[inline]
+    pub const fn zxwx(self) -> Double4 {
+        Double4 { x: self.z, y: self.x, z: self.w, w: self.x }
+    }
+
+    #[inline]
+    pub const fn zxwy(self) -> Double4 {
+        Double4 { x: self.z, y: self.x, z: self.w, w: self.y }
+    }
+
+    #[inline]
+    pub const fn zxwz(self) -> Double4 {
+        Double4 { x: self.z, y: self.x, z: self.w, w: self.z }
+    }
+
+    #[inline]
+    pub const fn zxww(self) -> Double4 {
+        Double4 { x: self.z, y: self.x, z: self.w, w: self.w }
+    }
+
+    #[inline]
+    pub const fn zyxx(self) -> Double4 {
+        Double4 { x: self.z, y: self.y, z: self.x, w: self.x }
+    }
+
+    #[inline]
+    pub const fn zyxy(self) -> Double4 {
+        Double4 { x: self.z, y: self.y, z: self.x, w: self.y }
+    }
+
+    #[inline]
+    pub const fn zyxz(self) -> Double4 {
+        Double4 { x: self.z, y: self.y, z: self.x, w: self.z }
+    }
+
+    #[inline]
+    pub const fn zyxw(self) -> Double4 {
+        Double4 { x: self.z, y: self.y, z: self.x, w: self.w }
+    }
+
+    #[inline]
+    pub const fn zyyx(self) -> Double4 {
+        Double4 { x: self.z, y: self.y, z: self.y, w: self.x }
+    }
+
+    #[inline]
+    pub const fn zyyy(self) -> Double4 {
+        Double4 { x: self.z, y: self.y, z: self.y, w: self.y }
+    }
+
+    #[inline]
+    pub const fn zyyz(self) -> Double4 {
+        Double4 { x: self.z, y: self.y, z: self.y, w: self.z }
+    }
+
+    #[inline]
+    pub const fn zyyw(self) -> Double4 {
+        Double4 { x: self.z, y: self.y, z: self.y, w: self.w }
+    }
+
+    #[inline]
+    pub const fn zyzx(self) -> Double4 {
+        Double4 { x: self.z, y: self.y, z: self.z, w: self.x }
+    }
+
+    #[inline]
+    pub const fn zyzy(self) -> Double4 {
+        Double4 { x: self.z, y: self.y, z: self.z, w: self.y }
+    }
+
+    #[inline]
+    pub const fn zyzz(self) -> Double4 {
+        Double4 { x: self.z, y: self.y, z: self.z, w: self.z }
+    }
+
+    #[inline]
+    pub const fn zyzw(self) -> Double4 {
+        Double4 { x: self.z, y: self.y, z: self.z, w: self.w }
+    }
+
+    #[inline]
+    pub const fn zywx(self) -> Double4 {
+        Double4 { x: self.z, y: self.y, z: self.w, w: self.x }
+    }
+
+    #[inline]
+    pub const fn zywy(self) -> Double4 {
+        Double4 { x: self.z, y: self.y, z: self.w, w: self.y }
+    }
+
+    #[inline]
+    pub const fn zywz(self) -> Double4 {
+        Double4 { x: self.z, y: self.y, z: self.w, w: self.z }
+    }
+
+    #[inline]
+    pub const fn zyww(self) -> Double4 {
+        Double4 { x: self.z, y: self.y, z: self.w, w: self.w }
+    }
+
+    #[inline]
+    pub const fn zzxx(self) -> Double4 {
+        Double4 { x: self.z, y: self.z, z: self.x, w: self.x }
+    }
+
+    #[inline]
+    pub const fn zzxy(self) -> Double4 {
+        Double4 { x: self.z, y: self.z, z: self.x, w: self.y }
+    }
+
+    #[inline]
+    pub const fn zzxz(self) -> Double4 {
+        Double4 { x: self.z, y: self.z, z: self.x, w: self.z }
+    }
+
+    #[inline]
+    pub const fn zzxw(self) -> Double4 {
+        Double4 { x: self.z, y: self.z, z: self.x, w: self.w }
+    }
+
+    #[inline]
+    pub const fn zzyx(self) -> Double4 {
+        Double4 { x: self.z, y: self.z, z: self.y, w: self.x }
+    }
+
+    #[inline]
+    pub const fn zzyy(self) -> Double4 {
+        Double4 { x: self.z, y: self.z, z: self.y, w: self.y }
+    }
+
+    #[inline]
+    pub const fn zzyz(self) -> Double4 {
+        Double4 { x: self.z, y: self.z, z: self.y, w: self.z }
+    }
+
+    #[inline]
+    pub const fn zzyw(self) -> Double4 {
+        Double4 { x: self.z, y: self.z, z: self.y, w: self.w }
+    }
+
+    #[inline]
+    pub const fn zzzx(self) -> Double4 {
+        Double4 { x: self.z, y: self.z, z: self.z, w: self.x }
+    }
+
+    #[inline]
+    pub const fn zzzy(self) -> Double4 {
+        Double4 { x: self.z, y: self.z, z: self.z, w: self.y }
+    }
+
+    #[inline]
+    pub const fn zzzz(self) -> Double4 {
+        Double4 { x: self.z, y: self.z, z: self.z, w: self.z }
+    }
+
+    #[inline]
+    pub const fn zzzw(self) -> Double4 {
+        Double4 { x: self.z, y: self.z, z: self.z, w: self.w }
+    }
+
+    #[inline]
+    pub const fn zzwx(self) -> Double4 {
+        Double4 { x: self.z, y: self.z, z: self.w, w: self.x }
+    }
+
+    #[inline]
+    pub const fn zzwy(self) -> Double4 {
+        Double4 { x: self.z, y: self.z, z: self.w, w: self.y }
+    }
+
+    #[inline]
+    pub const fn zzwz(self) -> Double4 {
+        Double4 { x: self.z, y: self.z, z: self.w, w: self.z }
+    }
+
+    #[inline]
+    pub const fn zzww(self) -> Double4 {
+        Double4 { x: self.z, y: self.z, z: self.w, w: self.w }
+    }
+    
+    #[inline]
+    pub const fn zwxx(self) -> Double4 {
+        Double4 { x: self.z, y: self.w, z: self.x, w: self.x }
+    }
+
+    #[inline]
+    pub const fn zwxy(self) -> Double4 {
+        Double4 { x: self.z, y: self.w, z: self.x, w: self.y }
+    }
+
+    #[inline]
+    pub const fn zwxz(self) -> Double4 {
+        Double4 { x: self.z, y: self.w, z: self.x, w: self.z }
+    }
+
+    #[inline]
+    pub const fn zwxw(self) -> Double4 {
+        Double4 { x: self.z, y: self.w, z: self.x, w: self.w }
+    }
+
+    #[inline]
+    pub const fn zwyx(self) -> Double4 {
+        Double4 { x: self.z, y: self.w, z: self.y, w: self.x }
+    }
+
+    #[inline]
+    pub const fn zwyy(self) -> Double4 {
+        Double4 { x: self.z, y: self.w, z: self.y, w: self.y }
+    }
+
+    #[inline]
+    pub const fn zwyz(self) -> Double4 {
+        Double4 { x: self.z, y: self.w, z: self.y, w: self.z }
+    }
+
+    #[inline]
+    pub const fn zwyw(self) -> Double4 {
+        Double4 { x: self.z, y: self.w, z: self.y, w: self.w }
+    }
+
+    #[inline]
+    pub const fn zwzx(self) -> Double4 {
+        Double4 { x: self.z, y: self.w, z: self.z, w: self.x }
+    }
+
+    #[inline]
+    pub const fn zwzy(self) -> Double4 {
+        Double4 { x: self.z, y: self.w, z: self.z, w: self.y }
+    }
+
+    #[inline]
+    pub const fn zwzz(self) -> Double4 {
+        Double4 { x: self.z, y: self.w, z: self.z, w: self.z }
+    }
+
+    #[inline]
+    pub const fn zwzw(self) -> Double4 {
+        Double4 { x: self.z, y: self.w, z: self.z, w: self.w }
+    }
+
+    #[inline]
+    pub const fn zwwx(self) -> Double4 {
+        Double4 { x: self.z, y: self.w, z: self.w, w: self.x }
+    }
+
+    #[inline]
+    pub const fn zwwy(self) -> Double4 {
+        Double4 { x: self.z, y: self.w, z: self.w, w: self.y }
+    }
+
+    #[inline]
+    pub const fn zwwz(self) -> Double4 {
+        Double4 { x: self.z, y: self.w, z: self.w, w: self.z }
+    }
+
+    #[inline]
+    pub const fn zwww(self) -> Double4 {
+        Double4 { x: self.z, y: self.w, z: self.w, w: self.w }
+    }
+
+    #[inline]
+    pub const fn wxxx(self) -> Double4 {
+        Double4 { x: self.w, y: self.x, z: self.x, w: self.x }
+    }
+
+    #[inline]
+    pub const fn wxxy(self) -> Double4 {
+        Double4 { x: self.w, y: self.x, z: self.x, w: self.y }
+    }
+
+    #[inline]
+    pub const fn wxxz(self) -> Double4 {
+        Double4 { x: self.w, y: self.x, z: self.x, w: self.z }
+    }
+
+    #[inline]
+    pub const fn wxxw(self) -> Double4 {
+        Double4 { x: self.w, y: self.x, z: self.x, w: self.w }
+    }
+
+    #[inline]
+    pub const fn wxyx(self) -> Double4 {
+        Double4 { x: self.w, y: self.x, z: self.y, w: self.x }
+    }
+
+    #[inline]
+    pub const fn wxyy(self) -> Double4 {
+        Double4 { x: self.w, y: self.x, z: self.y, w: self.y }
+    }
+
+    #[inline]
+    pub const fn wxyz(self) -> Double4 {
+        Double4 { x: self.w, y: self.x, z: self.y, w: self.z }
+    }
+
+    #[inline]
+    pub const fn wxyw(self) -> Double4 {
+        Double4 { x: self.w, y: self.x, z: self.y, w: self.w }
+    }
+
+    #[inline]
+    pub const fn wxzx(self) -> Double4 {
+        Double4 { x: self.w, y: self.x, z: self.z, w: self.x }
+    }
+
+    #[inline]
+    pub const fn wxzy(self) -> Double4 {
+        Double4 { x: self.w, y: self.x, z: self.z, w: self.y }
+    }
+
+    #[inline]
+    pub const fn wxzz(self) -> Double4 {
+        Double4 { x: self.w, y: self.x, z: self.z, w: self.z }
+    }
+
+    #[inline]
+    pub const fn wxzw(self) -> Double4 {
+        Double4 { x: self.w, y: self.x, z: self.z, w: self.w }
+    }
+
+    #[inline]
+    pub const fn wxwx(self) -> Double4 {
+        Double4 { x: self.w, y: self.x, z: self.w, w: self.x }
+    }
+
+    #[inline]
+    pub const fn wxwy(self) -> Double4 {
+        Double4 { x: self.w, y: self.x, z: self.w, w: self.y }
+    }
+
+    #[inline]
+    pub const fn wxwz(self) -> Double4 {
+        Double4 { x: self.w, y: self.x, z: self.w, w: self.z }
+    }
+
+    #[inline]
+    pub const fn wxww(self) -> Double4 {
+        Double4 { x: self.w, y: self.x, z: self.w, w: self.w }
+    }
+
+    #[inline]
+    pub const fn wyxx(self) -> Double4 {
+        Double4 { x: self.w, y: self.y, z: self.x, w: self.x }
+    }
+
+    #[inline]
+    pub const fn wyxy(self) -> Double4 {
+        Double4 { x: self.w, y: self.y, z: self.x, w: self.y }
+    }
+
+    #[inline]
+    pub const fn wyxz(self) -> Double4 {
+        Double4 { x: self.w, y: self.y, z: self.x, w: self.z }
+    }
+
+    #[inline]
+    pub const fn wyxw(self) -> Double4 {
+        Double4 { x: self.w, y: self.y, z: self.x, w: self.w }
+    }
+
+    #[inline]
+    pub const fn wyyx(self) -> Double4 {
+        Double4 { x: self.w, y: self.y, z: self.y, w: self.x }
+    }
+
+    #[inline]
+    pub const fn wyyy(self) -> Double4 {
+        Double4 { x: self.w, y: self.y, z: self.y, w: self.y }
+    }
+
+    #[inline]
+    pub const fn wyyz(self) -> Double4 {
+        Double4 { x: self.w, y: self.y, z: self.y, w: self.z }
+    }
+
+    #[inline]
+    pub const fn wyyw(self) -> Double4 {
+        Double4 { x: self.w, y: self.y, z: self.y, w: self.w }
+    }
+
+    #[inline]
+    pub const fn wyzx(self) -> Double4 {
+        Double4 { x: self.w, y: self.y, z: self.z, w: self.x }
+    }
+
+    #[inline]
+    pub const fn wyzy(self) -> Double4 {
+        Double4 { x: self.w, y: self.y, z: self.z, w: self.y }
+    }
+
+    #[inline]
+    pub const fn wyzz(self) -> Double4 {
+        Double4 { x: self.w, y: self.y, z: self.z, w: self.z }
+    }
+
+    #[inline]
+    pub const fn wyzw(self) -> Double4 {
+        Double4 { x: self.w, y: self.y, z: self.z, w: self.w }
+    }
+
+    #[inline]
+    pub const fn wywx(self) -> Double4 {
+        Double4 { x: self.w, y: self.y, z: self.w, w: self.x }
+    }
+
+    #[inline]
+    pub const fn wywy(self) -> Double4 {
+        Double4 { x: self.w, y: self.y, z: self.w, w: self.y }
+    }
+
+    #[inline]
+    pub const fn wywz(self) -> Double4 {
+        Double4 { x: self.w, y: self.y, z: self.w, w: self.z }
+    }
+
+    #[inline]
+    pub const fn wyww(self) -> Double4 {
+        Double4 { x: self.w, y: self.y, z: self.w, w: self.w }
+    }
+
+    #[inline]
+    pub const fn wzxx(self) -> Double4 {
+        Double4 { x: self.w, y: self.z, z: self.x, w: self.x }
+    }
+
+    #[inline]
+    pub const fn wzxy(self) -> Double4 {
+        Double4 { x: self.w, y: self.z, z: self.x, w: self.y }
+    }
+
+    #[inline]
+    pub const fn wzxz(self) -> Double4 {
+        Double4 { x: self.w, y: self.z, z: self.x, w: self.z }
+    }
+
+    #[inline]
+    pub const fn wzxw(self) -> Double4 {
+        Double4 { x: self.w, y: self.z, z: self.x, w: self.w }
+    }
+
+    #[inline]
+    pub const fn wzyx(self) -> Double4 {
+        Double4 { x: self.w, y: self.z, z: self.y, w: self.x }
+    }
+
+    #[inline]
+    pub const fn wzyy(self) -> Double4 {
+        Double4 { x: self.w, y: self.z, z: self.y, w: self.y }
+    }
+
+    #[inline]
+    pub const fn wzyz(self) -> Double4 {
+        Double4 { x: self.w, y: self.z, z: self.y, w: self.z }
+    }
+
+    #[inline]
+    pub const fn wzyw(self) -> Double4 {
+        Double4 { x: self.w, y: self.z, z: self.y, w: self.w }
+    }
+
+    #[inline]
+    pub const fn wzzx(self) -> Double4 {
+        Double4 { x: self.w, y: self.z, z: self.z, w: self.x }
+    }
+
+    #[inline]
+    pub const fn wzzy(self) -> Double4 {
+        Double4 { x: self.w, y: self.z, z: self.z, w: self.y }
+    }
+
+    #[inline]
+    pub const fn wzzz(self) -> Double4 {
+        Double4 { x: self.w, y: self.z, z: self.z, w: self.z }
+    }
+
+    #[inline]
+    pub const fn wzzw(self) -> Double4 {
+        Double4 { x: self.w, y: self.z, z: self.z, w: self.w }
+    }
+
+    #[inline]
+    pub const fn wzwx(self) -> Double4 {
+        Double4 { x: self.w, y: self.z, z: self.w, w: self.x }
+    }
+
+    #[inline]
+    pub const fn wzwy(self) -> Double4 {
+        Double4 { x: self.w, y: self.z, z: self.w, w: self.y }
+    }
+
+    #[inline]
+    pub const fn wzwz(self) -> Double4 {
+        Double4 { x: self.w, y: self.z, z: self.w, w: self.z }
+    }
+
+    #[inline]
+    pub const fn wzww(self) -> Double4 {
+        Double4 { x: self.w, y: self.z, z: self.w, w: self.w }
+    }
+    
+    #[inline]
+    pub const fn wwxx(self) -> Double4 {
+        Double4 { x: self.w, y: self.w, z: self.x, w: self.x }
+    }
+
+    #[inline]
+    pub const fn wwxy(self) -> Double4 {
+        Double4 { x: self.w, y: self.w, z: self.x, w: self.y }
+    }
+
+    #[inline]
+    pub const fn wwxz(self) -> Double4 {
+        Double4 { x: self.w, y: self.w, z: self.x, w: self.z }
+    }
+
+    #[inline]
+    pub const fn wwxw(self) -> Double4 {
+        Double4 { x: self.w, y: self.w, z: self.x, w: self.w }
+    }
+
+    #[inline]
+    pub const fn wwyx(self) -> Double4 {
+        Double4 { x: self.w, y: self.w, z: self.y, w: self.x }
+    }
+
+    #[inline]
+    pub const fn wwyy(self) -> Double4 {
+        Double4 { x: self.w, y: self.w, z: self.y, w: self.y }
+    }
+
+    #[inline]
+    pub const fn wwyz(self) -> Double4 {
+        Double4 { x: self.w, y: self.w, z: self.y, w: self.z }
+    }
+
+    #[inline]
+    pub const fn wwyw(self) -> Double4 {
+        Double4 { x: self.w, y: self.w, z: self.y, w: self.w }
+    }
+
+    #[inline]
+    pub const fn wwzx(self) -> Double4 {
+        Double4 { x: self.w, y: self.w, z: self.z, w: self.x }
+    }
+
+    #[inline]
+    pub const fn wwzy(self) -> Double4 {
+        Double4 { x: self.w, y: self.w, z: self.z, w: self.y }
+    }
+
+    #[inline]
+    pub const fn wwzz(self) -> Double4 {
+        Double4 { x: self.w, y: self.w, z: self.z, w: self.z }
+    }
+
+    #[inline]
+    pub const fn wwzw(self) -> Double4 {
+        Double4 { x: self.w, y: self.w, z: self.z, w: self.w }
+    }
+
+    #[inline]
+    pub const fn wwwx(self) -> Double4 {
+        Double4 { x: self.w, y: self.w, z: self.w, w: self.x }
+    }
+
+    #[inline]
+    pub const fn wwwy(self) -> Double4 {
+        Double4 { x: self.w, y: self.w, z: self.w, w: self.y }
+    }
+
+    #[inline]
+    pub const fn wwwz(self) -> Double4 {
+        Double4 { x: self.w, y: self.w, z: self.w, w: self.z }
+    }
+
+    #[inline]
+    pub const fn wwww(self) -> Double4 {
+        Double4 { x: self.w, y: self.w, z: self.w, w: self.w }
+    }
+}
+
+impl_element4!(f64, Double4, float);
+
+impl_element4_op!(f64, Double4);
+
+impl Double4 {
+    /// Converts to a single-precision vector.
+    #[inline]
+    #[must_use]
+    pub const fn as_f32(self) -> Float4 {
+        Float4 { x: self.x as f32, y: self.y as f32, z: self.z as f32, w: self.w as f32 }
+    }
+}
+
+impl Default for Double4 {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl From<Double2> for Double4 {
+    #[inline]
+    fn from(value: Double2) -> Self {
+        Double4 { x: value.x, y: value.y, z: 0.0, w: 0.0 }
+    }
+}
+
+impl From<Double3> for Double4 {
+    #[inline]
+    fn from(value: Double3) -> Self {
+        Double4 { x: value.x, y: value.y, z: value.z, w: 0.0 }
+    }
+}
+
+impl core::ops::Neg for Double4{
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self::Output {
+            x: -self.x, 
+            y: -self.y, 
+            z: -self.z, 
+            w: -self.w, 
+        }
+    }
+}
@@ -0,0 +1,456 @@
+use core::fmt;
+use core::ops;
+
+use super::bool4::Boolean4;
+use super::float4x4::Float4x4;
+use super::double4::Double4;
+use crate::near_eq;
+
+
+
+/// A structure that stores double-precision 4x4 column major matrix data.
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, PartialEq)]
+pub struct Double4x4 {
+    pub x_axis: Double4,
+    pub y_axis: Double4,
+    pub z_axis: Double4,
+    pub w_axis: Double4,
+}
+
+impl Double4x4 {
+    /// A 4x4 matrix where all elements of the matrix are zero.
+    pub const ZERO: Self = Self::from_columns(Double4::ZERO, Double4::ZERO, Double4::ZERO, Double4::ZERO);
+
+    /// 4x4 identity matrix.
+    pub const IDENTITY: Self = Self::from_columns(Double4::X, Double4::Y, Double4::Z, Double4::W);
+
+    /// Creates a 4x4 matrix with given column vectors.
+    #[inline]
+    #[must_use]
+    pub const fn from_columns(x_axis: Double4, y_axis: Double4, z_axis: Double4, w_axis: Double4) -> Self {
+        Self { x_axis, y_axis, z_axis, w_axis }
+    }
+
+    /// Creates with given array.
+    #[inline]
+    #[must_use]
+    pub fn from_column_array(arr: [f64; 16]) -> Self {
+        Self { 
+            x_axis: Double4::from_slice(&arr[0..4]), 
+            y_axis: Double4::from_slice(&arr[4..8]), 
+            z_axis: Double4::from_slice(&arr[8..12]), 
+            w_axis: Double4::from_slice(&arr[12..16]) 
+        }
+    }
+
+    /// Convert to array.
+    #[inline]
+    #[must_use]
+    pub const fn to_column_array(self) -> [f64; 16] {
+        [
+            self.x_axis.x, self.x_axis.y, self.x_axis.z, self.x_axis.w, 
+            self.y_axis.x, self.y_axis.y, self.y_axis.z, self.y_axis.w, 
+            self.z_axis.x, self.z_axis.y, self.z_axis.z, self.z_axis.w, 
+            self.w_axis.x, self.w_axis.y, self.w_axis.z, self.w_axis.w 
+        ]
+    }
+
+    /// Creates with given array.
+    /// 
+    /// # Panics
+    /// If the length of the given array is less than the number of elements in the matrix,
+    /// an index out of range error occurs.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn from_column_slice(slice: &[f64]) -> Self {
+        Self { 
+            x_axis: Double4::from_slice(&slice[0..4]), 
+            y_axis: Double4::from_slice(&slice[4..8]), 
+            z_axis: Double4::from_slice(&slice[8..12]), 
+            w_axis: Double4::from_slice(&slice[12..16]) 
+        }
+    }
+
+    /// Creates with given tuple.
+    #[inline]
+    #[must_use]
+    pub const fn from_column_tuple(tuple: (Double4, Double4, Double4, Double4)) -> Self {
+        Self { 
+            x_axis: tuple.0, 
+            y_axis: tuple.1, 
+            z_axis: tuple.2, 
+            w_axis: tuple.3 
+        }
+    }
+
+    /// Convert to tuple.
+    #[inline]
+    #[must_use]
+    pub const fn to_column_tuple(self) -> (Double4, Double4, Double4, Double4) {
+        (self.x_axis, self.y_axis, self.z_axis, self.w_axis)
+    }
+
+    /// Returns `true` if at least one element of the matrix is [`f64::NAN`].
+    #[inline]
+    pub fn is_nan(&self) -> bool {
+        Boolean4 {
+            x: self.x_axis.is_nan(),
+            y: self.y_axis.is_nan(),
+            z: self.z_axis.is_nan(),
+            w: self.w_axis.is_nan()
+        }.any()
+    }
+
+    /// Returns `true` if at least one element of the matrix is [`f64::INFINITY`].
+    #[inline]
+    pub fn is_infinite(&self) -> bool {
+        Boolean4 {
+            x: self.x_axis.is_infinite(),
+            y: self.y_axis.is_infinite(),
+            z: self.z_axis.is_infinite(),
+            w: self.w_axis.is_infinite()
+        }.any()
+    }
+
+    /// Returns `true` if the absolute difference of all elements is less than or equal to `max_abs_diff`.
+    #[inline]
+    #[must_use]
+    pub fn abs_diff_eq(self, rhs: Self, max_abs_diff: f64) -> bool {
+        near_eq::abs_diff_eq(&self.to_column_array(), &rhs.to_column_array(), max_abs_diff)
+    }
+
+    /// Returns `true` if all elements are equal within `max_abs_diff` or the relative tolerance `max_relative`.
+    /// 
+    /// ※ The relative tolerance is scaled by the larger absolute value of the two elements. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn relative_eq(self, rhs: Self, max_abs_diff: f64, max_relative: f64) -> bool {
+        near_eq::relative_eq(&self.to_column_array(), &rhs.to_column_array(), max_abs_diff, max_relative)
+    }
+
+    /// Returns `true` if all elements are equal within `max_abs_diff` or `max_ulps` representable values.
+    /// 
+    /// ※ Elements with different signs are equal only within `max_abs_diff`. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn ulps_eq(self, rhs: Self, max_abs_diff: f64, max_ulps: u32) -> bool {
+        near_eq::ulps_eq(&self.to_column_array(), &rhs.to_column_array(), max_abs_diff, max_ulps)
+    }
+}
+
+impl Double4x4 {
+    /// Converts to a single-precision matrix.
+    #[inline]
+    #[must_use]
+    pub fn as_f32(self) -> Float4x4 {
+        Float4x4 {
+            x_axis: self.x_axis.as_f32(), 
+            y_axis: self.y_axis.as_f32(), 
+            z_axis: self.z_axis.as_f32(), 
+            w_axis: self.w_axis.as_f32() 
+        }
+    }
+}
+
+impl Default for Double4x4 {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl From<[f64; 16]> for Double4x4 {
+    #[inline]
+    fn from(value: [f64; 16]) -> Self {
+        Self::from_column_array(value)
+    }
+}
+
+impl From<Double4x4> for [f64; 16] {
+    #[inline]
+    fn from(value: Double4x4) -> Self {
+        value.to_column_array()
+    }
+}
+
+impl From<(Double4, Double4, Double4, Double4)> for Double4x4 {
+    #[inline]
+    fn from(value: (Double4, Double4, Double4, Double4)) -> Self {
+        Self::from_column_tuple(value)
+    }
+}
+
+impl From<Double4x4> for (Double4, Double4, Double4, Double4) {
+    #[inline]
+    fn from(value: Double4x4) -> Self {
+        value.to_column_tuple()
+    }
+}
+
+impl AsRef<[f64; 16]> for Double4x4 {
+    #[inline]
+    fn as_ref(&self) -> &[f64; 16] {
+        unsafe { &*(self as *const Self as *const [f64; 16]) }
+    }
+}
+
+impl AsMut<[f64; 16]> for Double4x4 {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [f64; 16] {
+        unsafe { &mut *(self as *mut Self as *mut [f64; 16]) }
+    }
+}
+
+impl ops::Index<usize> for Double4x4 {
+    type Output = Double4;
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x_axis, 
+            1 => &self.y_axis,
+            2 => &self.z_axis,
+            3 => &self.w_axis,
+            _ => panic!("index out of range!")
+        }
+    }
+}
+
+impl ops::IndexMut<usize> for Double4x4 {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => &mut self.x_axis,
+            1 => &mut self.y_axis,
+            2 => &mut self.z_axis,
+            3 => &mut self.w_axis,
+            _ => panic!("index out of range!")
+        }
+    }
+}
+
+impl ops::Add<Double4x4> for f64 {
+    type Output = Double4x4;
+    /// Adds a matrix elements to scalar value.
+    #[inline]
+    fn add(self, rhs: Double4x4) -> Self::Output {
+        Double4x4 {
+            x_axis: self + rhs.x_axis, 
+            y_axis: self + rhs.y_axis, 
+            z_axis: self + rhs.z_axis, 
+            w_axis: self + rhs.w_axis 
+        }
+    }
+}
+
+impl ops::Add<f64> for Double4x4 {
+    type Output = Self;
+    /// Adds a scalar value to matrix elements.
+    #[inline]
+    fn add(self, rhs: f64) -> Self::Output {
+        Double4x4 {
+            x_axis: self.x_axis + rhs, 
+            y_axis: self.y_axis + rhs, 
+            z_axis: self.z_axis + rhs, 
+            w_axis: self.w_axis + rhs 
+        }
+    }
+}
+
+impl ops::AddAssign<f64> for Double4x4 {
+    /// Adds a scalar value to matrix elements. (assign)
+    #[inline]
+    fn add_assign(&mut self, rhs: f64) {
+        *self = *self + rhs
+    }
+}
+
+impl ops::Add<Self> for Double4x4 {
+    type Output = Self;
+    /// Adds two matrices.
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Double4x4 {
+            x_axis: self.x_axis + rhs.x_axis, 
+            y_axis: self.y_axis + rhs.y_axis, 
+            z_axis: self.z_axis + rhs.z_axis, 
+            w_axis: self.w_axis + rhs.w_axis 
+        }
+    }
+}
+
+impl ops::AddAssign<Self> for Double4x4 {
+    /// Adds two matrices. (assign)
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+impl ops::Sub<Double4x4> for f64 {
+    type Output = Double4x4;
+    /// Subtracts a matrix elements to scalar value.
+    #[inline]
+    fn sub(self, rhs: Double4x4) -> Self::Output {
+        Double4x4 {
+            x_axis: self - rhs.x_axis, 
+            y_axis: self - rhs.y_axis, 
+            z_axis: self - rhs.z_axis, 
+            w_axis: self - rhs.w_axis
+        }
+    }
+}
+
+impl ops::Sub<f64> for Double4x4 {
+    type Output = Self;
+    /// Subtracts a scalar value to matrix elements.
+    #[inline]
+    fn sub(self, rhs: f64) -> Self::Output {
+        Double4x4 {
+            x_axis: self.x_axis - rhs, 
+            y_axis: self.y_axis - rhs, 
+            z_axis: self.z_axis - rhs, 
+            w_axis: self.w_axis - rhs 
+        }
+    }
+}
+
+impl ops::SubAssign<f64> for Double4x4 {
+    /// Subtracts a scalar value to matrix elements. (assign)
+    #[inline]
+    fn sub_assign(&mut self, rhs: f64) {
+        *self = *self - rhs
+    }
+}
+
+impl ops::Sub<Self> for Double4x4 {
+    type Output = Self;
+    /// Subtracts two matrices.
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Double4x4 {
+            x_axis: self.x_axis - rhs.x_axis, 
+            y_axis: self.y_axis - rhs.y_axis, 
+            z_axis: self.z_axis - rhs.z_axis, 
+            w_axis: self.w_axis - rhs.w_axis 
+        }
+    }
+}
+
+impl ops::SubAssign<Self> for Double4x4 {
+    /// Subtracts two matrices. (assign)
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs
+    }
+}
+
+impl ops::Neg for Double4x4 {
+    type Output = Self;
+    /// Nagative.
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Double4x4 {
+            x_axis: -self.x_axis, 
+            y_axis: -self.y_axis, 
+            z_axis: -self.z_axis, 
+            w_axis: -self.w_axis 
+        }
+    }
+}
+
+impl ops::Mul<Double4x4> for f64 {
+    type Output = Double4x4;
+    /// Multiplies a matrix elements to scalar value.
+    #[inline]
+    fn mul(self, rhs: Double4x4) -> Self::Output {
+        Double4x4 {
+            x_axis: self * rhs.x_axis, 
+            y_axis: self * rhs.y_axis, 
+            z_axis: self * rhs.z_axis, 
+            w_axis: self * rhs.w_axis 
+        }
+    }
+}
+
+impl ops::Mul<f64> for Double4x4 {
+    type Output = Self;
+    /// Multiplies a scalar value to matrix elements.
+    #[inline]
+    fn mul(self, rhs: f64) -> Self::Output {
+        Double4x4 {
+            x_axis: self.x_axis * rhs, 
+            y_axis: self.y_axis * rhs, 
+            z_axis: self.z_axis * rhs, 
+            w_axis: self.w_axis * rhs 
+        }
+    }
+}
+
+impl ops::MulAssign<f64> for Double4x4 {
+    /// Multiplies a scalar value to matrix elements. (assign)
+    #[inline]
+    fn mul_assign(&mut self, rhs: f64) {
+        *self = *self * rhs
+    }
+}
+
+impl ops::Mul<Double4> for Double4x4 {
+    type Output = Double4;
+    /// Transformation of the vector.
+    fn mul(self, rhs: Double4) -> Self::Output {
+        Double4 {
+            x: self[0][0] * rhs[0] + self[0][1] * rhs[1] + self[0][2] * rhs[2] + self[0][3] * rhs[3], 
+            y: self[1][0] * rhs[0] + self[1][1] * rhs[1] + self[1][2] * rhs[2] + self[1][3] * rhs[3], 
+            z: self[2][0] * rhs[0] + self[2][1] * rhs[1] + self[2][2] * rhs[2] + self[2][3] * rhs[3], 
+            w: self[3][0] * rhs[0] + self[3][1] * rhs[1] + self[3][2] * rhs[2] + self[3][3] * rhs[3]  
+        }
+    }
+}
+
+impl ops::Mul<Self> for Double4x4 {
+    type Output = Self;
+    /// Multiply two matrices.
+    fn mul(self, rhs: Self) -> Self::Output {
+        let mut value = Self::default();
+        for i in 0..4 {
+            for j in 0..4 {
+                let mut sum = 0.0;
+                for k in 0..4 {
+                    sum += self[k][j] * rhs[i][k];
+                }
+                value[i][j] = sum;
+            }
+        }
+        value
+    }
+}
+
+impl ops::MulAssign<Self> for Double4x4 {
+    /// Multiply two matrices. (assign)
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs
+    }
+}
+
+impl fmt::Debug for Double4x4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple(stringify!(Double4x4))
+            .field(&self.x_axis)
+            .field(&self.y_axis)
+            .field(&self.z_axis)
+            .field(&self.w_axis)
+            .finish()
+    }
+}
+
+impl fmt::Display for Double4x4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {}, {}, {}]", &self.x_axis, &self.y_axis, &self.z_axis, &self.w_axis)
+    }
+}
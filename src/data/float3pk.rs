@@ -0,0 +1,157 @@
+use core::fmt;
+use super::float3::Float3;
+
+
+
+/// A structure that stores three-dimensional unsigned floating-point data packed into 32 bits. (11:11:10)
+/// 
+/// ※ The `x` and `y` elements use 11 bits (5-bit exponent, 6-bit mantissa),
+/// and the `z` element uses 10 bits (5-bit exponent, 5-bit mantissa), starting from the least significant bit. </br>
+/// ※ There is no sign bit, so negative values are stored as zero. </br>
+/// ※ Use [`Vector::load_float3pk`](crate::Vector::load_float3pk) and [`Vector::store_float3pk`](crate::Vector::store_float3pk) to convert with the vector type. </br>
+/// 
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Float3PK(u32);
+
+impl Float3PK {
+    /// All elements are zero.
+    pub const ZERO: Self = Self::from_bits(0);
+
+    /// All elements are one.
+    pub const ONE: Self = Self::from_bits(0x3C0 | (0x3C0 << 11) | (0x1E0 << 22));
+}
+
+impl Float3PK {
+    /// Creates from the given raw bits.
+    #[inline]
+    #[must_use]
+    pub const fn from_bits(bits: u32) -> Self {
+        Self(bits)
+    }
+
+    /// Returns the raw bits.
+    #[inline]
+    #[must_use]
+    pub const fn to_bits(self) -> u32 {
+        self.0
+    }
+
+    /// Converts from a single-precision vector.
+    /// 
+    /// ※ Each element is rounded to the nearest representable value, ties to even. </br>
+    /// ※ Negative values become zero and finite values that are too large become the largest finite value. </br>
+    /// ※ Positive infinity and NaN are kept. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn from_float3(val: Float3) -> Self {
+        Self(pack_float(val.x, 6) | (pack_float(val.y, 6) << 11) | (pack_float(val.z, 5) << 22))
+    }
+
+    /// Converts to a single-precision vector. (lossless)
+    #[inline]
+    #[must_use]
+    pub fn to_float3(self) -> Float3 {
+        Float3 {
+            x: unpack_float(self.0 & 0x7FF, 6), 
+            y: unpack_float((self.0 >> 11) & 0x7FF, 6), 
+            z: unpack_float(self.0 >> 22, 5) 
+        }
+    }
+}
+
+impl From<Float3PK> for Float3 {
+    #[inline]
+    fn from(value: Float3PK) -> Self {
+        value.to_float3()
+    }
+}
+
+impl fmt::Debug for Float3PK {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let val = self.to_float3();
+        f.debug_tuple(stringify!(Float3PK))
+            .field(&val.x)
+            .field(&val.y)
+            .field(&val.z)
+            .finish()
+    }
+}
+
+impl fmt::Display for Float3PK {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let val = self.to_float3();
+        write!(f, "{{ {}, {}, {} }}", val.x, val.y, val.z)
+    }
+}
+
+
+
+/// Converts a single-precision floating-point number to an unsigned floating-point number
+/// with a 5-bit exponent and a mantissa of the given number of bits.
+#[inline]
+fn pack_float(value: f32, man_bits: u32) -> u32 {
+    let inf = 0x1F << man_bits;
+    if value.is_nan() {
+        return inf | (1 << (man_bits - 1));
+    }
+
+    let bits = value.to_bits();
+    if bits & 0x8000_0000 != 0 {
+        return 0;
+    }
+
+    if value.is_infinite() {
+        return inf;
+    }
+
+    // Rebias the exponent. (finite overflow becomes the largest finite value)
+    let exp = (bits >> 23) as i32 - 127 + 15;
+    if exp >= 0x1F {
+        return inf - 1;
+    }
+
+    // Denormal numbers include the implicit bit in the mantissa.
+    let (man, shift) = match exp <= 0 {
+        true => ((bits & 0x007F_FFFF) | 0x0080_0000, (24 - man_bits) - exp as u32),
+        false => (bits & 0x007F_FFFF, 23 - man_bits),
+    };
+    if shift > 24 {
+        return 0;
+    }
+
+    // Rounded to nearest even. (the carry may propagate into the exponent)
+    let round_bit = 1 << (shift - 1);
+    let mut packed = ((exp.max(0) as u32) << man_bits) | (man >> shift);
+    if man & round_bit != 0 && man & (3 * round_bit - 1) != 0 {
+        packed += 1;
+    }
+    packed.min(inf - 1)
+}
+
+/// Converts an unsigned floating-point number with a 5-bit exponent
+/// and a mantissa of the given number of bits to a single-precision floating-point number.
+#[inline]
+fn unpack_float(packed: u32, man_bits: u32) -> f32 {
+    let exp = packed >> man_bits;
+    let man = packed & ((1 << man_bits) - 1);
+
+    let bits = match (exp, man) {
+        // Zero.
+        (0, 0) => 0,
+        // Denormal. (normalized in single-precision)
+        (0, _) => {
+            let shift = man.leading_zeros() - (31 - man_bits);
+            ((127 - 15 + 1 - shift) << 23) | (((man << shift) & ((1 << man_bits) - 1)) << (23 - man_bits))
+        },
+        // Infinity.
+        (0x1F, 0) => 0x7F80_0000,
+        // NaN. (becomes a quiet NaN)
+        (0x1F, _) => 0x7FC0_0000 | (man << (23 - man_bits)),
+        // Normal number.
+        _ => ((exp + 127 - 15) << 23) | (man << (23 - man_bits)),
+    };
+    f32::from_bits(bits)
+}
@@ -0,0 +1,262 @@
+use core::fmt;
+use core::ops;
+
+use super::bool4::Boolean4;
+use super::float3::Float3;
+use super::float4::Float4;
+use super::float4x4::Float4x4;
+use crate::near_eq;
+
+
+
+/// A structure that stores 3x4 column major matrix data.
+/// 
+/// ※ It is used to store affine transformations. 
+/// The last row of the matrix is always `(0, 0, 0, 1)` and is not stored. </br>
+/// 
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, PartialEq)]
+pub struct Float3x4 {
+    pub x_axis: Float3,
+    pub y_axis: Float3,
+    pub z_axis: Float3,
+    pub w_axis: Float3,
+}
+
+impl Float3x4 {
+    /// A 3x4 matrix where all elements of the matrix are zero.
+    pub const ZERO: Self = Self::from_columns(Float3::ZERO, Float3::ZERO, Float3::ZERO, Float3::ZERO);
+
+    /// 3x4 identity matrix.
+    pub const IDENTITY: Self = Self::from_columns(Float3::X, Float3::Y, Float3::Z, Float3::ZERO);
+
+    /// Creates a 3x4 matrix with given column vectors.
+    #[inline]
+    #[must_use]
+    pub const fn from_columns(x_axis: Float3, y_axis: Float3, z_axis: Float3, w_axis: Float3) -> Self {
+        Self { x_axis, y_axis, z_axis, w_axis }
+    }
+
+    /// Creates with given array.
+    #[inline]
+    #[must_use]
+    pub fn from_column_array(arr: [f32; 12]) -> Self {
+        Self { 
+            x_axis: Float3::from_slice(&arr[0..3]), 
+            y_axis: Float3::from_slice(&arr[3..6]), 
+            z_axis: Float3::from_slice(&arr[6..9]), 
+            w_axis: Float3::from_slice(&arr[9..12]) 
+        }
+    }
+
+    /// Convert to array.
+    #[inline]
+    #[must_use]
+    pub const fn to_column_array(self) -> [f32; 12] {
+        [
+            self.x_axis.x, self.x_axis.y, self.x_axis.z, 
+            self.y_axis.x, self.y_axis.y, self.y_axis.z, 
+            self.z_axis.x, self.z_axis.y, self.z_axis.z, 
+            self.w_axis.x, self.w_axis.y, self.w_axis.z 
+        ]
+    }
+
+    /// Creates with given slice.
+    /// 
+    /// # Panics
+    /// If the length of the given array is less than the number of elements in the matrix,
+    /// an index out of range error occurs.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn from_column_slice(slice: &[f32]) -> Self {
+        Self { 
+            x_axis: Float3::from_slice(&slice[0..3]), 
+            y_axis: Float3::from_slice(&slice[3..6]), 
+            z_axis: Float3::from_slice(&slice[6..9]), 
+            w_axis: Float3::from_slice(&slice[9..12]) 
+        }
+    }
+
+    /// Creates with given tuple.
+    #[inline]
+    #[must_use]
+    pub const fn from_column_tuple(tuple: (Float3, Float3, Float3, Float3)) -> Self {
+        Self { 
+            x_axis: tuple.0, 
+            y_axis: tuple.1, 
+            z_axis: tuple.2, 
+            w_axis: tuple.3 
+        }
+    }
+
+    /// Convert to tuple.
+    #[inline]
+    #[must_use]
+    pub const fn to_column_tuple(self) -> (Float3, Float3, Float3, Float3) {
+        (self.x_axis, self.y_axis, self.z_axis, self.w_axis)
+    }
+
+    /// Returns `true` if at least one element of the matrix is [`f32::NAN`].
+    #[inline]
+    pub fn is_nan(&self) -> bool {
+        Boolean4 {
+            x: self.x_axis.is_nan(),
+            y: self.y_axis.is_nan(),
+            z: self.z_axis.is_nan(),
+            w: self.w_axis.is_nan()
+        }.any()
+    }
+
+    /// Returns `true` if at least one element of the matrix is [`f32::INFINITY`].
+    #[inline]
+    pub fn is_infinite(&self) -> bool {
+        Boolean4 {
+            x: self.x_axis.is_infinite(),
+            y: self.y_axis.is_infinite(),
+            z: self.z_axis.is_infinite(),
+            w: self.w_axis.is_infinite()
+        }.any()
+    }
+
+    /// Returns `true` if the absolute difference of all elements is less than or equal to `max_abs_diff`.
+    #[inline]
+    #[must_use]
+    pub fn abs_diff_eq(self, rhs: Self, max_abs_diff: f32) -> bool {
+        near_eq::abs_diff_eq(&self.to_column_array(), &rhs.to_column_array(), max_abs_diff)
+    }
+
+    /// Returns `true` if all elements are equal within `max_abs_diff` or the relative tolerance `max_relative`.
+    /// 
+    /// ※ The relative tolerance is scaled by the larger absolute value of the two elements. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn relative_eq(self, rhs: Self, max_abs_diff: f32, max_relative: f32) -> bool {
+        near_eq::relative_eq(&self.to_column_array(), &rhs.to_column_array(), max_abs_diff, max_relative)
+    }
+
+    /// Returns `true` if all elements are equal within `max_abs_diff` or `max_ulps` representable values.
+    /// 
+    /// ※ Elements with different signs are equal only within `max_abs_diff`. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn ulps_eq(self, rhs: Self, max_abs_diff: f32, max_ulps: u32) -> bool {
+        near_eq::ulps_eq(&self.to_column_array(), &rhs.to_column_array(), max_abs_diff, max_ulps)
+    }
+}
+
+impl Default for Float3x4 {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl From<Float4x4> for Float3x4 {
+    /// Drops the last row of the matrix.
+    #[inline]
+    fn from(value: Float4x4) -> Self {
+        Self { 
+            x_axis: Float3::from(value.x_axis), 
+            y_axis: Float3::from(value.y_axis), 
+            z_axis: Float3::from(value.z_axis), 
+            w_axis: Float3::from(value.w_axis) 
+        }
+    }
+}
+
+impl From<Float3x4> for Float4x4 {
+    /// The last row of the matrix is set to `(0, 0, 0, 1)`.
+    #[inline]
+    fn from(value: Float3x4) -> Self {
+        Self { 
+            x_axis: Float4::from(value.x_axis), 
+            y_axis: Float4::from(value.y_axis), 
+            z_axis: Float4::from(value.z_axis), 
+            w_axis: Float4 { w: 1.0, ..Float4::from(value.w_axis) } 
+        }
+    }
+}
+
+impl From<[f32; 12]> for Float3x4 {
+    #[inline]
+    fn from(value: [f32; 12]) -> Self {
+        Self::from_column_array(value)
+    }
+}
+
+impl From<Float3x4> for [f32; 12] {
+    #[inline]
+    fn from(value: Float3x4) -> Self {
+        value.to_column_array()
+    }
+}
+
+impl From<(Float3, Float3, Float3, Float3)> for Float3x4 {
+    #[inline]
+    fn from(value: (Float3, Float3, Float3, Float3)) -> Self {
+        Self::from_column_tuple(value)
+    }
+}
+
+impl From<Float3x4> for (Float3, Float3, Float3, Float3) {
+    #[inline]
+    fn from(value: Float3x4) -> Self {
+        value.to_column_tuple()
+    }
+}
+
+impl AsRef<[f32; 12]> for Float3x4 {
+    #[inline]
+    fn as_ref(&self) -> &[f32; 12] {
+        unsafe { &*(self as *const Self as *const [f32; 12]) }
+    }
+}
+
+impl AsMut<[f32; 12]> for Float3x4 {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [f32; 12] {
+        unsafe { &mut *(self as *mut Self as *mut [f32; 12]) }
+    }
+}
+
+impl ops::Index<usize> for Float3x4 {
+    type Output = Float3;
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x_axis,
+            1 => &self.y_axis,
+            2 => &self.z_axis,
+            3 => &self.w_axis,
+            _ => panic!("index out of range!")
+        }
+    }
+}
+
+impl ops::IndexMut<usize> for Float3x4 {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => &mut self.x_axis,
+            1 => &mut self.y_axis,
+            2 => &mut self.z_axis,
+            3 => &mut self.w_axis,
+            _ => panic!("index out of range!")
+        }
+    }
+}
+
+impl fmt::Debug for Float3x4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple(stringify!(Float3x4))
+            .field(&self.x_axis)
+            .field(&self.y_axis)
+            .field(&self.z_axis)
+            .field(&self.w_axis)
+            .finish()
+    }
+}
@@ -1,8 +1,13 @@
 use crate::macros::impl_element4;
 use crate::macros::impl_element4_op;
+use crate::near_eq;
 use super::bool4::Boolean4;
 use super::float2::Float2;
 use super::float3::Float3;
+use super::double4::Double4;
+
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
 
 
 
@@ -87,6 +92,33 @@ impl Float4 {
             w: self.w.is_infinite(),
         }.any()
     }
+
+    /// Returns `true` if the absolute difference of all elements is less than or equal to `max_abs_diff`.
+    #[inline]
+    #[must_use]
+    pub fn abs_diff_eq(self, rhs: Self, max_abs_diff: f32) -> bool {
+        near_eq::abs_diff_eq(&self.to_array(), &rhs.to_array(), max_abs_diff)
+    }
+
+    /// Returns `true` if all elements are equal within `max_abs_diff` or the relative tolerance `max_relative`.
+    /// 
+    /// ※ The relative tolerance is scaled by the larger absolute value of the two elements. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn relative_eq(self, rhs: Self, max_abs_diff: f32, max_relative: f32) -> bool {
+        near_eq::relative_eq(&self.to_array(), &rhs.to_array(), max_abs_diff, max_relative)
+    }
+
+    /// Returns `true` if all elements are equal within `max_abs_diff` or `max_ulps` representable values.
+    /// 
+    /// ※ Elements with different signs are equal only within `max_abs_diff`. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn ulps_eq(self, rhs: Self, max_abs_diff: f32, max_ulps: u32) -> bool {
+        near_eq::ulps_eq(&self.to_array(), &rhs.to_array(), max_abs_diff, max_ulps)
+    }
 }
 
 // Vector swizzle code implementation.
@@ -1773,10 +1805,19 @@ impl Float4 {
     }
 }
 
-impl_element4!(f32, Float4);
+impl_element4!(f32, Float4, float);
 
 impl_element4_op!(f32, Float4);
 
+impl Float4 {
+    /// Converts to a double-precision vector.
+    #[inline]
+    #[must_use]
+    pub const fn as_f64(self) -> Double4 {
+        Double4 { x: self.x as f64, y: self.y as f64, z: self.z as f64, w: self.w as f64 }
+    }
+}
+
 impl Default for Float4 {
     #[inline(always)]
     fn default() -> Self {
@@ -4,6 +4,8 @@ use core::ops;
 use super::bool4::Boolean4;
 use super::float3x3::Float3x3;
 use super::float4::Float4;
+use super::double4x4::Double4x4;
+use crate::near_eq;
 
 
 
@@ -113,6 +115,47 @@ impl Float4x4 {
             w: self.w_axis.is_infinite()
         }.any()
     }
+
+    /// Returns `true` if the absolute difference of all elements is less than or equal to `max_abs_diff`.
+    #[inline]
+    #[must_use]
+    pub fn abs_diff_eq(self, rhs: Self, max_abs_diff: f32) -> bool {
+        near_eq::abs_diff_eq(&self.to_column_array(), &rhs.to_column_array(), max_abs_diff)
+    }
+
+    /// Returns `true` if all elements are equal within `max_abs_diff` or the relative tolerance `max_relative`.
+    /// 
+    /// ※ The relative tolerance is scaled by the larger absolute value of the two elements. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn relative_eq(self, rhs: Self, max_abs_diff: f32, max_relative: f32) -> bool {
+        near_eq::relative_eq(&self.to_column_array(), &rhs.to_column_array(), max_abs_diff, max_relative)
+    }
+
+    /// Returns `true` if all elements are equal within `max_abs_diff` or `max_ulps` representable values.
+    /// 
+    /// ※ Elements with different signs are equal only within `max_abs_diff`. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn ulps_eq(self, rhs: Self, max_abs_diff: f32, max_ulps: u32) -> bool {
+        near_eq::ulps_eq(&self.to_column_array(), &rhs.to_column_array(), max_abs_diff, max_ulps)
+    }
+}
+
+impl Float4x4 {
+    /// Converts to a double-precision matrix.
+    #[inline]
+    #[must_use]
+    pub fn as_f64(self) -> Double4x4 {
+        Double4x4 {
+            x_axis: self.x_axis.as_f64(), 
+            y_axis: self.y_axis.as_f64(), 
+            z_axis: self.z_axis.as_f64(), 
+            w_axis: self.w_axis.as_f64() 
+        }
+    }
 }
 
 impl Default for Float4x4 {
@@ -0,0 +1,304 @@
+use core::fmt;
+use core::cmp;
+use core::ops;
+use crate::Vector;
+use super::half4::Half4;
+
+
+
+/// A structure that stores a half-precision floating-point number. (IEEE 754 `binary16`)
+///
+/// ※ This is a storage type, so there are no arithmetic operations. </br>
+/// ※ To perform calculations, conversion to `f32` or vector type is required. </br>
+///
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Default)]
+pub struct Half(u16);
+
+impl Half {
+    /// Positive zero.
+    pub const ZERO: Self = Self::from_bits(0x0000);
+
+    /// Negative zero.
+    pub const NEG_ZERO: Self = Self::from_bits(0x8000);
+
+    /// One.
+    pub const ONE: Self = Self::from_bits(0x3C00);
+
+    /// Negative one.
+    pub const NEG_ONE: Self = Self::from_bits(0xBC00);
+
+    /// Largest finite value. (`65504`)
+    pub const MAX: Self = Self::from_bits(0x7BFF);
+
+    /// Smallest finite value. (`-65504`)
+    pub const MIN: Self = Self::from_bits(0xFBFF);
+
+    /// Smallest positive normal value. (`2⁻¹⁴`)
+    pub const MIN_POSITIVE: Self = Self::from_bits(0x0400);
+
+    /// Smallest positive denormal value. (`2⁻²⁴`)
+    pub const MIN_POSITIVE_SUBNORMAL: Self = Self::from_bits(0x0001);
+
+    /// Machine epsilon value. (`2⁻¹⁰`)
+    pub const EPSILON: Self = Self::from_bits(0x1400);
+
+    /// Not a Number. (NaN)
+    pub const NAN: Self = Self::from_bits(0x7E00);
+
+    /// Positive infinity.
+    pub const INFINITY: Self = Self::from_bits(0x7C00);
+
+    /// Negative infinity.
+    pub const NEG_INFINITY: Self = Self::from_bits(0xFC00);
+}
+
+impl Half {
+    /// Creates from the given raw bits.
+    #[inline]
+    #[must_use]
+    pub const fn from_bits(bits: u16) -> Self {
+        Self(bits)
+    }
+
+    /// Returns the raw bits.
+    #[inline]
+    #[must_use]
+    pub const fn to_bits(self) -> u16 {
+        self.0
+    }
+
+    /// Converts a single-precision floating-point number to a half-precision floating-point number.
+    ///
+    /// ※ The value is rounded to the nearest representable value, ties to even. </br>
+    /// ※ Values that are too large become infinity, and values that are too small become denormal or zero. </br>
+    /// ※ NaN is kept as a quiet NaN with the upper bits of the payload. </br>
+    ///
+    #[inline]
+    #[must_use]
+    pub fn from_f32(value: f32) -> Self {
+        Self(f32_to_f16(value))
+    }
+
+    /// Converts to a single-precision floating-point number. (lossless)
+    #[inline]
+    #[must_use]
+    pub fn to_f32(self) -> f32 {
+        f16_to_f32(self.0)
+    }
+
+    /// Returns `true` if the value is NaN.
+    #[inline]
+    #[must_use]
+    pub const fn is_nan(self) -> bool {
+        self.0 & 0x7FFF > 0x7C00
+    }
+
+    /// Returns `true` if the value is positive infinity or negative infinity.
+    #[inline]
+    #[must_use]
+    pub const fn is_infinite(self) -> bool {
+        self.0 & 0x7FFF == 0x7C00
+    }
+
+    /// Returns `true` if the value is neither infinite nor NaN.
+    #[inline]
+    #[must_use]
+    pub const fn is_finite(self) -> bool {
+        self.0 & 0x7C00 != 0x7C00
+    }
+
+    /// Returns `true` if the sign bit is set. (including negative zero)
+    #[inline]
+    #[must_use]
+    pub const fn is_sign_negative(self) -> bool {
+        self.0 & 0x8000 != 0
+    }
+
+    /// Converts the `f32` elements of the `src` slice and stores them in the `dst` slice.
+    ///
+    /// ※ Four elements are converted at a time using [`Vector::store_half4`]. </br>
+    /// ※ If the lengths are different, only the shorter length is converted. </br>
+    ///
+    /// # Panics
+    /// When the `use-assertion` feature is enabled, it will [`panic!`]
+    /// if the `src` and `dst` slices have different lengths.
+    ///
+    #[inline]
+    pub fn convert_from_f32_slice(src: &[f32], dst: &mut [Half]) {
+        #[cfg(feature = "use-assertion")]
+        assert!(src.len() == dst.len(), "The lengths of the `src` and `dst` slices are different!");
+
+        let len = src.len().min(dst.len());
+        let src_chunks = src[..len].chunks(4);
+        let dst_chunks = dst[..len].chunks_mut(4);
+        for (s, d) in src_chunks.zip(dst_chunks) {
+            let mut arr = [0.0; 4];
+            arr[..s.len()].copy_from_slice(s);
+            let arr = Vector::from_array(arr).store_half4().to_array();
+            d.copy_from_slice(&arr[..d.len()]);
+        }
+    }
+
+    /// Converts the elements of the `src` slice to `f32` and stores them in the `dst` slice.
+    ///
+    /// ※ Four elements are converted at a time using [`Vector::load_half4`]. </br>
+    /// ※ If the lengths are different, only the shorter length is converted. </br>
+    ///
+    /// # Panics
+    /// When the `use-assertion` feature is enabled, it will [`panic!`]
+    /// if the `src` and `dst` slices have different lengths.
+    ///
+    #[inline]
+    pub fn convert_to_f32_slice(src: &[Half], dst: &mut [f32]) {
+        #[cfg(feature = "use-assertion")]
+        assert!(src.len() == dst.len(), "The lengths of the `src` and `dst` slices are different!");
+
+        let len = src.len().min(dst.len());
+        let src_chunks = src[..len].chunks(4);
+        let dst_chunks = dst[..len].chunks_mut(4);
+        for (s, d) in src_chunks.zip(dst_chunks) {
+            let mut arr = [Half::ZERO; 4];
+            arr[..s.len()].copy_from_slice(s);
+            let arr = Vector::load_half4(Half4::from_array(arr)).into_array();
+            d.copy_from_slice(&arr[..d.len()]);
+        }
+    }
+}
+
+impl PartialEq for Half {
+    /// Compares as floating-point numbers.
+    ///
+    /// ※ NaN is not equal to any value, and positive zero is equal to negative zero. </br>
+    ///
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        if self.is_nan() || other.is_nan() {
+            return false;
+        }
+        self.0 == other.0 || (self.0 | other.0) & 0x7FFF == 0
+    }
+}
+
+impl PartialOrd for Half {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        self.to_f32().partial_cmp(&other.to_f32())
+    }
+}
+
+impl ops::Neg for Half {
+    type Output = Self;
+    /// Flips the sign bit.
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self(self.0 ^ 0x8000)
+    }
+}
+
+impl From<Half> for f32 {
+    #[inline]
+    fn from(value: Half) -> Self {
+        value.to_f32()
+    }
+}
+
+impl From<Half> for f64 {
+    #[inline]
+    fn from(value: Half) -> Self {
+        value.to_f32() as f64
+    }
+}
+
+impl fmt::Debug for Half {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.to_f32(), f)
+    }
+}
+
+impl fmt::Display for Half {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.to_f32(), f)
+    }
+}
+
+
+
+/// Converts the bits of a single-precision floating-point number to the bits of a half-precision floating-point number.
+///
+/// ※ This is the portable path used when the `f16c` instruction is not available. </br>
+///
+#[inline]
+pub(crate) fn f32_to_f16(value: f32) -> u16 {
+    let bits = value.to_bits();
+    let sign = (bits >> 16) as u16 & 0x8000;
+    let exp = (bits >> 23) & 0xFF;
+    let man = bits & 0x007F_FFFF;
+
+    // Infinity or NaN. (NaN keeps the upper bits of the payload and becomes a quiet NaN)
+    if exp == 0xFF {
+        let nan = if man != 0 { 0x0200 } else { 0 };
+        return sign | 0x7C00 | nan | (man >> 13) as u16;
+    }
+
+    // Rebias the exponent. (overflow becomes infinity)
+    let half_exp = exp as i32 - 127 + 15;
+    if half_exp >= 0x1F {
+        return sign | 0x7C00;
+    }
+
+    // Denormal or zero.
+    if half_exp <= 0 {
+        let shift = (14 - half_exp) as u32;
+        if shift > 24 {
+            return sign;
+        }
+
+        let man = man | 0x0080_0000;
+        let round_bit = 1 << (shift - 1);
+        let mut half_man = man >> shift;
+        if man & round_bit != 0 && man & (3 * round_bit - 1) != 0 {
+            half_man += 1;
+        }
+        return sign | half_man as u16;
+    }
+
+    // Normal number, rounded to nearest even. (the carry may propagate into the exponent)
+    let round_bit = 0x0000_1000;
+    let mut half = ((half_exp as u32) << 10) | (man >> 13);
+    if man & round_bit != 0 && man & (3 * round_bit - 1) != 0 {
+        half += 1;
+    }
+    sign | half as u16
+}
+
+/// Converts the bits of a half-precision floating-point number to a single-precision floating-point number.
+///
+/// ※ This is the portable path used when the `f16c` instruction is not available. </br>
+///
+#[inline]
+pub(crate) fn f16_to_f32(bits: u16) -> f32 {
+    let sign = (bits as u32 & 0x8000) << 16;
+    let exp = (bits as u32 >> 10) & 0x1F;
+    let man = bits as u32 & 0x03FF;
+
+    let bits = match (exp, man) {
+        // Zero.
+        (0, 0) => sign,
+        // Denormal. (normalized in single-precision)
+        (0, _) => {
+            let shift = man.leading_zeros() - 21;
+            sign | ((127 - 15 + 1 - shift) << 23) | (((man << shift) & 0x03FF) << 13)
+        },
+        // Infinity.
+        (0x1F, 0) => sign | 0x7F80_0000,
+        // NaN. (becomes a quiet NaN)
+        (0x1F, _) => sign | 0x7FC0_0000 | (man << 13),
+        // Normal number.
+        _ => sign | ((exp + 127 - 15) << 23) | (man << 13),
+    };
+    f32::from_bits(bits)
+}
@@ -0,0 +1,53 @@
+use crate::macros::impl_element2;
+use super::half::Half;
+use super::float2::Float2;
+
+
+
+/// A structure that stores two-dimensional half-precision vector data.
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Default, PartialEq)]
+pub struct Half2 {
+    pub x: Half,
+    pub y: Half,
+}
+
+impl Half2 {
+    /// All elements are zero.
+    pub const ZERO: Self = Self::fill(Half::ZERO);
+
+    /// All elements are one.
+    pub const ONE: Self = Self::fill(Half::ONE);
+
+    /// All elements are negative one.
+    pub const NEG_ONE: Self = Self::fill(Half::NEG_ONE);
+}
+
+impl_element2!(Half, Half2);
+
+impl Half2 {
+    /// Converts from a single-precision vector.
+    /// 
+    /// ※ Each element is rounded to the nearest representable value, ties to even. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn from_float2(val: Float2) -> Self {
+        Self { x: Half::from_f32(val.x), y: Half::from_f32(val.y) }
+    }
+
+    /// Converts to a single-precision vector. (lossless)
+    #[inline]
+    #[must_use]
+    pub fn to_float2(self) -> Float2 {
+        Float2 { x: self.x.to_f32(), y: self.y.to_f32() }
+    }
+}
+
+impl From<Half2> for Float2 {
+    #[inline]
+    fn from(value: Half2) -> Self {
+        value.to_float2()
+    }
+}
@@ -0,0 +1,84 @@
+use crate::macros::impl_element4;
+use super::half::Half;
+use super::half2::Half2;
+use super::float4::Float4;
+
+
+
+/// A structure that stores four-dimensional half-precision vector data.
+/// 
+/// ※ Use [`Vector::load_half4`](crate::Vector::load_half4) and [`Vector::store_half4`](crate::Vector::store_half4) to convert with the vector type. </br>
+/// 
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Default, PartialEq)]
+pub struct Half4 {
+    pub x: Half,
+    pub y: Half,
+    pub z: Half,
+    pub w: Half,
+}
+
+impl Half4 {
+    /// All elements are zero.
+    pub const ZERO: Self = Self::fill(Half::ZERO);
+
+    /// All elements are one.
+    pub const ONE: Self = Self::fill(Half::ONE);
+
+    /// All elements are negative one.
+    pub const NEG_ONE: Self = Self::fill(Half::NEG_ONE);
+}
+
+impl_element4!(Half, Half4);
+
+impl Half4 {
+    /// Converts from a single-precision vector.
+    /// 
+    /// ※ Each element is rounded to the nearest representable value, ties to even. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn from_float4(val: Float4) -> Self {
+        Self {
+            x: Half::from_f32(val.x), 
+            y: Half::from_f32(val.y), 
+            z: Half::from_f32(val.z), 
+            w: Half::from_f32(val.w) 
+        }
+    }
+
+    /// Converts to a single-precision vector. (lossless)
+    #[inline]
+    #[must_use]
+    pub fn to_float4(self) -> Float4 {
+        Float4 {
+            x: self.x.to_f32(), 
+            y: self.y.to_f32(), 
+            z: self.z.to_f32(), 
+            w: self.w.to_f32() 
+        }
+    }
+
+    /// Returns the `x` and `y` elements.
+    #[inline]
+    #[must_use]
+    pub const fn xy(self) -> Half2 {
+        Half2 { x: self.x, y: self.y }
+    }
+}
+
+impl From<Half2> for Half4 {
+    /// The `z` and `w` elements are zero.
+    #[inline]
+    fn from(value: Half2) -> Self {
+        Self { x: value.x, y: value.y, z: Half::ZERO, w: Half::ZERO }
+    }
+}
+
+impl From<Half4> for Float4 {
+    #[inline]
+    fn from(value: Half4) -> Self {
+        value.to_float4()
+    }
+}
@@ -22,9 +22,50 @@ pub use self::float4::Float4;
 
 mod float3x3;
 pub use self::float3x3::Float3x3;
+mod float3x4;
+pub use self::float3x4::Float3x4;
 mod float4x4;
 pub use self::float4x4::Float4x4;
 
+mod double2;
+pub use self::double2::Double2;
+mod double3;
+pub use self::double3::Double3;
+mod double4;
+pub use self::double4::Double4;
+
+mod double4x4;
+pub use self::double4x4::Double4x4;
+
+mod half;
+pub use self::half::Half;
+mod half2;
+pub use self::half2::Half2;
+mod half4;
+pub use self::half4::Half4;
+
+mod ubyten4;
+pub use self::ubyten4::UByteN4;
+mod byten4;
+pub use self::byten4::ByteN4;
+mod shortn2;
+pub use self::shortn2::ShortN2;
+mod ushortn4;
+pub use self::ushortn4::UShortN4;
+mod udecn4;
+pub use self::udecn4::UDecN4;
+mod float3pk;
+pub use self::float3pk::Float3PK;
+mod rgb9e5;
+pub use self::rgb9e5::Rgb9e5;
+
+mod aabb_data;
+pub use self::aabb_data::AabbData;
+mod bounding_sphere_data;
+pub use self::bounding_sphere_data::BoundingSphereData;
+mod oriented_box_data;
+pub use self::oriented_box_data::OrientedBoxData;
+
 mod int2;
 pub use self::int2::Integer2;
 mod int3;
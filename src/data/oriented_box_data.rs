@@ -0,0 +1,40 @@
+use super::float3::Float3;
+use super::float4::Float4;
+
+
+
+/// A structure that stores oriented bounding box data.
+///
+/// ※ The orientation is stored as a quaternion in the order of `x`, `y`, `z`, `w`. </br>
+/// ※ Use [`OrientedBox::load_data`](crate::OrientedBox::load_data) and [`OrientedBox::store_data`](crate::OrientedBox::store_data) to convert with the geometry type. </br>
+///
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct OrientedBoxData {
+    /// The center of the box.
+    pub center: Float3,
+
+    /// The half-lengths of the box along each local axis.
+    pub extents: Float3,
+
+    /// The rotation from the local axes of the box to the world axes.
+    pub orientation: Float4,
+}
+
+impl OrientedBoxData {
+    /// Creates with the given center, extents and orientation.
+    #[inline]
+    #[must_use]
+    pub const fn new(center: Float3, extents: Float3, orientation: Float4) -> Self {
+        Self { center, extents, orientation }
+    }
+}
+
+impl Default for OrientedBoxData {
+    /// Zero center, zero extents and identity orientation.
+    #[inline]
+    fn default() -> Self {
+        Self { center: Float3::ZERO, extents: Float3::ZERO, orientation: Float4::W }
+    }
+}
@@ -0,0 +1,127 @@
+use core::fmt;
+use super::float3::Float3;
+
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+
+
+
+/// Number of mantissa bits of each element.
+const MANTISSA_BITS: i32 = 9;
+
+/// Exponent bias of the shared exponent.
+const EXP_BIAS: i32 = 15;
+
+/// Largest representable value. (`65408`)
+const MAX_VALUE: f32 = 65408.0;
+
+
+
+/// A structure that stores three-dimensional unsigned floating-point data with a shared exponent packed into 32 bits. (RGB9E5)
+/// 
+/// ※ The `x`, `y` and `z` elements use 9-bit mantissas and share a 5-bit exponent, starting from the least significant bit. </br>
+/// ※ The encoding follows the `EXT_texture_shared_exponent` specification. </br>
+/// ※ Use [`Vector::load_rgb9e5`](crate::Vector::load_rgb9e5) and [`Vector::store_rgb9e5`](crate::Vector::store_rgb9e5) to convert with the vector type. </br>
+/// 
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Rgb9e5(u32);
+
+impl Rgb9e5 {
+    /// All elements are zero.
+    pub const ZERO: Self = Self::from_bits(0);
+
+    /// All elements are one.
+    pub const ONE: Self = Self::from_bits(0x100 | (0x100 << 9) | (0x100 << 18) | (16 << 27));
+
+    /// All elements are the largest representable value. (`65408`)
+    pub const MAX: Self = Self::from_bits(u32::MAX);
+}
+
+impl Rgb9e5 {
+    /// Creates from the given raw bits.
+    #[inline]
+    #[must_use]
+    pub const fn from_bits(bits: u32) -> Self {
+        Self(bits)
+    }
+
+    /// Returns the raw bits.
+    #[inline]
+    #[must_use]
+    pub const fn to_bits(self) -> u32 {
+        self.0
+    }
+
+    /// Converts from a single-precision vector.
+    /// 
+    /// ※ Each element is clamped to the range `[0, 65408]`. (NaN becomes zero) </br>
+    /// ※ The shared exponent is chosen from the largest element, and the mantissas are rounded to the nearest integer. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn from_float3(val: Float3) -> Self {
+        let clamp = |e: f32| if e > 0.0 { e.min(MAX_VALUE) } else { 0.0 };
+        let (r, g, b) = (clamp(val.x), clamp(val.y), clamp(val.z));
+        let max = r.max(g).max(b);
+
+        // `floor(log2(max))` is the unbiased exponent of the largest element.
+        let exp = ((max.to_bits() >> 23) as i32 - 127).max(-EXP_BIAS - 1) + 1 + EXP_BIAS;
+        let exp = match (max * exp2(MANTISSA_BITS + EXP_BIAS - exp)).round() as i32 == 1 << MANTISSA_BITS {
+            true => exp + 1,
+            false => exp,
+        };
+
+        let scale = exp2(MANTISSA_BITS + EXP_BIAS - exp);
+        let r = (r * scale).round() as u32;
+        let g = (g * scale).round() as u32;
+        let b = (b * scale).round() as u32;
+        Self(r | (g << 9) | (b << 18) | ((exp as u32) << 27))
+    }
+
+    /// Converts to a single-precision vector. (lossless)
+    #[inline]
+    #[must_use]
+    pub fn to_float3(self) -> Float3 {
+        let scale = exp2((self.0 >> 27) as i32 - EXP_BIAS - MANTISSA_BITS);
+        Float3 {
+            x: (self.0 & 0x1FF) as f32 * scale, 
+            y: ((self.0 >> 9) & 0x1FF) as f32 * scale, 
+            z: ((self.0 >> 18) & 0x1FF) as f32 * scale 
+        }
+    }
+}
+
+impl From<Rgb9e5> for Float3 {
+    #[inline]
+    fn from(value: Rgb9e5) -> Self {
+        value.to_float3()
+    }
+}
+
+impl fmt::Debug for Rgb9e5 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let val = self.to_float3();
+        f.debug_tuple(stringify!(Rgb9e5))
+            .field(&val.x)
+            .field(&val.y)
+            .field(&val.z)
+            .finish()
+    }
+}
+
+impl fmt::Display for Rgb9e5 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let val = self.to_float3();
+        write!(f, "{{ {}, {}, {} }}", val.x, val.y, val.z)
+    }
+}
+
+
+
+/// Returns `2^exp` for an exponent in the normal range of single-precision floating-point numbers.
+#[inline]
+fn exp2(exp: i32) -> f32 {
+    f32::from_bits(((exp + 127) as u32) << 23)
+}
@@ -0,0 +1,68 @@
+use crate::macros::impl_element2;
+use super::float2::Float2;
+
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+
+
+
+/// A structure that stores two-dimensional signed normalized 16-bit integer data.
+/// 
+/// ※ Each element maps the range `[-1, 1]` to the full range of the integer. </br>
+/// ※ Use [`Vector::load_shortn2`](crate::Vector::load_shortn2) and [`Vector::store_shortn2`](crate::Vector::store_shortn2) to convert with the vector type. </br>
+/// 
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub struct ShortN2 {
+    pub x: i16,
+    pub y: i16,
+}
+
+impl ShortN2 {
+    /// All elements are zero.
+    pub const ZERO: Self = Self::fill(0);
+
+    /// All elements are one. (`i16::MAX`)
+    pub const ONE: Self = Self::fill(i16::MAX);
+
+    /// All elements are negative one. (`-i16::MAX`)
+    pub const NEG_ONE: Self = Self::fill(-i16::MAX);
+}
+
+impl_element2!(i16, ShortN2);
+
+impl ShortN2 {
+    /// Converts from a single-precision vector.
+    /// 
+    /// ※ Each element is saturated to the range `[-1, 1]` and rounded to the nearest integer. (NaN becomes zero) </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn from_float2(val: Float2) -> Self {
+        Self {
+            x: (val.x.clamp(-1.0, 1.0) * 32767.0).round() as i16, 
+            y: (val.y.clamp(-1.0, 1.0) * 32767.0).round() as i16 
+        }
+    }
+
+    /// Converts to a single-precision vector.
+    /// 
+    /// ※ Both `i16::MIN` and `-i16::MAX` become negative one. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn to_float2(self) -> Float2 {
+        Float2 {
+            x: (self.x as f32 / 32767.0).max(-1.0), 
+            y: (self.y as f32 / 32767.0).max(-1.0) 
+        }
+    }
+}
+
+impl From<ShortN2> for Float2 {
+    #[inline]
+    fn from(value: ShortN2) -> Self {
+        value.to_float2()
+    }
+}
@@ -0,0 +1,68 @@
+use crate::macros::impl_element4;
+use super::float4::Float4;
+
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+
+
+
+/// A structure that stores four-dimensional unsigned normalized 8-bit integer data.
+/// 
+/// ※ Each element maps the range `[0, 1]` to the full range of the integer. </br>
+/// ※ Use [`Vector::load_ubyten4`](crate::Vector::load_ubyten4) and [`Vector::store_ubyten4`](crate::Vector::store_ubyten4) to convert with the vector type. </br>
+/// 
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub struct UByteN4 {
+    pub x: u8,
+    pub y: u8,
+    pub z: u8,
+    pub w: u8,
+}
+
+impl UByteN4 {
+    /// All elements are zero.
+    pub const ZERO: Self = Self::fill(0);
+
+    /// All elements are one. (`u8::MAX`)
+    pub const ONE: Self = Self::fill(u8::MAX);
+}
+
+impl_element4!(u8, UByteN4);
+
+impl UByteN4 {
+    /// Converts from a single-precision vector.
+    /// 
+    /// ※ Each element is saturated to the range `[0, 1]` and rounded to the nearest integer. (NaN becomes zero) </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn from_float4(val: Float4) -> Self {
+        Self {
+            x: (val.x.clamp(0.0, 1.0) * 255.0).round() as u8, 
+            y: (val.y.clamp(0.0, 1.0) * 255.0).round() as u8, 
+            z: (val.z.clamp(0.0, 1.0) * 255.0).round() as u8, 
+            w: (val.w.clamp(0.0, 1.0) * 255.0).round() as u8 
+        }
+    }
+
+    /// Converts to a single-precision vector.
+    #[inline]
+    #[must_use]
+    pub fn to_float4(self) -> Float4 {
+        Float4 {
+            x: self.x as f32 / 255.0, 
+            y: self.y as f32 / 255.0, 
+            z: self.z as f32 / 255.0, 
+            w: self.w as f32 / 255.0 
+        }
+    }
+}
+
+impl From<UByteN4> for Float4 {
+    #[inline]
+    fn from(value: UByteN4) -> Self {
+        value.to_float4()
+    }
+}
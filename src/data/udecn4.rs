@@ -0,0 +1,130 @@
+use core::fmt;
+use super::float4::Float4;
+
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+
+
+
+/// A structure that stores four-dimensional unsigned normalized data packed into 32 bits. (10:10:10:2)
+/// 
+/// ※ The `x`, `y` and `z` elements use 10 bits and the `w` element uses 2 bits, starting from the least significant bit. </br>
+/// ※ Use [`Vector::load_udecn4`](crate::Vector::load_udecn4) and [`Vector::store_udecn4`](crate::Vector::store_udecn4) to convert with the vector type. </br>
+/// 
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct UDecN4(u32);
+
+impl UDecN4 {
+    /// All elements are zero.
+    pub const ZERO: Self = Self::from_bits(0);
+
+    /// All elements are one.
+    pub const ONE: Self = Self::from_bits(u32::MAX);
+}
+
+impl UDecN4 {
+    /// Creates with given elements.
+    /// 
+    /// ※ The upper bits of the elements that do not fit are discarded. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub const fn new(x: u32, y: u32, z: u32, w: u32) -> Self {
+        Self((x & 0x3FF) | ((y & 0x3FF) << 10) | ((z & 0x3FF) << 20) | ((w & 0x3) << 30))
+    }
+
+    /// Creates from the given raw bits.
+    #[inline]
+    #[must_use]
+    pub const fn from_bits(bits: u32) -> Self {
+        Self(bits)
+    }
+
+    /// Returns the raw bits.
+    #[inline]
+    #[must_use]
+    pub const fn to_bits(self) -> u32 {
+        self.0
+    }
+
+    /// Returns the `x` element. (10 bits)
+    #[inline]
+    #[must_use]
+    pub const fn x(self) -> u32 {
+        self.0 & 0x3FF
+    }
+
+    /// Returns the `y` element. (10 bits)
+    #[inline]
+    #[must_use]
+    pub const fn y(self) -> u32 {
+        (self.0 >> 10) & 0x3FF
+    }
+
+    /// Returns the `z` element. (10 bits)
+    #[inline]
+    #[must_use]
+    pub const fn z(self) -> u32 {
+        (self.0 >> 20) & 0x3FF
+    }
+
+    /// Returns the `w` element. (2 bits)
+    #[inline]
+    #[must_use]
+    pub const fn w(self) -> u32 {
+        self.0 >> 30
+    }
+
+    /// Converts from a single-precision vector.
+    /// 
+    /// ※ Each element is saturated to the range `[0, 1]` and rounded to the nearest integer. (NaN becomes zero) </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn from_float4(val: Float4) -> Self {
+        Self::new(
+            (val.x.clamp(0.0, 1.0) * 1023.0).round() as u32, 
+            (val.y.clamp(0.0, 1.0) * 1023.0).round() as u32, 
+            (val.z.clamp(0.0, 1.0) * 1023.0).round() as u32, 
+            (val.w.clamp(0.0, 1.0) * 3.0).round() as u32 
+        )
+    }
+
+    /// Converts to a single-precision vector.
+    #[inline]
+    #[must_use]
+    pub fn to_float4(self) -> Float4 {
+        Float4 {
+            x: self.x() as f32 / 1023.0, 
+            y: self.y() as f32 / 1023.0, 
+            z: self.z() as f32 / 1023.0, 
+            w: self.w() as f32 / 3.0 
+        }
+    }
+}
+
+impl From<UDecN4> for Float4 {
+    #[inline]
+    fn from(value: UDecN4) -> Self {
+        value.to_float4()
+    }
+}
+
+impl fmt::Debug for UDecN4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple(stringify!(UDecN4))
+            .field(&self.x())
+            .field(&self.y())
+            .field(&self.z())
+            .field(&self.w())
+            .finish()
+    }
+}
+
+impl fmt::Display for UDecN4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{ {}, {}, {}, {} }}", self.x(), self.y(), self.z(), self.w())
+    }
+}
@@ -0,0 +1,68 @@
+use crate::macros::impl_element4;
+use super::float4::Float4;
+
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+
+
+
+/// A structure that stores four-dimensional unsigned normalized 16-bit integer data.
+/// 
+/// ※ Each element maps the range `[0, 1]` to the full range of the integer. </br>
+/// ※ Use [`Vector::load_ushortn4`](crate::Vector::load_ushortn4) and [`Vector::store_ushortn4`](crate::Vector::store_ushortn4) to convert with the vector type. </br>
+/// 
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub struct UShortN4 {
+    pub x: u16,
+    pub y: u16,
+    pub z: u16,
+    pub w: u16,
+}
+
+impl UShortN4 {
+    /// All elements are zero.
+    pub const ZERO: Self = Self::fill(0);
+
+    /// All elements are one. (`u16::MAX`)
+    pub const ONE: Self = Self::fill(u16::MAX);
+}
+
+impl_element4!(u16, UShortN4);
+
+impl UShortN4 {
+    /// Converts from a single-precision vector.
+    /// 
+    /// ※ Each element is saturated to the range `[0, 1]` and rounded to the nearest integer. (NaN becomes zero) </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn from_float4(val: Float4) -> Self {
+        Self {
+            x: (val.x.clamp(0.0, 1.0) * 65535.0).round() as u16, 
+            y: (val.y.clamp(0.0, 1.0) * 65535.0).round() as u16, 
+            z: (val.z.clamp(0.0, 1.0) * 65535.0).round() as u16, 
+            w: (val.w.clamp(0.0, 1.0) * 65535.0).round() as u16 
+        }
+    }
+
+    /// Converts to a single-precision vector.
+    #[inline]
+    #[must_use]
+    pub fn to_float4(self) -> Float4 {
+        Float4 {
+            x: self.x as f32 / 65535.0, 
+            y: self.y as f32 / 65535.0, 
+            z: self.z as f32 / 65535.0, 
+            w: self.w as f32 / 65535.0 
+        }
+    }
+}
+
+impl From<UShortN4> for Float4 {
+    #[inline]
+    fn from(value: UShortN4) -> Self {
+        value.to_float4()
+    }
+}
@@ -0,0 +1,100 @@
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use crate::{
+    Double2, Double3, Double4, Double4x4, 
+    Float2, Float3, Float3x3, Float3x4, Float4, Float4x4, 
+    Vector, Vector3x4, Vector4x4, Quaternion, Matrix, Matrix3, Affine3, 
+    DVector, DQuaternion, DMatrix
+};
+
+
+macro_rules! impl_approx {
+    ($t: ty, $s: ty) => {
+        impl AbsDiffEq for $t {
+            type Epsilon = $s;
+
+            #[inline]
+            fn default_epsilon() -> Self::Epsilon {
+                <$s>::EPSILON
+            }
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+                <$t>::abs_diff_eq(*self, *other, epsilon)
+            }
+        }
+
+        impl RelativeEq for $t {
+            #[inline]
+            fn default_max_relative() -> Self::Epsilon {
+                <$s>::EPSILON
+            }
+
+            #[inline]
+            fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+                <$t>::relative_eq(*self, *other, epsilon, max_relative)
+            }
+        }
+
+        impl UlpsEq for $t {
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                4
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+                <$t>::ulps_eq(*self, *other, epsilon, max_ulps)
+            }
+        }
+    };
+}
+
+impl_approx!(Float2, f32);
+impl_approx!(Float3, f32);
+impl_approx!(Float4, f32);
+impl_approx!(Float3x3, f32);
+impl_approx!(Float3x4, f32);
+impl_approx!(Float4x4, f32);
+
+impl_approx!(Double2, f64);
+impl_approx!(Double3, f64);
+impl_approx!(Double4, f64);
+impl_approx!(Double4x4, f64);
+
+impl_approx!(Vector, f32);
+impl_approx!(Vector3x4, f32);
+impl_approx!(Vector4x4, f32);
+impl_approx!(Quaternion, f32);
+impl_approx!(Matrix, f32);
+impl_approx!(Matrix3, f32);
+impl_approx!(Affine3, f32);
+
+impl_approx!(DVector, f64);
+impl_approx!(DQuaternion, f64);
+impl_approx!(DMatrix, f64);
+
+
+#[cfg(test)]
+mod tests {
+    use crate::{Float3, Matrix, Quaternion, Vector};
+
+    #[test]
+    fn impl_approx_vector() {
+        let a = Vector::new(1.0, 2.0, 3.0, 4.0);
+        let b = a + Vector::fill(f32::EPSILON);
+        approx::assert_abs_diff_eq!(a, b, epsilon = 4.0 * f32::EPSILON);
+        approx::assert_relative_eq!(a, b, max_relative = f32::EPSILON);
+        approx::assert_ulps_eq!(a, b);
+        approx::assert_abs_diff_ne!(a, Vector::ZERO);
+    }
+
+    #[test]
+    fn impl_approx_transform() {
+        let q = Quaternion::from_rotation_y(core::f32::consts::FRAC_PI_2);
+        let m = Matrix::from_quaternion(q);
+        let v = m * Vector::X;
+        approx::assert_abs_diff_eq!(v, -Vector::Z, epsilon = 1e-6);
+        approx::assert_abs_diff_eq!(v.store_float3(), Float3::new(0.0, 0.0, -1.0), epsilon = 1e-6);
+        approx::assert_relative_eq!(m, Matrix::from_rotation_y(core::f32::consts::FRAC_PI_2), epsilon = 1e-6);
+    }
+}
@@ -1,9 +1,14 @@
 use bytemuck::{Pod, Zeroable};
 use crate::{
     Boolean2, Boolean3, Boolean4, 
-    Float2, Float3, Float3x3, Float4, Float4x4, 
+    Double2, Double3, Double4, Double4x4, 
+    Float2, Float3, Float3x3, Float3x4, Float4, Float4x4, 
+    Half, Half2, Half4, 
+    UByteN4, ByteN4, ShortN2, UShortN4, UDecN4, Float3PK, Rgb9e5, 
+    AabbData, BoundingSphereData, OrientedBoxData, 
     Integer2, Integer3, Integer4, UInteger2, UInteger3, UInteger4, 
-    Vector, VectorInt, Quaternion, Matrix
+    Vector, Vector3x4, Vector4x4, VectorInt, Quaternion, Matrix, Matrix3, Affine3, 
+    DVector, DQuaternion, DMatrix, Plane
 };
 
 
@@ -18,6 +23,19 @@ unsafe impl Pod for Boolean4 {}
 unsafe impl Zeroable for Boolean4 {}
 
 
+unsafe impl Pod for Double2 {}
+unsafe impl Zeroable for Double2 {}
+
+unsafe impl Pod for Double3 {}
+unsafe impl Zeroable for Double3 {}
+
+unsafe impl Pod for Double4 {}
+unsafe impl Zeroable for Double4 {}
+
+unsafe impl Pod for Double4x4 {}
+unsafe impl Zeroable for Double4x4 {}
+
+
 unsafe impl Pod for Float2 {}
 unsafe impl Zeroable for Float2 {}
 
@@ -31,10 +49,53 @@ unsafe impl Zeroable for Float4 {}
 unsafe impl Pod for Float3x3 {}
 unsafe impl Zeroable for Float3x3 {}
 
+unsafe impl Pod for Float3x4 {}
+unsafe impl Zeroable for Float3x4 {}
+
 unsafe impl Pod for Float4x4 {}
 unsafe impl Zeroable for Float4x4 {}
 
 
+unsafe impl Pod for Half {}
+unsafe impl Zeroable for Half {}
+
+unsafe impl Pod for Half2 {}
+unsafe impl Zeroable for Half2 {}
+
+unsafe impl Pod for Half4 {}
+unsafe impl Zeroable for Half4 {}
+
+unsafe impl Pod for UByteN4 {}
+unsafe impl Zeroable for UByteN4 {}
+
+unsafe impl Pod for ByteN4 {}
+unsafe impl Zeroable for ByteN4 {}
+
+unsafe impl Pod for ShortN2 {}
+unsafe impl Zeroable for ShortN2 {}
+
+unsafe impl Pod for UShortN4 {}
+unsafe impl Zeroable for UShortN4 {}
+
+unsafe impl Pod for UDecN4 {}
+unsafe impl Zeroable for UDecN4 {}
+
+unsafe impl Pod for Float3PK {}
+unsafe impl Zeroable for Float3PK {}
+
+unsafe impl Pod for Rgb9e5 {}
+unsafe impl Zeroable for Rgb9e5 {}
+
+
+unsafe impl Pod for AabbData {}
+unsafe impl Zeroable for AabbData {}
+
+unsafe impl Pod for BoundingSphereData {}
+unsafe impl Zeroable for BoundingSphereData {}
+
+unsafe impl Pod for OrientedBoxData {}
+unsafe impl Zeroable for OrientedBoxData {}
+
 unsafe impl Pod for Integer2 {}
 unsafe impl Zeroable for Integer2 {}
 
@@ -57,6 +118,12 @@ unsafe impl Zeroable for UInteger4 {}
 unsafe impl Pod for Vector {}
 unsafe impl Zeroable for Vector {}
 
+unsafe impl Pod for Vector3x4 {}
+unsafe impl Zeroable for Vector3x4 {}
+
+unsafe impl Pod for Vector4x4 {}
+unsafe impl Zeroable for Vector4x4 {}
+
 unsafe impl Pod for VectorInt {}
 unsafe impl Zeroable for VectorInt {}
 
@@ -66,16 +133,39 @@ unsafe impl Zeroable for Quaternion {}
 unsafe impl Pod for Matrix {}
 unsafe impl Zeroable for Matrix {}
 
+unsafe impl Pod for Matrix3 {}
+unsafe impl Zeroable for Matrix3 {}
+
+unsafe impl Pod for Affine3 {}
+unsafe impl Zeroable for Affine3 {}
+
+unsafe impl Pod for DVector {}
+unsafe impl Zeroable for DVector {}
+
+unsafe impl Pod for DQuaternion {}
+unsafe impl Zeroable for DQuaternion {}
+
+unsafe impl Pod for DMatrix {}
+unsafe impl Zeroable for DMatrix {}
+
+unsafe impl Pod for Plane {}
+unsafe impl Zeroable for Plane {}
+
 // TODO: Add test function...
 #[cfg(test)]
 mod tests {
     use core::mem;
     use crate::{
         Boolean2, Boolean3, Boolean4, 
-        Float2, Float3, Float3x3, Float4, Float4x4, 
+        Double2, Double3, Double4, Double4x4, 
+        Float2, Float3, Float3x3, Float3x4, Float4, Float4x4, 
+        Half, Half2, Half4, 
+        UByteN4, ByteN4, ShortN2, UShortN4, UDecN4, Float3PK, Rgb9e5, 
+        AabbData, BoundingSphereData, OrientedBoxData, 
         Integer2, Integer3, Integer4, 
         UInteger2, UInteger3, UInteger4, 
-        Matrix, Quaternion, Vector, VectorInt
+        Matrix, Matrix3, Affine3, Quaternion, Vector, Vector3x4, Vector4x4, VectorInt, 
+        DMatrix, DQuaternion, DVector, Plane
     };
 
     macro_rules! test_pod_impl {
@@ -98,12 +188,34 @@ mod tests {
     test_pod_impl!(impl_bytemuck_boolean3, Boolean3);
     test_pod_impl!(impl_bytemuck_boolean4, Boolean4);
 
+    test_pod_impl!(impl_bytemuck_double2, Double2);
+    test_pod_impl!(impl_bytemuck_double3, Double3);
+    test_pod_impl!(impl_bytemuck_double4, Double4);
+    test_pod_impl!(impl_bytemuck_double4x4, Double4x4);
+
     test_pod_impl!(impl_bytemuck_float2, Float2);
     test_pod_impl!(impl_bytemuck_float3, Float3);
     test_pod_impl!(impl_bytemuck_float4, Float4);
     test_pod_impl!(impl_bytemuck_float3x3, Float3x3);
+    test_pod_impl!(impl_bytemuck_float3x4, Float3x4);
     test_pod_impl!(impl_bytemuck_float4x4, Float4x4);
 
+    test_pod_impl!(impl_bytemuck_half, Half);
+    test_pod_impl!(impl_bytemuck_half2, Half2);
+    test_pod_impl!(impl_bytemuck_half4, Half4);
+
+    test_pod_impl!(impl_bytemuck_ubyten4, UByteN4);
+    test_pod_impl!(impl_bytemuck_byten4, ByteN4);
+    test_pod_impl!(impl_bytemuck_shortn2, ShortN2);
+    test_pod_impl!(impl_bytemuck_ushortn4, UShortN4);
+    test_pod_impl!(impl_bytemuck_udecn4, UDecN4);
+    test_pod_impl!(impl_bytemuck_float3pk, Float3PK);
+    test_pod_impl!(impl_bytemuck_rgb9e5, Rgb9e5);
+
+    test_pod_impl!(impl_bytemuck_aabb_data, AabbData);
+    test_pod_impl!(impl_bytemuck_bounding_sphere_data, BoundingSphereData);
+    test_pod_impl!(impl_bytemuck_oriented_box_data, OrientedBoxData);
+
     test_pod_impl!(impl_bytemuck_integer2, Integer2);
     test_pod_impl!(impl_bytemuck_integer3, Integer3);
     test_pod_impl!(impl_bytemuck_integer4, Integer4);
@@ -113,7 +225,15 @@ mod tests {
     test_pod_impl!(impl_bytemuck_uinteger4, UInteger4);
 
     test_pod_impl!(impl_bytemuck_vector, Vector);
+    test_pod_impl!(impl_bytemuck_vector3x4, Vector3x4);
+    test_pod_impl!(impl_bytemuck_vector4x4, Vector4x4);
     test_pod_impl!(impl_bytemuck_vector_int, VectorInt);
     test_pod_impl!(impl_bytemuck_quaternion, Quaternion);
     test_pod_impl!(impl_bytemuck_matrix, Matrix);
+    test_pod_impl!(impl_bytemuck_matrix3, Matrix3);
+    test_pod_impl!(impl_bytemuck_affine3, Affine3);
+    test_pod_impl!(impl_bytemuck_dvector, DVector);
+    test_pod_impl!(impl_bytemuck_dquaternion, DQuaternion);
+    test_pod_impl!(impl_bytemuck_dmatrix, DMatrix);
+    test_pod_impl!(impl_bytemuck_plane, Plane);
 }
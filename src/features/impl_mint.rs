@@ -1,6 +1,7 @@
 use crate::{
     Boolean2, Boolean3, Boolean4, 
-    Float2, Float3, Float3x3, Float4, Float4x4, 
+    Double2, Double3, Double4, Double4x4, 
+    Float2, Float3, Float3x3, Float3x4, Float4, Float4x4, 
     Integer2, Integer3, Integer4, UInteger2, UInteger3, UInteger4, 
 };
 
@@ -99,6 +100,10 @@ impl_vector2_type!(f32, Float2);
 impl_vector3_type!(f32, Float3);
 impl_vector4_type!(f32, Float4);
 
+impl_vector2_type!(f64, Double2);
+impl_vector3_type!(f64, Double3);
+impl_vector4_type!(f64, Double4);
+
 impl_vector2_type!(i32, Integer2);
 impl_vector3_type!(i32, Integer3);
 impl_vector4_type!(i32, Integer4);
@@ -128,6 +133,25 @@ impl From<Float4> for mint::Quaternion<f32> {
     }
 }
 
+impl From<mint::Quaternion<f64>> for Double4 {
+    fn from(value: mint::Quaternion<f64>) -> Self {
+        Self { x: value.v.x, y: value.v.y, z: value.v.z, w: value.s }
+    }
+}
+
+impl From<Double4> for mint::Quaternion<f64> {
+    fn from(value: Double4) -> Self {
+        Self { 
+            s: value.w, 
+            v: mint::Vector3 { 
+                x: value.x, 
+                y: value.y, 
+                z: value.z 
+            }, 
+        }
+    }
+}
+
 
 
 impl From<mint::RowMatrix3<f32>> for Float3x3 {
@@ -176,6 +200,55 @@ impl mint::IntoMint for Float3x3 {
 
 
 
+impl From<mint::RowMatrix3x4<f32>> for Float3x4 {
+    fn from(value: mint::RowMatrix3x4<f32>) -> Self {
+        Self { 
+            x_axis: Float3 { x: value.x.x, y: value.y.x, z: value.z.x }, 
+            y_axis: Float3 { x: value.x.y, y: value.y.y, z: value.z.y }, 
+            z_axis: Float3 { x: value.x.z, y: value.y.z, z: value.z.z }, 
+            w_axis: Float3 { x: value.x.w, y: value.y.w, z: value.z.w } 
+        }
+    }
+}
+
+impl From<mint::ColumnMatrix3x4<f32>> for Float3x4 {
+    fn from(value: mint::ColumnMatrix3x4<f32>) -> Self {
+        Self { 
+            x_axis: Float3 { x: value.x.x, y: value.x.y, z: value.x.z }, 
+            y_axis: Float3 { x: value.y.x, y: value.y.y, z: value.y.z }, 
+            z_axis: Float3 { x: value.z.x, y: value.z.y, z: value.z.z }, 
+            w_axis: Float3 { x: value.w.x, y: value.w.y, z: value.w.z } 
+        }
+    }
+}
+
+impl From<Float3x4> for mint::RowMatrix3x4<f32> {
+    fn from(value: Float3x4) -> Self {
+        Self { 
+            x: mint::Vector4 { x: value.x_axis.x, y: value.y_axis.x, z: value.z_axis.x, w: value.w_axis.x }, 
+            y: mint::Vector4 { x: value.x_axis.y, y: value.y_axis.y, z: value.z_axis.y, w: value.w_axis.y }, 
+            z: mint::Vector4 { x: value.x_axis.z, y: value.y_axis.z, z: value.z_axis.z, w: value.w_axis.z } 
+        }
+    }
+}
+
+impl From<Float3x4> for mint::ColumnMatrix3x4<f32> {
+    fn from(value: Float3x4) -> Self {
+        Self { 
+            x: mint::Vector3 { x: value.x_axis.x, y: value.x_axis.y, z: value.x_axis.z }, 
+            y: mint::Vector3 { x: value.y_axis.x, y: value.y_axis.y, z: value.y_axis.z }, 
+            z: mint::Vector3 { x: value.z_axis.x, y: value.z_axis.y, z: value.z_axis.z }, 
+            w: mint::Vector3 { x: value.w_axis.x, y: value.w_axis.y, z: value.w_axis.z } 
+        }
+    }
+}
+
+impl mint::IntoMint for Float3x4 {
+    type MintType = mint::ColumnMatrix3x4<f32>;
+}
+
+
+
 impl From<mint::RowMatrix4<f32>> for Float4x4 {
     fn from(value: mint::RowMatrix4<f32>) -> Self {
         Self { 
@@ -223,3 +296,51 @@ impl From<Float4x4> for mint::ColumnMatrix4<f32> {
 impl mint::IntoMint for Float4x4 {
     type MintType = mint::ColumnMatrix4<f32>;
 }
+
+impl From<mint::RowMatrix4<f64>> for Double4x4 {
+    fn from(value: mint::RowMatrix4<f64>) -> Self {
+        Self { 
+            x_axis: Double4 { x: value.x.x, y: value.y.x, z: value.z.x, w: value.w.x }, 
+            y_axis: Double4 { x: value.x.y, y: value.y.y, z: value.z.y, w: value.w.y }, 
+            z_axis: Double4 { x: value.x.z, y: value.y.z, z: value.z.z, w: value.w.z }, 
+            w_axis: Double4 { x: value.x.w, y: value.y.w, z: value.z.w, w: value.w.w } 
+        }
+    }
+}
+
+impl From<mint::ColumnMatrix4<f64>> for Double4x4 {
+    fn from(value: mint::ColumnMatrix4<f64>) -> Self {
+        Self { 
+            x_axis: Double4 { x: value.x.x, y: value.x.y, z: value.x.z, w: value.x.w }, 
+            y_axis: Double4 { x: value.y.x, y: value.y.y, z: value.y.z, w: value.y.w }, 
+            z_axis: Double4 { x: value.z.x, y: value.z.y, z: value.z.z, w: value.z.w }, 
+            w_axis: Double4 { x: value.w.x, y: value.w.y, z: value.w.z, w: value.w.w } 
+        }
+    }
+}
+
+impl From<Double4x4> for mint::RowMatrix4<f64> {
+    fn from(value: Double4x4) -> Self {
+        Self { 
+            x: mint::Vector4 { x: value.x_axis.x, y: value.y_axis.x, z: value.z_axis.x, w: value.w_axis.x }, 
+            y: mint::Vector4 { x: value.x_axis.y, y: value.y_axis.y, z: value.z_axis.y, w: value.w_axis.y }, 
+            z: mint::Vector4 { x: value.x_axis.z, y: value.y_axis.z, z: value.z_axis.z, w: value.w_axis.z }, 
+            w: mint::Vector4 { x: value.x_axis.w, y: value.y_axis.w, z: value.z_axis.w, w: value.w_axis.w } 
+        }
+    }
+}
+
+impl From<Double4x4> for mint::ColumnMatrix4<f64> {
+    fn from(value: Double4x4) -> Self {
+        Self { 
+            x: mint::Vector4 { x: value.x_axis.x, y: value.x_axis.y, z: value.x_axis.z, w: value.x_axis.w }, 
+            y: mint::Vector4 { x: value.y_axis.x, y: value.y_axis.y, z: value.y_axis.z, w: value.y_axis.w }, 
+            z: mint::Vector4 { x: value.z_axis.x, y: value.z_axis.y, z: value.z_axis.z, w: value.z_axis.w }, 
+            w: mint::Vector4 { x: value.w_axis.x, y: value.w_axis.y, z: value.w_axis.z, w: value.w_axis.w } 
+        }
+    }
+}
+
+impl mint::IntoMint for Double4x4 {
+    type MintType = mint::ColumnMatrix4<f64>;
+}
@@ -0,0 +1,175 @@
+use core::fmt;
+use crate::{ Vector, Matrix, Float3, AabbData };
+use super::{ all_xyz, transform_point, BoundingSphere, OrientedBox };
+
+
+
+/// This is an axis-aligned bounding box stored as the center and the half-lengths of each axis.
+///
+/// ※ This has a similar purpose to `BoundingBox` in the `DirectXCollision` library. </br>
+/// ※ The extents must not be negative. </br>
+///
+#[derive(Clone, Copy, Default, PartialEq)]
+pub struct Aabb {
+    /// The center of the box.
+    pub center: Vector,
+
+    /// The half-lengths of the box along each axis.
+    pub extents: Vector,
+}
+
+impl Aabb {
+    /// Creates with the given center and extents.
+    #[inline]
+    #[must_use]
+    pub const fn new(center: Vector, extents: Vector) -> Self {
+        Self { center, extents }
+    }
+
+    /// Creates from the minimum corner and the maximum corner.
+    #[inline]
+    #[must_use]
+    pub fn from_min_max(min: Vector, max: Vector) -> Self {
+        Self { center: (min + max) * 0.5, extents: (max - min) * 0.5 }
+    }
+
+    /// Creates the smallest box that contains all the given points.
+    ///
+    /// ※ If the slice is empty, a box with zero center and zero extents is returned. </br>
+    ///
+    #[must_use]
+    pub fn from_points(points: &[Float3]) -> Self {
+        let (first, rest) = match points.split_first() {
+            Some(split) => split,
+            None => return Self::default(),
+        };
+
+        let first = Vector::load_float3(*first);
+        let (min, max) = rest.iter().fold((first, first), |(min, max), p| {
+            let p = Vector::load_float3(*p);
+            (min.min(p), max.max(p))
+        });
+        Self::from_min_max(min, max)
+    }
+
+    /// Loads a value from a given `AabbData`.
+    #[inline]
+    #[must_use]
+    pub fn load_data(val: AabbData) -> Self {
+        Self { center: Vector::load_float3(val.center), extents: Vector::load_float3(val.extents) }
+    }
+
+    /// Stores the value in an `AabbData`.
+    #[inline]
+    #[must_use]
+    pub fn store_data(self) -> AabbData {
+        AabbData { center: self.center.store_float3(), extents: self.extents.store_float3() }
+    }
+
+    /// Returns the minimum corner of the box.
+    #[inline]
+    #[must_use]
+    pub fn min(self) -> Vector {
+        self.center - self.extents
+    }
+
+    /// Returns the maximum corner of the box.
+    #[inline]
+    #[must_use]
+    pub fn max(self) -> Vector {
+        self.center + self.extents
+    }
+
+    /// Returns the eight corners of the box.
+    ///
+    /// ※ The `n`-th corner takes the maximum along the `x`, `y` and `z` axes if the bits `0`, `1` and `2` of `n` are set. </br>
+    ///
+    #[must_use]
+    pub fn corners(self) -> [Vector; 8] {
+        let mut corners = [Vector::ZERO; 8];
+        for (n, corner) in corners.iter_mut().enumerate() {
+            let sign = Vector::new(
+                if n & 1 != 0 { 1.0 } else { -1.0 },
+                if n & 2 != 0 { 1.0 } else { -1.0 },
+                if n & 4 != 0 { 1.0 } else { -1.0 },
+                0.0
+            );
+            *corner = self.extents.mul_add(sign, self.center);
+        }
+        corners
+    }
+
+    /// Returns `true` if the box contains the given point. (including the boundary)
+    #[inline]
+    #[must_use]
+    pub fn contains_point(self, point: Vector) -> bool {
+        all_xyz((point - self.center).abs().le(self.extents))
+    }
+
+    /// Returns `true` if the two boxes intersect. (including touching)
+    #[inline]
+    #[must_use]
+    pub fn intersects_aabb(self, other: Aabb) -> bool {
+        all_xyz((self.center - other.center).abs().le(self.extents + other.extents))
+    }
+
+    /// Returns `true` if the box and the sphere intersect. (including touching)
+    #[inline]
+    #[must_use]
+    pub fn intersects_sphere(self, sphere: BoundingSphere) -> bool {
+        let closest = sphere.center.max(self.min()).min(self.max());
+        (sphere.center - closest).vec3_len_sq() <= sphere.radius * sphere.radius
+    }
+
+    /// Returns `true` if the box and the oriented box intersect. (including touching)
+    #[inline]
+    #[must_use]
+    pub fn intersects_oriented_box(self, obb: OrientedBox) -> bool {
+        OrientedBox::from_aabb(self).intersects_oriented_box(obb)
+    }
+
+    /// Returns the smallest box that contains both boxes.
+    #[inline]
+    #[must_use]
+    pub fn merge(self, other: Aabb) -> Self {
+        Self::from_min_max(self.min().min(other.min()), self.max().max(other.max()))
+    }
+
+    /// Returns the smallest axis-aligned box that contains the box transformed by the given matrix.
+    ///
+    /// ※ The matrix must be an affine transformation. </br>
+    ///
+    #[must_use]
+    pub fn transform(self, m: Matrix) -> Self {
+        let center = transform_point(&m, self.center);
+        let mut extents = m.get_x_axis().abs() * self.extents.splat_x()
+            + m.get_y_axis().abs() * self.extents.splat_y()
+            + m.get_z_axis().abs() * self.extents.splat_z();
+        extents.set_w(0.0);
+        Self { center, extents }
+    }
+}
+
+impl From<AabbData> for Aabb {
+    #[inline]
+    fn from(value: AabbData) -> Self {
+        Self::load_data(value)
+    }
+}
+
+impl From<Aabb> for AabbData {
+    #[inline]
+    fn from(value: Aabb) -> Self {
+        value.store_data()
+    }
+}
+
+impl fmt::Debug for Aabb {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(stringify!(Aabb))
+            .field("center", &self.center)
+            .field("extents", &self.extents)
+            .finish()
+    }
+}
@@ -0,0 +1,194 @@
+use core::fmt;
+use crate::{ Vector, Matrix, Float3, BoundingSphereData };
+use super::{ transform_point, Aabb, OrientedBox };
+
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+
+
+
+/// This is a bounding sphere stored as the center and the radius.
+///
+/// ※ This has a similar purpose to `BoundingSphere` in the `DirectXCollision` library. </br>
+/// ※ The radius must not be negative. </br>
+///
+#[derive(Clone, Copy, Default, PartialEq)]
+pub struct BoundingSphere {
+    /// The center of the sphere.
+    pub center: Vector,
+
+    /// The radius of the sphere.
+    pub radius: f32,
+}
+
+impl BoundingSphere {
+    /// Creates with the given center and radius.
+    #[inline]
+    #[must_use]
+    pub const fn new(center: Vector, radius: f32) -> Self {
+        Self { center, radius }
+    }
+
+    /// Creates a sphere that contains all the given points.
+    ///
+    /// ※ The initial sphere is spanned by the most distant pair of the extreme points along each axis,
+    /// and it grows to contain the points outside of it. (Ritter's method) </br>
+    /// ※ The result is not always the smallest sphere. </br>
+    /// ※ If the slice is empty, a sphere with zero center and zero radius is returned. </br>
+    ///
+    #[must_use]
+    pub fn from_points(points: &[Float3]) -> Self {
+        let first = match points.first() {
+            Some(first) => Vector::load_float3(*first),
+            None => return Self::default(),
+        };
+
+        // Finds the points with the minimum and maximum value along each axis.
+        let mut min = [first; 3];
+        let mut max = [first; 3];
+        for p in points.iter().skip(1) {
+            let p = Vector::load_float3(*p);
+            let arr = p.into_array();
+            for axis in 0..3 {
+                if arr[axis] < min[axis].into_array()[axis] {
+                    min[axis] = p;
+                }
+                if arr[axis] > max[axis].into_array()[axis] {
+                    max[axis] = p;
+                }
+            }
+        }
+
+        // Uses the most distant pair as the initial sphere.
+        let (a, b) = (0..3)
+            .map(|axis| (min[axis], max[axis]))
+            .fold((first, first), |(a, b), (min, max)| {
+                match (max - min).vec3_len_sq() > (b - a).vec3_len_sq() {
+                    true => (min, max),
+                    false => (a, b),
+                }
+            });
+        let mut center = (a + b) * 0.5;
+        let mut radius = (b - a).vec3_len() * 0.5;
+
+        // Grows the sphere to contain all the points.
+        for p in points.iter() {
+            let p = Vector::load_float3(*p);
+            let dist = (p - center).vec3_len();
+            if dist > radius {
+                let new_radius = (radius + dist) * 0.5;
+                center = center + (p - center) * ((new_radius - radius) / dist);
+                radius = new_radius;
+            }
+        }
+
+        Self { center, radius }
+    }
+
+    /// Creates the smallest sphere that contains the given box.
+    #[inline]
+    #[must_use]
+    pub fn from_aabb(aabb: Aabb) -> Self {
+        Self { center: aabb.center, radius: aabb.extents.vec3_len() }
+    }
+
+    /// Loads a value from a given `BoundingSphereData`.
+    #[inline]
+    #[must_use]
+    pub fn load_data(val: BoundingSphereData) -> Self {
+        Self { center: Vector::load_float3(val.center), radius: val.radius }
+    }
+
+    /// Stores the value in a `BoundingSphereData`.
+    #[inline]
+    #[must_use]
+    pub fn store_data(self) -> BoundingSphereData {
+        BoundingSphereData { center: self.center.store_float3(), radius: self.radius }
+    }
+
+    /// Returns `true` if the sphere contains the given point. (including the boundary)
+    #[inline]
+    #[must_use]
+    pub fn contains_point(self, point: Vector) -> bool {
+        (point - self.center).vec3_len_sq() <= self.radius * self.radius
+    }
+
+    /// Returns `true` if the sphere and the box intersect. (including touching)
+    #[inline]
+    #[must_use]
+    pub fn intersects_aabb(self, aabb: Aabb) -> bool {
+        aabb.intersects_sphere(self)
+    }
+
+    /// Returns `true` if the two spheres intersect. (including touching)
+    #[inline]
+    #[must_use]
+    pub fn intersects_sphere(self, other: BoundingSphere) -> bool {
+        let radius = self.radius + other.radius;
+        (self.center - other.center).vec3_len_sq() <= radius * radius
+    }
+
+    /// Returns `true` if the sphere and the oriented box intersect. (including touching)
+    #[inline]
+    #[must_use]
+    pub fn intersects_oriented_box(self, obb: OrientedBox) -> bool {
+        obb.intersects_sphere(self)
+    }
+
+    /// Returns the smallest sphere that contains both spheres.
+    #[must_use]
+    pub fn merge(self, other: BoundingSphere) -> Self {
+        let offset = other.center - self.center;
+        let dist = offset.vec3_len();
+
+        // One sphere is inside the other.
+        if self.radius + dist <= other.radius {
+            return other;
+        }
+        if other.radius + dist <= self.radius {
+            return self;
+        }
+
+        let radius = (self.radius + other.radius + dist) * 0.5;
+        let center = self.center + offset * ((radius - self.radius) / dist);
+        Self { center, radius }
+    }
+
+    /// Returns the sphere transformed by the given matrix.
+    ///
+    /// ※ The matrix must be an affine transformation. </br>
+    /// ※ The radius is scaled by the largest scale of the matrix,
+    /// so the result contains the transformed sphere even if the scale is not uniform. </br>
+    ///
+    #[must_use]
+    pub fn transform(self, m: Matrix) -> Self {
+        let scale_sq = m.get_x_axis().vec3_len_sq()
+            .max(m.get_y_axis().vec3_len_sq())
+            .max(m.get_z_axis().vec3_len_sq());
+        Self { center: transform_point(&m, self.center), radius: self.radius * scale_sq.sqrt() }
+    }
+}
+
+impl From<BoundingSphereData> for BoundingSphere {
+    #[inline]
+    fn from(value: BoundingSphereData) -> Self {
+        Self::load_data(value)
+    }
+}
+
+impl From<BoundingSphere> for BoundingSphereData {
+    #[inline]
+    fn from(value: BoundingSphere) -> Self {
+        value.store_data()
+    }
+}
+
+impl fmt::Debug for BoundingSphere {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(stringify!(BoundingSphere))
+            .field("center", &self.center)
+            .field("radius", &self.radius)
+            .finish()
+    }
+}
@@ -0,0 +1,57 @@
+use core::fmt;
+use crate::Vector;
+
+
+
+/// The result of a ray query against a box, a sphere or a plane.
+#[derive(Clone, Copy, PartialEq)]
+pub struct RayHit {
+    /// The distance from the origin of the ray to the hit point.
+    pub distance: f32,
+
+    /// The unit normal of the surface at the hit point.
+    pub normal: Vector,
+}
+
+impl fmt::Debug for RayHit {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(stringify!(RayHit))
+            .field("distance", &self.distance)
+            .field("normal", &self.normal)
+            .finish()
+    }
+}
+
+
+
+/// The result of a ray query against a triangle.
+///
+/// ※ The hit point is `(1 - u - v) * a + u * b + v * c` for the triangle `(a, b, c)`. </br>
+///
+#[derive(Clone, Copy, PartialEq)]
+pub struct TriangleHit {
+    /// The distance from the origin of the ray to the hit point.
+    pub distance: f32,
+
+    /// The unit normal of the triangle. (the direction of `(b - a) × (c - a)`)
+    pub normal: Vector,
+
+    /// The barycentric coordinate of the hit point for the vertex `b`.
+    pub u: f32,
+
+    /// The barycentric coordinate of the hit point for the vertex `c`.
+    pub v: f32,
+}
+
+impl fmt::Debug for TriangleHit {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(stringify!(TriangleHit))
+            .field("distance", &self.distance)
+            .field("normal", &self.normal)
+            .field("u", &self.u)
+            .field("v", &self.v)
+            .finish()
+    }
+}
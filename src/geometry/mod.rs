@@ -0,0 +1,48 @@
+//! # Geometry types
+//! Geometry types are used for culling, picking and collision queries.
+//! This has a similar purpose to `DirectXCollision` in the `DirectXMath` library.
+//!
+//! Geometry types are built on the vector types, so the calculations use SIMD instructions where available.
+//! To store them in files or exchange them with other systems, conversion to the data types is required.
+//!
+//! ※ Only the `x`, `y` and `z` elements of the vectors are used. </br>
+//!
+
+mod aabb;
+pub use self::aabb::Aabb;
+mod bounding_sphere;
+pub use self::bounding_sphere::BoundingSphere;
+mod oriented_box;
+pub use self::oriented_box::OrientedBox;
+
+mod plane;
+pub use self::plane::Plane;
+
+mod hit;
+pub use self::hit::RayHit;
+pub use self::hit::TriangleHit;
+mod ray;
+pub use self::ray::Ray;
+mod segment;
+pub use self::segment::Segment;
+
+use crate::{ Vector, VectorInt, Matrix };
+
+
+
+/// Returns `true` if the `x`, `y` and `z` lanes of the mask are all set.
+#[inline]
+pub(crate) fn all_xyz(mask: VectorInt) -> bool {
+    let [x, y, z, _] = mask.into_array();
+    x != 0 && y != 0 && z != 0
+}
+
+/// Transforms the point by the matrix. (the `w` element is treated as one)
+#[inline]
+pub(crate) fn transform_point(m: &Matrix, p: Vector) -> Vector {
+    let mut p = p;
+    p.set_w(1.0);
+    let mut p = *m * p;
+    p.set_w(0.0);
+    p
+}
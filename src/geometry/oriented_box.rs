@@ -0,0 +1,345 @@
+use core::fmt;
+use crate::{ Vector, Quaternion, Matrix, Float3, OrientedBoxData };
+use super::{ all_xyz, transform_point, Aabb, BoundingSphere };
+
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+
+
+
+/// Maximum number of sweeps of the Jacobi eigenvalue algorithm.
+const MAX_JACOBI_SWEEPS: usize = 32;
+
+
+
+/// This is an oriented bounding box stored as the center, the half-lengths of each local axis and the orientation.
+///
+/// ※ This has a similar purpose to `BoundingOrientedBox` in the `DirectXCollision` library. </br>
+/// ※ The extents must not be negative, and the orientation must be a normalized quaternion. </br>
+///
+#[derive(Clone, Copy, Default, PartialEq)]
+pub struct OrientedBox {
+    /// The center of the box.
+    pub center: Vector,
+
+    /// The half-lengths of the box along each local axis.
+    pub extents: Vector,
+
+    /// The rotation from the local axes of the box to the world axes.
+    pub orientation: Quaternion,
+}
+
+impl OrientedBox {
+    /// Creates with the given center, extents and orientation.
+    #[inline]
+    #[must_use]
+    pub const fn new(center: Vector, extents: Vector, orientation: Quaternion) -> Self {
+        Self { center, extents, orientation }
+    }
+
+    /// Creates from an axis-aligned box. (identity orientation)
+    #[inline]
+    #[must_use]
+    pub fn from_aabb(aabb: Aabb) -> Self {
+        Self { center: aabb.center, extents: aabb.extents, orientation: Quaternion::IDENTITY }
+    }
+
+    /// Creates a box that contains all the given points.
+    ///
+    /// ※ The axes of the box are the principal axes of the covariance of the points. </br>
+    /// ※ The result is not always the smallest box. </br>
+    /// ※ If the slice is empty, a box with zero center, zero extents and identity orientation is returned. </br>
+    ///
+    #[must_use]
+    pub fn from_points(points: &[Float3]) -> Self {
+        if points.is_empty() {
+            return Self::default();
+        }
+
+        // Mean of the points.
+        let inv_len = 1.0 / points.len() as f32;
+        let mean = points.iter().fold(Vector::ZERO, |sum, p| sum + Vector::load_float3(*p)) * inv_len;
+
+        // Covariance matrix of the points.
+        let mut cov = [[0.0; 3]; 3];
+        for p in points.iter() {
+            let d = (Vector::load_float3(*p) - mean).into_array();
+            for (i, row) in cov.iter_mut().enumerate() {
+                for (j, e) in row.iter_mut().enumerate() {
+                    *e += d[i] * d[j] * inv_len;
+                }
+            }
+        }
+
+        // The eigenvectors are the principal axes. (right-handed)
+        let v = jacobi_eigenvectors(cov);
+        let x_axis = Vector::new(v[0][0], v[1][0], v[2][0], 0.0).vec3_normalize();
+        let y_axis = Vector::new(v[0][1], v[1][1], v[2][1], 0.0).vec3_normalize();
+        let z_axis = x_axis.vec3_cross(y_axis).vec3_normalize();
+
+        // Range of the points along each axis.
+        let first = Vector::load_float3(points[0]);
+        let first = Vector::new(first.vec3_dot_into(x_axis), first.vec3_dot_into(y_axis), first.vec3_dot_into(z_axis), 0.0);
+        let (min, max) = points.iter().skip(1).fold((first, first), |(min, max), p| {
+            let p = Vector::load_float3(*p);
+            let p = Vector::new(p.vec3_dot_into(x_axis), p.vec3_dot_into(y_axis), p.vec3_dot_into(z_axis), 0.0);
+            (min.min(p), max.max(p))
+        });
+
+        let mid = (min + max) * 0.5;
+        let center = x_axis * mid.splat_x() + y_axis * mid.splat_y() + z_axis * mid.splat_z();
+        let orientation = Quaternion::from_rotation_axes(x_axis, y_axis, z_axis);
+        Self { center, extents: (max - min) * 0.5, orientation }
+    }
+
+    /// Loads a value from a given `OrientedBoxData`.
+    #[inline]
+    #[must_use]
+    pub fn load_data(val: OrientedBoxData) -> Self {
+        Self {
+            center: Vector::load_float3(val.center),
+            extents: Vector::load_float3(val.extents),
+            orientation: Quaternion::load_float4(val.orientation)
+        }
+    }
+
+    /// Stores the value in an `OrientedBoxData`.
+    #[inline]
+    #[must_use]
+    pub fn store_data(self) -> OrientedBoxData {
+        OrientedBoxData {
+            center: self.center.store_float3(),
+            extents: self.extents.store_float3(),
+            orientation: self.orientation.store_float4()
+        }
+    }
+
+    /// Returns the eight corners of the box.
+    ///
+    /// ※ The `n`-th corner takes the positive side of the local `x`, `y` and `z` axes if the bits `0`, `1` and `2` of `n` are set. </br>
+    ///
+    #[must_use]
+    pub fn corners(self) -> [Vector; 8] {
+        let mut corners = Aabb::new(Vector::ZERO, self.extents).corners();
+        for corner in corners.iter_mut() {
+            *corner = self.center + self.to_world(*corner);
+        }
+        corners
+    }
+
+    /// Returns `true` if the box contains the given point. (including the boundary)
+    #[inline]
+    #[must_use]
+    pub fn contains_point(self, point: Vector) -> bool {
+        let local = self.to_local(point - self.center);
+        all_xyz(local.abs().le(self.extents))
+    }
+
+    /// Returns `true` if the oriented box and the axis-aligned box intersect. (including touching)
+    #[inline]
+    #[must_use]
+    pub fn intersects_aabb(self, aabb: Aabb) -> bool {
+        self.intersects_oriented_box(Self::from_aabb(aabb))
+    }
+
+    /// Returns `true` if the box and the sphere intersect. (including touching)
+    #[inline]
+    #[must_use]
+    pub fn intersects_sphere(self, sphere: BoundingSphere) -> bool {
+        let local = self.to_local(sphere.center - self.center);
+        let closest = local.max(-self.extents).min(self.extents);
+        (local - closest).vec3_len_sq() <= sphere.radius * sphere.radius
+    }
+
+    /// Returns `true` if the two boxes intersect. (including touching)
+    ///
+    /// ※ Uses the separating axis theorem with the fifteen candidate axes. </br>
+    ///
+    #[must_use]
+    pub fn intersects_oriented_box(self, other: OrientedBox) -> bool {
+        let (a0, a1, a2) = self.orientation.to_rotation_axes();
+        let (b0, b1, b2) = other.orientation.to_rotation_axes();
+        let (a, b) = ([a0, a1, a2], [b0, b1, b2]);
+        let ea = self.extents.into_array();
+        let eb = other.extents.into_array();
+
+        // Rotation of the other box in the local space of this box.
+        // (an epsilon is added to the absolute values to handle the nearly parallel edges)
+        let mut r = [[0.0; 3]; 3];
+        let mut abs_r = [[0.0; 3]; 3];
+        for i in 0..3 {
+            for j in 0..3 {
+                r[i][j] = a[i].vec3_dot_into(b[j]);
+                abs_r[i][j] = r[i][j].abs() + f32::EPSILON;
+            }
+        }
+
+        // Translation in the local space of this box.
+        let d = other.center - self.center;
+        let t = [d.vec3_dot_into(a0), d.vec3_dot_into(a1), d.vec3_dot_into(a2)];
+
+        // The axes of this box.
+        for i in 0..3 {
+            let rb = eb[0] * abs_r[i][0] + eb[1] * abs_r[i][1] + eb[2] * abs_r[i][2];
+            if t[i].abs() > ea[i] + rb {
+                return false;
+            }
+        }
+
+        // The axes of the other box.
+        for j in 0..3 {
+            let ra = ea[0] * abs_r[0][j] + ea[1] * abs_r[1][j] + ea[2] * abs_r[2][j];
+            let dist = t[0] * r[0][j] + t[1] * r[1][j] + t[2] * r[2][j];
+            if dist.abs() > ra + eb[j] {
+                return false;
+            }
+        }
+
+        // The cross products of the axes of both boxes.
+        for i in 0..3 {
+            let (i1, i2) = ((i + 1) % 3, (i + 2) % 3);
+            for j in 0..3 {
+                let (j1, j2) = ((j + 1) % 3, (j + 2) % 3);
+                let ra = ea[i1] * abs_r[i2][j] + ea[i2] * abs_r[i1][j];
+                let rb = eb[j1] * abs_r[i][j2] + eb[j2] * abs_r[i][j1];
+                let dist = t[i2] * r[i1][j] - t[i1] * r[i2][j];
+                if dist.abs() > ra + rb {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+    /// Returns the smallest box with the orientation of this box that contains both boxes.
+    #[must_use]
+    pub fn merge(self, other: OrientedBox) -> Self {
+        let (min, max) = other.corners().iter().fold((-self.extents, self.extents), |(min, max), p| {
+            let p = self.to_local(*p - self.center);
+            (min.min(p), max.max(p))
+        });
+
+        let mid = (min + max) * 0.5;
+        Self {
+            center: self.center + self.to_world(mid),
+            extents: (max - min) * 0.5,
+            orientation: self.orientation
+        }
+    }
+
+    /// Returns a box that contains the box transformed by the given matrix.
+    ///
+    /// ※ The matrix must be an invertible affine transformation. </br>
+    /// ※ If the matrix has a non-uniform scale, the transformed box can be skewed.
+    /// In this case, the result is the box with orthogonal axes that contains the skewed box. </br>
+    ///
+    #[must_use]
+    pub fn transform(self, m: Matrix) -> Self {
+        // Transformed local axes of the box.
+        let (x_axis, y_axis, z_axis) = self.orientation.to_rotation_axes();
+        let (x_axis, y_axis, z_axis) = (m * x_axis, m * y_axis, m * z_axis);
+        let half = [
+            x_axis * self.extents.splat_x(),
+            y_axis * self.extents.splat_y(),
+            z_axis * self.extents.splat_z()
+        ];
+
+        // Orthonormal axes of the result. (right-handed, even if the matrix has a reflection)
+        let x_axis = x_axis.vec3_normalize();
+        let y_axis = (y_axis - x_axis * y_axis.vec3_dot(x_axis)).vec3_normalize();
+        let z_axis = x_axis.vec3_cross(y_axis).vec3_normalize();
+
+        // Extents that contain the transformed box.
+        let extent = |axis: Vector| half.iter().map(|h| h.vec3_dot_into(axis).abs()).sum::<f32>();
+        Self {
+            center: transform_point(&m, self.center),
+            extents: Vector::new(extent(x_axis), extent(y_axis), extent(z_axis), 0.0),
+            orientation: Quaternion::from_rotation_axes(x_axis, y_axis, z_axis)
+        }
+    }
+}
+
+impl OrientedBox {
+    /// Rotates the given world space offset into the local space of the box.
+    #[inline]
+    fn to_local(self, v: Vector) -> Vector {
+        let (x_axis, y_axis, z_axis) = self.orientation.to_rotation_axes();
+        Vector::new(v.vec3_dot_into(x_axis), v.vec3_dot_into(y_axis), v.vec3_dot_into(z_axis), 0.0)
+    }
+
+    /// Rotates the given local space offset into the world space.
+    #[inline]
+    fn to_world(self, v: Vector) -> Vector {
+        let (x_axis, y_axis, z_axis) = self.orientation.to_rotation_axes();
+        x_axis * v.splat_x() + y_axis * v.splat_y() + z_axis * v.splat_z()
+    }
+}
+
+impl From<OrientedBoxData> for OrientedBox {
+    #[inline]
+    fn from(value: OrientedBoxData) -> Self {
+        Self::load_data(value)
+    }
+}
+
+impl From<OrientedBox> for OrientedBoxData {
+    #[inline]
+    fn from(value: OrientedBox) -> Self {
+        value.store_data()
+    }
+}
+
+impl fmt::Debug for OrientedBox {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(stringify!(OrientedBox))
+            .field("center", &self.center)
+            .field("extents", &self.extents)
+            .field("orientation", &self.orientation)
+            .finish()
+    }
+}
+
+
+
+/// Returns the eigenvectors of the given symmetric matrix as the columns of a matrix.
+///
+/// ※ Uses the cyclic Jacobi eigenvalue algorithm. </br>
+///
+fn jacobi_eigenvectors(mut a: [[f32; 3]; 3]) -> [[f32; 3]; 3] {
+    let mut v = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+    for _ in 0..MAX_JACOBI_SWEEPS {
+        let off = a[0][1] * a[0][1] + a[0][2] * a[0][2] + a[1][2] * a[1][2];
+        if off <= f32::MIN_POSITIVE {
+            break;
+        }
+
+        for &(p, q) in [(0, 1), (0, 2), (1, 2)].iter() {
+            if a[p][q] == 0.0 {
+                continue;
+            }
+
+            // Jacobi rotation that eliminates the element `(p, q)`.
+            let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+            let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+            let c = 1.0 / (t * t + 1.0).sqrt();
+            let s = t * c;
+
+            for row in a.iter_mut() {
+                let (akp, akq) = (row[p], row[q]);
+                row[p] = c * akp - s * akq;
+                row[q] = s * akp + c * akq;
+            }
+            let (row_p, row_q) = (a[p], a[q]);
+            a[p] = [0, 1, 2].map(|k| c * row_p[k] - s * row_q[k]);
+            a[q] = [0, 1, 2].map(|k| s * row_p[k] + c * row_q[k]);
+            for row in v.iter_mut() {
+                let (vkp, vkq) = (row[p], row[q]);
+                row[p] = c * vkp - s * vkq;
+                row[q] = s * vkp + c * vkq;
+            }
+        }
+    }
+    v
+}
@@ -0,0 +1,237 @@
+use core::fmt;
+use crate::{ Vector, Matrix, Float4 };
+
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+
+
+
+/// This is a plane stored as `(a, b, c, d)` of the equation `ax + by + cz + d = 0`.
+///
+/// ※ This has a similar purpose to `XMPlane*` in the `DirectXMath` library. </br>
+/// ※ The `(a, b, c)` elements are the normal of the plane, and it points to the positive side. </br>
+/// ※ Most functions do not require a normalized plane, but the distances are in world units only if it is normalized. </br>
+///
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq)]
+pub struct Plane {
+    inner: Vector,
+}
+
+impl Plane {
+    /// Creates with the given coefficients of the plane equation.
+    #[inline]
+    #[must_use]
+    pub fn new(a: f32, b: f32, c: f32, d: f32) -> Self {
+        Self { inner: Vector::new(a, b, c, d) }
+    }
+
+    /// Creates from a vector of `(a, b, c, d)`.
+    #[inline]
+    #[must_use]
+    pub const fn from_vector(v: Vector) -> Self {
+        Self { inner: v }
+    }
+
+    /// Converts to a vector of `(a, b, c, d)`.
+    #[inline]
+    #[must_use]
+    pub const fn into_vector(self) -> Vector {
+        self.inner
+    }
+
+    /// Loads a value from a given `Float4` of `(a, b, c, d)`.
+    #[inline]
+    #[must_use]
+    pub fn load_float4(val: Float4) -> Self {
+        Self { inner: Vector::load_float4(val) }
+    }
+
+    /// Stores the value in a `Float4` of `(a, b, c, d)`.
+    #[inline]
+    #[must_use]
+    pub fn store_float4(self) -> Float4 {
+        self.inner.store_float4()
+    }
+
+    /// Creates a plane that passes through the given point with the given normal.
+    ///
+    /// ※ The plane is normalized only if the given normal is a unit vector. </br>
+    ///
+    #[inline]
+    #[must_use]
+    pub fn from_point_normal(point: Vector, normal: Vector) -> Self {
+        let mut inner = normal;
+        inner.set_w(-point.vec3_dot_into(normal));
+        Self { inner }
+    }
+
+    /// Creates a normalized plane that passes through the three given points.
+    ///
+    /// ※ The normal is the direction of `(b - a) × (c - a)`,
+    /// so it points to the side where the points are counter-clockwise in a right-handed coordinate system. </br>
+    ///
+    /// Undefined behavior may occur if the three points are on the same line.
+    ///
+    #[inline]
+    #[must_use]
+    pub fn from_points(a: Vector, b: Vector, c: Vector) -> Self {
+        let normal = (b - a).vec3_cross(c - a).vec3_normalize();
+        Self::from_point_normal(a, normal)
+    }
+
+    /// Returns the normal of the plane. (the `w` element is zero)
+    #[inline]
+    #[must_use]
+    pub fn normal(self) -> Vector {
+        let mut normal = self.inner;
+        normal.set_w(0.0);
+        normal
+    }
+
+    /// Returns the `d` coefficient of the plane equation.
+    #[inline]
+    #[must_use]
+    pub fn d(self) -> f32 {
+        self.inner.get_w()
+    }
+
+    /// Returns `true` if the normal of the plane is a unit vector.
+    #[inline]
+    #[must_use]
+    pub fn is_normalized(self) -> bool {
+        self.inner.is_vec3_normalized()
+    }
+
+    /// Normalizes the plane so that the normal is a unit vector.
+    ///
+    /// Undefined behavior may occur if the length of the normal is less than or equal to [`f32::EPSILON`].
+    ///
+    #[inline]
+    #[must_use]
+    pub fn normalize(self) -> Self {
+        Self { inner: self.inner * self.inner.vec3_len().recip() }
+    }
+
+    /// Normalizes the plane so that the normal is a unit vector.
+    ///
+    /// Returns `None` if the length of the normal is less than or equal to [`f32::EPSILON`].
+    ///
+    #[inline]
+    #[must_use]
+    pub fn try_normalize(self) -> Option<Self> {
+        let length = self.inner.vec3_len();
+        if length <= f32::EPSILON {
+            return None;
+        }
+        Some(Self { inner: self.inner * length.recip() })
+    }
+
+    /// Dot product of the plane and the given four-element vector.
+    #[inline]
+    #[must_use]
+    pub fn dot(self, v: Vector) -> f32 {
+        self.inner.vec4_dot_into(v)
+    }
+
+    /// Returns `ax + by + cz + d` for the given point. (the `w` element of the point is treated as one)
+    ///
+    /// ※ If the plane is normalized, this is the signed distance from the plane to the point. </br>
+    ///
+    #[inline]
+    #[must_use]
+    pub fn dot_coord(self, point: Vector) -> f32 {
+        self.inner.vec3_dot_into(point) + self.d()
+    }
+
+    /// Returns `ax + by + cz` for the given vector. (the `w` element of the vector is treated as zero)
+    ///
+    /// ※ This is the dot product of the normal and the vector. </br>
+    ///
+    #[inline]
+    #[must_use]
+    pub fn dot_normal(self, v: Vector) -> f32 {
+        self.inner.vec3_dot_into(v)
+    }
+
+    /// Returns the point where the infinite line through the two given points intersects the plane.
+    ///
+    /// ※ Returns `None` if the line is parallel to the plane or the two points are the same. </br>
+    ///
+    #[must_use]
+    pub fn intersect_line(self, p0: Vector, p1: Vector) -> Option<Vector> {
+        let dir = p1 - p0;
+        let denom = self.dot_normal(dir);
+        if denom.abs() <= f32::EPSILON * self.inner.vec3_len() * dir.vec3_len() {
+            return None;
+        }
+
+        let t = -self.dot_coord(p0) / denom;
+        Some(dir.mul_add(Vector::fill(t), p0))
+    }
+
+    /// Returns the line where the two planes intersect as a point on the line and the unit direction of the line.
+    ///
+    /// ※ The point is the closest point on the line to the origin. </br>
+    /// ※ The direction is the direction of the cross product of the two normals. </br>
+    /// ※ Returns `None` if the two planes are parallel. </br>
+    ///
+    #[must_use]
+    pub fn intersect_plane(self, other: Plane) -> Option<(Vector, Vector)> {
+        let (n0, n1) = (self.normal(), other.normal());
+        let dir = n0.vec3_cross(n1);
+        let len_sq = dir.vec3_len_sq();
+        if len_sq <= f32::EPSILON * f32::EPSILON * n0.vec3_len_sq() * n1.vec3_len_sq() {
+            return None;
+        }
+
+        let point = (n0 * other.d() - n1 * self.d()).vec3_cross(dir) / len_sq;
+        Some((point, dir * len_sq.sqrt().recip()))
+    }
+
+    /// Returns the plane transformed by the given matrix.
+    ///
+    /// ※ The plane is multiplied by the inverse-transpose of the matrix, so the matrix must be invertible. </br>
+    /// ※ The result is not normalized if the matrix has a scale. </br>
+    ///
+    #[inline]
+    #[must_use]
+    pub fn transform(self, m: Matrix) -> Self {
+        Self { inner: m.inverse().transpose() * self.inner }
+    }
+}
+
+impl Default for Plane {
+    /// The `xz` plane with the normal of the positive `y` axis.
+    #[inline]
+    fn default() -> Self {
+        Self { inner: Vector::Y }
+    }
+}
+
+impl From<Float4> for Plane {
+    #[inline]
+    fn from(value: Float4) -> Self {
+        Self::load_float4(value)
+    }
+}
+
+impl From<Plane> for Float4 {
+    #[inline]
+    fn from(value: Plane) -> Self {
+        value.store_float4()
+    }
+}
+
+impl fmt::Debug for Plane {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [a, b, c, d] = self.inner.into_array();
+        f.debug_struct(stringify!(Plane))
+            .field("a", &a)
+            .field("b", &b)
+            .field("c", &c)
+            .field("d", &d)
+            .finish()
+    }
+}
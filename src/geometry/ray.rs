@@ -0,0 +1,255 @@
+use core::fmt;
+use crate::Vector;
+use super::{ Aabb, BoundingSphere, Plane, RayHit, TriangleHit };
+
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+
+
+
+/// This is a ray stored as the origin and the unit direction.
+///
+/// ※ This has a similar purpose to the ray tests of the `DirectXCollision` library. </br>
+/// ※ The direction must be a unit vector, so the hit distances are in world units. </br>
+/// ※ The queries only report hits in front of the origin. (including the origin) </br>
+///
+#[derive(Clone, Copy, PartialEq)]
+pub struct Ray {
+    /// The origin of the ray.
+    pub origin: Vector,
+
+    /// The unit direction of the ray.
+    pub direction: Vector,
+}
+
+impl Ray {
+    /// Creates with the given origin and direction.
+    ///
+    /// # Panics
+    /// When the `use-assertion` feature is enabled, [`panic!`] will be called
+    /// if the direction is not a unit vector.
+    ///
+    #[inline]
+    #[must_use]
+    pub fn new(origin: Vector, direction: Vector) -> Self {
+        #[cfg(feature = "use-assertion")]
+        assert!(direction.is_vec3_normalized(), "The direction must be a unit vector!");
+        Self { origin, direction }
+    }
+
+    /// Creates a ray from the `origin` toward the `target`.
+    ///
+    /// Undefined behavior may occur if the distance between the two points is less than or equal to [`f32::EPSILON`].
+    ///
+    #[inline]
+    #[must_use]
+    pub fn from_points(origin: Vector, target: Vector) -> Self {
+        Self { origin, direction: (target - origin).vec3_normalize() }
+    }
+
+    /// Returns the point at the given distance along the ray.
+    #[inline]
+    #[must_use]
+    pub fn point_at(self, distance: f32) -> Vector {
+        self.direction.mul_add(Vector::fill(distance), self.origin)
+    }
+
+    /// Finds the hit point of the ray and the triangle `(a, b, c)`. (Möller–Trumbore)
+    ///
+    /// ※ Both sides of the triangle are hit. </br>
+    /// ※ Returns `None` if the ray is parallel to the triangle or the triangle is degenerate. </br>
+    ///
+    #[inline]
+    #[must_use]
+    pub fn intersect_triangle(self, a: Vector, b: Vector, c: Vector) -> Option<TriangleHit> {
+        self.intersect_triangle_within(a, b, c, f32::INFINITY)
+    }
+
+    /// Finds the hit point of the ray and the triangle `(a, b, c)` within the given distance. (Möller–Trumbore)
+    ///
+    /// ※ Both sides of the triangle are hit. </br>
+    /// ※ Returns `None` if the ray is parallel to the triangle or the triangle is degenerate. </br>
+    ///
+    #[must_use]
+    pub fn intersect_triangle_within(self, a: Vector, b: Vector, c: Vector, max_distance: f32) -> Option<TriangleHit> {
+        let edge1 = b - a;
+        let edge2 = c - a;
+        let p = self.direction.vec3_cross(edge2);
+        let det = edge1.vec3_dot_into(p);
+        if det.abs() <= f32::EPSILON * edge1.vec3_len() * edge2.vec3_len() {
+            return None;
+        }
+
+        let inv_det = det.recip();
+        let s = self.origin - a;
+        let u = s.vec3_dot_into(p) * inv_det;
+        if !(0.0..=1.0).contains(&u) {
+            return None;
+        }
+
+        let q = s.vec3_cross(edge1);
+        let v = self.direction.vec3_dot_into(q) * inv_det;
+        if v < 0.0 || u + v > 1.0 {
+            return None;
+        }
+
+        let distance = edge2.vec3_dot_into(q) * inv_det;
+        if !(0.0..=max_distance).contains(&distance) {
+            return None;
+        }
+
+        let normal = edge1.vec3_cross(edge2).vec3_normalize();
+        Some(TriangleHit { distance, normal, u, v })
+    }
+
+    /// Finds the hit point of the ray and the axis-aligned box. (slab method)
+    ///
+    /// ※ If the origin is inside the box, the hit point is where the ray leaves the box. </br>
+    ///
+    #[inline]
+    #[must_use]
+    pub fn intersect_aabb(self, aabb: Aabb) -> Option<RayHit> {
+        self.intersect_aabb_within(aabb, f32::INFINITY)
+    }
+
+    /// Finds the hit point of the ray and the axis-aligned box within the given distance. (slab method)
+    ///
+    /// ※ If the origin is inside the box, the hit point is where the ray leaves the box. </br>
+    ///
+    #[must_use]
+    pub fn intersect_aabb_within(self, aabb: Aabb, max_distance: f32) -> Option<RayHit> {
+        let origin = self.origin.into_array();
+        let direction = self.direction.into_array();
+        let min = aabb.min().into_array();
+        let max = aabb.max().into_array();
+
+        // The entering and leaving distances, and the axes of the slabs.
+        let (mut near, mut near_axis) = (f32::NEG_INFINITY, 0);
+        let (mut far, mut far_axis) = (f32::INFINITY, 0);
+        for axis in 0..3 {
+            if direction[axis] == 0.0 {
+                // The ray is parallel to the slab.
+                if origin[axis] < min[axis] || origin[axis] > max[axis] {
+                    return None;
+                }
+                continue;
+            }
+
+            let inv = direction[axis].recip();
+            let t0 = (min[axis] - origin[axis]) * inv;
+            let t1 = (max[axis] - origin[axis]) * inv;
+            let (t0, t1) = if t0 <= t1 { (t0, t1) } else { (t1, t0) };
+            if t0 > near {
+                near = t0;
+                near_axis = axis;
+            }
+            if t1 < far {
+                far = t1;
+                far_axis = axis;
+            }
+        }
+
+        if near > far || far < 0.0 {
+            return None;
+        }
+
+        // The normal faces against the ray when entering, and along the ray when leaving.
+        let (distance, axis, sign) = match near >= 0.0 {
+            true => (near, near_axis, -direction[near_axis].signum()),
+            false => (far, far_axis, direction[far_axis].signum()),
+        };
+        if distance > max_distance {
+            return None;
+        }
+
+        let mut normal = [0.0; 4];
+        normal[axis] = sign;
+        Some(RayHit { distance, normal: Vector::from_array(normal) })
+    }
+
+    /// Finds the hit point of the ray and the sphere.
+    ///
+    /// ※ If the origin is inside the sphere, the hit point is where the ray leaves the sphere. </br>
+    ///
+    #[inline]
+    #[must_use]
+    pub fn intersect_sphere(self, sphere: BoundingSphere) -> Option<RayHit> {
+        self.intersect_sphere_within(sphere, f32::INFINITY)
+    }
+
+    /// Finds the hit point of the ray and the sphere within the given distance.
+    ///
+    /// ※ If the origin is inside the sphere, the hit point is where the ray leaves the sphere. </br>
+    ///
+    #[must_use]
+    pub fn intersect_sphere_within(self, sphere: BoundingSphere, max_distance: f32) -> Option<RayHit> {
+        let m = self.origin - sphere.center;
+        let b = m.vec3_dot_into(self.direction);
+        let c = m.vec3_len_sq() - sphere.radius * sphere.radius;
+
+        // The origin is outside of the sphere and the ray points away from it.
+        if c > 0.0 && b > 0.0 {
+            return None;
+        }
+
+        // Same as `b * b - c`, but it is more accurate when the ray is far from the sphere.
+        let perpendicular = m - self.direction * b;
+        let discriminant = sphere.radius * sphere.radius - perpendicular.vec3_len_sq();
+        if discriminant < 0.0 {
+            return None;
+        }
+
+        let root = discriminant.sqrt();
+        let distance = if -b - root >= 0.0 { -b - root } else { -b + root };
+        if distance > max_distance {
+            return None;
+        }
+
+        let normal = (self.point_at(distance) - sphere.center)
+            .try_vec3_normalize()
+            .unwrap_or(-self.direction);
+        Some(RayHit { distance, normal })
+    }
+
+    /// Finds the hit point of the ray and the plane.
+    ///
+    /// ※ The normal of the hit is the normalized normal of the plane, regardless of the side that is hit. </br>
+    /// ※ Returns `None` if the ray is parallel to the plane. </br>
+    ///
+    #[inline]
+    #[must_use]
+    pub fn intersect_plane(self, plane: Plane) -> Option<RayHit> {
+        self.intersect_plane_within(plane, f32::INFINITY)
+    }
+
+    /// Finds the hit point of the ray and the plane within the given distance.
+    ///
+    /// ※ The normal of the hit is the normalized normal of the plane, regardless of the side that is hit. </br>
+    /// ※ Returns `None` if the ray is parallel to the plane. </br>
+    ///
+    #[must_use]
+    pub fn intersect_plane_within(self, plane: Plane, max_distance: f32) -> Option<RayHit> {
+        let normal = plane.normal();
+        let denom = plane.dot_normal(self.direction);
+        if denom.abs() <= f32::EPSILON * normal.vec3_len() {
+            return None;
+        }
+
+        let distance = -plane.dot_coord(self.origin) / denom;
+        if !(0.0..=max_distance).contains(&distance) {
+            return None;
+        }
+
+        Some(RayHit { distance, normal: normal.vec3_normalize() })
+    }
+}
+
+impl fmt::Debug for Ray {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(stringify!(Ray))
+            .field("origin", &self.origin)
+            .field("direction", &self.direction)
+            .finish()
+    }
+}
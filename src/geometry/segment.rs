@@ -0,0 +1,102 @@
+use core::fmt;
+use crate::Vector;
+use super::{ Aabb, BoundingSphere, Plane, Ray, RayHit, TriangleHit };
+
+
+
+/// This is a line segment stored as the start point and the end point.
+///
+/// ※ The queries are the ray queries limited to the length of the segment. </br>
+/// ※ The hit distances are measured from the start point in world units. </br>
+/// ※ A segment shorter than or equal to [`f32::EPSILON`] hits nothing. </br>
+///
+#[derive(Clone, Copy, Default, PartialEq)]
+pub struct Segment {
+    /// The start point of the segment.
+    pub start: Vector,
+
+    /// The end point of the segment.
+    pub end: Vector,
+}
+
+impl Segment {
+    /// Creates with the given start point and end point.
+    #[inline]
+    #[must_use]
+    pub const fn new(start: Vector, end: Vector) -> Self {
+        Self { start, end }
+    }
+
+    /// Returns the length of the segment.
+    #[inline]
+    #[must_use]
+    pub fn len(self) -> f32 {
+        (self.end - self.start).vec3_len()
+    }
+
+    /// Returns the ray from the start point toward the end point and the length of the segment.
+    ///
+    /// Returns `None` if the length of the segment is less than or equal to [`f32::EPSILON`].
+    ///
+    #[inline]
+    #[must_use]
+    pub fn to_ray(self) -> Option<(Ray, f32)> {
+        let offset = self.end - self.start;
+        let length = offset.vec3_len();
+        if length <= f32::EPSILON {
+            return None;
+        }
+        Some((Ray { origin: self.start, direction: offset * length.recip() }, length))
+    }
+
+    /// Finds the hit point of the segment and the triangle `(a, b, c)`. (Möller–Trumbore)
+    ///
+    /// ※ Both sides of the triangle are hit. </br>
+    ///
+    #[inline]
+    #[must_use]
+    pub fn intersect_triangle(self, a: Vector, b: Vector, c: Vector) -> Option<TriangleHit> {
+        let (ray, length) = self.to_ray()?;
+        ray.intersect_triangle_within(a, b, c, length)
+    }
+
+    /// Finds the hit point of the segment and the axis-aligned box. (slab method)
+    ///
+    /// ※ If the start point is inside the box, the hit point is where the segment leaves the box. </br>
+    ///
+    #[inline]
+    #[must_use]
+    pub fn intersect_aabb(self, aabb: Aabb) -> Option<RayHit> {
+        let (ray, length) = self.to_ray()?;
+        ray.intersect_aabb_within(aabb, length)
+    }
+
+    /// Finds the hit point of the segment and the sphere.
+    ///
+    /// ※ If the start point is inside the sphere, the hit point is where the segment leaves the sphere. </br>
+    ///
+    #[inline]
+    #[must_use]
+    pub fn intersect_sphere(self, sphere: BoundingSphere) -> Option<RayHit> {
+        let (ray, length) = self.to_ray()?;
+        ray.intersect_sphere_within(sphere, length)
+    }
+
+    /// Finds the hit point of the segment and the plane.
+    #[inline]
+    #[must_use]
+    pub fn intersect_plane(self, plane: Plane) -> Option<RayHit> {
+        let (ray, length) = self.to_ray()?;
+        ray.intersect_plane_within(plane, length)
+    }
+}
+
+impl fmt::Debug for Segment {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(stringify!(Segment))
+            .field("start", &self.start)
+            .field("end", &self.end)
+            .finish()
+    }
+}
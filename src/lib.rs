@@ -38,25 +38,49 @@
 //! ### Supports SIMD operations
 //! The gmm library supports SIMD instructions on the `x86`, `x86_64` and the `aarch64` architecture.
 //! 
+//! On `x86` and `x86_64`, the `fma` instructions are used when the target feature is enabled.
+//! (e.g. `RUSTFLAGS="-C target-feature=+fma"` or `-C target-cpu=native`)
+//! 
 //! ### Compile Features
+//! - `std` - Uses the standard library for floating-point functions. (enabled by default)
+//! - `libm` - Uses the libm library for floating-point functions. (for `no_std` targets)
 //! - `bytemuck` - Enables the bytemuck library implementation.
 //! - `serde` - Enables the serde library implementation.
 //! - `mint` - Enables the mint library implementation.
+//! - `approx` - Enables the approx library implementation.
 //! - `scalar-math` - Disable the simd instruction in the library.
 //! - `use-assertion` - Allow panic calls within a function.
 //! 
+//! ### `no_std` Support
+//! The gmm library is `no_std`. Disable the default features and enable the `libm` feature
+//! to use it on targets without the standard library.
+//! 
 //! # License
 //! MIT license (LICENSE or http://opensource.org/licenses/MIT)
 //! 
 
+#![no_std]
+
+#[cfg(any(feature = "std", test))]
+extern crate std;
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("Either the `std` feature or the `libm` feature must be enabled.");
+
 #[cfg(test)]
 mod tests;
 
 mod macros;
 mod features;
 
+#[cfg(not(feature = "std"))]
+mod math;
+mod near_eq;
+
 pub mod data;
 pub mod vec;
+pub mod color;
+pub mod geometry;
 
 
 
@@ -69,8 +93,31 @@ pub use self::data::Float3;
 pub use self::data::Float4;
 
 pub use self::data::Float3x3;
+pub use self::data::Float3x4;
 pub use self::data::Float4x4;
 
+pub use self::data::Double2;
+pub use self::data::Double3;
+pub use self::data::Double4;
+
+pub use self::data::Double4x4;
+
+pub use self::data::Half;
+pub use self::data::Half2;
+pub use self::data::Half4;
+
+pub use self::data::UByteN4;
+pub use self::data::ByteN4;
+pub use self::data::ShortN2;
+pub use self::data::UShortN4;
+pub use self::data::UDecN4;
+pub use self::data::Float3PK;
+pub use self::data::Rgb9e5;
+
+pub use self::data::AabbData;
+pub use self::data::BoundingSphereData;
+pub use self::data::OrientedBoxData;
+
 pub use self::data::Integer2;
 pub use self::data::Integer3;
 pub use self::data::Integer4;
@@ -80,6 +127,24 @@ pub use self::data::UInteger3;
 pub use self::data::UInteger4;
 
 pub use self::vec::Vector;
+pub use self::vec::Vector3x4;
+pub use self::vec::Vector4x4;
 pub use self::vec::VectorInt;
 pub use self::vec::Quaternion;
 pub use self::vec::Matrix;
+pub use self::vec::Matrix3;
+pub use self::vec::Affine3;
+pub use self::vec::EulerRot;
+pub use self::vec::DepthRange;
+pub use self::vec::DVector;
+pub use self::vec::DQuaternion;
+pub use self::vec::DMatrix;
+
+pub use self::geometry::Aabb;
+pub use self::geometry::BoundingSphere;
+pub use self::geometry::OrientedBox;
+pub use self::geometry::Plane;
+pub use self::geometry::Ray;
+pub use self::geometry::Segment;
+pub use self::geometry::RayHit;
+pub use self::geometry::TriangleHit;
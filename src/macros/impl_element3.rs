@@ -1,4 +1,158 @@
 macro_rules! impl_element3 {
+    ($s:ty, $t:ty, float) => {
+        crate::macros::impl_element3!($s, $t);
+
+        impl $t {
+            /// Takes the smaller of the elements of the two vectors.
+            #[inline]
+            #[must_use]
+            pub fn min(self, rhs: Self) -> Self {
+                Self { x: self.x.min(rhs.x), y: self.y.min(rhs.y), z: self.z.min(rhs.z) }
+            }
+
+            /// Takes the larger of the elements of the two vectors.
+            #[inline]
+            #[must_use]
+            pub fn max(self, rhs: Self) -> Self {
+                Self { x: self.x.max(rhs.x), y: self.y.max(rhs.y), z: self.z.max(rhs.z) }
+            }
+
+            /// Absolute value on vector elements.
+            #[inline]
+            #[must_use]
+            pub fn abs(self) -> Self {
+                Self { x: self.x.abs(), y: self.y.abs(), z: self.z.abs() }
+            }
+
+            /// Returns a vector containing the smallest integer less than or equal to the number for each element of the vector.
+            #[inline]
+            #[must_use]
+            pub fn floor(self) -> Self {
+                Self { x: self.x.floor(), y: self.y.floor(), z: self.z.floor() }
+            }
+
+            /// Returns a vector containing the smallest integer greater than or equal to the number for each element of the vector.
+            #[inline]
+            #[must_use]
+            pub fn ceil(self) -> Self {
+                Self { x: self.x.ceil(), y: self.y.ceil(), z: self.z.ceil() }
+            }
+
+            /// Returns a vector with the numbers for each element of the vector rounded to the nearest integer.
+            /// 
+            /// ※ Half-way cases are rounded away from zero. </br>
+            /// 
+            #[inline]
+            #[must_use]
+            pub fn round(self) -> Self {
+                Self { x: self.x.round(), y: self.y.round(), z: self.z.round() }
+            }
+
+            /// Returns the sum of all elements in a vector.
+            #[inline]
+            #[must_use]
+            pub fn sum(self) -> $s {
+                self.x + self.y + self.z
+            }
+
+            /// Dot product of two vectors.
+            #[inline]
+            #[must_use]
+            pub fn dot(self, rhs: Self) -> $s {
+                self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
+            }
+
+            /// Cross product of two vectors.
+            #[inline]
+            #[must_use]
+            pub fn cross(self, rhs: Self) -> Self {
+                Self {
+                    x: self.y * rhs.z - self.z * rhs.y, 
+                    y: self.z * rhs.x - self.x * rhs.z, 
+                    z: self.x * rhs.y - self.y * rhs.x 
+                }
+            }
+
+            /// Returns the length squared of a vector.
+            #[inline]
+            #[must_use]
+            pub fn len_sq(self) -> $s {
+                self.dot(self)
+            }
+
+            /// Returns the length of a vector.
+            #[inline]
+            #[must_use]
+            pub fn len(self) -> $s {
+                self.len_sq().sqrt()
+            }
+
+            /// Returns the distance squared between two points.
+            #[inline]
+            #[must_use]
+            pub fn distance_sq(self, rhs: Self) -> $s {
+                (rhs - self).len_sq()
+            }
+
+            /// Returns the distance between two points.
+            #[inline]
+            #[must_use]
+            pub fn distance(self, rhs: Self) -> $s {
+                (rhs - self).len()
+            }
+
+            /// Returns `true` if it is a unit vector.
+            #[inline]
+            #[must_use]
+            pub fn is_normalized(self) -> bool {
+                (self.len() - 1.0).abs() <= <$s>::EPSILON
+            }
+
+            /// Normalizes a vector.
+            /// 
+            /// Undefined behavior may occur if the length of the vector is less than or equal to the machine epsilon.
+            /// 
+            /// # Panics
+            /// When `use-assertion` feature is enabled, [`panic!`] will be called 
+            /// if the length of the vector is less than or equal to the machine epsilon.
+            /// 
+            #[inline]
+            #[must_use]
+            pub fn normalize(self) -> Self {
+                #[cfg(feature = "use-assertion")]
+                assert!(self.len() > <$s>::EPSILON, "The length of the vector is less than or equal to the machine epsilon!");
+
+                self * self.len().recip()
+            }
+
+            /// Normalizes a vector.
+            /// 
+            /// Returns `None` if the length of the vector is less than or equal to the machine epsilon.
+            /// 
+            #[inline]
+            #[must_use]
+            pub fn try_normalize(self) -> Option<Self> {
+                let length = self.len();
+                if length <= <$s>::EPSILON {
+                    return None;
+                }
+                Some(self * length.recip())
+            }
+
+            /// Returns a vector that is a linear interpolation of two vectors.
+            /// 
+            /// The given `t` must be in the range zero to one.
+            ///  
+            /// The closer `t` is to one, the more it becomes equal to the given `rhs`.
+            /// 
+            #[inline]
+            #[must_use]
+            pub fn lerp(self, rhs: Self, t: $s) -> Self {
+                self * (1.0 - t) + rhs * t
+            }
+        }
+    };
+
     ($s:ty, $t:ty) => {
         impl $t {
             /// Creates with given elements.
@@ -87,10 +241,10 @@ macro_rules! impl_element3 {
             }
         }
 
-        impl Into<[$s; 3]> for $t {
+        impl From<$t> for [$s; 3] {
             #[inline]
-            fn into(self) -> [$s; 3] {
-                self.to_array()
+            fn from(value: $t) -> Self {
+                value.to_array()
             }
         }
 
@@ -101,10 +255,10 @@ macro_rules! impl_element3 {
             }
         }
 
-        impl Into<($s, $s, $s)> for $t {
+        impl From<$t> for ($s, $s, $s) {
             #[inline]
-            fn into(self) -> ($s, $s, $s) {
-                self.to_tuple()
+            fn from(value: $t) -> Self {
+                value.to_tuple()
             }
         }
 
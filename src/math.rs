@@ -0,0 +1,71 @@
+//! # Floating-point functions
+//! `core` does not provide floating-point functions such as `sqrt` or `sin_cos`.
+//!
+//! Without the `std` feature, the [`Float`] trait provides these functions using the `libm` library.
+//! It has the same method names as the standard library, so the code does not change depending on the feature.
+//!
+//! ※ When the `std` feature is enabled, the methods of the standard library are used. </br>
+//!
+
+/// Floating-point functions routed to the `libm` library.
+pub(crate) trait Float: Sized {
+    /// ※ `abs` is provided by `core` in recent Rust versions. </br>
+    #[allow(dead_code)]
+    fn abs(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn round(self) -> Self;
+    fn sin(self) -> Self;
+    fn sin_cos(self) -> (Self, Self);
+    fn acos(self) -> Self;
+}
+
+macro_rules! impl_float {
+    ($t:ty, $abs:ident, $sqrt:ident, $floor:ident, $ceil:ident, $round:ident, $sin:ident, $cos:ident, $acos:ident) => {
+        impl Float for $t {
+            #[inline]
+            fn abs(self) -> Self {
+                libm::$abs(self)
+            }
+
+            #[inline]
+            fn sqrt(self) -> Self {
+                libm::$sqrt(self)
+            }
+
+            #[inline]
+            fn floor(self) -> Self {
+                libm::$floor(self)
+            }
+
+            #[inline]
+            fn ceil(self) -> Self {
+                libm::$ceil(self)
+            }
+
+            #[inline]
+            fn round(self) -> Self {
+                libm::$round(self)
+            }
+
+            #[inline]
+            fn sin(self) -> Self {
+                libm::$sin(self)
+            }
+
+            #[inline]
+            fn sin_cos(self) -> (Self, Self) {
+                (libm::$sin(self), libm::$cos(self))
+            }
+
+            #[inline]
+            fn acos(self) -> Self {
+                libm::$acos(self)
+            }
+        }
+    };
+}
+
+impl_float!(f32, fabsf, sqrtf, floorf, ceilf, roundf, sinf, cosf, acosf);
+impl_float!(f64, fabs, sqrt, floor, ceil, round, sin, cos, acos);
@@ -0,0 +1,88 @@
+//! # Approximate equality
+//! The results of floating-point operations rarely match bit by bit, so they are compared with a tolerance.
+//!
+//! The [`NearEq`] trait compares two elements, and the functions in this module compare all elements of two slices.
+//!
+//! ※ The comparisons follow the semantics of the `approx` crate. </br>
+//!
+
+
+
+/// Approximate equality of floating-point elements.
+pub(crate) trait NearEq: Copy {
+    /// Returns `true` if the absolute difference is less than or equal to `max_abs_diff`.
+    fn abs_diff_eq(self, other: Self, max_abs_diff: Self) -> bool;
+
+    /// Returns `true` if the absolute difference is less than or equal to `max_abs_diff`,
+    /// or the relative difference is less than or equal to `max_relative`.
+    fn relative_eq(self, other: Self, max_abs_diff: Self, max_relative: Self) -> bool;
+
+    /// Returns `true` if the absolute difference is less than or equal to `max_abs_diff`,
+    /// or the two elements are at most `max_ulps` representable values apart.
+    fn ulps_eq(self, other: Self, max_abs_diff: Self, max_ulps: u32) -> bool;
+}
+
+macro_rules! impl_near_eq {
+    ($t:ty) => {
+        impl NearEq for $t {
+            #[inline]
+            fn abs_diff_eq(self, other: Self, max_abs_diff: Self) -> bool {
+                (self - other).abs() <= max_abs_diff
+            }
+
+            #[inline]
+            fn relative_eq(self, other: Self, max_abs_diff: Self, max_relative: Self) -> bool {
+                if self == other {
+                    return true;
+                }
+
+                if self.is_infinite() || other.is_infinite() {
+                    return false;
+                }
+
+                let abs_diff = (self - other).abs();
+                if abs_diff <= max_abs_diff {
+                    return true;
+                }
+
+                abs_diff <= self.abs().max(other.abs()) * max_relative
+            }
+
+            #[inline]
+            fn ulps_eq(self, other: Self, max_abs_diff: Self, max_ulps: u32) -> bool {
+                if self.abs_diff_eq(other, max_abs_diff) {
+                    return true;
+                }
+
+                if self.is_nan() || other.is_nan() || self.is_sign_negative() != other.is_sign_negative() {
+                    return false;
+                }
+
+                let (a, b) = (self.to_bits(), other.to_bits());
+                let ulps = if a > b { a - b } else { b - a };
+                ulps <= max_ulps as _
+            }
+        }
+    };
+}
+
+impl_near_eq!(f32);
+impl_near_eq!(f64);
+
+/// Returns `true` if the absolute difference of all elements is less than or equal to `max_abs_diff`.
+#[inline]
+pub(crate) fn abs_diff_eq<T: NearEq>(a: &[T], b: &[T], max_abs_diff: T) -> bool {
+    a.iter().zip(b).all(|(&a, &b)| a.abs_diff_eq(b, max_abs_diff))
+}
+
+/// Returns `true` if all elements are equal within `max_abs_diff` or the relative tolerance `max_relative`.
+#[inline]
+pub(crate) fn relative_eq<T: NearEq>(a: &[T], b: &[T], max_abs_diff: T, max_relative: T) -> bool {
+    a.iter().zip(b).all(|(&a, &b)| a.relative_eq(b, max_abs_diff, max_relative))
+}
+
+/// Returns `true` if all elements are equal within `max_abs_diff` or `max_ulps` representable values.
+#[inline]
+pub(crate) fn ulps_eq<T: NearEq>(a: &[T], b: &[T], max_abs_diff: T, max_ulps: u32) -> bool {
+    a.iter().zip(b).all(|(&a, &b)| a.ulps_eq(b, max_abs_diff, max_ulps))
+}
@@ -0,0 +1,147 @@
+use rand::Rng;
+use crate::{Affine3, Float3, Float3x4, Float4, Float4x4, Matrix, Quaternion, Vector};
+use super::NUM_TEST;
+
+
+
+/// Creates a random transformation and the same transformation of the control group.
+fn random_affine(rng: &mut impl Rng) -> Option<(Affine3, glam::Affine3A)> {
+    let scale: [f32; 3] = [(); 3].map(|_| rng.gen_range(0.1..=2.0) * if rng.gen() { 1.0 } else { -1.0 });
+    let rotation: [f32; 4] = [(); 4].map(|_| rng.gen_range(-1.0..=1.0));
+    let translation: [f32; 3] = [(); 3].map(|_| rng.gen_range(-10.0..=10.0));
+    let rotation = glam::Vec4::from_array(rotation).try_normalize()?;
+
+    let glam_affine = glam::Affine3A::from_scale_rotation_translation(
+        glam::Vec3::from_array(scale), 
+        glam::Quat::from_vec4(rotation), 
+        glam::Vec3::from_array(translation)
+    );
+    let affine = Affine3::from_scale_rotation_translation(
+        { let t: Float3 = scale.into(); t.into() }, 
+        { let t: Float4 = rotation.to_array().into(); let q: Quaternion = t.into(); q }, 
+        { let t: Float3 = translation.into(); t.into() }
+    );
+    Some((affine, glam_affine))
+}
+
+#[test]
+fn affine_from_scale_rotation_translation() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Affine3 and Control group
+        let (affine, glam_affine) = match random_affine(&mut rng) {
+            Some(pair) => pair, 
+            None => continue
+        };
+
+        // Compare `Affine3` and `Control group`
+        let a: [f32; 12] = affine.into();
+        let b: [f32; 12] = glam_affine.to_cols_array();
+        let validate = (0..12).all(|idx| (a[idx] - b[idx]).abs() <= 1.0e-5);
+        assert!(validate, "Test:{} >> Creating `Affine3` is invalid! (Affine3:{:?}, Control group:{:?})", test, a, b);
+    }
+}
+
+#[test]
+fn affine_mul() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Affine3 and Control group
+        let ((affine_a, glam_a), (affine_b, glam_b)) = match (random_affine(&mut rng), random_affine(&mut rng)) {
+            (Some(a), Some(b)) => (a, b), 
+            _ => continue
+        };
+        let affine_c = affine_a * affine_b;
+        let glam_c = glam_a * glam_b;
+
+        // Compare `Affine3` and `Control group`
+        let a: [f32; 12] = affine_c.into();
+        let b: [f32; 12] = glam_c.to_cols_array();
+        let validate = (0..12).all(|idx| (a[idx] - b[idx]).abs() <= 1.0e-4);
+        assert!(validate, "Test:{} >> Multiply operation on `Affine3` is invalid! (Affine3:{:?}, Control group:{:?})", test, a, b);
+    }
+}
+
+#[test]
+fn affine_inverse() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Affine3 and Control group
+        let (affine, glam_affine) = match random_affine(&mut rng) {
+            Some(pair) => pair, 
+            None => continue
+        };
+        let affine_inv = affine.try_inverse();
+        let glam_inv = glam_affine.inverse();
+
+        // Compare `Affine3` and `Control group`
+        let affine_inv = match affine_inv {
+            Some(affine_inv) => affine_inv, 
+            None => panic!("Test:{} >> Inverse operation on `Affine3` is invalid! (Affine3:{:?})", test, affine)
+        };
+        let a: [f32; 12] = affine_inv.into();
+        let b: [f32; 12] = glam_inv.to_cols_array();
+        let validate = (0..12).all(|idx| (a[idx] - b[idx]).abs() <= 1.0e-4);
+        assert!(validate, "Test:{} >> Inverse operation on `Affine3` is invalid! (Affine3:{:?}, Control group:{:?})", test, a, b);
+    }
+}
+
+#[test]
+fn affine_transform() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin: [f32; 3] = [(); 3].map(|_| rng.gen_range(-10.0..=10.0));
+
+        // Affine3 and Control group
+        let (affine, glam_affine) = match random_affine(&mut rng) {
+            Some(pair) => pair, 
+            None => continue
+        };
+        let vector: Vector = { let t: Float3 = origin.into(); t.into() };
+        let point = affine.transform_point3(vector);
+        let vector = affine.transform_vector3(vector);
+        let glam_point = glam_affine.transform_point3(glam::Vec3::from_array(origin));
+        let glam_vector = glam_affine.transform_vector3(glam::Vec3::from_array(origin));
+
+        // Compare `Affine3` and `Control group`
+        let a: [f32; 4] = { let t: Float4 = point.into(); t.into() };
+        let b: [f32; 4] = glam_point.extend(1.0).into();
+        let validate = (0..4).all(|idx| (a[idx] - b[idx]).abs() <= 1.0e-4);
+        assert!(validate, "Test:{} >> Transforming a point with `Affine3` is invalid! (Vector:{:?}, Control group:{:?})", test, a, b);
+
+        let a: [f32; 4] = { let t: Float4 = vector.into(); t.into() };
+        let b: [f32; 4] = glam_vector.extend(0.0).into();
+        let validate = (0..4).all(|idx| (a[idx] - b[idx]).abs() <= 1.0e-4);
+        assert!(validate, "Test:{} >> Transforming a vector with `Affine3` is invalid! (Vector:{:?}, Control group:{:?})", test, a, b);
+    }
+}
+
+#[test]
+fn affine_conversion() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin: [f32; 12] = rng.gen();
+
+        // Affine3
+        let affine: Affine3 = { let t: Float3x4 = origin.into(); t.into() };
+        let matrix: Matrix = affine.into();
+        let affine_back: Affine3 = matrix.into();
+
+        // Control group
+        let glam_affine = glam::Affine3A::from_cols_array(&origin);
+        let glam_matrix = glam::Mat4::from(glam_affine);
+
+        // Compare `Affine3` and `Control group`
+        let a: [f32; 16] = { let t: Float4x4 = matrix.into(); t.into() };
+        let b: [f32; 16] = glam_matrix.to_cols_array();
+        assert_eq!(a, b, "Test:{} >> Converting `Affine3` to `Matrix` is invalid! (Matrix:{:?}, Control group:{:?})", test, a, b);
+
+        let a: [f32; 12] = { let t: Float3x4 = affine_back.into(); t.into() };
+        assert_eq!(a, origin, "Test:{} >> Converting `Matrix` to `Affine3` is invalid! (Affine3:{:?}, Control group:{:?})", test, a, origin);
+
+        let a: [f32; 16] = Float4x4::from(Float3x4::from(origin)).into();
+        assert_eq!(a, b, "Test:{} >> Converting `Float3x4` to `Float4x4` is invalid! (Float4x4:{:?}, Control group:{:?})", test, a, b);
+    }
+}
@@ -0,0 +1,171 @@
+use rand::Rng;
+use crate::{color, Float4, Vector};
+use super::{NUM_TEST, EPSILON};
+
+
+
+#[test]
+fn color_srgb_linear() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin: [f32; 4] = rng.gen();
+
+        // Vector
+        let vec = Vector::from_array(origin);
+        let linear = color::srgb_to_linear(vec).into_array();
+        let srgb = color::linear_to_srgb(vec).into_array();
+        let round_trip = color::linear_to_srgb(color::srgb_to_linear(vec)).into_array();
+
+        // Control group
+        let to_linear = |e: f32| {
+            let e = e as f64;
+            (if e <= 0.04045 { e / 12.92 } else { ((e + 0.055) / 1.055).powf(2.4) }) as f32
+        };
+        let to_srgb = |e: f32| {
+            let e = e as f64;
+            (if e <= 0.0031308 { e * 12.92 } else { 1.055 * e.powf(1.0 / 2.4) - 0.055 }) as f32
+        };
+        let control_linear = [to_linear(origin[0]), to_linear(origin[1]), to_linear(origin[2]), origin[3]];
+        let control_srgb = [to_srgb(origin[0]), to_srgb(origin[1]), to_srgb(origin[2]), origin[3]];
+
+        // Compare `Vector` and `Control group`
+        let validate = (0..4).all(|idx| (linear[idx] - control_linear[idx]).abs() <= EPSILON);
+        assert!(validate, "Test:{} >> sRGB to linear conversion on `Vector` is invalid! (Vector:{:?}, Control group:{:?})", test, linear, control_linear);
+        let validate = (0..4).all(|idx| (srgb[idx] - control_srgb[idx]).abs() <= EPSILON);
+        assert!(validate, "Test:{} >> Linear to sRGB conversion on `Vector` is invalid! (Vector:{:?}, Control group:{:?})", test, srgb, control_srgb);
+        let validate = (0..4).all(|idx| (round_trip[idx] - origin[idx]).abs() <= EPSILON);
+        assert!(validate, "Test:{} >> sRGB round trip on `Vector` is invalid! (Vector:{:?}, Origin:{:?})", test, round_trip, origin);
+    }
+}
+
+#[test]
+fn color_hsv_hsl() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin: [f32; 4] = rng.gen();
+
+        // Vector
+        let vec = Vector::from_array(origin);
+        let hsv = color::rgb_to_hsv(vec);
+        let hsl = color::rgb_to_hsl(vec);
+        let a = color::hsv_to_rgb(hsv).into_array();
+        let b = color::hsl_to_rgb(hsl).into_array();
+
+        // Control group
+        let [r, g, b_, _] = origin;
+        let max = r.max(g).max(b_);
+        let min = r.min(g).min(b_);
+        let control_v = max;
+        let control_l = (max + min) * 0.5;
+
+        // Compare `Vector` and `Control group`
+        let validate = hsv.into_array().iter().chain(hsl.into_array().iter()).all(|e| (0.0..=1.0).contains(e));
+        assert!(validate, "Test:{} >> Range of `HSV` or `HSL` is invalid! (HSV:{:?}, HSL:{:?})", test, hsv, hsl);
+        assert!((hsv.get_z() - control_v).abs() <= EPSILON, "Test:{} >> RGB to HSV conversion on `Vector` is invalid! (Vector:{:?}, Control group:{:?})", test, hsv, control_v);
+        assert!((hsl.get_z() - control_l).abs() <= EPSILON, "Test:{} >> RGB to HSL conversion on `Vector` is invalid! (Vector:{:?}, Control group:{:?})", test, hsl, control_l);
+        let validate = (0..4).all(|idx| (a[idx] - origin[idx]).abs() <= EPSILON);
+        assert!(validate, "Test:{} >> HSV round trip on `Vector` is invalid! (Vector:{:?}, Origin:{:?})", test, a, origin);
+        let validate = (0..4).all(|idx| (b[idx] - origin[idx]).abs() <= EPSILON);
+        assert!(validate, "Test:{} >> HSL round trip on `Vector` is invalid! (Vector:{:?}, Origin:{:?})", test, b, origin);
+    }
+
+    // Primary colors.
+    let cases = [
+        ([1.0, 0.0, 0.0], [0.0, 1.0, 1.0], [0.0, 1.0, 0.5]), 
+        ([0.0, 1.0, 0.0], [1.0 / 3.0, 1.0, 1.0], [1.0 / 3.0, 1.0, 0.5]), 
+        ([0.0, 0.0, 1.0], [2.0 / 3.0, 1.0, 1.0], [2.0 / 3.0, 1.0, 0.5]), 
+        ([0.5, 0.5, 0.5], [0.0, 0.0, 0.5], [0.0, 0.0, 0.5]), 
+    ];
+    for (test, (rgb, hsv, hsl)) in cases.into_iter().enumerate() {
+        let vec = Vector::new(rgb[0], rgb[1], rgb[2], 1.0);
+        let a = color::rgb_to_hsv(vec).store_float3();
+        let b = color::rgb_to_hsl(vec).store_float3();
+        assert!((0..3).all(|idx| (a[idx] - hsv[idx]).abs() <= EPSILON), "Test:{} >> RGB to HSV conversion on `Vector` is invalid! (Vector:{:?}, Control group:{:?})", test, a, hsv);
+        assert!((0..3).all(|idx| (b[idx] - hsl[idx]).abs() <= EPSILON), "Test:{} >> RGB to HSL conversion on `Vector` is invalid! (Vector:{:?}, Control group:{:?})", test, b, hsl);
+    }
+}
+
+#[test]
+fn color_adjust() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin: [f32; 4] = rng.gen();
+        let factor: f32 = rng.gen_range(0.0..=2.0);
+
+        // Vector
+        let vec = Vector::from_array(origin);
+        let lum709 = color::luminance_rec709(vec);
+        let lum2020 = color::luminance_rec2020(vec);
+        let premul = color::premultiply_alpha(vec).into_array();
+        let unpremul = color::unpremultiply_alpha(color::premultiply_alpha(vec)).into_array();
+        let saturation = color::adjust_saturation(vec, factor).into_array();
+        let contrast = color::adjust_contrast(vec, factor).into_array();
+
+        // Control group
+        let [r, g, b, a] = origin;
+        let control_709 = 0.2126 * r + 0.7152 * g + 0.0722 * b;
+        let control_2020 = 0.2627 * r + 0.6780 * g + 0.0593 * b;
+        let control_premul = [r * a, g * a, b * a, a];
+        let control_saturation = [(r - control_709) * factor + control_709, (g - control_709) * factor + control_709, (b - control_709) * factor + control_709, a];
+        let control_contrast = [(r - 0.5) * factor + 0.5, (g - 0.5) * factor + 0.5, (b - 0.5) * factor + 0.5, a];
+
+        // Compare `Vector` and `Control group`
+        assert!((lum709 - control_709).abs() <= EPSILON, "Test:{} >> Rec. 709 luminance on `Vector` is invalid! (Vector:{:?}, Control group:{:?})", test, lum709, control_709);
+        assert!((lum2020 - control_2020).abs() <= EPSILON, "Test:{} >> Rec. 2020 luminance on `Vector` is invalid! (Vector:{:?}, Control group:{:?})", test, lum2020, control_2020);
+        let validate = (0..4).all(|idx| (premul[idx] - control_premul[idx]).abs() <= EPSILON);
+        assert!(validate, "Test:{} >> Premultiply alpha on `Vector` is invalid! (Vector:{:?}, Control group:{:?})", test, premul, control_premul);
+        let validate = a <= 0.001 || (0..4).all(|idx| (unpremul[idx] - origin[idx]).abs() <= EPSILON / a);
+        assert!(validate, "Test:{} >> Unpremultiply alpha on `Vector` is invalid! (Vector:{:?}, Origin:{:?})", test, unpremul, origin);
+        let validate = (0..4).all(|idx| (saturation[idx] - control_saturation[idx]).abs() <= EPSILON);
+        assert!(validate, "Test:{} >> Saturation adjustment on `Vector` is invalid! (Vector:{:?}, Control group:{:?})", test, saturation, control_saturation);
+        let validate = (0..4).all(|idx| (contrast[idx] - control_contrast[idx]).abs() <= EPSILON);
+        assert!(validate, "Test:{} >> Contrast adjustment on `Vector` is invalid! (Vector:{:?}, Control group:{:?})", test, contrast, control_contrast);
+    }
+
+    // Zero alpha.
+    let a = color::unpremultiply_alpha(Vector::new(0.5, 0.5, 0.5, 0.0)).into_array();
+    assert_eq!(a, [0.0; 4], "Test:0 >> Unpremultiply alpha on `Vector` is invalid! (Vector:{:?}, Control group:{:?})", a, [0.0; 4]);
+}
+
+#[test]
+fn color_slice() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST / 100 {
+        // Data
+        let len = rng.gen_range(0..=16);
+        let mut origin = [Float4::ZERO; 16];
+        origin.iter_mut().for_each(|e| *e = Float4::from_array(rng.gen()));
+
+        // Slice
+        let mut linear = [Float4::ZERO; 16];
+        let mut srgb = [Float4::ZERO; 16];
+        let mut hsv = [Float4::ZERO; 16];
+        let mut rgb = [Float4::ZERO; 16];
+        let mut premul = [Float4::ZERO; 16];
+        let mut unpremul = [Float4::ZERO; 16];
+        color::srgb_to_linear_slice(&origin[..len], &mut linear[..len]);
+        color::linear_to_srgb_slice(&origin[..len], &mut srgb[..len]);
+        color::rgb_to_hsv_slice(&origin[..len], &mut hsv[..len]);
+        color::hsv_to_rgb_slice(&origin[..len], &mut rgb[..len]);
+        color::premultiply_alpha_slice(&origin[..len], &mut premul[..len]);
+        color::unpremultiply_alpha_slice(&origin[..len], &mut unpremul[..len]);
+
+        // Compare `Slice` and `Vector`
+        for idx in 0..len {
+            let vec = Vector::load_float4(origin[idx]);
+            let validate = 
+                linear[idx] == color::srgb_to_linear(vec).store_float4() && 
+                srgb[idx] == color::linear_to_srgb(vec).store_float4() && 
+                hsv[idx] == color::rgb_to_hsv(vec).store_float4() && 
+                rgb[idx] == color::hsv_to_rgb(vec).store_float4() && 
+                premul[idx] == color::premultiply_alpha(vec).store_float4() && 
+                unpremul[idx] == color::unpremultiply_alpha(vec).store_float4();
+            assert!(validate, "Test:{} >> Slice conversion of colors is invalid! (Index:{:?}, Origin:{:?})", test, idx, origin[idx]);
+        }
+        let validate = linear[len..].iter().all(|e| *e == Float4::ZERO);
+        assert!(validate, "Test:{} >> Slice conversion of colors is invalid! (Slice:{:?}, Length:{:?})", test, linear, len);
+    }
+}
@@ -0,0 +1,260 @@
+use rand::Rng;
+use crate::{Double3, Double4, Double4x4, DMatrix, DQuaternion, DVector, Float4, Float4x4, Matrix, Vector};
+use super::{NUM_TEST, DOUBLE_EPSILON};
+
+
+
+#[test]
+fn dvector_dot() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin_a: [f64; 4] = rng.gen();
+        let origin_b: [f64; 4] = rng.gen();
+
+        // DVector
+        let vec_a: DVector = { let t: Double4 = origin_a.into(); t.into() };
+        let vec_b: DVector = { let t: Double4 = origin_b.into(); t.into() };
+        let vec2_dot = vec_a.vec2_dot_into(vec_b);
+        let vec3_dot = vec_a.vec3_dot_into(vec_b);
+        let vec4_dot = vec_a.vec4_dot_into(vec_b);
+
+        // Control group
+        let glam_a = glam::DVec4::from_array(origin_a);
+        let glam_b = glam::DVec4::from_array(origin_b);
+        let glam2_dot = glam_a.truncate().truncate().dot(glam_b.truncate().truncate());
+        let glam3_dot = glam_a.truncate().dot(glam_b.truncate());
+        let glam4_dot = glam_a.dot(glam_b);
+
+        // Compare `DVector` and `Control group`
+        assert!((vec2_dot - glam2_dot).abs() <= DOUBLE_EPSILON, "Test:{} >> Dot operation on `DVector` is invalid! (DVector:{:?}, Control group:{:?})", test, vec2_dot, glam2_dot);
+        assert!((vec3_dot - glam3_dot).abs() <= DOUBLE_EPSILON, "Test:{} >> Dot operation on `DVector` is invalid! (DVector:{:?}, Control group:{:?})", test, vec3_dot, glam3_dot);
+        assert!((vec4_dot - glam4_dot).abs() <= DOUBLE_EPSILON, "Test:{} >> Dot operation on `DVector` is invalid! (DVector:{:?}, Control group:{:?})", test, vec4_dot, glam4_dot);
+    }
+}
+
+#[test]
+fn dvector_cross() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin_a: [f64; 3] = rng.gen();
+        let origin_b: [f64; 3] = rng.gen();
+
+        // DVector
+        let vec_a: DVector = { let t: Double3 = origin_a.into(); t.into() };
+        let vec_b: DVector = { let t: Double3 = origin_b.into(); t.into() };
+        let vec_c = vec_a.vec3_cross(vec_b);
+
+        // Control group
+        let glam_a = glam::DVec3::from_array(origin_a);
+        let glam_b = glam::DVec3::from_array(origin_b);
+        let glam_c = glam_a.cross(glam_b);
+
+        // Compare `DVector` and `Control group`
+        let a: [f64; 3] = { let t: Double3 = vec_c.into(); t.into() };
+        let b: [f64; 3] = glam_c.into();
+        let validate = (0..3).all(|idx| (a[idx] - b[idx]).abs() <= DOUBLE_EPSILON);
+        assert!(validate, "Test:{} >> Cross operation on `DVector` is invalid! (DVector:{:?}, Control group:{:?})", test, a, b);
+    }
+}
+
+#[test]
+fn dvector_precision() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin: [f32; 4] = rng.gen();
+
+        // Vector
+        let vec_a: Vector = { let t: Float4 = origin.into(); t.into() };
+        let vec_b = vec_a.as_f64();
+        let vec_c = vec_b.as_f32();
+
+        // Compare `Vector` and `DVector`
+        let a: [f64; 4] = { let t: Double4 = vec_b.into(); t.into() };
+        let b: [f32; 4] = { let t: Float4 = vec_c.into(); t.into() };
+        assert_eq!(a, origin.map(|e| e as f64), "Test:{} >> Precision conversion on `Vector` is invalid! (DVector:{:?}, Origin:{:?})", test, a, origin);
+        assert_eq!(b, origin, "Test:{} >> Precision conversion on `DVector` is invalid! (Vector:{:?}, Origin:{:?})", test, b, origin);
+    }
+}
+
+#[test]
+fn dmatrix_mul() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin_a: [f64; 16] = rng.gen();
+        let origin_b: [f64; 16] = rng.gen();
+        let origin_v: [f64; 4] = rng.gen();
+
+        // DMatrix
+        let matrix_a: DMatrix = { let t: Double4x4 = origin_a.into(); t.into() };
+        let matrix_b: DMatrix = { let t: Double4x4 = origin_b.into(); t.into() };
+        let vector_v: DVector = { let t: Double4 = origin_v.into(); t.into() };
+        let matrix_c = matrix_a * matrix_b;
+        let vector_c = matrix_a * vector_v;
+
+        // Control group
+        let glam_a = glam::DMat4::from_cols_array(&origin_a);
+        let glam_b = glam::DMat4::from_cols_array(&origin_b);
+        let glam_v = glam::DVec4::from_array(origin_v);
+        let glam_c = glam_a * glam_b;
+        let glam_vc = glam_a * glam_v;
+
+        // Compare `DMatrix` and `Control group`
+        let a: [f64; 16] = { let t: Double4x4 = matrix_c.into(); t.into() };
+        let b: [f64; 16] = glam_c.to_cols_array();
+        let validate = (0..16).all(|idx| (a[idx] - b[idx]).abs() <= DOUBLE_EPSILON);
+        assert!(validate, "Test:{} >> Multiply operation on `DMatrix` is invalid! (DMatrix:{:?}, Control group:{:?})", test, a, b);
+
+        let a: [f64; 4] = { let t: Double4 = vector_c.into(); t.into() };
+        let b: [f64; 4] = glam_vc.into();
+        let validate = (0..4).all(|idx| (a[idx] - b[idx]).abs() <= DOUBLE_EPSILON);
+        assert!(validate, "Test:{} >> Multiply operation on `DMatrix` and `DVector` is invalid! (DVector:{:?}, Control group:{:?})", test, a, b);
+    }
+}
+
+#[test]
+fn dmatrix_inverse() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin: [f64; 16] = rng.gen();
+
+        // DMatrix
+        let matrix_a: DMatrix = { let t: Double4x4 = origin.into(); t.into() };
+        let matrix_det = matrix_a.determinant_into();
+        let matrix_inv = matrix_a.inverse();
+
+        // Control group
+        let glam_a = glam::DMat4::from_cols_array(&origin);
+        let glam_det = glam_a.determinant();
+        let glam_inv = glam_a.inverse();
+
+        // Compare `DMatrix` and `Control group`
+        assert!((matrix_det - glam_det).abs() <= DOUBLE_EPSILON, "Determinant operation on `DMatrix` is invalid! (DMatrix:{:?}, Control group:{:?})", matrix_det, glam_det);
+        if matrix_det.abs() <= f32::EPSILON as f64 {
+            continue;
+        }
+
+        let a: [f64; 16] = { let t: Double4x4 = matrix_inv.into(); t.into() };
+        let b: [f64; 16] = glam_inv.to_cols_array();
+        for idx in 0..16 {
+            // The inverse of an ill-conditioned matrix amplifies rounding errors, so compare relatively.
+            let validate = (a[idx] - b[idx]).abs() <= f32::EPSILON as f64 * b[idx].abs().max(1.0);
+            assert!(validate, "Test:{}-{} >> Inverse operation on `DMatrix` is invalid! (DMatrix:{:?}, Control group:{:?})", test, idx, a, b);
+        }
+    }
+}
+
+#[test]
+fn dmatrix_precision() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin: [f32; 16] = rng.gen();
+
+        // Matrix
+        let matrix_a: Matrix = { let t: Float4x4 = origin.into(); t.into() };
+        let matrix_b = matrix_a.as_f64();
+        let matrix_c = matrix_b.as_f32();
+
+        // Compare `Matrix` and `DMatrix`
+        let a: [f64; 16] = { let t: Double4x4 = matrix_b.into(); t.into() };
+        let b: [f32; 16] = { let t: Float4x4 = matrix_c.into(); t.into() };
+        assert_eq!(a, origin.map(|e| e as f64), "Test:{} >> Precision conversion on `Matrix` is invalid! (DMatrix:{:?}, Origin:{:?})", test, a, origin);
+        assert_eq!(b, origin, "Test:{} >> Precision conversion on `DMatrix` is invalid! (Matrix:{:?}, Origin:{:?})", test, b, origin);
+    }
+}
+
+#[test]
+fn dquaternion_mul() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin_a: [f64; 4] = rng.gen();
+        let origin_b: [f64; 4] = rng.gen();
+
+        // DQuaternion
+        let quat_a: DQuaternion = { let t: Double4 = origin_a.into(); t.into() };
+        let quat_b: DQuaternion = { let t: Double4 = origin_b.into(); t.into() };
+        let quat_c = quat_a * quat_b;
+
+        // Control group
+        let glam_a = glam::DQuat::from_array(origin_a);
+        let glam_b = glam::DQuat::from_array(origin_b);
+        let glam_c = glam_a * glam_b;
+
+        // Compare `DQuaternion` and `Control group`
+        let a: [f64; 4] = { let t: Double4 = quat_c.into(); t.into() };
+        let b: [f64; 4] = glam_c.into();
+        let validate = (0..4).all(|idx| (a[idx] - b[idx]).abs() <= DOUBLE_EPSILON);
+        assert!(validate, "Test:{} >> Multiply operation on `DQuaternion` is invalid! (DQuaternion:{:?}, Control group:{:?})", test, a, b);
+    }
+}
+
+#[test]
+fn dquaternion_slerp() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin_a: [f64; 4] = [(); 4].map(|_| rng.gen_range(-1.0..=1.0));
+        let origin_b: [f64; 4] = [(); 4].map(|_| rng.gen_range(-1.0..=1.0));
+        let t: f64 = rng.gen();
+
+        // Control group
+        let (glam_a, glam_b) = match (glam::DVec4::from_array(origin_a).try_normalize(), glam::DVec4::from_array(origin_b).try_normalize()) {
+            (Some(a), Some(b)) => (glam::DQuat::from_vec4(a), glam::DQuat::from_vec4(b)), 
+            _ => continue
+        };
+        let glam_c = glam_a.slerp(glam_b, t);
+
+        // DQuaternion
+        let quat_a: DQuaternion = { let t: Double4 = glam_a.to_array().into(); t.into() };
+        let quat_b: DQuaternion = { let t: Double4 = glam_b.to_array().into(); t.into() };
+        let quat_c = quat_a.slerp(quat_b, t);
+
+        // Compare `DQuaternion` and `Control group`
+        let a: [f64; 4] = { let t: Double4 = quat_c.into(); t.into() };
+        let b: [f64; 4] = glam_c.into();
+        let validate = (0..4).all(|idx| (a[idx] - b[idx]).abs() <= DOUBLE_EPSILON);
+        assert!(validate, "Test:{} >> Spherical linear interpolation on `DQuaternion` is invalid! (DQuaternion:{:?}, Control group:{:?})", test, a, b);
+    }
+}
+
+#[test]
+fn double3_scalar_geometry() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin_a: [f64; 3] = rng.gen();
+        let origin_b: [f64; 3] = rng.gen();
+
+        // Double3
+        let double_a: Double3 = origin_a.into();
+        let double_b: Double3 = origin_b.into();
+
+        // Control group
+        let glam_a = glam::DVec3::from_array(origin_a);
+        let glam_b = glam::DVec3::from_array(origin_b);
+
+        // Compare `Double3` and `Control group`
+        let (a, b) = (double_a.dot(double_b), glam_a.dot(glam_b));
+        assert!((a - b).abs() <= DOUBLE_EPSILON, "Test:{} >> Dot operation on `Double3` is invalid! (Double3:{:?}, Control group:{:?})", test, a, b);
+        let (a, b) = (double_a.distance(double_b), glam_a.distance(glam_b));
+        assert!((a - b).abs() <= DOUBLE_EPSILON, "Test:{} >> Distance operation on `Double3` is invalid! (Double3:{:?}, Control group:{:?})", test, a, b);
+
+        let a: [f64; 3] = double_a.cross(double_b).into();
+        let b: [f64; 3] = glam_a.cross(glam_b).into();
+        let validate = (0..3).all(|idx| (a[idx] - b[idx]).abs() <= DOUBLE_EPSILON);
+        assert!(validate, "Test:{} >> Cross operation on `Double3` is invalid! (Double3:{:?}, Control group:{:?})", test, a, b);
+
+        if let Some(norm) = double_a.try_normalize() {
+            let a: [f64; 3] = norm.into();
+            let b: [f64; 3] = glam_a.normalize().into();
+            let validate = (0..3).all(|idx| (a[idx] - b[idx]).abs() <= DOUBLE_EPSILON);
+            assert!(validate, "Test:{} >> Normalize operation on `Double3` is invalid! (Double3:{:?}, Control group:{:?})", test, a, b);
+        }
+    }
+}
@@ -0,0 +1,244 @@
+use rand::Rng;
+use rand::rngs::ThreadRng;
+use crate::{Aabb, BoundingSphere, OrientedBox, Float3, Matrix, Quaternion, Vector};
+use super::{NUM_TEST, TOLERANCE, random_point, to_vector, to_glam};
+
+
+
+/// Returns a random normalized quaternion.
+fn random_quat(rng: &mut ThreadRng) -> glam::Quat {
+    loop {
+        let q = glam::Quat::from_array([(); 4].map(|_| rng.gen_range(-1.0..=1.0)));
+        if q.length() > 0.1 {
+            return q.normalize();
+        }
+    }
+}
+
+/// Returns a random affine transformation with scale, rotation and translation.
+fn random_affine(rng: &mut ThreadRng) -> glam::Mat4 {
+    let scale = glam::Vec3::from_array([(); 3].map(|_| rng.gen_range(0.1..=2.0)));
+    let translation = random_point(rng);
+    glam::Mat4::from_scale_rotation_translation(scale, random_quat(rng), translation)
+}
+
+fn to_quaternion(q: glam::Quat) -> Quaternion {
+    Quaternion::from_array(q.to_array())
+}
+
+
+
+#[test]
+fn geometry_aabb() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST / 100 {
+        // Data
+        let points_a: [[f32; 3]; 8] = [(); 8].map(|_| random_point(&mut rng).to_array());
+        let points_b: [[f32; 3]; 8] = [(); 8].map(|_| random_point(&mut rng).to_array());
+        let point = random_point(&mut rng);
+        let affine = random_affine(&mut rng);
+
+        // Aabb
+        let a = Aabb::from_points(&points_a.map(Float3::from_array));
+        let b = Aabb::from_points(&points_b.map(Float3::from_array));
+        let contains = a.contains_point(to_vector(point));
+        let intersects = a.intersects_aabb(b);
+        let merged = a.merge(b);
+        let transformed = a.transform(Matrix::from_column_array(affine.to_cols_array()));
+
+        // Control group
+        let bounds = |points: &[[f32; 3]; 8]| points.iter().fold(
+            (glam::Vec3::INFINITY, glam::Vec3::NEG_INFINITY),
+            |(min, max), p| (min.min(glam::Vec3::from_array(*p)), max.max(glam::Vec3::from_array(*p)))
+        );
+        let (min_a, max_a) = bounds(&points_a);
+        let (min_b, max_b) = bounds(&points_b);
+        let control_contains = point.cmpge(min_a).all() && point.cmple(max_a).all();
+        let control_intersects = min_a.cmple(max_b).all() && min_b.cmple(max_a).all();
+        let near_point = (point - min_a).abs().min_element() <= TOLERANCE || (point - max_a).abs().min_element() <= TOLERANCE;
+        let near_touch = (min_a - max_b).abs().min_element() <= TOLERANCE || (min_b - max_a).abs().min_element() <= TOLERANCE;
+
+        // Compare `Aabb` and `Control group`
+        let validate = to_glam(a.min()).abs_diff_eq(min_a, TOLERANCE) && to_glam(a.max()).abs_diff_eq(max_a, TOLERANCE);
+        assert!(validate, "Test:{} >> Aabb from points is invalid! (Aabb:{:?}, Control group:{:?})", test, a, (min_a, max_a));
+        assert!(near_point || contains == control_contains, "Test:{} >> Aabb contains point is invalid! (Aabb:{:?}, Control group:{:?})", test, contains, control_contains);
+        assert!(near_touch || intersects == control_intersects, "Test:{} >> Aabb intersects aabb is invalid! (Aabb:{:?}, Control group:{:?})", test, intersects, control_intersects);
+        let validate = to_glam(merged.min()).abs_diff_eq(min_a.min(min_b), TOLERANCE) && to_glam(merged.max()).abs_diff_eq(max_a.max(max_b), TOLERANCE);
+        assert!(validate, "Test:{} >> Aabb merge is invalid! (Aabb:{:?}, Control group:{:?})", test, merged, (min_a.min(min_b), max_a.max(max_b)));
+
+        let tolerant = Aabb::new(transformed.center, transformed.extents + Vector::fill(TOLERANCE));
+        for corner in a.corners() {
+            let control = to_vector(affine.transform_point3(to_glam(corner)));
+            assert!(tolerant.contains_point(control), "Test:{} >> Aabb transform is invalid! (Aabb:{:?}, Control group:{:?})", test, transformed, control);
+        }
+    }
+}
+
+#[test]
+fn geometry_bounding_sphere() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST / 100 {
+        // Data
+        let points: [[f32; 3]; 8] = [(); 8].map(|_| random_point(&mut rng).to_array());
+        let center_a = random_point(&mut rng);
+        let center_b = random_point(&mut rng);
+        let radius_a: f32 = rng.gen_range(0.1..=8.0);
+        let radius_b: f32 = rng.gen_range(0.1..=8.0);
+        let box_points: [[f32; 3]; 2] = [(); 2].map(|_| random_point(&mut rng).to_array());
+        let affine = random_affine(&mut rng);
+
+        // BoundingSphere
+        let from_points = BoundingSphere::from_points(&points.map(Float3::from_array));
+        let a = BoundingSphere::new(to_vector(center_a), radius_a);
+        let b = BoundingSphere::new(to_vector(center_b), radius_b);
+        let aabb = Aabb::from_points(&box_points.map(Float3::from_array));
+        let intersects = a.intersects_sphere(b);
+        let intersects_aabb = a.intersects_aabb(aabb);
+        let merged = a.merge(b);
+        let transformed = a.transform(Matrix::from_column_array(affine.to_cols_array()));
+
+        // Control group
+        let dist = center_a.distance(center_b);
+        let control_intersects = dist <= radius_a + radius_b;
+        let near_touch = (dist - (radius_a + radius_b)).abs() <= TOLERANCE;
+        let min = glam::Vec3::from_array(box_points[0]).min(glam::Vec3::from_array(box_points[1]));
+        let max = glam::Vec3::from_array(box_points[0]).max(glam::Vec3::from_array(box_points[1]));
+        let box_dist = center_a.distance(center_a.clamp(min, max));
+        let control_intersects_aabb = box_dist <= radius_a;
+        let near_box = (box_dist - radius_a).abs() <= TOLERANCE;
+
+        // Compare `BoundingSphere` and `Control group`
+        for p in points.iter() {
+            let dist = glam::Vec3::from_array(*p).distance(to_glam(from_points.center));
+            assert!(dist <= from_points.radius + TOLERANCE, "Test:{} >> BoundingSphere from points is invalid! (BoundingSphere:{:?}, Control group:{:?})", test, from_points, p);
+        }
+        assert!(near_touch || intersects == control_intersects, "Test:{} >> BoundingSphere intersects sphere is invalid! (BoundingSphere:{:?}, Control group:{:?})", test, intersects, control_intersects);
+        assert!(near_box || intersects_aabb == control_intersects_aabb, "Test:{} >> BoundingSphere intersects aabb is invalid! (BoundingSphere:{:?}, Control group:{:?})", test, intersects_aabb, control_intersects_aabb);
+        for (center, radius) in [(center_a, radius_a), (center_b, radius_b)] {
+            let validate = center.distance(to_glam(merged.center)) + radius <= merged.radius + TOLERANCE;
+            assert!(validate, "Test:{} >> BoundingSphere merge is invalid! (BoundingSphere:{:?}, Control group:{:?})", test, merged, (center, radius));
+        }
+        let validate = merged.radius <= radius_a.max(radius_b).max((dist + radius_a + radius_b) * 0.5) + TOLERANCE;
+        assert!(validate, "Test:{} >> BoundingSphere merge is not tight! (BoundingSphere:{:?}, Control group:{:?})", test, merged, (center_a, radius_a, center_b, radius_b));
+
+        let dir = random_point(&mut rng).normalize_or_zero();
+        let control = affine.transform_point3(center_a + dir * radius_a);
+        let validate = control.distance(to_glam(transformed.center)) <= transformed.radius + TOLERANCE;
+        assert!(validate, "Test:{} >> BoundingSphere transform is invalid! (BoundingSphere:{:?}, Control group:{:?})", test, transformed, control);
+    }
+}
+
+#[test]
+fn geometry_oriented_box() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST / 100 {
+        // Data
+        let random_box = |rng: &mut ThreadRng| {
+            let center = random_point(rng);
+            let extents = glam::Vec3::from_array([(); 3].map(|_| rng.gen_range(0.1..=5.0)));
+            (center, extents, random_quat(rng))
+        };
+        let (center_a, extents_a, quat_a) = random_box(&mut rng);
+        let (center_b, extents_b, quat_b) = random_box(&mut rng);
+        let points: [[f32; 3]; 8] = [(); 8].map(|_| random_point(&mut rng).to_array());
+        let point = random_point(&mut rng);
+        let radius: f32 = rng.gen_range(0.1..=8.0);
+        let affine = random_affine(&mut rng);
+
+        // OrientedBox
+        let a = OrientedBox::new(to_vector(center_a), to_vector(extents_a), to_quaternion(quat_a));
+        let b = OrientedBox::new(to_vector(center_b), to_vector(extents_b), to_quaternion(quat_b));
+        let from_points = OrientedBox::from_points(&points.map(Float3::from_array));
+        let contains = a.contains_point(to_vector(point));
+        let intersects_sphere = a.intersects_sphere(BoundingSphere::new(to_vector(point), radius));
+        let intersects = a.intersects_oriented_box(b);
+        let merged = a.merge(b);
+        let transformed = a.transform(Matrix::from_column_array(affine.to_cols_array()));
+
+        // Control group
+        let local = quat_a.inverse() * (point - center_a);
+        let control_contains = local.abs().cmple(extents_a).all();
+        let near_point = (local.abs() - extents_a).abs().min_element() <= TOLERANCE;
+        let sphere_dist = local.distance(local.clamp(-extents_a, extents_a));
+        let control_intersects_sphere = sphere_dist <= radius;
+        let near_sphere = (sphere_dist - radius).abs() <= TOLERANCE;
+        let glam_corners = |center: glam::Vec3, extents: glam::Vec3, quat: glam::Quat| {
+            (0..8).map(|n| {
+                let sign = glam::Vec3::new(
+                    if n & 1 != 0 { 1.0 } else { -1.0 },
+                    if n & 2 != 0 { 1.0 } else { -1.0 },
+                    if n & 4 != 0 { 1.0 } else { -1.0 }
+                );
+                center + quat * (extents * sign)
+            }).collect::<std::vec::Vec<_>>()
+        };
+        let corners_a = glam_corners(center_a, extents_a, quat_a);
+        let corners_b = glam_corners(center_b, extents_b, quat_b);
+        let shrink = |center: glam::Vec3, extents: glam::Vec3, quat: glam::Quat| {
+            OrientedBox::new(to_vector(center), to_vector(extents - TOLERANCE), to_quaternion(quat))
+        };
+        let corner_inside = corners_a.iter().any(|p| shrink(center_b, extents_b, quat_b).contains_point(to_vector(*p)))
+            || corners_b.iter().any(|p| shrink(center_a, extents_a, quat_a).contains_point(to_vector(*p)));
+        let spheres_apart = center_a.distance(center_b) > extents_a.length() + extents_b.length() + TOLERANCE;
+
+        // Compare `OrientedBox` and `Control group`
+        assert!(near_point || contains == control_contains, "Test:{} >> OrientedBox contains point is invalid! (OrientedBox:{:?}, Control group:{:?})", test, contains, control_contains);
+        assert!(near_sphere || intersects_sphere == control_intersects_sphere, "Test:{} >> OrientedBox intersects sphere is invalid! (OrientedBox:{:?}, Control group:{:?})", test, intersects_sphere, control_intersects_sphere);
+        assert!(!corner_inside || intersects, "Test:{} >> OrientedBox intersects oriented box is invalid! (OrientedBox:{:?}, Control group:{:?})", test, intersects, corner_inside);
+        assert!(!spheres_apart || !intersects, "Test:{} >> OrientedBox intersects oriented box is invalid! (OrientedBox:{:?}, Control group:{:?})", test, intersects, spheres_apart);
+
+        let tolerant = |obb: OrientedBox| OrientedBox::new(obb.center, obb.extents + Vector::fill(TOLERANCE), obb.orientation);
+        for p in points.iter() {
+            let p = Vector::load_float3(Float3::from_array(*p));
+            assert!(tolerant(from_points).contains_point(p), "Test:{} >> OrientedBox from points is invalid! (OrientedBox:{:?}, Control group:{:?})", test, from_points, p);
+        }
+        for p in corners_a.iter().chain(corners_b.iter()) {
+            assert!(tolerant(merged).contains_point(to_vector(*p)), "Test:{} >> OrientedBox merge is invalid! (OrientedBox:{:?}, Control group:{:?})", test, merged, p);
+        }
+        for p in corners_a.iter() {
+            let control = to_vector(affine.transform_point3(*p));
+            assert!(tolerant(transformed).contains_point(control), "Test:{} >> OrientedBox transform is invalid! (OrientedBox:{:?}, Control group:{:?})", test, transformed, control);
+        }
+    }
+}
+
+#[test]
+fn geometry_mixed_intersects() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST / 100 {
+        // Data
+        let box_points_a: [[f32; 3]; 2] = [(); 2].map(|_| random_point(&mut rng).to_array());
+        let box_points_b: [[f32; 3]; 2] = [(); 2].map(|_| random_point(&mut rng).to_array());
+        let center = random_point(&mut rng);
+        let radius: f32 = rng.gen_range(0.1..=8.0);
+
+        // Geometry
+        let a = Aabb::from_points(&box_points_a.map(Float3::from_array));
+        let b = Aabb::from_points(&box_points_b.map(Float3::from_array));
+        let sphere = BoundingSphere::new(to_vector(center), radius);
+        let obb_a = OrientedBox::from_aabb(a);
+        let obb_b = OrientedBox::from_aabb(b);
+
+        // Identity oriented boxes must behave like the axis-aligned boxes.
+        let near_touch = (to_glam(a.min()) - to_glam(b.max())).abs().min_element() <= TOLERANCE
+            || (to_glam(b.min()) - to_glam(a.max())).abs().min_element() <= TOLERANCE;
+        let control = a.intersects_aabb(b);
+        for result in [obb_a.intersects_oriented_box(obb_b), obb_a.intersects_aabb(b), a.intersects_oriented_box(obb_b)] {
+            assert!(near_touch || result == control, "Test:{} >> OrientedBox intersects aabb is invalid! (OrientedBox:{:?}, Control group:{:?})", test, result, control);
+        }
+
+        let box_dist = to_glam(sphere.center).distance(to_glam(sphere.center).clamp(to_glam(a.min()), to_glam(a.max())));
+        let near_sphere = (box_dist - radius).abs() <= TOLERANCE;
+        let control = a.intersects_sphere(sphere);
+        for result in [sphere.intersects_aabb(a), obb_a.intersects_sphere(sphere), sphere.intersects_oriented_box(obb_a)] {
+            assert!(near_sphere || result == control, "Test:{} >> BoundingSphere intersects box is invalid! (BoundingSphere:{:?}, Control group:{:?})", test, result, control);
+        }
+
+        // Data round trip.
+        let sphere_data = sphere.store_data();
+        let obb_data = obb_a.store_data();
+        assert!(Aabb::load_data(a.store_data()) == a, "Test:{} >> Aabb data round trip is invalid! (Aabb:{:?})", test, a);
+        assert!(BoundingSphere::from(sphere_data) == sphere, "Test:{} >> BoundingSphere data round trip is invalid! (BoundingSphere:{:?})", test, sphere);
+        assert!(OrientedBox::from(obb_data) == obb_a, "Test:{} >> OrientedBox data round trip is invalid! (OrientedBox:{:?})", test, obb_a);
+    }
+}
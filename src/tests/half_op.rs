@@ -0,0 +1,103 @@
+use rand::Rng;
+use crate::{Float4, Half, Half4, Vector};
+use super::NUM_TEST;
+
+
+
+/// Generates a random value in the range where half-precision numbers are normal, denormal or overflow.
+fn gen_f32<R: Rng>(rng: &mut R) -> f32 {
+    match rng.gen_range(0..4) {
+        0 => f32::from_bits(rng.gen()),
+        1 => rng.gen_range(-70000.0..=70000.0),
+        2 => rng.gen_range(-1.0..=1.0),
+        _ => rng.gen_range(-0.0001..=0.0001),
+    }
+}
+
+#[test]
+fn half_to_f32() {
+    for bits in 0..=u16::MAX {
+        // Half
+        let a = Half::from_bits(bits).to_f32();
+        let b = Vector::load_half4(Half4::fill(Half::from_bits(bits))).into_array();
+
+        // Control group
+        let c = half::f16::from_bits(bits).to_f32();
+
+        // Compare `Half` and `Control group`
+        assert_eq!(a.to_bits(), c.to_bits(), "Test:{} >> Conversion of `Half` is invalid! (Half:{:?}, Control group:{:?})", bits, a, c);
+        assert!(b.iter().all(|b| b.to_bits() == c.to_bits()), "Test:{} >> Load operation on `Vector` is invalid! (Vector:{:?}, Control group:{:?})", bits, b, c);
+
+        // Round trip
+        let d = Half::from_f32(a).to_bits();
+        assert!(d == bits || Half::from_bits(d).is_nan(), "Test:{} >> Round trip of `Half` is invalid! (Half:{:?}, Origin:{:?})", bits, d, bits);
+    }
+}
+
+#[test]
+fn half_from_f32() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin: [f32; 4] = [(); 4].map(|_| gen_f32(&mut rng));
+
+        // Half
+        let a = origin.map(|e| Half::from_f32(e).to_bits());
+        let b = Vector::from_array(origin).store_half4().to_array().map(Half::to_bits);
+
+        // Control group
+        let c = origin.map(|e| half::f16::from_f32(e).to_bits());
+
+        // Compare `Half` and `Control group`
+        assert_eq!(a, c, "Test:{} >> Conversion of `Half` is invalid! (Half:{:?}, Control group:{:?})", test, a, c);
+        assert_eq!(b, c, "Test:{} >> Store operation on `Vector` is invalid! (Vector:{:?}, Control group:{:?})", test, b, c);
+    }
+}
+
+#[test]
+fn half_slice() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST / 100 {
+        // Data
+        let len = rng.gen_range(0..=32);
+        let mut origin = [0.0f32; 32];
+        origin.iter_mut().for_each(|e| *e = gen_f32(&mut rng));
+
+        // Half
+        let mut halves = [Half::ZERO; 32];
+        let mut floats = [0.0f32; 32];
+        Half::convert_from_f32_slice(&origin[..len], &mut halves[..len]);
+        Half::convert_to_f32_slice(&halves[..len], &mut floats[..len]);
+
+        // Control group
+        for idx in 0..len {
+            let a = halves[idx].to_bits();
+            let b = floats[idx].to_bits();
+            let c = half::f16::from_f32(origin[idx]);
+
+            // Compare `Half` and `Control group`
+            assert_eq!(a, c.to_bits(), "Test:{} >> Slice conversion of `Half` is invalid! (Half:{:?}, Control group:{:?})", test, a, c.to_bits());
+            assert_eq!(b, c.to_f32().to_bits(), "Test:{} >> Slice conversion of `f32` is invalid! (f32:{:?}, Control group:{:?})", test, floats[idx], c.to_f32());
+        }
+    }
+}
+
+#[test]
+fn half4_float4() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin: [f32; 4] = [(); 4].map(|_| gen_f32(&mut rng));
+
+        // Half4
+        let a = Half4::from_float4(Float4::from(origin));
+        let b: [f32; 4] = a.to_float4().into();
+
+        // Control group
+        let c = origin.map(|e| half::f16::from_f32(e).to_f32());
+
+        // Compare `Half4` and `Control group`
+        let validate = (0..4).all(|idx| b[idx].to_bits() == c[idx].to_bits());
+        assert!(validate, "Test:{} >> Conversion of `Half4` is invalid! (Half4:{:?}, Control group:{:?})", test, b, c);
+    }
+}
@@ -1,5 +1,5 @@
 use rand::Rng;
-use crate::{Float3x3, Float4x4, Matrix};
+use crate::{Float3, Float3x3, Float4x4, Matrix, Matrix3, Vector};
 use super::{NUM_TEST, EPSILON};
 
 
@@ -339,3 +339,65 @@ fn matrix4x4_mul() {
         assert!(!invalidate, "Test:{} >> Multiply operation on `Matrix` is invalid! (Matrix:{:?}, Control group:{:?})", test, a, b);
     }
 }
+
+#[test]
+fn matrix3_mul() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin_a: [f32; 9] = rng.gen();
+        let origin_b: [f32; 9] = rng.gen();
+        let origin_v: [f32; 3] = rng.gen();
+
+        // Matrix3
+        let matrix_a: Matrix3 = { let t: Float3x3 = origin_a.into(); t.into() };
+        let matrix_b: Matrix3 = { let t: Float3x3 = origin_b.into(); t.into() };
+        let vector_v: Vector = { let t: Float3 = origin_v.into(); t.into() };
+        let matrix_c = matrix_a * matrix_b;
+        let vector_c = matrix_a * vector_v;
+
+        // Control group
+        let glam_a = glam::Mat3::from_cols_array(&origin_a);
+        let glam_b = glam::Mat3::from_cols_array(&origin_b);
+        let glam_v = glam::Vec3::from_array(origin_v);
+        let glam_c = glam_a * glam_b;
+        let glam_d = glam_a * glam_v;
+
+        // Compare `Matrix3` and `Control group`
+        let a: [f32; 9] = { let t: Float3x3 = matrix_c.into(); t.into() };
+        let b: [f32; 9] = glam_c.to_cols_array();
+        let validate = (0..9).all(|idx| (a[idx] - b[idx]).abs() <= EPSILON);
+        assert!(validate, "Test:{} >> Multiply operation on `Matrix3` is invalid! (Matrix3:{:?}, Control group:{:?})", test, a, b);
+
+        let a: [f32; 3] = { let t: Float3 = vector_c.into(); t.into() };
+        let b: [f32; 3] = glam_d.into();
+        let validate = (0..3).all(|idx| (a[idx] - b[idx]).abs() <= EPSILON) && vector_c.get_w() == 0.0;
+        assert!(validate, "Test:{} >> Transformation of the vector on `Matrix3` is invalid! (Vector:{:?}, Control group:{:?})", test, a, b);
+    }
+}
+
+#[test]
+fn matrix4x4_mul_vector() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin_a: [f32; 16] = rng.gen();
+        let origin_v: [f32; 4] = rng.gen();
+
+        // Matrix
+        let matrix_a: Matrix = { let t: Float4x4 = origin_a.into(); t.into() };
+        let vector_v = Vector::from(origin_v);
+        let vector_c = matrix_a * vector_v;
+
+        // Control group
+        let glam_a = glam::Mat4::from_cols_array(&origin_a);
+        let glam_v = glam::Vec4::from_array(origin_v);
+        let glam_c = glam_a * glam_v;
+
+        // Compare `Matrix` and `Control group`
+        let a: [f32; 4] = vector_c.into();
+        let b: [f32; 4] = glam_c.into();
+        let validate = (0..4).all(|idx| (a[idx] - b[idx]).abs() <= EPSILON);
+        assert!(validate, "Test:{} >> Transformation of the vector on `Matrix` is invalid! (Vector:{:?}, Control group:{:?})", test, a, b);
+    }
+}
@@ -1,6 +1,6 @@
 use rand::Rng;
-use crate::{Float4, Float3x3, Float4x4, Matrix};
-use super::{NUM_TEST, EPSILON};
+use crate::{DepthRange, EulerRot, Float3, Float4, Float3x3, Float4x4, Matrix, Matrix3, Quaternion, Vector};
+use super::{NUM_TEST, EPSILON, FUSED_MUL_ADD};
 
 
 
@@ -94,39 +94,50 @@ fn matrix4x4_determinant() {
     }
 }
 
-// #[test]
-// fn matrix3x3_inverse() {
-//     // `Float3x3` data type does not support this operation.
-//     let mut rng = rand::thread_rng();
-//     for test in 0..NUM_TEST {
-//         // Data
-//         let origin: [f32; 9] = rng.gen();
+#[test]
+fn matrix3x3_inverse() {
+    // `Float3x3` data type does not support this operation.
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin: [f32; 9] = rng.gen();
 
-//         // Matrix
-//         let matrix_a: Matrix = { let t: Float3x3 = origin.into(); t.into() };
-//         let matrix_inv = matrix_a.inverse();
+        // Matrix
+        let matrix_a: Matrix3 = { let t: Float3x3 = origin.into(); t.into() };
+        let matrix_inv = matrix_a.try_inverse();
+
+        // Control group
+        let glam_a = glam::Mat3::from_cols_array(&origin);
+        let glam_inv = (glam_a.determinant().abs() > f32::EPSILON).then(|| glam_a.inverse());
 
-//         // Control group
-//         let glam_a = glam::Mat3::from_cols_array(&origin);
-//         let glam_inv = (glam_a.determinant().abs() > f32::EPSILON).then(|| glam_a.inverse());
+        // Skip the determinants near the threshold, where the rounding of the determinant decides the result.
+        if (glam_a.determinant().abs() - f32::EPSILON).abs() <= EPSILON {
+            continue;
+        }
 
-//         // Compare `Matrix` and `Control group`
-//         if matrix_inv.is_none() & glam_inv.is_none() {
-//             continue;
-//         }
+        // Compare `Matrix` and `Control group`
+        if matrix_inv.is_none() & glam_inv.is_none() {
+            continue;
+        }
 
-//         if let Some((matrix_inv, glam_inv)) = matrix_inv.zip(glam_inv) {
-//             let a: [f32; 9] = { let t: Float3x3 = matrix_inv.into(); t.into() };
-//             let b: [f32; 9] = glam_inv.to_cols_array();
-//             for idx in 0..9 {
-//                 let validate = (a[idx] - b[idx]).abs() <= EPSILON;
-//                 assert!(validate, "Test:{}-{} >> Inverse operation on `Matrix` is invalid! (Matrix:{:?}, Control group:{:?})", test, idx, a, b);
-//             }
-//         } else {
-//             panic!("Test:{} >> Inverse operation on `Matrix` is invalid! (Matrix:{:?}, Control group:{:?})", test, matrix_inv, glam_inv);
-//         }
-//     }
-// }
+        if let Some((matrix_inv, glam_inv)) = matrix_inv.zip(glam_inv) {
+            let a: [f32; 9] = { let t: Float3x3 = matrix_inv.into(); t.into() };
+            let b: [f32; 9] = glam_inv.to_cols_array();
+            // Rounding errors of the inverse grow with the condition number of the matrix.
+            let cond = origin.iter().chain(b.iter()).fold(0.0f32, |m, e| m.max(e.abs()));
+            for idx in 0..9 {
+                let tolerance = match FUSED_MUL_ADD {
+                    true => EPSILON * cond * b[idx].abs().max(1.0), 
+                    false => EPSILON
+                };
+                let validate = (a[idx] - b[idx]).abs() <= tolerance;
+                assert!(validate, "Test:{}-{} >> Inverse operation on `Matrix` is invalid! (Matrix:{:?}, Control group:{:?})", test, idx, a, b);
+            }
+        } else {
+            panic!("Test:{} >> Inverse operation on `Matrix` is invalid! (Matrix:{:?}, Control group:{:?})", test, matrix_inv, glam_inv);
+        }
+    }
+}
 
 #[test]
 fn matrix4x4_inverse() {
@@ -160,3 +171,464 @@ fn matrix4x4_inverse() {
         }
     }
 }
+
+#[test]
+fn matrix4x4_try_inverse() {
+    // `Float4x4` data type does not support this operation.
+    let mut rng = rand::thread_rng();
+    let reflection: [f32; 16] = [2.0, 0.0, 0.0, 0.0, 0.0, -3.0, 0.0, 0.0, 0.0, 0.0, 4.0, 0.0, 1.0, 2.0, 3.0, 1.0];
+    let singular: [f32; 16] = [1.0, 2.0, 3.0, 4.0, 2.0, 4.0, 6.0, 8.0, 0.0, 1.0, 0.0, 1.0, 5.0, 6.0, 7.0, 1.0];
+    for test in 0..NUM_TEST {
+        // Data
+        let origin: [f32; 16] = match test {
+            0 => reflection,
+            1 => singular,
+            _ => [(); 16].map(|_| rng.gen_range(-1.0..=1.0))
+        };
+
+        // Matrix
+        let matrix_a: Matrix = { let t: Float4x4 = origin.into(); t.into() };
+        let matrix_inv = matrix_a.try_inverse();
+
+        // Control group
+        let glam_a = glam::Mat4::from_cols_array(&origin);
+        let glam_inv = (glam_a.determinant().abs() > f32::EPSILON).then(|| glam_a.inverse());
+
+        // Compare `Matrix` and `Control group`
+        assert!(test != 0 || glam_a.determinant() < 0.0, "Test:{} >> The determinant of the reflection must be negative!", test);
+        assert!(test != 1 || matrix_inv.is_none(), "Test:{} >> Inverse operation on the singular `Matrix` is invalid! (Matrix:{:?})", test, matrix_inv);
+
+        // Skip the determinants near the threshold, where the rounding of the determinant decides the result.
+        if (glam_a.determinant().abs() - f32::EPSILON).abs() <= EPSILON {
+            continue;
+        }
+
+        if matrix_inv.is_none() & glam_inv.is_none() {
+            continue;
+        }
+
+        if let Some((matrix_inv, glam_inv)) = matrix_inv.zip(glam_inv) {
+            let a: [f32; 16] = { let t: Float4x4 = matrix_inv.into(); t.into() };
+            let b: [f32; 16] = glam_inv.to_cols_array();
+            // Rounding errors of the inverse grow with the condition number of the matrix.
+            let cond = origin.iter().chain(b.iter()).fold(0.0f32, |m, e| m.max(e.abs()));
+            for idx in 0..16 {
+                let tolerance = EPSILON * cond * b[idx].abs().max(1.0);
+                let validate = (a[idx] - b[idx]).abs() <= tolerance;
+                assert!(validate, "Test:{}-{} >> Inverse operation on `Matrix` is invalid! (Matrix:{:?}, Control group:{:?})", test, idx, a, b);
+            }
+        } else {
+            panic!("Test:{} >> Inverse operation on `Matrix` is invalid! (Matrix:{:?}, Control group:{:?})", test, matrix_inv, glam_inv);
+        }
+    }
+}
+
+#[test]
+fn matrix_euler() {
+    const ORDERS: [(EulerRot, glam::EulerRot); 4] = [
+        (EulerRot::XYZ, glam::EulerRot::XYZ), (EulerRot::YXZ, glam::EulerRot::YXZ), 
+        (EulerRot::ZYX, glam::EulerRot::ZYX), (EulerRot::ZXZ, glam::EulerRot::ZXZ), 
+    ];
+
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let angles: [f32; 3] = [(); 3].map(|_| rng.gen_range(-core::f32::consts::PI..=core::f32::consts::PI));
+
+        for (order, glam_order) in ORDERS {
+            // Matrix
+            let matrix = Matrix::from_euler(order, angles[0], angles[1], angles[2]);
+            let (x, y, z) = matrix.to_euler_angles(order);
+            let matrix_back = Matrix::from_euler(order, x, y, z);
+
+            // Control group
+            let glam_mat = glam::Mat4::from_euler(glam_order, angles[0], angles[1], angles[2]);
+
+            // Compare `Matrix` and `Control group`
+            let a: [f32; 16] = { let t: Float4x4 = matrix.into(); t.into() };
+            let b: [f32; 16] = glam_mat.to_cols_array();
+            let c: [f32; 16] = { let t: Float4x4 = matrix_back.into(); t.into() };
+            let validate = (0..16).all(|idx| (a[idx] - b[idx]).abs() <= EPSILON && (c[idx] - b[idx]).abs() <= 1.0e-5);
+            assert!(validate, "Test:{} >> Euler angles ({:?}) of `Matrix` is invalid! (Matrix:{:?}, Control group:{:?})", test, order, c, b);
+        }
+    }
+}
+
+#[test]
+fn matrix3_transpose() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data 
+        let origin: [f32; 9] = rng.gen();
+        
+        // Matrix3
+        let matrix_a: Matrix3 = origin.into();
+        let matrix_t = matrix_a.transpose();
+
+        // Control group
+        let glam_a = glam::Mat3::from_cols_array(&origin);
+        let glam_t = glam_a.transpose();
+
+        // Compare `Matrix3` and `Control group`
+        let a: [f32; 9] = matrix_t.into();
+        let b: [f32; 9] = glam_t.to_cols_array();
+        let validate = a == b && [matrix_t.get_x_axis(), matrix_t.get_y_axis(), matrix_t.get_z_axis()].iter().all(|v| v.get_w() == 0.0);
+        assert!(validate, "Test:{} >> Transpose operation on `Matrix3` is invalid! (Matrix3:{:?}, Control group:{:?})", test, a, b);
+    }
+}
+
+#[test]
+fn matrix3_determinant() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data 
+        let origin: [f32; 9] = rng.gen();
+        
+        // Matrix3
+        let matrix_a: Matrix3 = origin.into();
+        let matrix_d = matrix_a.determinant_into();
+
+        // Control group
+        let glam_a = glam::Mat3::from_cols_array(&origin);
+        let glam_d = glam_a.determinant();
+
+        // Compare `Matrix3` and `Control group`
+        let validate = (matrix_d - glam_d).abs() <= EPSILON;
+        assert!(validate, "Test:{} >> Determinant operation on `Matrix3` is invalid! (Matrix3:{:?}, Control group:{:?})", test, matrix_d, glam_d);
+    }
+}
+
+#[test]
+fn matrix3_rotation() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let angle: f32 = rng.gen_range(-core::f32::consts::PI..=core::f32::consts::PI);
+        let axis: [f32; 3] = [(); 3].map(|_| rng.gen_range(-1.0..=1.0));
+        let glam_axis = match glam::Vec3::from_array(axis).try_normalize() {
+            Some(axis) => axis, 
+            None => continue
+        };
+
+        // Matrix3
+        let axis: Vector = { let t: Float3 = glam_axis.to_array().into(); t.into() };
+        let matrices = [
+            Matrix3::from_rotation_x(angle), 
+            Matrix3::from_rotation_y(angle), 
+            Matrix3::from_rotation_z(angle), 
+            Matrix3::from_axis_angle(axis, angle), 
+        ];
+
+        // Control group
+        let glam_matrices = [
+            glam::Mat3::from_rotation_x(angle), 
+            glam::Mat3::from_rotation_y(angle), 
+            glam::Mat3::from_rotation_z(angle), 
+            glam::Mat3::from_axis_angle(glam_axis, angle), 
+        ];
+
+        // Compare `Matrix3` and `Control group`
+        for (matrix, glam_matrix) in matrices.into_iter().zip(glam_matrices) {
+            let a: [f32; 9] = matrix.into();
+            let b: [f32; 9] = glam_matrix.to_cols_array();
+            let validate = (0..9).all(|idx| (a[idx] - b[idx]).abs() <= EPSILON);
+            assert!(validate, "Test:{} >> Rotation of `Matrix3` is invalid! (Matrix3:{:?}, Control group:{:?})", test, a, b);
+        }
+    }
+}
+
+#[test]
+fn matrix3_quaternion() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin: [f32; 4] = [(); 4].map(|_| rng.gen_range(-1.0..=1.0));
+        let glam_quat = match glam::Vec4::from_array(origin).try_normalize() {
+            Some(v) => glam::Quat::from_vec4(v), 
+            None => continue
+        };
+
+        // Matrix3
+        let quat: Quaternion = { let t: Float4 = glam_quat.to_array().into(); t.into() };
+        let matrix = Matrix3::from_quaternion(quat);
+        let quat_back = matrix.into_quaternion();
+
+        // Control group
+        let glam_matrix = glam::Mat3::from_quat(glam_quat);
+
+        // Compare `Matrix3` and `Control group`
+        let a: [f32; 9] = matrix.into();
+        let b: [f32; 9] = glam_matrix.to_cols_array();
+        let validate = (0..9).all(|idx| (a[idx] - b[idx]).abs() <= EPSILON);
+        assert!(validate, "Test:{} >> `Quaternion` to `Matrix3` is invalid! (Matrix3:{:?}, Control group:{:?})", test, a, b);
+
+        let a: [f32; 4] = { let t: Float4 = quat_back.into(); t.into() };
+        let b: [f32; 4] = glam_quat.into();
+        let sign = if glam_quat.dot(glam::Quat::from_array(a)) < 0.0 { -1.0 } else { 1.0 };
+        let validate = (0..4).all(|idx| (a[idx] - sign * b[idx]).abs() <= 1.0e-5);
+        assert!(validate, "Test:{} >> `Matrix3` to `Quaternion` is invalid! (Quaternion:{:?}, Control group:{:?})", test, a, b);
+
+        let matrix: Matrix = matrix.into();
+        let a: [f32; 16] = matrix.into();
+        let b: [f32; 16] = glam::Mat4::from_quat(glam_quat).to_cols_array();
+        let validate = (0..16).all(|idx| (a[idx] - b[idx]).abs() <= EPSILON);
+        assert!(validate, "Test:{} >> `Matrix3` to `Matrix` is invalid! (Matrix:{:?}, Control group:{:?})", test, a, b);
+    }
+}
+
+#[test]
+fn matrix_decompose() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let scale: [f32; 3] = [(); 3].map(|_| rng.gen_range(0.1..=2.0) * if rng.gen() { 1.0 } else { -1.0 });
+        let rotation: [f32; 4] = [(); 4].map(|_| rng.gen_range(-1.0..=1.0));
+        let translation: [f32; 3] = [(); 3].map(|_| rng.gen_range(-10.0..=10.0));
+        let rotation = match glam::Vec4::from_array(rotation).try_normalize() {
+            Some(rotation) => glam::Quat::from_vec4(rotation), 
+            None => continue
+        };
+
+        // Matrix
+        let matrix = Matrix::from_scale_rotation_translation(
+            { let t: Float3 = scale.into(); t.into() }, 
+            { let t: Float4 = rotation.to_array().into(); t.into() }, 
+            { let t: Float3 = translation.into(); t.into() }
+        );
+        let (s, r, t) = match matrix.try_decompose() {
+            Some(srt) => srt, 
+            None => panic!("Test:{} >> Decomposition of `Matrix` is invalid! (Matrix:{:?})", test, matrix)
+        };
+        let matrix_back = Matrix::from_scale_rotation_translation(s, r, t);
+
+        // Control group
+        let glam_matrix = glam::Mat4::from_scale_rotation_translation(
+            glam::Vec3::from_array(scale), 
+            rotation, 
+            glam::Vec3::from_array(translation)
+        );
+        let (glam_s, _, glam_t) = glam_matrix.to_scale_rotation_translation();
+
+        // Compare `Matrix` and `Control group`
+        let a: [f32; 16] = matrix_back.into();
+        let b: [f32; 16] = glam_matrix.to_cols_array();
+        let validate = (0..16).all(|idx| (a[idx] - b[idx]).abs() <= 1.0e-4);
+        assert!(validate, "Test:{} >> Decomposition of `Matrix` is invalid! (Matrix:{:?}, Control group:{:?})", test, a, b);
+
+        let a: [f32; 6] = { let s: Float3 = s.into(); let t: Float3 = t.into(); [s.x, s.y, s.z, t.x, t.y, t.z] };
+        let b: [f32; 6] = [glam_s.x, glam_s.y, glam_s.z, glam_t.x, glam_t.y, glam_t.z];
+        let validate = (0..6).all(|idx| (a[idx] - b[idx]).abs() <= 1.0e-4);
+        assert!(validate, "Test:{} >> Decomposition of `Matrix` is invalid! (Scale, Translation:{:?}, Control group:{:?})", test, a, b);
+    }
+}
+
+#[test]
+fn matrix_decompose_degenerate() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let scale: [f32; 3] = [(); 3].map(|_| rng.gen_range(0.1..=2.0));
+        let shear: f32 = rng.gen_range(0.1..=2.0);
+        let zero: usize = rng.gen_range(0..3);
+
+        // Matrix
+        let matrix_a = Matrix::diagonal(Vector::new(scale[0], scale[1], scale[2], 1.0));
+        let mut matrix_shear = matrix_a;
+        matrix_shear.set_y_axis(Vector::new(shear, scale[1], 0.0, 0.0));
+        let mut matrix_zero = matrix_a;
+        match zero {
+            0 => matrix_zero.set_x_axis(Vector::ZERO), 
+            1 => matrix_zero.set_y_axis(Vector::ZERO), 
+            _ => matrix_zero.set_z_axis(Vector::ZERO)
+        };
+        let mut matrix_projective = matrix_a;
+        matrix_projective.set_z_axis(Vector::new(0.0, 0.0, scale[2], 1.0));
+
+        // Compare
+        assert!(matrix_a.try_decompose().is_some(), "Test:{} >> Decomposition of `Matrix` is invalid! (Matrix:{:?})", test, matrix_a);
+        assert!(matrix_shear.try_decompose().is_none(), "Test:{} >> Decomposition of the sheared `Matrix` must fail! (Matrix:{:?})", test, matrix_shear);
+        assert!(matrix_zero.try_decompose().is_none(), "Test:{} >> Decomposition of the degenerate `Matrix` must fail! (Matrix:{:?})", test, matrix_zero);
+        assert!(matrix_projective.try_decompose().is_none(), "Test:{} >> Decomposition of the projective `Matrix` must fail! (Matrix:{:?})", test, matrix_projective);
+    }
+}
+
+#[test]
+fn matrix_perspective() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let fov_y: f32 = rng.gen_range(0.1..=3.0);
+        let aspect_ratio: f32 = rng.gen_range(0.5..=2.0);
+        let z_near: f32 = rng.gen_range(0.01..=1.0);
+        let z_far: f32 = rng.gen_range(10.0..=1000.0);
+
+        // Matrix
+        let matrices = [
+            Matrix::perspective_rh(fov_y, aspect_ratio, z_near, z_far), 
+            Matrix::perspective_lh(fov_y, aspect_ratio, z_near, z_far), 
+            Matrix::perspective_depth_range_rh(fov_y, aspect_ratio, z_near, z_far, DepthRange::NegOneToOne), 
+            Matrix::perspective_infinite_rh(fov_y, aspect_ratio, z_near), 
+            Matrix::perspective_infinite_lh(fov_y, aspect_ratio, z_near), 
+            Matrix::perspective_infinite_reverse_rh(fov_y, aspect_ratio, z_near), 
+            Matrix::perspective_infinite_reverse_lh(fov_y, aspect_ratio, z_near), 
+        ];
+
+        // Control group
+        let glam_matrices = [
+            glam::Mat4::perspective_rh(fov_y, aspect_ratio, z_near, z_far), 
+            glam::Mat4::perspective_lh(fov_y, aspect_ratio, z_near, z_far), 
+            glam::Mat4::perspective_rh_gl(fov_y, aspect_ratio, z_near, z_far), 
+            glam::Mat4::perspective_infinite_rh(fov_y, aspect_ratio, z_near), 
+            glam::Mat4::perspective_infinite_lh(fov_y, aspect_ratio, z_near), 
+            glam::Mat4::perspective_infinite_reverse_rh(fov_y, aspect_ratio, z_near), 
+            glam::Mat4::perspective_infinite_reverse_lh(fov_y, aspect_ratio, z_near), 
+        ];
+
+        // Compare `Matrix` and `Control group`
+        for (idx, (matrix, glam_matrix)) in matrices.into_iter().zip(glam_matrices).enumerate() {
+            let a: [f32; 16] = matrix.into();
+            let b: [f32; 16] = glam_matrix.to_cols_array();
+            let validate = (0..16).all(|i| (a[i] - b[i]).abs() <= 1.0e-5 * b[i].abs().max(1.0));
+            assert!(validate, "Test:{}-{} >> Perspective projection of `Matrix` is invalid! (Matrix:{:?}, Control group:{:?})", test, idx, a, b);
+        }
+
+        // The depth of the near plane and the far plane.
+        let depth = |m: Matrix, z: f32| { let v: Float4 = (m * Vector::new(0.0, 0.0, z, 1.0)).into(); v.z / v.w };
+        let cases = [
+            (Matrix::perspective_depth_range_lh(fov_y, aspect_ratio, z_near, z_far, DepthRange::NegOneToOne), z_near, z_far, -1.0, 1.0), 
+            (Matrix::perspective_reverse_rh(fov_y, aspect_ratio, z_near, z_far), -z_near, -z_far, 1.0, 0.0), 
+            (Matrix::perspective_reverse_lh(fov_y, aspect_ratio, z_near, z_far), z_near, z_far, 1.0, 0.0), 
+        ];
+        for (idx, (matrix, near, far, near_depth, far_depth)) in cases.into_iter().enumerate() {
+            let (a, b) = (depth(matrix, near), depth(matrix, far));
+            let validate = (a - near_depth).abs() <= 1.0e-4 && (b - far_depth).abs() <= 1.0e-4;
+            assert!(validate, "Test:{}-{} >> Depth of the perspective projection of `Matrix` is invalid! (Depth:{:?}, Expected:{:?})", test, idx, (a, b), (near_depth, far_depth));
+        }
+    }
+}
+
+#[test]
+fn matrix_orthographic() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let left: f32 = rng.gen_range(-100.0..=-1.0);
+        let right: f32 = rng.gen_range(1.0..=100.0);
+        let bottom: f32 = rng.gen_range(-100.0..=-1.0);
+        let top: f32 = rng.gen_range(1.0..=100.0);
+        let near: f32 = rng.gen_range(0.01..=1.0);
+        let far: f32 = rng.gen_range(10.0..=1000.0);
+
+        // Matrix
+        let matrices = [
+            Matrix::orthographic_rh(left, right, bottom, top, near, far), 
+            Matrix::orthographic_lh(left, right, bottom, top, near, far), 
+            Matrix::orthographic_depth_range_rh(left, right, bottom, top, near, far, DepthRange::NegOneToOne), 
+        ];
+
+        // Control group
+        let glam_matrices = [
+            glam::Mat4::orthographic_rh(left, right, bottom, top, near, far), 
+            glam::Mat4::orthographic_lh(left, right, bottom, top, near, far), 
+            glam::Mat4::orthographic_rh_gl(left, right, bottom, top, near, far), 
+        ];
+
+        // Compare `Matrix` and `Control group`
+        for (idx, (matrix, glam_matrix)) in matrices.into_iter().zip(glam_matrices).enumerate() {
+            let a: [f32; 16] = matrix.into();
+            let b: [f32; 16] = glam_matrix.to_cols_array();
+            let validate = (0..16).all(|i| (a[i] - b[i]).abs() <= 1.0e-5 * b[i].abs().max(1.0));
+            assert!(validate, "Test:{}-{} >> Orthographic projection of `Matrix` is invalid! (Matrix:{:?}, Control group:{:?})", test, idx, a, b);
+        }
+
+        // The depth of the near plane and the far plane.
+        let matrix = Matrix::orthographic_depth_range_lh(left, right, bottom, top, near, far, DepthRange::NegOneToOne);
+        let a: Float4 = (matrix * Vector::new(0.0, 0.0, near, 1.0)).into();
+        let b: Float4 = (matrix * Vector::new(0.0, 0.0, far, 1.0)).into();
+        let validate = (a.z + 1.0).abs() <= 1.0e-4 && (b.z - 1.0).abs() <= 1.0e-4;
+        assert!(validate, "Test:{} >> Depth of the orthographic projection of `Matrix` is invalid! (Depth:{:?})", test, (a.z, b.z));
+    }
+}
+
+#[test]
+fn matrix_transform_slice() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin: [f32; 16] = rng.gen();
+        let len = rng.gen_range(0..=9);
+        let origin_a: [[f32; 3]; 9] = rng.gen();
+        let origin_b: [[f32; 4]; 9] = rng.gen();
+
+        // Matrix
+        let matrix: Matrix = { let t: Float4x4 = origin.into(); t.into() };
+        let src_a = origin_a.map(Float3::from);
+        let src_b = origin_b.map(Float4::from);
+        let mut points = [Float3::ZERO; 9];
+        let mut vectors = [Float3::ZERO; 9];
+        let mut float4s = [Float4::ZERO; 9];
+        matrix.transform_point3_slice(&src_a[..len], &mut points[..len]);
+        matrix.transform_vector3_slice(&src_a[..len], &mut vectors[..len]);
+        matrix.transform_float4_slice(&src_b[..len], &mut float4s[..len]);
+
+        // Control group
+        let glam_m = glam::Mat4::from_cols_array(&origin);
+        for idx in 0..len {
+            let glam_point = glam_m.transform_point3(glam::Vec3::from_array(origin_a[idx])).to_array();
+            let glam_vector = glam_m.transform_vector3(glam::Vec3::from_array(origin_a[idx])).to_array();
+            let glam_float4 = (glam_m * glam::Vec4::from_array(origin_b[idx])).to_array();
+
+            // Compare `Matrix` and `Control group`
+            let a: [f32; 3] = points[idx].into();
+            let validate = (0..3).all(|i| (a[i] - glam_point[i]).abs() <= EPSILON * glam_point[i].abs().max(1.0));
+            assert!(validate, "Test:{} >> Point slice transformation on `Matrix` is invalid! (Matrix:{:?}, Control group:{:?})", test, a, glam_point);
+            let a: [f32; 3] = vectors[idx].into();
+            let validate = (0..3).all(|i| (a[i] - glam_vector[i]).abs() <= EPSILON * glam_vector[i].abs().max(1.0));
+            assert!(validate, "Test:{} >> Vector slice transformation on `Matrix` is invalid! (Matrix:{:?}, Control group:{:?})", test, a, glam_vector);
+            let a: [f32; 4] = float4s[idx].into();
+            let validate = (0..4).all(|i| (a[i] - glam_float4[i]).abs() <= EPSILON * glam_float4[i].abs().max(1.0));
+            assert!(validate, "Test:{} >> Float4 slice transformation on `Matrix` is invalid! (Matrix:{:?}, Control group:{:?})", test, a, glam_float4);
+        }
+    }
+}
+
+#[test]
+fn matrix_transform_slice_strided() {
+    // Interleaved vertex: position(3), normal(3), uv(2)
+    const STRIDE: usize = 8;
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin: [f32; 16] = rng.gen();
+        let count = rng.gen_range(0..=9);
+        let mut vertices = [0.0; 9 * STRIDE];
+        vertices.iter_mut().for_each(|e| *e = rng.gen());
+
+        // Matrix
+        let matrix: Matrix = { let t: Float4x4 = origin.into(); t.into() };
+        let mut output = vertices;
+        matrix.transform_point3_slice_strided(&vertices, STRIDE, &mut output, STRIDE, count);
+        let mut normals = [0.0; 9 * 3];
+        matrix.transform_vector3_slice_strided(&vertices[3..], STRIDE, &mut normals, 3, count);
+        let mut float4s = [0.0; 9 * 4];
+        matrix.transform_float4_slice_strided(&vertices, STRIDE, &mut float4s, 4, count);
+
+        // Compare `Matrix` and `Slice transformation`
+        for idx in 0..count {
+            let vertex = &vertices[idx * STRIDE..(idx + 1) * STRIDE];
+            let (mut point, mut normal, mut float4) = ([Float3::ZERO], [Float3::ZERO], [Float4::ZERO]);
+            matrix.transform_point3_slice(&[Float3::from_slice(&vertex[0..3])], &mut point);
+            matrix.transform_vector3_slice(&[Float3::from_slice(&vertex[3..6])], &mut normal);
+            matrix.transform_float4_slice(&[Float4::from_slice(&vertex[0..4])], &mut float4);
+            let (point, normal, float4) = (point[0], normal[0], float4[0]);
+
+            let a = Float3::from_slice(&output[idx * STRIDE..idx * STRIDE + 3]);
+            assert!(a == point, "Test:{} >> Strided point transformation on `Matrix` is invalid! (Matrix:{:?}, Control group:{:?})", test, a, point);
+            assert_eq!(&output[idx * STRIDE + 3..(idx + 1) * STRIDE], &vertex[3..], "Test:{} >> Strided point transformation on `Matrix` modified other attributes!", test);
+            let a = Float3::from_slice(&normals[idx * 3..idx * 3 + 3]);
+            assert!(a == normal, "Test:{} >> Strided vector transformation on `Matrix` is invalid! (Matrix:{:?}, Control group:{:?})", test, a, normal);
+            let a = Float4::from_slice(&float4s[idx * 4..idx * 4 + 4]);
+            assert!(a == float4, "Test:{} >> Strided float4 transformation on `Matrix` is invalid! (Matrix:{:?}, Control group:{:?})", test, a, float4);
+        }
+    }
+}
@@ -1,3 +1,9 @@
+use rand::Rng;
+use rand::rngs::ThreadRng;
+use crate::Vector;
+
+
+
 /// Number of tests.
 const NUM_TEST: usize = 10_000_000;
 
@@ -7,6 +13,47 @@ const NUM_TEST: usize = 10_000_000;
 /// 
 const EPSILON: f32 = 1.192092896e-6;
 
+/// With the `fma` instruction, a multiply-add is rounded only once.
+/// 
+/// So the results can differ from the control group by rounding errors.
+/// 
+const FUSED_MUL_ADD: bool = cfg!(all(target_feature = "sse2", target_feature = "fma", not(feature = "scalar-math")));
+
+/// Epsilon constant used to compare double-precision types.
+const DOUBLE_EPSILON: f64 = 1e-12;
+
+/// The geometry queries accumulate rounding errors of several operations.
+/// 
+/// So we compare using a separate tolerance constant.
+/// 
+const TOLERANCE: f32 = 1e-3;
+
+/// Returns a random point in the range `[-10, 10]`.
+fn random_point(rng: &mut ThreadRng) -> glam::Vec3 {
+    glam::Vec3::from_array([(); 3].map(|_| rng.gen_range(-10.0..=10.0)))
+}
+
+/// Returns a random unit vector.
+fn random_dir(rng: &mut ThreadRng) -> glam::Vec3 {
+    loop {
+        let v = glam::Vec3::from_array([(); 3].map(|_| rng.gen_range(-1.0..=1.0)));
+        if v.length() > 0.1 {
+            return v.normalize();
+        }
+    }
+}
+
+/// Converts the `glam` vector to a vector with the `w` element set to zero.
+fn to_vector(v: glam::Vec3) -> Vector {
+    Vector::new(v.x, v.y, v.z, 0.0)
+}
+
+/// Converts the `x`, `y` and `z` elements of the vector to the `glam` vector.
+fn to_glam(v: Vector) -> glam::Vec3 {
+    let [x, y, z, _] = v.into_array();
+    glam::Vec3::new(x, y, z)
+}
+
 
 
 mod vector_op;
@@ -17,3 +64,21 @@ mod quaternion_op;
 
 mod matrix_op;
 mod matrix_base_op;
+
+mod affine_op;
+
+mod double_op;
+
+mod soa_op;
+
+mod half_op;
+
+mod packed_op;
+
+mod color_op;
+
+mod geometry_op;
+
+mod ray_op;
+
+mod plane_op;
@@ -0,0 +1,247 @@
+use rand::Rng;
+use crate::{Float2, Float3, Float4, UByteN4, ByteN4, ShortN2, UShortN4, UDecN4, Float3PK, Rgb9e5, Vector};
+use super::NUM_TEST;
+
+
+
+/// Generates a random value around the normalized range, including some special values.
+fn gen_f32<R: Rng>(rng: &mut R) -> f32 {
+    match rng.gen_range(0..8) {
+        0 => [f32::NAN, f32::INFINITY, f32::NEG_INFINITY, -0.0, 0.5, -0.5][rng.gen_range(0..6)],
+        1 => f32::from_bits(rng.gen()),
+        _ => rng.gen_range(-1.5..=1.5),
+    }
+}
+
+/// Decodes an unsigned floating-point number with a 5-bit exponent and a mantissa of the given number of bits.
+fn decode_float(packed: u32, man_bits: u32) -> f64 {
+    let exp = (packed >> man_bits) as i32;
+    let man = (packed & ((1 << man_bits) - 1)) as f64 / (1 << man_bits) as f64;
+    match exp {
+        0 => man * 2f64.powi(-14),
+        0x1F if man == 0.0 => f64::INFINITY,
+        0x1F => f64::NAN,
+        _ => (1.0 + man) * 2f64.powi(exp - 15),
+    }
+}
+
+/// Returns `true` if `packed` is the correctly rounded value of `value`.
+fn is_nearest(value: f32, packed: u32, man_bits: u32) -> bool {
+    let max = (0x1F << man_bits) - 1;
+    let value = value as f64;
+    if value.is_nan() {
+        return decode_float(packed, man_bits).is_nan();
+    }
+    if value <= 0.0 {
+        return packed == 0;
+    }
+    if value.is_infinite() {
+        return packed == max + 1;
+    }
+    if value >= decode_float(max, man_bits) {
+        return packed == max;
+    }
+
+    let diff = (decode_float(packed, man_bits) - value).abs();
+    let lower = match packed { 0 => f64::INFINITY, _ => (decode_float(packed - 1, man_bits) - value).abs() };
+    let upper = (decode_float(packed + 1, man_bits) - value).abs();
+    (diff < lower || (diff == lower && packed & 1 == 0)) && (diff < upper || (diff == upper && packed & 1 == 0))
+}
+
+/// Encodes a color in `RGB9E5` format following the `EXT_texture_shared_exponent` specification.
+fn encode_rgb9e5(color: [f32; 3]) -> u32 {
+    let color = color.map(|e| match e > 0.0 { true => (e as f64).min(65408.0), false => 0.0 });
+    let max = color[0].max(color[1]).max(color[2]);
+    let exp = (max.log2().floor() as i32).max(-16) + 1 + 15;
+    let exp = match (max / 2f64.powi(exp - 15 - 9) + 0.5).floor() as i32 == 512 { true => exp + 1, false => exp };
+    let [r, g, b] = color.map(|e| (e / 2f64.powi(exp - 15 - 9) + 0.5).floor() as u32);
+    r | (g << 9) | (b << 18) | ((exp as u32) << 27)
+}
+
+#[test]
+fn unorm_load_store() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin: [f32; 4] = [(); 4].map(|_| gen_f32(&mut rng));
+        let bytes: [u8; 4] = rng.gen();
+        let shorts: [u16; 4] = rng.gen();
+
+        // Vector
+        let vec = Vector::from_array(origin);
+        let ubyte = vec.store_ubyten4();
+        let ushort = vec.store_ushortn4();
+        let udec = vec.store_udecn4();
+
+        // Control group
+        let unorm = |e: f32, max: f32| (e.clamp(0.0, 1.0) * max).round();
+        let control_ubyte = origin.map(|e| unorm(e, 255.0) as u8);
+        let control_ushort = origin.map(|e| unorm(e, 65535.0) as u16);
+        let control_udec = [unorm(origin[0], 1023.0), unorm(origin[1], 1023.0), unorm(origin[2], 1023.0), unorm(origin[3], 3.0)].map(|e| e as u32);
+
+        // Compare `Vector` and `Control group`
+        assert_eq!(ubyte.to_array(), control_ubyte, "Test:{} >> Store operation on `UByteN4` is invalid! (UByteN4:{:?}, Control group:{:?})", test, ubyte, control_ubyte);
+        assert_eq!(ushort.to_array(), control_ushort, "Test:{} >> Store operation on `UShortN4` is invalid! (UShortN4:{:?}, Control group:{:?})", test, ushort, control_ushort);
+        let a = [udec.x(), udec.y(), udec.z(), udec.w()];
+        assert_eq!(a, control_udec, "Test:{} >> Store operation on `UDecN4` is invalid! (UDecN4:{:?}, Control group:{:?})", test, a, control_udec);
+        assert!(ubyte == UByteN4::from_float4(Float4::from(origin)), "Test:{} >> Conversion of `UByteN4` is invalid! (UByteN4:{:?}, Control group:{:?})", test, ubyte, control_ubyte);
+        assert!(ushort == UShortN4::from_float4(Float4::from(origin)), "Test:{} >> Conversion of `UShortN4` is invalid! (UShortN4:{:?}, Control group:{:?})", test, ushort, control_ushort);
+        assert!(udec == UDecN4::from_float4(Float4::from(origin)), "Test:{} >> Conversion of `UDecN4` is invalid! (UDecN4:{:?}, Control group:{:?})", test, udec, control_udec);
+
+        // Round trip
+        let a = Vector::load_ubyten4(UByteN4::from_array(bytes));
+        assert_eq!(a.store_ubyten4().to_array(), bytes, "Test:{} >> Load operation on `UByteN4` is invalid! (Vector:{:?}, Origin:{:?})", test, a, bytes);
+        assert_eq!(a.into_array(), bytes.map(|e| e as f32 / 255.0), "Test:{} >> Load operation on `UByteN4` is invalid! (Vector:{:?}, Origin:{:?})", test, a, bytes);
+        let a = Vector::load_ushortn4(UShortN4::from_array(shorts));
+        assert_eq!(a.store_ushortn4().to_array(), shorts, "Test:{} >> Load operation on `UShortN4` is invalid! (Vector:{:?}, Origin:{:?})", test, a, shorts);
+        let udec = UDecN4::from_bits(rng.gen());
+        let a = Vector::load_udecn4(udec);
+        assert_eq!(a.store_udecn4(), udec, "Test:{} >> Load operation on `UDecN4` is invalid! (Vector:{:?}, Origin:{:?})", test, a, udec);
+        assert_eq!(a.into_array(), <[f32; 4]>::from(udec.to_float4()), "Test:{} >> Load operation on `UDecN4` is invalid! (Vector:{:?}, Origin:{:?})", test, a, udec);
+    }
+}
+
+#[test]
+fn snorm_load_store() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin: [f32; 4] = [(); 4].map(|_| gen_f32(&mut rng));
+        let bytes: [i8; 4] = rng.gen();
+        let shorts: [i16; 2] = rng.gen();
+
+        // Vector
+        let vec = Vector::from_array(origin);
+        let byte = vec.store_byten4();
+        let short = vec.store_shortn2();
+
+        // Control group
+        let snorm = |e: f32, max: f32| (e.clamp(-1.0, 1.0) * max).round();
+        let control_byte = origin.map(|e| snorm(e, 127.0) as i8);
+        let control_short = [snorm(origin[0], 32767.0) as i16, snorm(origin[1], 32767.0) as i16];
+
+        // Compare `Vector` and `Control group`
+        assert_eq!(byte.to_array(), control_byte, "Test:{} >> Store operation on `ByteN4` is invalid! (ByteN4:{:?}, Control group:{:?})", test, byte, control_byte);
+        assert_eq!(short.to_array(), control_short, "Test:{} >> Store operation on `ShortN2` is invalid! (ShortN2:{:?}, Control group:{:?})", test, short, control_short);
+        assert!(byte == ByteN4::from_float4(Float4::from(origin)), "Test:{} >> Conversion of `ByteN4` is invalid! (ByteN4:{:?}, Control group:{:?})", test, byte, control_byte);
+        assert!(short == ShortN2::from_float2(Float2::new(origin[0], origin[1])), "Test:{} >> Conversion of `ShortN2` is invalid! (ShortN2:{:?}, Control group:{:?})", test, short, control_short);
+
+        // Round trip
+        let a = Vector::load_byten4(ByteN4::from_array(bytes));
+        let b = bytes.map(|e| e.max(-i8::MAX));
+        assert_eq!(a.store_byten4().to_array(), b, "Test:{} >> Load operation on `ByteN4` is invalid! (Vector:{:?}, Origin:{:?})", test, a, bytes);
+        assert_eq!(a.into_array(), <[f32; 4]>::from(ByteN4::from_array(bytes).to_float4()), "Test:{} >> Load operation on `ByteN4` is invalid! (Vector:{:?}, Origin:{:?})", test, a, bytes);
+        let a = Vector::load_shortn2(ShortN2::from_array(shorts));
+        let b = shorts.map(|e| e.max(-i16::MAX));
+        assert_eq!(a.store_shortn2().to_array(), b, "Test:{} >> Load operation on `ShortN2` is invalid! (Vector:{:?}, Origin:{:?})", test, a, shorts);
+        assert!(a.get_z() == 0.0 && a.get_w() == 0.0, "Test:{} >> Load operation on `ShortN2` is invalid! (Vector:{:?}, Origin:{:?})", test, a, shorts);
+    }
+}
+
+#[test]
+fn float3pk_load_store() {
+    // Every representable value must survive a round trip.
+    for bits in 0..(1 << 11) {
+        let packed = Float3PK::from_bits(bits | (bits << 11) | ((bits >> 1) << 22));
+        let a = Vector::load_float3pk(packed);
+        let b = a.store_float3pk();
+        let x = decode_float(bits, 6);
+        assert!(a.get_x() as f64 == x || x.is_nan(), "Test:{} >> Load operation on `Float3PK` is invalid! (Vector:{:?}, Control group:{:?})", bits, a, x);
+        assert!(b == packed || x.is_nan(), "Test:{} >> Round trip of `Float3PK` is invalid! (Float3PK:{:?}, Origin:{:?})", bits, b, packed);
+    }
+
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin: [f32; 3] = match rng.gen_range(0..4) {
+            0 => [(); 3].map(|_| f32::from_bits(rng.gen())),
+            1 => [(); 3].map(|_| rng.gen_range(-10.0..=70000.0)),
+            2 => [(); 3].map(|_| rng.gen_range(0.0..=0.0001)),
+            _ => [(); 3].map(|_| gen_f32(&mut rng)),
+        };
+
+        // Vector
+        let packed = Vector::load_float3(Float3::from(origin)).store_float3pk();
+        let bits = packed.to_bits();
+        let (x, y, z) = (bits & 0x7FF, (bits >> 11) & 0x7FF, bits >> 22);
+
+        // Compare `Vector` and `Control group`
+        assert!(packed == Float3PK::from_float3(Float3::from(origin)), "Test:{} >> Conversion of `Float3PK` is invalid! (Float3PK:{:?}, Origin:{:?})", test, packed, origin);
+        let validate = is_nearest(origin[0], x, 6) && is_nearest(origin[1], y, 6) && is_nearest(origin[2], z, 5);
+        assert!(validate, "Test:{} >> Store operation on `Float3PK` is invalid! (Float3PK:{:?}, Origin:{:?})", test, packed, origin);
+    }
+}
+
+#[test]
+fn rgb9e5_load_store() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin: [f32; 3] = match rng.gen_range(0..4) {
+            0 => [(); 3].map(|_| f32::from_bits(rng.gen())),
+            1 => [(); 3].map(|_| rng.gen_range(-10.0..=70000.0)),
+            2 => [(); 3].map(|_| rng.gen_range(0.0..=0.0001)),
+            _ => [(); 3].map(|_| gen_f32(&mut rng)),
+        };
+
+        // Vector
+        let packed = Vector::load_float3(Float3::from(origin)).store_rgb9e5();
+
+        // Control group
+        let control = encode_rgb9e5(origin);
+
+        // Compare `Vector` and `Control group`
+        assert_eq!(packed.to_bits(), control, "Test:{} >> Store operation on `Rgb9e5` is invalid! (Rgb9e5:{:?}, Control group:{:?})", test, packed, Rgb9e5::from_bits(control));
+        assert!(packed == Rgb9e5::from_float3(Float3::from(origin)), "Test:{} >> Conversion of `Rgb9e5` is invalid! (Rgb9e5:{:?}, Origin:{:?})", test, packed, origin);
+
+        // Round trip
+        let packed = Rgb9e5::from_bits(rng.gen());
+        let a = Vector::load_rgb9e5(packed);
+        let b = Vector::load_rgb9e5(a.store_rgb9e5());
+        let scale = 2f32.powi((packed.to_bits() >> 27) as i32 - 15 - 9);
+        let c = [packed.to_bits() & 0x1FF, (packed.to_bits() >> 9) & 0x1FF, (packed.to_bits() >> 18) & 0x1FF].map(|e| e as f32 * scale);
+        assert_eq!(a.store_float3(), Float3::from(c), "Test:{} >> Load operation on `Rgb9e5` is invalid! (Vector:{:?}, Control group:{:?})", test, a, c);
+        assert_eq!(a.into_array(), b.into_array(), "Test:{} >> Round trip of `Rgb9e5` is invalid! (Vector:{:?}, Origin:{:?})", test, b, a);
+    }
+}
+
+#[test]
+fn rgbm_rgbd_encode_decode() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let range = rng.gen_range(1.0..=64.0);
+        let origin: [f32; 3] = [(); 3].map(|_| rng.gen_range(0.0..=range));
+        let color = Vector::load_float3(Float3::from(origin));
+
+        // RGBM (quantized to 8 bits)
+        let rgbm = color.encode_rgbm(range);
+        let stored = Vector::load_ubyten4(rgbm.store_ubyten4());
+        let a = rgbm.decode_rgbm(range).store_float3();
+        let b = stored.decode_rgbm(range).store_float3();
+        let tolerance = stored.get_w() * range / 255.0;
+
+        // Compare `Vector` and `Origin`
+        let validate = rgbm.into_array().iter().all(|e| (0.0..=1.0).contains(e));
+        assert!(validate, "Test:{} >> Encode operation on `RGBM` is invalid! (RGBM:{:?}, Origin:{:?})", test, rgbm, origin);
+        let validate = (0..3).all(|idx| (a[idx] - origin[idx]).abs() <= 1e-5 * range);
+        assert!(validate, "Test:{} >> Decode operation on `RGBM` is invalid! (Vector:{:?}, Origin:{:?})", test, a, origin);
+        let validate = (0..3).all(|idx| (b[idx] - origin[idx]).abs() <= tolerance * 0.5 + 1e-5 * range);
+        assert!(validate, "Test:{} >> Quantized `RGBM` is invalid! (Vector:{:?}, Origin:{:?})", test, b, origin);
+
+        // RGBD (quantized to 8 bits)
+        let rgbd = color.encode_rgbd(range);
+        let stored = Vector::load_ubyten4(rgbd.store_ubyten4());
+        let a = rgbd.decode_rgbd(range).store_float3();
+        let b = stored.decode_rgbd(range).store_float3();
+        let tolerance = range / (stored.get_w() * 255.0) / 255.0;
+
+        // Compare `Vector` and `Origin`
+        let validate = rgbd.into_array().iter().all(|e| (0.0..=1.0).contains(e));
+        assert!(validate, "Test:{} >> Encode operation on `RGBD` is invalid! (RGBD:{:?}, Origin:{:?})", test, rgbd, origin);
+        let validate = (0..3).all(|idx| (a[idx] - origin[idx]).abs() <= 1e-5 * range);
+        assert!(validate, "Test:{} >> Decode operation on `RGBD` is invalid! (Vector:{:?}, Origin:{:?})", test, a, origin);
+        let validate = (0..3).all(|idx| (b[idx] - origin[idx]).abs() <= tolerance * 0.5 + 1e-5 * range);
+        assert!(validate, "Test:{} >> Quantized `RGBD` is invalid! (Vector:{:?}, Origin:{:?})", test, b, origin);
+    }
+}
@@ -0,0 +1,188 @@
+use rand::Rng;
+use rand::rngs::ThreadRng;
+use crate::{Matrix, Plane, Vector};
+use super::{NUM_TEST, TOLERANCE, random_point, random_dir, to_vector, to_glam};
+
+
+
+/// Returns a random normalized plane as `(a, b, c, d)`.
+fn random_plane(rng: &mut ThreadRng) -> glam::Vec4 {
+    let normal = random_dir(rng);
+    normal.extend(-normal.dot(random_point(rng)))
+}
+
+fn to_plane(v: glam::Vec4) -> Plane {
+    Plane::from_vector(Vector::from_array(v.to_array()))
+}
+
+
+
+#[test]
+fn plane_construction() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST / 100 {
+        // Data
+        let (a, b, c) = (random_point(&mut rng), random_point(&mut rng), random_point(&mut rng));
+        let normal = random_dir(&mut rng);
+        let scale: f32 = rng.gen_range(0.5..=4.0);
+        let coefficients = random_plane(&mut rng) * scale;
+        let point = random_point(&mut rng);
+        let v = glam::Vec4::from_array([(); 4].map(|_| rng.gen_range(-10.0..=10.0)));
+        let cross = (b - a).cross(c - a);
+        if cross.length() < 1.0 {
+            continue;
+        }
+
+        // Plane
+        let from_point_normal = Plane::from_point_normal(to_vector(a), to_vector(normal));
+        let from_points = Plane::from_points(to_vector(a), to_vector(b), to_vector(c));
+        let plane = to_plane(coefficients);
+        let normalized = plane.normalize().into_vector().into_array();
+        let dot = plane.dot(Vector::from_array(v.to_array()));
+        let dot_coord = plane.dot_coord(to_vector(point));
+        let dot_normal = plane.dot_normal(to_vector(point));
+
+        // Control group
+        let control_normalized = (coefficients / coefficients.truncate().length()).to_array();
+        let control_dot = coefficients.dot(v);
+        let control_dot_coord = coefficients.dot(point.extend(1.0));
+        let control_dot_normal = coefficients.truncate().dot(point);
+
+        // Compare `Plane` and `Control group`
+        let validate = from_point_normal.dot_coord(to_vector(a)).abs() <= TOLERANCE && to_glam(from_point_normal.normal()) == normal;
+        assert!(validate, "Test:{} >> Plane from point and normal is invalid! (Plane:{:?}, Control group:{:?})", test, from_point_normal, (a, normal));
+        let validate = [a, b, c].iter().all(|p| from_points.dot_coord(to_vector(*p)).abs() <= TOLERANCE)
+            && to_glam(from_points.normal()).abs_diff_eq(cross.normalize(), TOLERANCE)
+            && (from_points.normal().vec3_len() - 1.0).abs() <= TOLERANCE;
+        assert!(validate, "Test:{} >> Plane from points is invalid! (Plane:{:?}, Control group:{:?})", test, from_points, (a, b, c));
+        let validate = (0..4).all(|i| (normalized[i] - control_normalized[i]).abs() <= TOLERANCE);
+        assert!(validate, "Test:{} >> Plane normalize is invalid! (Plane:{:?}, Control group:{:?})", test, normalized, control_normalized);
+        let validate = plane.try_normalize().map_or(false, |p| p == plane.normalize()) && Plane::new(0.0, 0.0, 0.0, 1.0).try_normalize().is_none();
+        assert!(validate, "Test:{} >> Plane try normalize is invalid! (Plane:{:?})", test, plane);
+        let validate = (dot - control_dot).abs() <= TOLERANCE
+            && (dot_coord - control_dot_coord).abs() <= TOLERANCE
+            && (dot_normal - control_dot_normal).abs() <= TOLERANCE;
+        assert!(validate, "Test:{} >> Plane dot is invalid! (Plane:{:?}, Control group:{:?})", test, (dot, dot_coord, dot_normal), (control_dot, control_dot_coord, control_dot_normal));
+        let validate = Plane::load_float4(plane.store_float4()) == plane;
+        assert!(validate, "Test:{} >> Plane load and store is invalid! (Plane:{:?})", test, plane);
+    }
+}
+
+#[test]
+fn plane_intersect() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST / 100 {
+        // Data
+        let coefficients_a = random_plane(&mut rng);
+        let coefficients_b = random_plane(&mut rng);
+        let (p0, p1) = (random_point(&mut rng), random_point(&mut rng));
+        let normal_a = coefficients_a.truncate();
+        let normal_b = coefficients_b.truncate();
+
+        // Plane
+        let a = to_plane(coefficients_a);
+        let b = to_plane(coefficients_b);
+        let line = a.intersect_line(to_vector(p0), to_vector(p1));
+        let line_parallel = Plane::new(0.0, 1.0, 0.0, coefficients_a.w).intersect_line(to_vector(p0), to_vector(p0 + glam::Vec3::X));
+        let planes = a.intersect_plane(b);
+        let planes_parallel = a.intersect_plane(to_plane(normal_a.extend(coefficients_b.w)));
+
+        // Compare `Plane` and `Control group`
+        let dir = p1 - p0;
+        if normal_a.dot(dir).abs() > 0.1 * dir.length() {
+            let validate = line.map_or(false, |p| {
+                let p = to_glam(p);
+                a.dot_coord(to_vector(p)).abs() <= TOLERANCE && (p - p0).cross(dir).length() <= TOLERANCE * dir.length() * (p - p0).length().max(1.0)
+            });
+            assert!(validate, "Test:{} >> Plane line intersection is invalid! (Plane:{:?}, Control group:{:?})", test, line, (a, p0, p1));
+        }
+        if normal_a.cross(normal_b).length() > 0.1 {
+            let validate = planes.map_or(false, |(p, d)| {
+                a.dot_coord(p).abs() <= TOLERANCE && b.dot_coord(p).abs() <= TOLERANCE
+                    && to_glam(d).abs_diff_eq(normal_a.cross(normal_b).normalize(), TOLERANCE)
+                    && p.vec3_dot_into(d).abs() <= TOLERANCE
+            });
+            assert!(validate, "Test:{} >> Plane plane intersection is invalid! (Plane:{:?}, Control group:{:?})", test, planes, (a, b));
+        }
+        assert!(line_parallel.is_none() && planes_parallel.is_none(), "Test:{} >> Parallel plane intersection is invalid! (Line:{:?}, Plane:{:?})", test, line_parallel, planes_parallel);
+    }
+}
+
+#[test]
+fn plane_transform() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST / 100 {
+        // Data
+        let coefficients = random_plane(&mut rng);
+        let scale = glam::Vec3::from_array([(); 3].map(|_| rng.gen_range(0.5..=2.0)));
+        let rotation = glam::Quat::from_axis_angle(random_dir(&mut rng), rng.gen_range(-3.0..=3.0));
+        let affine = glam::Mat4::from_scale_rotation_translation(scale, rotation, random_point(&mut rng));
+        let normal = coefficients.truncate();
+        let point = normal * -coefficients.w;
+        let tangent = normal.any_orthonormal_vector();
+        let bitangent = normal.cross(tangent);
+
+        // Plane
+        let plane = to_plane(coefficients).transform(Matrix::from_column_array(affine.to_cols_array())).normalize();
+
+        // Control group
+        let control = affine.inverse().transpose() * coefficients;
+        let control = control / control.truncate().length();
+
+        // Compare `Plane` and `Control group`
+        let validate = glam::Vec4::from_array(plane.into_vector().into_array()).abs_diff_eq(control, TOLERANCE);
+        assert!(validate, "Test:{} >> Plane transform is invalid! (Plane:{:?}, Control group:{:?})", test, plane, control);
+        for p in [point, point + tangent * 5.0, point - bitangent * 5.0] {
+            let p = affine.transform_point3(p);
+            let validate = plane.dot_coord(to_vector(p)).abs() <= TOLERANCE;
+            assert!(validate, "Test:{} >> Plane transform is invalid! (Plane:{:?}, Control group:{:?})", test, plane, p);
+        }
+    }
+}
+
+#[test]
+fn matrix_reflect_shadow() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST / 100 {
+        // Data
+        let scale: f32 = rng.gen_range(0.5..=4.0);
+        let coefficients = random_plane(&mut rng);
+        let normal = coefficients.truncate();
+        let point = random_point(&mut rng);
+        let light = random_point(&mut rng);
+        let light_dir = random_dir(&mut rng);
+
+        // Matrix
+        let plane = to_plane(coefficients * scale);
+        let reflect = Matrix::reflect(plane);
+        let reflected = to_glam(reflect * Vector::new(point.x, point.y, point.z, 1.0));
+        let twice = reflect * reflect;
+        let shadow_point = Matrix::shadow(plane, Vector::new(light.x, light.y, light.z, 1.0)) * Vector::new(point.x, point.y, point.z, 1.0);
+        let shadow_dir = Matrix::shadow(plane, to_vector(light_dir)) * Vector::new(point.x, point.y, point.z, 1.0);
+
+        // Control group
+        let signed_dist = coefficients.dot(point.extend(1.0));
+        let control_reflected = point - normal * (2.0 * signed_dist);
+
+        // Compare `Matrix` and `Control group`
+        let validate = reflected.abs_diff_eq(control_reflected, TOLERANCE);
+        assert!(validate, "Test:{} >> Matrix reflect is invalid! (Matrix:{:?}, Control group:{:?})", test, reflected, control_reflected);
+        let validate = twice.abs_diff_eq(Matrix::IDENTITY, TOLERANCE);
+        assert!(validate, "Test:{} >> Matrix reflect twice is invalid! (Matrix:{:?})", test, twice);
+
+        // The shadow lies on the plane and on the line from the light through the point.
+        let light_dist = coefficients.dot(light.extend(1.0));
+        if (light_dist - signed_dist).abs() > 0.1 && shadow_point.get_w().abs() > 0.1 {
+            let shadow = to_glam(shadow_point / shadow_point.get_w());
+            let validate = coefficients.dot(shadow.extend(1.0)).abs() <= TOLERANCE
+                && (shadow - light).cross(point - light).length() <= TOLERANCE * (shadow - light).length().max(1.0) * (point - light).length();
+            assert!(validate, "Test:{} >> Matrix point light shadow is invalid! (Matrix:{:?}, Control group:{:?})", test, shadow, (coefficients, light, point));
+        }
+        if normal.dot(light_dir).abs() > 0.1 && shadow_dir.get_w().abs() > 0.1 {
+            let shadow = to_glam(shadow_dir / shadow_dir.get_w());
+            let validate = coefficients.dot(shadow.extend(1.0)).abs() <= TOLERANCE
+                && (shadow - point).cross(light_dir).length() <= TOLERANCE * (shadow - point).length().max(1.0);
+            assert!(validate, "Test:{} >> Matrix directional light shadow is invalid! (Matrix:{:?}, Control group:{:?})", test, shadow, (coefficients, light_dir, point));
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use rand::Rng;
-use crate::{Float4, Quaternion};
+use crate::{EulerRot, Float3, Float4, Quaternion};
 use super::{NUM_TEST, EPSILON};
 
 
@@ -86,3 +86,233 @@ fn quaternion_inverse() {
         }
     }
 }
+
+#[test]
+fn quaternion_nlerp() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data 
+        let origin_a: [f32; 4] = [(); 4].map(|_| rng.gen_range(-1.0..=1.0));
+        let origin_b: [f32; 4] = [(); 4].map(|_| rng.gen_range(-1.0..=1.0));
+        let t: f32 = rng.gen();
+
+        // Control group
+        let (glam_a, glam_b) = match (glam::Vec4::from_array(origin_a).try_normalize(), glam::Vec4::from_array(origin_b).try_normalize()) {
+            (Some(a), Some(b)) => (glam::Quat::from_vec4(a), glam::Quat::from_vec4(b)), 
+            _ => continue
+        };
+
+        // Skip almost orthogonal quaternions, where the rounding of the dot product decides the hemisphere.
+        if glam_a.dot(glam_b).abs() <= EPSILON {
+            continue;
+        }
+        let glam_c = glam_a.lerp(glam_b, t);
+
+        // Quaternion
+        let quat_a: Quaternion = { let t: Float4 = glam_a.to_array().into(); t.into() };
+        let quat_b: Quaternion = { let t: Float4 = glam_b.to_array().into(); t.into() };
+        let quat_c = quat_a.nlerp(quat_b, t);
+
+        // Compare `Quaternion` and `Control group`
+        let a: [f32; 4] = { let t: Float4 = quat_c.into(); t.into() };
+        let b: [f32; 4] = glam_c.into();
+        let validate = (0..4).all(|idx| (a[idx] - b[idx]).abs() <= EPSILON);
+        assert!(validate, "Test:{} >> Normalized linear interpolation on `Quaternion` is invalid! (Quaternion:{:?}, Control group:{:?})", test, a, b);
+    }
+}
+
+#[test]
+fn quaternion_slerp() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data 
+        let origin_a: [f32; 4] = [(); 4].map(|_| rng.gen_range(-1.0..=1.0));
+        let origin_b: [f32; 4] = [(); 4].map(|_| rng.gen_range(-1.0..=1.0));
+        let t: f32 = rng.gen();
+
+        // Control group
+        let (glam_a, glam_b) = match (glam::Vec4::from_array(origin_a).try_normalize(), glam::Vec4::from_array(origin_b).try_normalize()) {
+            (Some(a), Some(b)) => (glam::Quat::from_vec4(a), glam::Quat::from_vec4(b)), 
+            _ => continue
+        };
+
+        // Skip almost orthogonal quaternions, where the rounding of the dot product decides the hemisphere.
+        if glam_a.dot(glam_b).abs() <= EPSILON {
+            continue;
+        }
+        let glam_c = glam_a.slerp(glam_b, t);
+
+        // Quaternion
+        let quat_a: Quaternion = { let t: Float4 = glam_a.to_array().into(); t.into() };
+        let quat_b: Quaternion = { let t: Float4 = glam_b.to_array().into(); t.into() };
+        let quat_c = quat_a.slerp(quat_b, t);
+
+        // Compare `Quaternion` and `Control group`
+        let a: [f32; 4] = { let t: Float4 = quat_c.into(); t.into() };
+        let b: [f32; 4] = glam_c.into();
+        let validate = (0..4).all(|idx| (a[idx] - b[idx]).abs() <= EPSILON);
+        assert!(validate, "Test:{} >> Spherical linear interpolation on `Quaternion` is invalid! (Quaternion:{:?}, Control group:{:?})", test, a, b);
+    }
+}
+
+#[test]
+fn quaternion_squad() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data 
+        let axis: [f32; 3] = [(); 3].map(|_| rng.gen_range(-1.0..=1.0));
+        let angle: f32 = rng.gen_range(-core::f32::consts::PI..=core::f32::consts::PI);
+        let step: f32 = rng.gen_range(0.01..=0.5);
+        let t: f32 = rng.gen();
+
+        // Control group
+        // Uniform rotation about a fixed axis, so `squad` must be equal to `slerp`.
+        let axis = match glam::Vec3::from_array(axis).try_normalize() {
+            Some(axis) => axis, 
+            None => continue
+        };
+        let glam_q = [-1.0, 0.0, 1.0, 2.0].map(|i| glam::Quat::from_axis_angle(axis, angle + step * i));
+        let glam_c = glam_q[1].slerp(glam_q[2], t);
+
+        // Quaternion
+        let quat_q: [Quaternion; 4] = glam_q.map(|q| { let t: Float4 = q.to_array().into(); t.into() });
+        let quat_a = Quaternion::squad_control_point(quat_q[0], quat_q[1], quat_q[2]);
+        let quat_b = Quaternion::squad_control_point(quat_q[1], quat_q[2], quat_q[3]);
+        let quat_c = quat_q[1].squad(quat_a, quat_b, quat_q[2], t);
+
+        // Compare `Quaternion` and `Control group`
+        let a: [f32; 4] = { let t: Float4 = quat_c.into(); t.into() };
+        let b: [f32; 4] = glam_c.into();
+        let validate = (0..4).all(|idx| (a[idx] - b[idx]).abs() <= 1.0e-5);
+        assert!(validate, "Test:{} >> Spherical quadrangle interpolation on `Quaternion` is invalid! (Quaternion:{:?}, Control group:{:?})", test, a, b);
+    }
+}
+
+const EULER_ORDERS: [(EulerRot, glam::EulerRot); 12] = [
+    (EulerRot::XYZ, glam::EulerRot::XYZ), (EulerRot::XZY, glam::EulerRot::XZY), 
+    (EulerRot::YXZ, glam::EulerRot::YXZ), (EulerRot::YZX, glam::EulerRot::YZX), 
+    (EulerRot::ZXY, glam::EulerRot::ZXY), (EulerRot::ZYX, glam::EulerRot::ZYX), 
+    (EulerRot::XYX, glam::EulerRot::XYX), (EulerRot::XZX, glam::EulerRot::XZX), 
+    (EulerRot::YXY, glam::EulerRot::YXY), (EulerRot::YZY, glam::EulerRot::YZY), 
+    (EulerRot::ZXZ, glam::EulerRot::ZXZ), (EulerRot::ZYZ, glam::EulerRot::ZYZ), 
+];
+
+#[test]
+fn quaternion_from_euler() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let angles: [f32; 3] = [(); 3].map(|_| rng.gen_range(-core::f32::consts::PI..=core::f32::consts::PI));
+
+        for (order, glam_order) in EULER_ORDERS {
+            // Quaternion
+            let quat = Quaternion::from_euler(order, angles[0], angles[1], angles[2]);
+
+            // Control group
+            let glam_quat = glam::Quat::from_euler(glam_order, angles[0], angles[1], angles[2]);
+
+            // Compare `Quaternion` and `Control group`
+            let a: [f32; 4] = { let t: Float4 = quat.into(); t.into() };
+            let b: [f32; 4] = glam_quat.into();
+            let validate = (0..4).all(|idx| (a[idx] - b[idx]).abs() <= EPSILON);
+            assert!(validate, "Test:{} >> Euler angles ({:?}) to `Quaternion` is invalid! (Quaternion:{:?}, Control group:{:?})", test, order, a, b);
+        }
+    }
+}
+
+#[test]
+fn quaternion_to_euler() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin: [f32; 4] = [(); 4].map(|_| rng.gen_range(-1.0..=1.0));
+        let glam_quat = match glam::Vec4::from_array(origin).try_normalize() {
+            Some(v) => glam::Quat::from_vec4(v), 
+            None => continue
+        };
+
+        for (order, glam_order) in EULER_ORDERS {
+            // Quaternion
+            let quat: Quaternion = { let t: Float4 = glam_quat.to_array().into(); t.into() };
+            let (x, y, z) = quat.to_euler(order);
+
+            // Control group
+            let glam_back = glam::Quat::from_euler(glam_order, x, y, z);
+
+            // Compare `Quaternion` and `Control group`
+            // The rotation must be the same, so the quaternion is equal up to the sign.
+            let a: [f32; 4] = glam_quat.into();
+            let b: [f32; 4] = glam_back.into();
+            let sign = if glam_quat.dot(glam_back) < 0.0 { -1.0 } else { 1.0 };
+            let validate = (0..4).all(|idx| (a[idx] - sign * b[idx]).abs() <= 1.0e-5);
+            assert!(validate, "Test:{} >> `Quaternion` to Euler angles ({:?}) is invalid! (Angles:{:?}, Quaternion:{:?}, Control group:{:?})", test, order, (x, y, z), a, b);
+        }
+    }
+}
+
+#[test]
+fn quaternion_to_euler_gimbal_lock() {
+    use core::f32::consts::{PI, FRAC_PI_2};
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let first: f32 = rng.gen_range(-PI..=PI);
+        let third: f32 = rng.gen_range(-PI..=PI);
+
+        for (order, glam_order) in EULER_ORDERS {
+            let locks: [f32; 2] = match order.is_proper_euler() {
+                true => [0.0, PI], 
+                false => [-FRAC_PI_2, FRAC_PI_2]
+            };
+            for second in locks {
+                // Quaternion
+                let quat = Quaternion::from_euler(order, first, second, third);
+                let (x, y, z) = quat.to_euler(order);
+
+                // Control group
+                let glam_quat = glam::Quat::from_euler(glam_order, first, second, third);
+                let glam_back = glam::Quat::from_euler(glam_order, x, y, z);
+
+                // Compare `Quaternion` and `Control group`
+                let a: [f32; 4] = glam_quat.into();
+                let b: [f32; 4] = glam_back.into();
+                let sign = if glam_quat.dot(glam_back) < 0.0 { -1.0 } else { 1.0 };
+                let validate = x == 0.0 && (y - second).abs() <= 1.0e-3 
+                    && (0..4).all(|idx| (a[idx] - sign * b[idx]).abs() <= 1.0e-5);
+                assert!(validate, "Test:{} >> `Quaternion` to Euler angles ({:?}) in gimbal lock is invalid! (Angles:{:?}, Quaternion:{:?}, Control group:{:?})", test, order, (x, y, z), a, b);
+            }
+        }
+    }
+}
+
+#[test]
+fn quaternion_rotate_slice() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin: [f32; 4] = rng.gen();
+        if glam::Quat::from_array(origin).length() <= 0.001 {
+            continue;
+        }
+        let origin = glam::Quat::from_array(origin).normalize().to_array();
+        let len = rng.gen_range(0..=9);
+        let origin_v: [[f32; 3]; 9] = rng.gen();
+
+        // Quaternion
+        let quat: Quaternion = { let t: Float4 = origin.into(); t.into() };
+        let src = origin_v.map(Float3::from);
+        let mut dst = [Float3::ZERO; 9];
+        quat.rotate_slice(&src[..len], &mut dst[..len]);
+
+        // Control group
+        let glam_q = glam::Quat::from_array(origin);
+        for idx in 0..len {
+            let glam_v = (glam_q * glam::Vec3::from_array(origin_v[idx])).to_array();
+
+            // Compare `Quaternion` and `Control group`
+            let a: [f32; 3] = dst[idx].into();
+            let validate = (0..3).all(|i| (a[i] - glam_v[i]).abs() <= EPSILON);
+            assert!(validate, "Test:{} >> Slice rotation on `Quaternion` is invalid! (Quaternion:{:?}, Control group:{:?})", test, a, glam_v);
+        }
+    }
+}
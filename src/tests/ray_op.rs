@@ -0,0 +1,177 @@
+use rand::Rng;
+use crate::{Aabb, BoundingSphere, Plane, Ray, Segment, Vector};
+use super::{NUM_TEST, TOLERANCE, random_point, random_dir, to_vector, to_glam};
+
+
+
+#[test]
+fn ray_triangle() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST / 100 {
+        // Data
+        let (a, b, c) = (random_point(&mut rng), random_point(&mut rng), random_point(&mut rng));
+        let origin = random_point(&mut rng);
+        let u: f32 = rng.gen_range(0.05..=0.9);
+        let v: f32 = rng.gen_range(0.05..=0.95 - u);
+        let target = a * (1.0 - u - v) + b * u + c * v;
+        let dist = origin.distance(target);
+        let dir = (target - origin) / dist;
+        let normal = (b - a).cross(c - a);
+
+        // Skip the degenerate triangles and the rays nearly parallel to the triangle.
+        if normal.length() < 1.0 || dist < 0.1 || normal.normalize().dot(dir).abs() < 0.1 {
+            continue;
+        }
+        let normal = normal.normalize();
+
+        // Rounding errors of the hit distance grow with the distance.
+        let tolerance = TOLERANCE * dist.max(1.0);
+
+        // Ray
+        let ray = Ray::new(to_vector(origin), to_vector(dir));
+        let (va, vb, vc) = (to_vector(a), to_vector(b), to_vector(c));
+        let hit = ray.intersect_triangle(va, vb, vc);
+        let within = ray.intersect_triangle_within(va, vb, vc, dist * 0.5);
+        let behind = Ray::new(to_vector(origin), to_vector(-dir)).intersect_triangle(va, vb, vc);
+        let outside = Ray::from_points(to_vector(origin), to_vector(a * (1.0 + u) - b * u)).intersect_triangle(va, vb, vc);
+        let segment = Segment::new(to_vector(origin), to_vector(origin + dir * (dist + 1.0))).intersect_triangle(va, vb, vc);
+        let short = Segment::new(to_vector(origin), to_vector(origin + dir * (dist * 0.5))).intersect_triangle(va, vb, vc);
+
+        // Compare `Ray` and `Control group`
+        let validate = hit.map_or(false, |hit| {
+            (hit.distance - dist).abs() <= tolerance
+                && (hit.u - u).abs() <= TOLERANCE
+                && (hit.v - v).abs() <= TOLERANCE
+                && to_glam(hit.normal).abs_diff_eq(normal, TOLERANCE)
+        });
+        assert!(validate, "Test:{} >> Ray triangle intersection is invalid! (Ray:{:?}, Control group:{:?})", test, hit, (dist, u, v, normal));
+        let validate = segment.map_or(false, |hit| (hit.distance - dist).abs() <= tolerance);
+        assert!(validate, "Test:{} >> Segment triangle intersection is invalid! (Segment:{:?}, Control group:{:?})", test, segment, dist);
+        assert!(within.is_none() && short.is_none(), "Test:{} >> Limited triangle intersection is invalid! (Ray:{:?}, Segment:{:?})", test, within, short);
+        assert!(behind.is_none() && outside.is_none(), "Test:{} >> Ray triangle miss is invalid! (Behind:{:?}, Outside:{:?})", test, behind, outside);
+    }
+}
+
+#[test]
+fn ray_aabb() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST / 100 {
+        // Data
+        let center = random_point(&mut rng);
+        let extents = glam::Vec3::from_array([(); 3].map(|_| rng.gen_range(0.1..=5.0)));
+        let axis = rng.gen_range(0..3);
+        let side = if rng.gen::<bool>() { 1.0 } else { -1.0 };
+        let normal = glam::Vec3::AXES[axis] * side;
+
+        // A point on the face, and a ray coming from the outside of the face.
+        let mut local = glam::Vec3::from_array([(); 3].map(|_| rng.gen_range(-0.9..=0.9))) * extents;
+        local[axis] = extents[axis] * side;
+        let target = center + local;
+        let mut dir = random_dir(&mut rng);
+        if dir.dot(normal) > -0.1 {
+            dir -= normal * (dir.dot(normal) + 0.5);
+            dir = dir.normalize();
+        }
+        let dist: f32 = rng.gen_range(0.1..=10.0);
+        let origin = target - dir * dist;
+
+        // Ray
+        let aabb = Aabb::new(to_vector(center), to_vector(extents));
+        let ray = Ray::new(to_vector(origin), to_vector(dir));
+        let hit = ray.intersect_aabb(aabb);
+        let within = ray.intersect_aabb_within(aabb, dist * 0.5);
+        let behind = Ray::new(to_vector(origin), to_vector(-dir)).intersect_aabb(aabb);
+        let segment = Segment::new(to_vector(origin), to_vector(target + dir * 0.01)).intersect_aabb(aabb);
+        let inside = Ray::new(to_vector(center), to_vector(dir)).intersect_aabb(aabb);
+
+        // Control group
+        let exit = (extents / dir.abs()).min_element();
+        let exit_axis = (0..3).find(|&i| extents[i] / dir[i].abs() == exit).unwrap();
+        let exit_normal = glam::Vec3::AXES[exit_axis] * dir[exit_axis].signum();
+
+        // Compare `Ray` and `Control group`
+        let validate = hit.map_or(false, |hit| (hit.distance - dist).abs() <= TOLERANCE && to_glam(hit.normal) == normal);
+        assert!(validate, "Test:{} >> Ray aabb intersection is invalid! (Ray:{:?}, Control group:{:?})", test, hit, (dist, normal));
+        let validate = segment.map_or(false, |hit| (hit.distance - dist).abs() <= TOLERANCE);
+        assert!(validate, "Test:{} >> Segment aabb intersection is invalid! (Segment:{:?}, Control group:{:?})", test, segment, dist);
+        let validate = inside.map_or(false, |hit| (hit.distance - exit).abs() <= TOLERANCE && to_glam(hit.normal) == exit_normal);
+        assert!(validate, "Test:{} >> Ray aabb intersection from inside is invalid! (Ray:{:?}, Control group:{:?})", test, inside, (exit, exit_normal));
+        assert!(within.is_none() && behind.is_none(), "Test:{} >> Ray aabb miss is invalid! (Within:{:?}, Behind:{:?})", test, within, behind);
+    }
+}
+
+#[test]
+fn ray_sphere() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST / 100 {
+        // Data
+        let center = random_point(&mut rng);
+        let radius: f32 = rng.gen_range(0.1..=5.0);
+        let dir = random_dir(&mut rng);
+        let side = dir.any_orthonormal_vector();
+        let offset: f32 = rng.gen_range(0.0..=0.95) * radius;
+        let dist: f32 = rng.gen_range(0.1..=10.0);
+        let origin = center - dir * (radius + dist) + side * offset;
+
+        // Ray
+        let sphere = BoundingSphere::new(to_vector(center), radius);
+        let ray = Ray::new(to_vector(origin), to_vector(dir));
+        let hit = ray.intersect_sphere(sphere);
+        let within = ray.intersect_sphere_within(sphere, dist * 0.5);
+        let behind = Ray::new(to_vector(origin), to_vector(-dir)).intersect_sphere(sphere);
+        let beside = Ray::new(to_vector(origin + side * (radius * 1.1)), to_vector(dir)).intersect_sphere(sphere);
+        let segment = Segment::new(to_vector(origin), to_vector(origin + dir * (radius + dist))).intersect_sphere(sphere);
+        let inside = Ray::new(to_vector(center), to_vector(dir)).intersect_sphere(sphere);
+
+        // Control group
+        let control = radius + dist - (radius * radius - offset * offset).sqrt();
+        let control_normal = (origin + dir * control - center) / radius;
+
+        // Compare `Ray` and `Control group`
+        let validate = hit.map_or(false, |hit| (hit.distance - control).abs() <= TOLERANCE && to_glam(hit.normal).abs_diff_eq(control_normal, TOLERANCE));
+        assert!(validate, "Test:{} >> Ray sphere intersection is invalid! (Ray:{:?}, Control group:{:?})", test, hit, (control, control_normal));
+        let validate = segment.map_or(false, |hit| (hit.distance - control).abs() <= TOLERANCE);
+        assert!(validate, "Test:{} >> Segment sphere intersection is invalid! (Segment:{:?}, Control group:{:?})", test, segment, control);
+        let validate = inside.map_or(false, |hit| (hit.distance - radius).abs() <= TOLERANCE && to_glam(hit.normal).abs_diff_eq(dir, TOLERANCE));
+        assert!(validate, "Test:{} >> Ray sphere intersection from inside is invalid! (Ray:{:?}, Control group:{:?})", test, inside, (radius, dir));
+        assert!(within.is_none() && behind.is_none() && beside.is_none(), "Test:{} >> Ray sphere miss is invalid! (Within:{:?}, Behind:{:?}, Beside:{:?})", test, within, behind, beside);
+    }
+}
+
+#[test]
+fn ray_plane() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST / 100 {
+        // Data
+        let normal = random_dir(&mut rng);
+        let point = random_point(&mut rng);
+        let scale: f32 = rng.gen_range(0.5..=4.0);
+        let origin = random_point(&mut rng);
+        let dir = random_dir(&mut rng);
+        let plane = (normal * scale).extend(-normal.dot(point) * scale);
+
+        // Control group
+        let denom = normal.dot(dir) as f64;
+        let control = -((normal.dot(origin) - normal.dot(point)) as f64) / denom;
+        if denom.abs() < 0.1 || control.abs() < 0.01 {
+            continue;
+        }
+        let control = control as f32;
+
+        // Ray
+        let plane = Plane::from_vector(Vector::from_array(plane.to_array()));
+        let hit = Ray::new(to_vector(origin), to_vector(dir)).intersect_plane(plane);
+        let reverse = Ray::new(to_vector(origin), to_vector(-dir)).intersect_plane(plane);
+        let within = Ray::new(to_vector(origin), to_vector(dir)).intersect_plane_within(plane, control.abs() * 0.5);
+        let parallel = Ray::new(to_vector(origin), Vector::X).intersect_plane(Plane::new(0.0, scale, 0.0, 1.0));
+        let (front, back) = if control > 0.0 { (hit, reverse) } else { (reverse, hit) };
+        let segment = Segment::new(to_vector(origin), to_vector(origin + dir * control * 2.0)).intersect_plane(plane);
+
+        // Compare `Ray` and `Control group`
+        let validate = front.map_or(false, |hit| (hit.distance - control.abs()).abs() <= TOLERANCE && to_glam(hit.normal).abs_diff_eq(normal, TOLERANCE));
+        assert!(validate, "Test:{} >> Ray plane intersection is invalid! (Ray:{:?}, Control group:{:?})", test, front, (control, normal));
+        let validate = segment.map_or(false, |hit| (hit.distance - control.abs()).abs() <= TOLERANCE);
+        assert!(validate, "Test:{} >> Segment plane intersection is invalid! (Segment:{:?}, Control group:{:?})", test, segment, control);
+        assert!(back.is_none() && within.is_none() && parallel.is_none(), "Test:{} >> Ray plane miss is invalid! (Back:{:?}, Within:{:?}, Parallel:{:?})", test, back, within, parallel);
+    }
+}
@@ -0,0 +1,180 @@
+use rand::Rng;
+use crate::{Float3, Float4, Float4x4, Matrix, Vector, Vector3x4, Vector4x4};
+use super::{NUM_TEST, EPSILON};
+
+
+
+#[test]
+fn vector3x4_load_store() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin: [[f32; 3]; 4] = rng.gen();
+
+        // Vector3x4
+        let arr = origin.map(Float3::from);
+        let soa = Vector3x4::load_float3_array(arr);
+        let vectors = soa.into_vectors();
+
+        // Compare `Vector3x4` and `Origin`
+        let a: [[f32; 3]; 4] = soa.store_float3_array().map(|t| t.into());
+        assert_eq!(a, origin, "Test:{} >> Store operation on `Vector3x4` is invalid! (Vector3x4:{:?}, Origin:{:?})", test, a, origin);
+
+        let a: [f32; 4] = soa.x.into_array();
+        let b: [f32; 4] = origin.map(|e| e[0]);
+        assert_eq!(a, b, "Test:{} >> Load operation on `Vector3x4` is invalid! (Vector3x4:{:?}, Origin:{:?})", test, a, b);
+
+        let a: [[f32; 3]; 4] = vectors.map(|v| v.store_float3().into());
+        assert_eq!(a, origin, "Test:{} >> Transposition on `Vector3x4` is invalid! (Vector3x4:{:?}, Origin:{:?})", test, a, origin);
+    }
+}
+
+#[test]
+fn vector4x4_load_store() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin: [[f32; 4]; 4] = rng.gen();
+
+        // Vector4x4
+        let arr = origin.map(Float4::from);
+        let soa = Vector4x4::load_float4_array(arr);
+        let vectors = Vector4x4::from_vectors(arr.map(Vector::load_float4)).into_vectors();
+
+        // Compare `Vector4x4` and `Origin`
+        let a: [[f32; 4]; 4] = soa.store_float4_array().map(|t| t.into());
+        assert_eq!(a, origin, "Test:{} >> Store operation on `Vector4x4` is invalid! (Vector4x4:{:?}, Origin:{:?})", test, a, origin);
+
+        let a: [f32; 4] = soa.w.into_array();
+        let b: [f32; 4] = origin.map(|e| e[3]);
+        assert_eq!(a, b, "Test:{} >> Load operation on `Vector4x4` is invalid! (Vector4x4:{:?}, Origin:{:?})", test, a, b);
+
+        let a: [[f32; 4]; 4] = vectors.map(|v| v.into_array());
+        assert_eq!(a, origin, "Test:{} >> Transposition on `Vector4x4` is invalid! (Vector4x4:{:?}, Origin:{:?})", test, a, origin);
+    }
+}
+
+#[test]
+fn vector3x4_dot_cross() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin_a: [[f32; 3]; 4] = rng.gen();
+        let origin_b: [[f32; 3]; 4] = rng.gen();
+
+        // Vector3x4
+        let soa_a = Vector3x4::load_float3_array(origin_a.map(Float3::from));
+        let soa_b = Vector3x4::load_float3_array(origin_b.map(Float3::from));
+        let dot = soa_a.dot(soa_b).into_array();
+        let cross: [[f32; 3]; 4] = soa_a.cross(soa_b).store_float3_array().map(|t| t.into());
+
+        // Control group
+        for lane in 0..4 {
+            let glam_a = glam::Vec3::from_array(origin_a[lane]);
+            let glam_b = glam::Vec3::from_array(origin_b[lane]);
+            let glam_dot = glam_a.dot(glam_b);
+            let glam_cross = glam_a.cross(glam_b).to_array();
+
+            // Compare `Vector3x4` and `Control group`
+            assert!((dot[lane] - glam_dot).abs() <= EPSILON, "Test:{} >> Dot operation on `Vector3x4` is invalid! (Vector3x4:{:?}, Control group:{:?})", test, dot[lane], glam_dot);
+            let validate = (0..3).all(|idx| (cross[lane][idx] - glam_cross[idx]).abs() <= EPSILON);
+            assert!(validate, "Test:{} >> Cross operation on `Vector3x4` is invalid! (Vector3x4:{:?}, Control group:{:?})", test, cross[lane], glam_cross);
+        }
+    }
+}
+
+#[test]
+fn vector3x4_normalize() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin: [[f32; 3]; 4] = [(); 4].map(|_| [(); 3].map(|_| rng.gen_range(-1.0..=1.0)));
+        if origin.iter().any(|e| glam::Vec3::from_array(*e).length() <= 0.001) {
+            continue;
+        }
+
+        // Vector3x4
+        let soa = Vector3x4::load_float3_array(origin.map(Float3::from));
+        let len = soa.len().into_array();
+        let norm: [[f32; 3]; 4] = soa.normalize().store_float3_array().map(|t| t.into());
+
+        // Control group
+        for lane in 0..4 {
+            let glam_a = glam::Vec3::from_array(origin[lane]);
+            let glam_len = glam_a.length();
+            let glam_norm = glam_a.normalize().to_array();
+
+            // Compare `Vector3x4` and `Control group`
+            assert!((len[lane] - glam_len).abs() <= EPSILON, "Test:{} >> Length operation on `Vector3x4` is invalid! (Vector3x4:{:?}, Control group:{:?})", test, len[lane], glam_len);
+            let validate = (0..3).all(|idx| (norm[lane][idx] - glam_norm[idx]).abs() <= EPSILON);
+            assert!(validate, "Test:{} >> Normalize operation on `Vector3x4` is invalid! (Vector3x4:{:?}, Control group:{:?})", test, norm[lane], glam_norm);
+        }
+    }
+}
+
+#[test]
+fn vector4x4_dot_select() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin_a: [[f32; 4]; 4] = rng.gen();
+        let origin_b: [[f32; 4]; 4] = rng.gen();
+
+        // Vector4x4
+        let soa_a = Vector4x4::load_float4_array(origin_a.map(Float4::from));
+        let soa_b = Vector4x4::load_float4_array(origin_b.map(Float4::from));
+        let dot = soa_a.dot(soa_b).into_array();
+        let mask = soa_a.len_sq().lt(soa_b.len_sq());
+        let select: [[f32; 4]; 4] = Vector4x4::select(mask, soa_a, soa_b).store_float4_array().map(|t| t.into());
+
+        // Control group
+        for lane in 0..4 {
+            let glam_a = glam::Vec4::from_array(origin_a[lane]);
+            let glam_b = glam::Vec4::from_array(origin_b[lane]);
+            let glam_dot = glam_a.dot(glam_b);
+            let glam_select = match soa_a.len_sq().into_array()[lane] < soa_b.len_sq().into_array()[lane] {
+                true => origin_a[lane],
+                false => origin_b[lane]
+            };
+
+            // Compare `Vector4x4` and `Control group`
+            assert!((dot[lane] - glam_dot).abs() <= EPSILON, "Test:{} >> Dot operation on `Vector4x4` is invalid! (Vector4x4:{:?}, Control group:{:?})", test, dot[lane], glam_dot);
+            assert_eq!(select[lane], glam_select, "Test:{} >> Select operation on `Vector4x4` is invalid! (Vector4x4:{:?}, Control group:{:?})", test, select[lane], glam_select);
+        }
+    }
+}
+
+#[test]
+fn matrix_transform_soa() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin_m: [f32; 16] = rng.gen();
+        let origin_a: [[f32; 3]; 4] = rng.gen();
+        let origin_b: [[f32; 4]; 4] = rng.gen();
+
+        // Matrix
+        let matrix: Matrix = { let t: Float4x4 = origin_m.into(); t.into() };
+        let soa_a = Vector3x4::load_float3_array(origin_a.map(Float3::from));
+        let soa_b = Vector4x4::load_float4_array(origin_b.map(Float4::from));
+        let point: [[f32; 3]; 4] = matrix.transform_point3x4(soa_a).store_float3_array().map(|t| t.into());
+        let vector: [[f32; 3]; 4] = matrix.transform_vector3x4(soa_a).store_float3_array().map(|t| t.into());
+        let vector4: [[f32; 4]; 4] = (matrix * soa_b).store_float4_array().map(|t| t.into());
+
+        // Control group
+        let glam_m = glam::Mat4::from_cols_array(&origin_m);
+        for lane in 0..4 {
+            let glam_point = glam_m.transform_point3(glam::Vec3::from_array(origin_a[lane])).to_array();
+            let glam_vector = glam_m.transform_vector3(glam::Vec3::from_array(origin_a[lane])).to_array();
+            let glam_vector4 = (glam_m * glam::Vec4::from_array(origin_b[lane])).to_array();
+
+            // Compare `Matrix` and `Control group`
+            let validate = (0..3).all(|idx| (point[lane][idx] - glam_point[idx]).abs() <= EPSILON * glam_point[idx].abs().max(1.0));
+            assert!(validate, "Test:{} >> Point transformation on `Vector3x4` is invalid! (Vector3x4:{:?}, Control group:{:?})", test, point[lane], glam_point);
+            let validate = (0..3).all(|idx| (vector[lane][idx] - glam_vector[idx]).abs() <= EPSILON * glam_vector[idx].abs().max(1.0));
+            assert!(validate, "Test:{} >> Vector transformation on `Vector3x4` is invalid! (Vector3x4:{:?}, Control group:{:?})", test, vector[lane], glam_vector);
+            let validate = (0..4).all(|idx| (vector4[lane][idx] - glam_vector4[idx]).abs() <= EPSILON * glam_vector4[idx].abs().max(1.0));
+            assert!(validate, "Test:{} >> Transformation on `Vector4x4` is invalid! (Vector4x4:{:?}, Control group:{:?})", test, vector4[lane], glam_vector4);
+        }
+    }
+}
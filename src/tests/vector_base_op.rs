@@ -1,6 +1,6 @@
 use rand::Rng;
 use crate::{Float2, Float3, Float4, Vector};
-use super::NUM_TEST;
+use super::{NUM_TEST, EPSILON};
 
 
 
@@ -804,3 +804,148 @@ fn vector4_div_scalar() {
         assert_eq!(a, b, "Test:{} >> Divide scalar operation on `Float4` is invalid! (Float4:{:?}, Control Group:{:?})", test, a, b);
     }
 }
+
+#[test]
+fn vector2_scalar_geometry() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin_a: [f32; 2] = rng.gen();
+        let origin_b: [f32; 2] = rng.gen();
+        let t: f32 = rng.gen();
+
+        // Float2
+        let float_a: Float2 = origin_a.into();
+        let float_b: Float2 = origin_b.into();
+
+        // Control group
+        let glam_a: glam::Vec2 = origin_a.into();
+        let glam_b: glam::Vec2 = origin_b.into();
+
+        // Compare `Float2` and `Control group`
+        let (a, b) = (float_a.dot(float_b), glam_a.dot(glam_b));
+        assert!((a - b).abs() <= EPSILON, "Test:{} >> Dot operation on `Float2` is invalid! (Float2:{:?}, Control Group:{:?})", test, a, b);
+        let (a, b) = (float_a.len(), glam_a.length());
+        assert!((a - b).abs() <= EPSILON, "Test:{} >> Length operation on `Float2` is invalid! (Float2:{:?}, Control Group:{:?})", test, a, b);
+        let (a, b) = (float_a.distance(float_b), glam_a.distance(glam_b));
+        assert!((a - b).abs() <= EPSILON, "Test:{} >> Distance operation on `Float2` is invalid! (Float2:{:?}, Control Group:{:?})", test, a, b);
+
+        let a: [f32; 2] = float_a.min(float_b).into();
+        let b: [f32; 2] = glam_a.min(glam_b).into();
+        assert_eq!(a, b, "Test:{} >> Min operation on `Float2` is invalid! (Float2:{:?}, Control Group:{:?})", test, a, b);
+        let a: [f32; 2] = float_a.max(float_b).into();
+        let b: [f32; 2] = glam_a.max(glam_b).into();
+        assert_eq!(a, b, "Test:{} >> Max operation on `Float2` is invalid! (Float2:{:?}, Control Group:{:?})", test, a, b);
+
+        let a: [f32; 2] = float_a.lerp(float_b, t).into();
+        let b: [f32; 2] = glam_a.lerp(glam_b, t).into();
+        let validate = (0..2).all(|idx| (a[idx] - b[idx]).abs() <= EPSILON);
+        assert!(validate, "Test:{} >> Lerp operation on `Float2` is invalid! (Float2:{:?}, Control Group:{:?})", test, a, b);
+
+        if let Some(norm) = float_a.try_normalize() {
+            let a: [f32; 2] = norm.into();
+            let b: [f32; 2] = glam_a.normalize().into();
+            let validate = (0..2).all(|idx| (a[idx] - b[idx]).abs() <= EPSILON);
+            assert!(validate, "Test:{} >> Normalize operation on `Float2` is invalid! (Float2:{:?}, Control Group:{:?})", test, a, b);
+        }
+    }
+}
+
+#[test]
+fn vector3_scalar_geometry() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin_a: [f32; 3] = rng.gen();
+        let origin_b: [f32; 3] = rng.gen();
+        let t: f32 = rng.gen();
+
+        // Float3
+        let float_a: Float3 = origin_a.into();
+        let float_b: Float3 = origin_b.into();
+
+        // Control group
+        let glam_a: glam::Vec3 = origin_a.into();
+        let glam_b: glam::Vec3 = origin_b.into();
+
+        // Compare `Float3` and `Control group`
+        let (a, b) = (float_a.dot(float_b), glam_a.dot(glam_b));
+        assert!((a - b).abs() <= EPSILON, "Test:{} >> Dot operation on `Float3` is invalid! (Float3:{:?}, Control Group:{:?})", test, a, b);
+        let (a, b) = (float_a.len(), glam_a.length());
+        assert!((a - b).abs() <= EPSILON, "Test:{} >> Length operation on `Float3` is invalid! (Float3:{:?}, Control Group:{:?})", test, a, b);
+        let (a, b) = (float_a.distance(float_b), glam_a.distance(glam_b));
+        assert!((a - b).abs() <= EPSILON, "Test:{} >> Distance operation on `Float3` is invalid! (Float3:{:?}, Control Group:{:?})", test, a, b);
+
+        let a: [f32; 3] = float_a.min(float_b).into();
+        let b: [f32; 3] = glam_a.min(glam_b).into();
+        assert_eq!(a, b, "Test:{} >> Min operation on `Float3` is invalid! (Float3:{:?}, Control Group:{:?})", test, a, b);
+        let a: [f32; 3] = float_a.max(float_b).into();
+        let b: [f32; 3] = glam_a.max(glam_b).into();
+        assert_eq!(a, b, "Test:{} >> Max operation on `Float3` is invalid! (Float3:{:?}, Control Group:{:?})", test, a, b);
+
+        let a: [f32; 3] = float_a.lerp(float_b, t).into();
+        let b: [f32; 3] = glam_a.lerp(glam_b, t).into();
+        let validate = (0..3).all(|idx| (a[idx] - b[idx]).abs() <= EPSILON);
+        assert!(validate, "Test:{} >> Lerp operation on `Float3` is invalid! (Float3:{:?}, Control Group:{:?})", test, a, b);
+
+        if let Some(norm) = float_a.try_normalize() {
+            let a: [f32; 3] = norm.into();
+            let b: [f32; 3] = glam_a.normalize().into();
+            let validate = (0..3).all(|idx| (a[idx] - b[idx]).abs() <= EPSILON);
+            assert!(validate, "Test:{} >> Normalize operation on `Float3` is invalid! (Float3:{:?}, Control Group:{:?})", test, a, b);
+        }
+
+        let float_cross = float_a.cross(float_b);
+        let glam_cross = glam_a.cross(glam_b);
+        let a: [f32; 3] = float_cross.into();
+        let b: [f32; 3] = glam_cross.into();
+        let validate = (0..3).all(|idx| (a[idx] - b[idx]).abs() <= EPSILON);
+        assert!(validate, "Test:{} >> Cross operation on `Float3` is invalid! (Float3:{:?}, Control Group:{:?})", test, a, b);
+    }
+}
+
+#[test]
+fn vector4_scalar_geometry() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin_a: [f32; 4] = rng.gen();
+        let origin_b: [f32; 4] = rng.gen();
+        let t: f32 = rng.gen();
+
+        // Float4
+        let float_a: Float4 = origin_a.into();
+        let float_b: Float4 = origin_b.into();
+
+        // Control group
+        let glam_a: glam::Vec4 = origin_a.into();
+        let glam_b: glam::Vec4 = origin_b.into();
+
+        // Compare `Float4` and `Control group`
+        let (a, b) = (float_a.dot(float_b), glam_a.dot(glam_b));
+        assert!((a - b).abs() <= EPSILON, "Test:{} >> Dot operation on `Float4` is invalid! (Float4:{:?}, Control Group:{:?})", test, a, b);
+        let (a, b) = (float_a.len(), glam_a.length());
+        assert!((a - b).abs() <= EPSILON, "Test:{} >> Length operation on `Float4` is invalid! (Float4:{:?}, Control Group:{:?})", test, a, b);
+        let (a, b) = (float_a.distance(float_b), glam_a.distance(glam_b));
+        assert!((a - b).abs() <= EPSILON, "Test:{} >> Distance operation on `Float4` is invalid! (Float4:{:?}, Control Group:{:?})", test, a, b);
+
+        let a: [f32; 4] = float_a.min(float_b).into();
+        let b: [f32; 4] = glam_a.min(glam_b).into();
+        assert_eq!(a, b, "Test:{} >> Min operation on `Float4` is invalid! (Float4:{:?}, Control Group:{:?})", test, a, b);
+        let a: [f32; 4] = float_a.max(float_b).into();
+        let b: [f32; 4] = glam_a.max(glam_b).into();
+        assert_eq!(a, b, "Test:{} >> Max operation on `Float4` is invalid! (Float4:{:?}, Control Group:{:?})", test, a, b);
+
+        let a: [f32; 4] = float_a.lerp(float_b, t).into();
+        let b: [f32; 4] = glam_a.lerp(glam_b, t).into();
+        let validate = (0..4).all(|idx| (a[idx] - b[idx]).abs() <= EPSILON);
+        assert!(validate, "Test:{} >> Lerp operation on `Float4` is invalid! (Float4:{:?}, Control Group:{:?})", test, a, b);
+
+        if let Some(norm) = float_a.try_normalize() {
+            let a: [f32; 4] = norm.into();
+            let b: [f32; 4] = glam_a.normalize().into();
+            let validate = (0..4).all(|idx| (a[idx] - b[idx]).abs() <= EPSILON);
+            assert!(validate, "Test:{} >> Normalize operation on `Float4` is invalid! (Float4:{:?}, Control Group:{:?})", test, a, b);
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use rand::Rng;
-use crate::{Boolean4, Float4, UInteger4, Vector};
+use crate::{Boolean4, Float4, UInteger4, Vector, VectorInt};
 use super::NUM_TEST;
 
 
@@ -175,3 +175,137 @@ fn vector_ge() {
         assert_eq!(a, b, "Test:{} >> Greater than or equal comparison on `Vector` is invalid! (Vector:{:?}, Control Group:{:?})", test, a, b);
     }
 }
+
+#[test]
+fn vector_select() {
+    // `Float*` data type does not support this operation.
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data 
+        let origin_a: [f32; 4] = rng.gen();
+        let origin_b: [f32; 4] = rng.gen();
+
+        // Vector
+        let vector_a: Vector = { let t: Float4 = origin_a.into(); t.into() };
+        let vector_b: Vector = { let t: Float4 = origin_b.into(); t.into() };
+        let vector_c = Vector::select(vector_a.lt(vector_b), vector_a, vector_b);
+
+        // Control group
+        let glam_a: glam::Vec4 = origin_a.into();
+        let glam_b: glam::Vec4 = origin_b.into();
+        let glam_c = glam::Vec4::select(glam_a.cmplt(glam_b), glam_a, glam_b);
+
+        // Compare `Vector` and `Control group`
+        let a: [f32; 4] = { let t: Float4 = vector_c.into(); t.into() };
+        let b: [f32; 4] = glam_c.into();
+        assert_eq!(a, b, "Test:{} >> Select operation on `Vector` is invalid! (Vector:{:?}, Control Group:{:?})", test, a, b);
+    }
+}
+
+#[test]
+fn vector_int_select() {
+    // `Integer*` data type does not support this operation.
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data 
+        let origin_a: [i32; 4] = rng.gen();
+        let origin_b: [i32; 4] = rng.gen();
+
+        // Vector
+        let vector_a = VectorInt::from_array(origin_a);
+        let vector_b = VectorInt::from_array(origin_b);
+        let vector_c = VectorInt::select(vector_a.gt(vector_b), vector_a, vector_b);
+
+        // Control group
+        let glam_a: glam::IVec4 = origin_a.into();
+        let glam_b: glam::IVec4 = origin_b.into();
+        let glam_c = glam::IVec4::select(glam_a.cmpgt(glam_b), glam_a, glam_b);
+
+        // Compare `VectorInt` and `Control group`
+        let a: [i32; 4] = vector_c.into_array();
+        let b: [i32; 4] = glam_c.into();
+        assert_eq!(a, b, "Test:{} >> Select operation on `VectorInt` is invalid! (VectorInt:{:?}, Control Group:{:?})", test, a, b);
+    }
+}
+
+#[test]
+fn vector_bits() {
+    // `Float*` data type does not support this operation.
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data 
+        let origin: [f32; 4] = rng.gen();
+
+        // Vector
+        let vector_a: Vector = { let t: Float4 = origin.into(); t.into() };
+        let vector_b = vector_a.to_bits();
+        let vector_c = Vector::from_bits(vector_b);
+
+        // Control group
+        let bits: [i32; 4] = origin.map(|v| v.to_bits() as i32);
+
+        // Compare `Vector` and `Control group`
+        let a: [i32; 4] = vector_b.into_array();
+        assert_eq!(a, bits, "Test:{} >> Bit-cast operation on `Vector` is invalid! (Vector:{:?}, Control Group:{:?})", test, a, bits);
+        let a: [f32; 4] = { let t: Float4 = vector_c.into(); t.into() };
+        assert_eq!(a, origin, "Test:{} >> Bit-cast operation on `Vector` is invalid! (Vector:{:?}, Control Group:{:?})", test, a, origin);
+    }
+}
+
+#[test]
+fn vector_abs_diff_eq() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data 
+        let origin_a: [f32; 4] = rng.gen();
+        let origin_b: [f32; 4] = origin_a.map(|e| e + rng.gen_range(-2e-6..2e-6));
+        let max_abs_diff: f32 = rng.gen_range(0.0..2e-6);
+
+        // Vector
+        let vector_a: Vector = { let t: Float4 = origin_a.into(); t.into() };
+        let vector_b: Vector = { let t: Float4 = origin_b.into(); t.into() };
+        let a = vector_a.abs_diff_eq(vector_b, max_abs_diff);
+        let data = Float4::from(origin_a).abs_diff_eq(Float4::from(origin_b), max_abs_diff);
+
+        // Control group
+        let glam_a: glam::Vec4 = origin_a.into();
+        let glam_b: glam::Vec4 = origin_b.into();
+        let b = glam_a.abs_diff_eq(glam_b, max_abs_diff);
+
+        // Compare `Vector` and `Control group`
+        assert_eq!(a, b, "Test:{} >> Approximate equality on `Vector` is invalid! (Vector:{:?}, Control Group:{:?})", test, a, b);
+        assert_eq!(data, b, "Test:{} >> Approximate equality on `Float4` is invalid! (Float4:{:?}, Control Group:{:?})", test, data, b);
+    }
+}
+
+#[test]
+fn vector_relative_ulps_eq() {
+    // The control group does not support this operation.
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data 
+        let origin_a: [f32; 4] = [(); 4].map(|_| rng.gen_range(-1e3..1e3));
+        let ulps: [u32; 4] = [(); 4].map(|_| rng.gen_range(0..8));
+        let mut origin_b = origin_a;
+        for idx in 0..4 {
+            if origin_a[idx] != 0.0 {
+                origin_b[idx] = f32::from_bits(origin_a[idx].to_bits() + ulps[idx]);
+            }
+        }
+
+        // Vector
+        let vector_a: Vector = { let t: Float4 = origin_a.into(); t.into() };
+        let vector_b: Vector = { let t: Float4 = origin_b.into(); t.into() };
+        let ulps_eq = vector_a.ulps_eq(vector_b, 0.0, 4);
+        let relative_eq = vector_a.relative_eq(vector_b, 0.0, 4.0 * f32::EPSILON);
+
+        // Expected
+        let expected_ulps = (0..4).all(|idx| origin_a[idx] == origin_b[idx] || ulps[idx] <= 4);
+        let expected_relative = (0..4).all(|idx| (origin_a[idx] - origin_b[idx]).abs() <= origin_a[idx].abs().max(origin_b[idx].abs()) * 4.0 * f32::EPSILON);
+
+        // Compare `Vector` and `Expected`
+        assert_eq!(ulps_eq, expected_ulps, "Test:{} >> Ulps equality on `Vector` is invalid! (Vector:{:?}, Expected:{:?})", test, ulps_eq, expected_ulps);
+        assert_eq!(relative_eq, expected_relative, "Test:{} >> Relative equality on `Vector` is invalid! (Vector:{:?}, Expected:{:?})", test, relative_eq, expected_relative);
+        assert!(!vector_a.ulps_eq(-vector_a, 0.0, u32::MAX) || vector_a == Vector::ZERO, "Test:{} >> Ulps equality on `Vector` must be false for different signs!", test);
+    }
+}
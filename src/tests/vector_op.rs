@@ -1,6 +1,6 @@
 use rand::Rng;
-use crate::{Float2, Float3, Float4, Vector};
-use super::{NUM_TEST, EPSILON};
+use crate::{Float2, Float3, Float4, Vector, VectorInt};
+use super::{NUM_TEST, EPSILON, FUSED_MUL_ADD};
 
 
 
@@ -71,7 +71,11 @@ fn vector2_dot() {
         // Compare `Vector` and `Control group`
         let a: f32 = { let t: Float2 = vector_c.into(); t.x };
         let b: f32 = glam_c;
-        assert_eq!(a, b, "Test:{} >> Dot2 operation on `Vector` is invalid! (Vector:{:?}, Control Group:{:?})", test, a, b);
+        let validate = match FUSED_MUL_ADD {
+            true => (a - b).abs() <= EPSILON, 
+            false => a == b
+        };
+        assert!(validate, "Test:{} >> Dot2 operation on `Vector` is invalid! (Vector:{:?}, Control Group:{:?})", test, a, b);
     }
 }
 
@@ -97,7 +101,11 @@ fn vector3_dot() {
         // Compare `Vector` and `Control group`
         let a: f32 = { let t: Float3 = vector_c.into(); t.x };
         let b: f32 = glam_c;
-        assert_eq!(a, b, "Test:{} >> Dot3 operation on `Vector` is invalid! (Vector:{:?}, Control Group:{:?})", test, a, b);
+        let validate = match FUSED_MUL_ADD {
+            true => (a - b).abs() <= EPSILON, 
+            false => a == b
+        };
+        assert!(validate, "Test:{} >> Dot3 operation on `Vector` is invalid! (Vector:{:?}, Control Group:{:?})", test, a, b);
     }
 }
 
@@ -127,6 +135,36 @@ fn vector4_dot() {
     }
 }
 
+#[test]
+fn vector_mul_add() {
+    // `Float*` data type does not support this operation.
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data 
+        let origin_a: [f32; 4] = rng.gen();
+        let origin_b: [f32; 4] = rng.gen();
+        let origin_c: [f32; 4] = rng.gen();
+
+        // Vector
+        let vector_a: Vector = { let t: Float4 = origin_a.into(); t.into() };
+        let vector_b: Vector = { let t: Float4 = origin_b.into(); t.into() };
+        let vector_c: Vector = { let t: Float4 = origin_c.into(); t.into() };
+        let vector_d = vector_a.mul_add(vector_b, vector_c);
+
+        // Control group
+        let glam_a: glam::Vec4 = origin_a.into();
+        let glam_b: glam::Vec4 = origin_b.into();
+        let glam_c: glam::Vec4 = origin_c.into();
+        let glam_d = glam_a.mul_add(glam_b, glam_c);
+
+        // Compare `Vector` and `Control group`
+        let a: [f32; 4] = { let t: Float4 = vector_d.into(); t.into() };
+        let b: [f32; 4] = glam_d.into();
+        let validate = (0..4).all(|idx| (a[idx] - b[idx]).abs() <= EPSILON);
+        assert!(validate, "Test:{} >> Multiply-add operation on `Vector` is invalid! (Vector:{:?}, Control Group:{:?})", test, a, b);
+    }
+}
+
 #[test]
 fn vector3_cross() {
     // `Float*` data type does not support this operation.
@@ -152,3 +190,556 @@ fn vector3_cross() {
         assert_eq!(a, b, "Test:{} >> Cross operation on `Vector` is invalid! (Vector:{:?}, Control Group:{:?})", test, a, b);
     }
 }
+
+#[test]
+fn vector_sin_cos() {
+    // `Float*` data type does not support this operation.
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin: [f32; 4] = [(); 4].map(|_| rng.gen_range(-4.0 * core::f32::consts::PI..=4.0 * core::f32::consts::PI));
+
+        // Vector
+        let vector_a: Vector = { let t: Float4 = origin.into(); t.into() };
+        let (vector_s, vector_c) = vector_a.sin_cos();
+
+        // Control group
+        let sin: [f64; 4] = origin.map(|v| (v as f64).sin());
+        let cos: [f64; 4] = origin.map(|v| (v as f64).cos());
+
+        // Compare `Vector` and `Control group`
+        let a: [f32; 4] = { let t: Float4 = vector_s.into(); t.into() };
+        let b: [f32; 4] = { let t: Float4 = vector_c.into(); t.into() };
+        let validate = (0..4).all(|i| (a[i] as f64 - sin[i]).abs() <= 4.0e-7 && (b[i] as f64 - cos[i]).abs() <= 4.0e-7);
+        assert!(validate, "Test:{} >> Sine and cosine operation on `Vector` is invalid! (Vector:{:?}, Control Group:{:?})", test, (a, b), (sin, cos));
+    }
+}
+
+#[test]
+fn vector_tan() {
+    // `Float*` data type does not support this operation.
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin: [f32; 4] = [(); 4].map(|_| loop {
+            let v = rng.gen_range(-4.0 * core::f32::consts::PI..=4.0 * core::f32::consts::PI);
+            if (v as f64).cos().abs() > 0.1 { break v; }
+        });
+
+        // Vector
+        let vector_a: Vector = { let t: Float4 = origin.into(); t.into() };
+        let vector_b = vector_a.tan();
+
+        // Control group
+        let b: [f64; 4] = origin.map(|v| (v as f64).tan());
+
+        // Compare `Vector` and `Control group`
+        let a: [f32; 4] = { let t: Float4 = vector_b.into(); t.into() };
+        let validate = (0..4).all(|i| (a[i] as f64 - b[i]).abs() <= 4.0e-6 * b[i].abs().max(1.0));
+        assert!(validate, "Test:{} >> Tangent operation on `Vector` is invalid! (Vector:{:?}, Control Group:{:?})", test, a, b);
+    }
+}
+
+#[test]
+fn vector_asin_acos() {
+    // `Float*` data type does not support this operation.
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin: [f32; 4] = [(); 4].map(|_| rng.gen_range(-1.0..=1.0));
+
+        // Vector
+        let vector_a: Vector = { let t: Float4 = origin.into(); t.into() };
+        let vector_s = vector_a.asin();
+        let vector_c = vector_a.acos();
+
+        // Control group
+        let asin: [f64; 4] = origin.map(|v| (v as f64).asin());
+        let acos: [f64; 4] = origin.map(|v| (v as f64).acos());
+
+        // Compare `Vector` and `Control group`
+        let a: [f32; 4] = { let t: Float4 = vector_s.into(); t.into() };
+        let b: [f32; 4] = { let t: Float4 = vector_c.into(); t.into() };
+        let validate = (0..4).all(|i| (a[i] as f64 - asin[i]).abs() <= 5.0e-7 && (b[i] as f64 - acos[i]).abs() <= 5.0e-7);
+        assert!(validate, "Test:{} >> Arcsine and arccosine operation on `Vector` is invalid! (Vector:{:?}, Control Group:{:?})", test, (a, b), (asin, acos));
+    }
+}
+
+#[test]
+fn vector_atan() {
+    // `Float*` data type does not support this operation.
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin: [f32; 4] = [(); 4].map(|_| rng.gen_range(-1000.0..=1000.0));
+
+        // Vector
+        let vector_a: Vector = { let t: Float4 = origin.into(); t.into() };
+        let vector_b = vector_a.atan();
+
+        // Control group
+        let b: [f64; 4] = origin.map(|v| (v as f64).atan());
+
+        // Compare `Vector` and `Control group`
+        let a: [f32; 4] = { let t: Float4 = vector_b.into(); t.into() };
+        let validate = (0..4).all(|i| (a[i] as f64 - b[i]).abs() <= 2.0e-7);
+        assert!(validate, "Test:{} >> Arctangent operation on `Vector` is invalid! (Vector:{:?}, Control Group:{:?})", test, a, b);
+    }
+}
+
+#[test]
+fn vector_atan2() {
+    // `Float*` data type does not support this operation.
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin_y: [f32; 4] = [(); 4].map(|_| rng.gen_range(-1000.0..=1000.0));
+        let origin_x: [f32; 4] = [(); 4].map(|_| rng.gen_range(-1000.0..=1000.0));
+
+        // Vector
+        let vector_y: Vector = { let t: Float4 = origin_y.into(); t.into() };
+        let vector_x: Vector = { let t: Float4 = origin_x.into(); t.into() };
+        let vector_c = vector_y.atan2(vector_x);
+
+        // Control group
+        let b: [f64; 4] = [0, 1, 2, 3].map(|i| (origin_y[i] as f64).atan2(origin_x[i] as f64));
+
+        // Compare `Vector` and `Control group`
+        let a: [f32; 4] = { let t: Float4 = vector_c.into(); t.into() };
+        let validate = (0..4).all(|i| (a[i] as f64 - b[i]).abs() <= 4.0e-7);
+        assert!(validate, "Test:{} >> Arctangent2 operation on `Vector` is invalid! (Vector:{:?}, Control Group:{:?})", test, a, b);
+    }
+
+    // Special values
+    let values = [0.0, -0.0, 1.0, -1.0, f32::INFINITY, f32::NEG_INFINITY, f32::NAN];
+    for &y in values.iter() {
+        for &x in values.iter() {
+            let a = Vector::fill(y).atan2(Vector::fill(x)).get_x();
+            let b = y.atan2(x);
+            let validate = (a.is_nan() && b.is_nan()) || ((a - b).abs() <= 4.0e-7 && a.is_sign_negative() == b.is_sign_negative());
+            assert!(validate, "Arctangent2 operation on `Vector` is invalid! (y:{}, x:{}, Vector:{:?}, Control Group:{:?})", y, x, a, b);
+        }
+    }
+}
+
+#[test]
+fn vector_exp() {
+    // `Float*` data type does not support this operation.
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin: [f32; 4] = [(); 4].map(|_| rng.gen_range(-87.0..=88.0));
+
+        // Vector
+        let vector_a: Vector = { let t: Float4 = origin.into(); t.into() };
+        let vector_b = vector_a.exp();
+
+        // Control group
+        let b: [f64; 4] = origin.map(|v| (v as f64).exp());
+
+        // Compare `Vector` and `Control group`
+        let a: [f32; 4] = { let t: Float4 = vector_b.into(); t.into() };
+        let validate = (0..4).all(|i| ((a[i] as f64 - b[i]) / b[i]).abs() <= 2.0e-7);
+        assert!(validate, "Test:{} >> Exponential operation on `Vector` is invalid! (Vector:{:?}, Control Group:{:?})", test, a, b);
+    }
+
+    // Special values
+    assert_eq!(Vector::fill(f32::INFINITY).exp().get_x(), f32::INFINITY);
+    assert_eq!(Vector::fill(f32::NEG_INFINITY).exp().get_x(), 0.0);
+    assert!(Vector::fill(f32::NAN).exp().get_x().is_nan());
+}
+
+#[test]
+fn vector_exp2() {
+    // `Float*` data type does not support this operation.
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin: [f32; 4] = [(); 4].map(|_| rng.gen_range(-126.0..=127.0));
+
+        // Vector
+        let vector_a: Vector = { let t: Float4 = origin.into(); t.into() };
+        let vector_b = vector_a.exp2();
+
+        // Control group
+        let b: [f64; 4] = origin.map(|v| (v as f64).exp2());
+
+        // Compare `Vector` and `Control group`
+        let a: [f32; 4] = { let t: Float4 = vector_b.into(); t.into() };
+        let validate = (0..4).all(|i| ((a[i] as f64 - b[i]) / b[i]).abs() <= 2.0e-7);
+        assert!(validate, "Test:{} >> Exponential2 operation on `Vector` is invalid! (Vector:{:?}, Control Group:{:?})", test, a, b);
+    }
+}
+
+#[test]
+fn vector_ln() {
+    // `Float*` data type does not support this operation.
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin: [f32; 4] = [(); 4].map(|_| match rng.gen() {
+            true => rng.gen_range(f32::MIN_POSITIVE..10.0), 
+            false => rng.gen_range(10.0..f32::MAX)
+        });
+
+        // Vector
+        let vector_a: Vector = { let t: Float4 = origin.into(); t.into() };
+        let vector_b = vector_a.ln();
+
+        // Control group
+        let b: [f64; 4] = origin.map(|v| (v as f64).ln());
+
+        // Compare `Vector` and `Control group`
+        let a: [f32; 4] = { let t: Float4 = vector_b.into(); t.into() };
+        let validate = (0..4).all(|i| (a[i] as f64 - b[i]).abs() <= (2.0e-7 * b[i].abs()).max(5.0e-7));
+        assert!(validate, "Test:{} >> Logarithm operation on `Vector` is invalid! (Vector:{:?}, Control Group:{:?})", test, a, b);
+    }
+
+    // Special values
+    assert_eq!(Vector::fill(0.0).ln().get_x(), f32::NEG_INFINITY);
+    assert_eq!(Vector::fill(f32::INFINITY).ln().get_x(), f32::INFINITY);
+    assert!(Vector::fill(-1.0).ln().get_x().is_nan());
+    assert!(Vector::fill(f32::NAN).ln().get_x().is_nan());
+}
+
+#[test]
+fn vector_log2() {
+    // `Float*` data type does not support this operation.
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin: [f32; 4] = [(); 4].map(|_| match rng.gen() {
+            true => rng.gen_range(f32::MIN_POSITIVE..10.0), 
+            false => rng.gen_range(10.0..f32::MAX)
+        });
+
+        // Vector
+        let vector_a: Vector = { let t: Float4 = origin.into(); t.into() };
+        let vector_b = vector_a.log2();
+
+        // Control group
+        let b: [f64; 4] = origin.map(|v| (v as f64).log2());
+
+        // Compare `Vector` and `Control group`
+        let a: [f32; 4] = { let t: Float4 = vector_b.into(); t.into() };
+        let validate = (0..4).all(|i| (a[i] as f64 - b[i]).abs() <= (2.0e-7 * b[i].abs()).max(1.0e-6));
+        assert!(validate, "Test:{} >> Logarithm2 operation on `Vector` is invalid! (Vector:{:?}, Control Group:{:?})", test, a, b);
+    }
+}
+
+#[test]
+fn vector_sqrt_rsqrt_recip() {
+    // `Float*` data type does not support this operation.
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin: [f32; 4] = [(); 4].map(|_| rng.gen_range(f32::MIN_POSITIVE..=1.0e+6));
+
+        // Vector
+        let vector_a: Vector = { let t: Float4 = origin.into(); t.into() };
+        let vector_sqrt = vector_a.sqrt();
+        let vector_rsqrt = vector_a.rsqrt();
+        let vector_recip = vector_a.recip();
+
+        // Control group
+        let sqrt: [f32; 4] = origin.map(|v| v.sqrt());
+        let rsqrt: [f32; 4] = origin.map(|v| v.sqrt().recip());
+        let recip: [f32; 4] = origin.map(|v| v.recip());
+
+        // Compare `Vector` and `Control group`
+        let a: [f32; 4] = { let t: Float4 = vector_sqrt.into(); t.into() };
+        assert_eq!(a, sqrt, "Test:{} >> Square root operation on `Vector` is invalid! (Vector:{:?}, Control Group:{:?})", test, a, sqrt);
+        let a: [f32; 4] = { let t: Float4 = vector_rsqrt.into(); t.into() };
+        assert_eq!(a, rsqrt, "Test:{} >> Reciprocal square root operation on `Vector` is invalid! (Vector:{:?}, Control Group:{:?})", test, a, rsqrt);
+        let a: [f32; 4] = { let t: Float4 = vector_recip.into(); t.into() };
+        assert_eq!(a, recip, "Test:{} >> Reciprocal operation on `Vector` is invalid! (Vector:{:?}, Control Group:{:?})", test, a, recip);
+    }
+}
+
+#[test]
+fn vector_sqrt_rsqrt_recip_est() {
+    // `Float*` data type does not support this operation.
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin: [f32; 4] = [(); 4].map(|_| rng.gen_range(1.0e-30..=1.0e+30));
+
+        // Vector
+        let vector_a: Vector = { let t: Float4 = origin.into(); t.into() };
+        let vector_sqrt = vector_a.sqrt_est();
+        let vector_rsqrt = vector_a.rsqrt_est();
+        let vector_recip = vector_a.recip_est();
+
+        // Control group
+        let sqrt: [f64; 4] = origin.map(|v| (v as f64).sqrt());
+        let rsqrt: [f64; 4] = origin.map(|v| (v as f64).sqrt().recip());
+        let recip: [f64; 4] = origin.map(|v| (v as f64).recip());
+
+        // Compare `Vector` and `Control group`
+        let a: [f32; 4] = { let t: Float4 = vector_sqrt.into(); t.into() };
+        let validate = (0..4).all(|i| ((a[i] as f64 - sqrt[i]) / sqrt[i]).abs() <= 4.0e-4);
+        assert!(validate, "Test:{} >> Estimated square root operation on `Vector` is invalid! (Vector:{:?}, Control Group:{:?})", test, a, sqrt);
+        let a: [f32; 4] = { let t: Float4 = vector_rsqrt.into(); t.into() };
+        let validate = (0..4).all(|i| ((a[i] as f64 - rsqrt[i]) / rsqrt[i]).abs() <= 4.0e-4);
+        assert!(validate, "Test:{} >> Estimated reciprocal square root operation on `Vector` is invalid! (Vector:{:?}, Control Group:{:?})", test, a, rsqrt);
+        let a: [f32; 4] = { let t: Float4 = vector_recip.into(); t.into() };
+        let validate = (0..4).all(|i| ((a[i] as f64 - recip[i]) / recip[i]).abs() <= 4.0e-4);
+        assert!(validate, "Test:{} >> Estimated reciprocal operation on `Vector` is invalid! (Vector:{:?}, Control Group:{:?})", test, a, recip);
+    }
+
+    // Special values
+    assert_eq!(Vector::fill(0.0).sqrt_est().get_x(), 0.0);
+    assert_eq!(Vector::fill(f32::INFINITY).sqrt_est().get_x(), f32::INFINITY);
+}
+
+#[test]
+fn vector3_len_est() {
+    // `Float*` data type does not support this operation.
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin: [f32; 3] = rng.gen();
+
+        // Vector
+        let vector_a: Vector = { let t: Float3 = origin.into(); t.into() };
+        let a = vector_a.vec3_len_est();
+
+        // Control group
+        let glam_a: glam::Vec3 = origin.into();
+        let b = glam_a.length();
+
+        // Compare `Vector` and `Control group`
+        let validate = (a - b).abs() <= 4.0e-4 * b;
+        assert!(validate, "Test:{} >> Estimated length operation on `Vector` is invalid! (Vector:{:?}, Control Group:{:?})", test, a, b);
+    }
+}
+
+#[test]
+fn vector3_normalize_est() {
+    // `Float*` data type does not support this operation.
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin: [f32; 3] = [(); 3].map(|_| rng.gen_range(0.1..=1.0));
+
+        // Vector
+        let vector_a: Vector = { let t: Float3 = origin.into(); t.into() };
+        let vector_b = vector_a.vec3_normalize_est();
+
+        // Control group
+        let glam_a: glam::Vec3 = origin.into();
+        let glam_b = glam_a.normalize();
+
+        // Compare `Vector` and `Control group`
+        let a: [f32; 4] = { let t: Float4 = vector_b.into(); t.into() };
+        let b: [f32; 3] = glam_b.into();
+        let validate = (0..3).all(|i| (a[i] - b[i]).abs() <= 4.0e-4 * b[i]) && a[3] == 0.0;
+        assert!(validate, "Test:{} >> Estimated normalize operation on `Vector` is invalid! (Vector:{:?}, Control Group:{:?})", test, a, b);
+    }
+}
+
+#[test]
+fn vector_shuffle() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin: [f32; 4] = rng.gen();
+
+        // Float4
+        let float_a: Float4 = origin.into();
+        let float_b = [float_a.wzyx(), float_a.xxyy(), float_a.zwxy(), float_a.yzxw()];
+
+        // Vector
+        let vector_a: Vector = float_a.into();
+        let vector_b = [vector_a.wzyx(), vector_a.xxyy(), vector_a.shuffle::<2, 3, 0, 1>(), vector_a.shuffle::<1, 2, 0, 3>()];
+
+        // Compare `Vector` and `Float4`
+        let a: [[f32; 4]; 4] = vector_b.map(|v| { let t: Float4 = v.into(); t.into() });
+        let b: [[f32; 4]; 4] = float_b.map(|v| v.into());
+        assert_eq!(a, b, "Test:{} >> Shuffle operation on `Vector` is invalid! (Vector:{:?}, Float4:{:?})", test, a, b);
+    }
+}
+
+#[test]
+fn vector_permute() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin_a: [f32; 4] = rng.gen();
+        let origin_b: [f32; 4] = rng.gen();
+
+        // Vector
+        let vector_a: Vector = { let t: Float4 = origin_a.into(); t.into() };
+        let vector_b: Vector = { let t: Float4 = origin_b.into(); t.into() };
+        let vector_c = [
+            vector_a.permute::<0, 1, 4, 5>(vector_b), 
+            vector_a.permute::<6, 7, 2, 3>(vector_b), 
+            vector_a.permute::<7, 2, 5, 0>(vector_b), 
+            vector_a.permute::<4, 5, 6, 7>(vector_b), 
+            vector_a.merge_xy(vector_b), 
+            vector_a.merge_zw(vector_b)
+        ];
+
+        // Control group
+        let src = [origin_a, origin_b].concat();
+        let control = [
+            [src[0], src[1], src[4], src[5]], 
+            [src[6], src[7], src[2], src[3]], 
+            [src[7], src[2], src[5], src[0]], 
+            [src[4], src[5], src[6], src[7]], 
+            [src[0], src[4], src[1], src[5]], 
+            [src[2], src[6], src[3], src[7]]
+        ];
+
+        // Compare `Vector` and `Control group`
+        let a: [[f32; 4]; 6] = vector_c.map(|v| { let t: Float4 = v.into(); t.into() });
+        assert_eq!(a, control, "Test:{} >> Permute operation on `Vector` is invalid! (Vector:{:?}, Control Group:{:?})", test, a, control);
+    }
+}
+
+#[test]
+fn vector_splat() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin: [f32; 4] = rng.gen();
+
+        // Vector
+        let vector_a: Vector = { let t: Float4 = origin.into(); t.into() };
+        let vector_b = [vector_a.splat_x(), vector_a.splat_y(), vector_a.splat_z(), vector_a.splat_w()];
+
+        // Control group
+        let control = origin.map(|v| [v; 4]);
+
+        // Compare `Vector` and `Control group`
+        let a: [[f32; 4]; 4] = vector_b.map(|v| { let t: Float4 = v.into(); t.into() });
+        assert_eq!(a, control, "Test:{} >> Splat operation on `Vector` is invalid! (Vector:{:?}, Control Group:{:?})", test, a, control);
+    }
+}
+
+#[test]
+fn vector_int_shuffle() {
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data
+        let origin_a: [i32; 4] = rng.gen();
+        let origin_b: [i32; 4] = rng.gen();
+
+        // VectorInt
+        let vector_a = VectorInt::from_array(origin_a);
+        let vector_b = VectorInt::from_array(origin_b);
+        let vector_c = [
+            vector_a.wzyx(), 
+            vector_a.shuffle::<1, 1, 3, 0>(), 
+            vector_a.permute::<7, 2, 5, 0>(vector_b), 
+            vector_a.splat_z(), 
+            vector_a.merge_xy(vector_b), 
+            vector_a.merge_zw(vector_b)
+        ];
+
+        // Control group
+        let src = [origin_a, origin_b].concat();
+        let control = [
+            [src[3], src[2], src[1], src[0]], 
+            [src[1], src[1], src[3], src[0]], 
+            [src[7], src[2], src[5], src[0]], 
+            [src[2]; 4], 
+            [src[0], src[4], src[1], src[5]], 
+            [src[2], src[6], src[3], src[7]]
+        ];
+
+        // Compare `VectorInt` and `Control group`
+        let a: [[i32; 4]; 6] = vector_c.map(|v| v.into_array());
+        assert_eq!(a, control, "Test:{} >> Shuffle operation on `VectorInt` is invalid! (VectorInt:{:?}, Control Group:{:?})", test, a, control);
+    }
+}
+
+// The following tests compare the results bit by bit.
+// The `sse2` backend uses `sse3`, `ssse3` and `sse4.1` instructions when the target features are enabled,
+// so running them with `RUSTFLAGS="-C target-feature=+sse3,+ssse3,+sse4.1"` checks that both paths are identical.
+
+#[test]
+fn vector_floor_ceil_round() {
+    // `Float*` data type does not support this operation.
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data 
+        // Includes half-way cases, negative zero and numbers that are too large to have a fraction.
+        let origin: [f32; 4] = [
+            rng.gen_range(-1024.0..=1024.0), 
+            (rng.gen_range(-1024..=1024) as f32) + 0.5, 
+            [-0.0, 0.49999997, -0.5, 2.5][test % 4], 
+            rng.gen_range(-1.0e9..=1.0e9)
+        ];
+
+        // Vector
+        let vector_a = Vector::from_array(origin);
+        let vector_b = [vector_a.floor(), vector_a.ceil(), vector_a.round()];
+
+        // Control group
+        let control = [origin.map(f32::floor), origin.map(f32::ceil), origin.map(f32::round)];
+
+        // Compare `Vector` and `Control group`
+        let a: [[u32; 4]; 3] = vector_b.map(|v| v.into_array().map(f32::to_bits));
+        let b: [[u32; 4]; 3] = control.map(|v| v.map(f32::to_bits));
+        assert_eq!(a, b, "Test:{} >> Rounding operation on `Vector` is invalid! (Vector:{:?}, Control Group:{:?})", test, vector_b, control);
+    }
+}
+
+#[test]
+#[cfg(all(target_feature = "sse2", not(target_feature = "fma"), not(feature = "scalar-math")))]
+fn vector_dot_order() {
+    // `Float*` data type does not support this operation.
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data 
+        let origin_a: [f32; 4] = [(); 4].map(|_| rng.gen_range(-1.0..=1.0));
+        let origin_b: [f32; 4] = [(); 4].map(|_| rng.gen_range(-1.0..=1.0));
+
+        // Vector
+        let vector_a = Vector::from_array(origin_a);
+        let vector_b = Vector::from_array(origin_b);
+        let vector_c = [vector_a.vec2_dot_into(vector_b), vector_a.vec3_dot_into(vector_b), vector_a.vec4_dot_into(vector_b), (vector_a * vector_b).sum_into()];
+
+        // Control group
+        // Adds in the same order as the `dpps` and `haddps` instructions.
+        let p = [0, 1, 2, 3].map(|i| origin_a[i] * origin_b[i]);
+        let control = [(p[0] + p[1]) + (0.0 + 0.0), (p[0] + p[1]) + (p[2] + 0.0), (p[0] + p[1]) + (p[2] + p[3]), (p[0] + p[1]) + (p[2] + p[3])];
+
+        // Compare `Vector` and `Control group`
+        let a: [u32; 4] = vector_c.map(f32::to_bits);
+        let b: [u32; 4] = control.map(f32::to_bits);
+        assert_eq!(a, b, "Test:{} >> Dot operation on `Vector` is invalid! (Vector:{:?}, Control Group:{:?})", test, vector_c, control);
+    }
+}
+
+#[test]
+fn vector_int_mul_abs() {
+    // `Integer*` data type does not support this operation.
+    // The products overflow, and the lower 32 bits of them are kept like `i32::wrapping_mul`.
+    let mut rng = rand::thread_rng();
+    for test in 0..NUM_TEST {
+        // Data 
+        let (origin_a, origin_b): ([i32; 4], [i32; 4]) = match test {
+            0 => ([i32::MIN, i32::MAX, i32::MIN + 1, -1], [i32::MAX, i32::MIN, -1, i32::MIN]), 
+            _ => (rng.gen(), rng.gen())
+        };
+
+        // VectorInt
+        let vector_a = VectorInt::from_array(origin_a);
+        let vector_b = VectorInt::from_array(origin_b);
+        let vector_c = [vector_a * vector_b, vector_a.abs(), vector_a.min(vector_b), vector_a.max(vector_b)];
+
+        // Control group
+        let control = [
+            [0, 1, 2, 3].map(|i| origin_a[i].wrapping_mul(origin_b[i])), 
+            origin_a.map(i32::wrapping_abs), 
+            [0, 1, 2, 3].map(|i| origin_a[i].min(origin_b[i])), 
+            [0, 1, 2, 3].map(|i| origin_a[i].max(origin_b[i]))
+        ];
+
+        // Compare `VectorInt` and `Control group`
+        let a: [[i32; 4]; 4] = vector_c.map(|v| v.into_array());
+        assert_eq!(a, control, "Test:{} >> Integer operation on `VectorInt` is invalid! (VectorInt:{:?}, Control Group:{:?})", test, a, control);
+    }
+}
@@ -0,0 +1,477 @@
+use core::fmt;
+use core::ops;
+use crate::{ Vector, Matrix, Matrix3, Quaternion, Float3x4 };
+use crate::near_eq;
+
+
+
+/// This is an affine transformation data type built on [`Matrix3`] and [`Vector`].
+/// 
+/// It does not depend on the instruction set, so it uses the instructions of [`Matrix3`] and [`Vector`].
+/// 
+/// ※ It consists of a 3x3 linear part and a translation. </br>
+/// ※ It is cheaper than [`Matrix`] to compose and to invert, 
+/// because the last row of the matrix is always `(0, 0, 0, 1)`. </br>
+/// 
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Affine3 {
+    pub(crate) matrix3: Matrix3, 
+
+    pub(crate) translation: Vector, 
+}
+
+impl Affine3 {
+    /// All elements are zeros.
+    pub const ZERO: Self = Self { matrix3: Matrix3::ZERO, translation: Vector::ZERO };
+
+    /// Identity transformation.
+    pub const IDENTITY: Self = Self { matrix3: Matrix3::IDENTITY, translation: Vector::ZERO };
+}
+
+impl Affine3 {
+    /// Creates with given column vectors.
+    /// 
+    /// ※ The w element of each column vector is set to zero. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn from_columns(
+        x_axis: Vector, 
+        y_axis: Vector, 
+        z_axis: Vector, 
+        mut w_axis: Vector
+    ) -> Self {
+        w_axis.set_w(0.0);
+        Self { 
+            matrix3: Matrix3::from_columns(x_axis, y_axis, z_axis), 
+            translation: w_axis 
+        }
+    }
+
+    /// Creates with a given linear part and `translation`.
+    #[inline]
+    #[must_use]
+    pub fn from_matrix3_translation(matrix3: Matrix3, mut translation: Vector) -> Self {
+        translation.set_w(0.0);
+        Self { matrix3, translation }
+    }
+
+    /// Creates from a given array.
+    #[inline]
+    #[must_use]
+    pub fn from_column_array(arr: [f32; 12]) -> Self {
+        Self::load_float3x4(arr.into())
+    }
+
+    /// Stores the value in an array.
+    #[inline]
+    #[must_use]
+    pub fn into_column_array(self) -> [f32; 12] {
+        self.store_float3x4().into()
+    }
+
+    /// Loads a value from a given `Float3x4`.
+    #[inline]
+    #[must_use]
+    pub fn load_float3x4(val: Float3x4) -> Self {
+        Self::from_columns(
+            Vector::load_float3(val.x_axis), 
+            Vector::load_float3(val.y_axis), 
+            Vector::load_float3(val.z_axis), 
+            Vector::load_float3(val.w_axis) 
+        )
+    }
+
+    /// Stores the value in a `Float3x4`.
+    #[inline]
+    #[must_use]
+    pub fn store_float3x4(self) -> Float3x4 {
+        Float3x4 {
+            x_axis: self.get_x_axis().store_float3(), 
+            y_axis: self.get_y_axis().store_float3(), 
+            z_axis: self.get_z_axis().store_float3(), 
+            w_axis: self.translation.store_float3() 
+        }
+    }
+
+    /// Creates from a given `Matrix`.
+    /// 
+    /// ※ The last row of the matrix is ignored. 
+    /// The conversion is lossless if the last row of the matrix is `(0, 0, 0, 1)`. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn from_matrix(m: Matrix) -> Self {
+        Self::from_columns(*m.get_x_axis(), *m.get_y_axis(), *m.get_z_axis(), *m.get_w_axis())
+    }
+
+    /// Converts to a `Matrix`.
+    /// 
+    /// ※ The last row of the matrix is set to `(0, 0, 0, 1)`. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn into_matrix(self) -> Matrix {
+        let mut w_axis = self.translation;
+        w_axis.set_w(1.0);
+        Matrix::from_columns(*self.get_x_axis(), *self.get_y_axis(), *self.get_z_axis(), w_axis)
+    }
+
+    /// Create a transformation with the given `translation`.
+    #[inline]
+    #[must_use]
+    pub fn from_translation(translation: Vector) -> Self {
+        Self::from_matrix3_translation(Matrix3::IDENTITY, translation)
+    }
+
+    /// Create a transformation with the given `scale`.
+    #[inline]
+    #[must_use]
+    pub fn from_scale(scale: Vector) -> Self {
+        Self::from_matrix3_translation(Matrix3::diagonal(scale), Vector::ZERO)
+    }
+
+    /// Creates a transformation with the given `rotation`.
+    /// 
+    /// ※ The given `rotation` must be normalized.
+    /// 
+    /// # Panics
+    /// When the `use-assertion` feature is enabled, it will [`panic!`]
+    /// if the given quaternion is not a normalized quaternion.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn from_quaternion(rotation: Quaternion) -> Self {
+        Self::from_matrix3_translation(Matrix3::from_quaternion(rotation), Vector::ZERO)
+    }
+
+    /// Creates a transformation rotated by a given angle about a given axis.
+    /// 
+    /// ※ The angles given are in radians. </br>
+    /// ※ The given `axis` must be a unit vector. </br>
+    /// 
+    /// # Panics
+    /// When the `use-assertion` feature is enabled, [`panic!`] will be called
+    /// if the given axis is not a unit vector.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn from_axis_angle(axis: Vector, angle: f32) -> Self {
+        Self::from_matrix3_translation(Matrix3::from_axis_angle(axis, angle), Vector::ZERO)
+    }
+
+    /// Creates a transformation rotated by a given x-axis angle.
+    /// 
+    /// ※ The angles given are in radians.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn from_rotation_x(angle: f32) -> Self {
+        Self::from_matrix3_translation(Matrix3::from_rotation_x(angle), Vector::ZERO)
+    }
+
+    /// Creates a transformation rotated by a given y-axis angle.
+    /// 
+    /// ※ The angles given are in radians.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn from_rotation_y(angle: f32) -> Self {
+        Self::from_matrix3_translation(Matrix3::from_rotation_y(angle), Vector::ZERO)
+    }
+
+    /// Creates a transformation rotated by a given z-axis angle.
+    /// 
+    /// ※ The angles given are in radians.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn from_rotation_z(angle: f32) -> Self {
+        Self::from_matrix3_translation(Matrix3::from_rotation_z(angle), Vector::ZERO)
+    }
+
+    /// Creates a transformation with the given `rotation` and `translation`.
+    /// 
+    /// ※ The given `rotation` must be normalized.
+    /// 
+    /// # Panics
+    /// When the `use-assertion` feature is enabled, it will [`panic!`]
+    /// if the given quaternion is not a normalized quaternion.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn from_rotation_translation(rotation: Quaternion, translation: Vector) -> Self {
+        Self::from_matrix3_translation(Matrix3::from_quaternion(rotation), translation)
+    }
+
+    /// Creates a transformation with the given `scale`, `rotation` and `translation`.
+    /// 
+    /// ※ The given `rotation` must be normalized.
+    /// 
+    /// # Panics
+    /// When the `use-assertion` feature is enabled, it will [`panic!`]
+    /// if the given quaternion is not a normalized quaternion.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn from_scale_rotation_translation(
+        scale: Vector, 
+        rotation: Quaternion, 
+        translation: Vector
+    ) -> Self {
+        let (x_axis, y_axis, z_axis) = rotation.to_rotation_axes();
+        Self::from_columns(
+            x_axis * scale.get_x(), 
+            y_axis * scale.get_y(), 
+            z_axis * scale.get_z(), 
+            translation
+        )
+    }
+}
+
+impl Affine3 {
+    /// Get the x-axis of the linear part.
+    #[inline]
+    #[must_use]
+    pub fn get_x_axis(&self) -> &Vector {
+        self.matrix3.get_x_axis()
+    }
+
+    /// Get the y-axis of the linear part.
+    #[inline]
+    #[must_use]
+    pub fn get_y_axis(&self) -> &Vector {
+        self.matrix3.get_y_axis()
+    }
+
+    /// Get the z-axis of the linear part.
+    #[inline]
+    #[must_use]
+    pub fn get_z_axis(&self) -> &Vector {
+        self.matrix3.get_z_axis()
+    }
+
+    /// Get the linear part of the transformation.
+    #[inline]
+    #[must_use]
+    pub fn get_matrix3(&self) -> &Matrix3 {
+        &self.matrix3
+    }
+
+    /// Set the linear part of the transformation.
+    #[inline]
+    pub fn set_matrix3(&mut self, m: Matrix3) {
+        self.matrix3 = m
+    }
+
+    /// Get the translation of the transformation.
+    #[inline]
+    #[must_use]
+    pub fn get_translation(&self) -> &Vector {
+        &self.translation
+    }
+
+    /// Set the translation of the transformation.
+    /// 
+    /// ※ The w element of the given vector is set to zero. </br>
+    /// 
+    #[inline]
+    pub fn set_translation(&mut self, mut v: Vector) {
+        v.set_w(0.0);
+        self.translation = v
+    }
+
+    /// Transforms the given point.
+    /// 
+    /// ※ The w element of the given vector is ignored and the w element of the returned vector is one. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn transform_point3(self, point: Vector) -> Vector {
+        let mut v = self.matrix3 * point + self.translation;
+        v.set_w(1.0);
+        v
+    }
+
+    /// Transforms the given vector. (The translation is not applied)
+    /// 
+    /// ※ The w element of the given vector is ignored and the w element of the returned vector is zero. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn transform_vector3(self, vector: Vector) -> Vector {
+        self.matrix3 * vector
+    }
+
+    /// Determinant of the linear part.
+    #[inline]
+    #[must_use]
+    pub fn determinant_into(self) -> f32 {
+        self.matrix3.determinant_into()
+    }
+
+    /// Inverse of the transformation.
+    /// 
+    /// ※ Only the linear part is inverted with the 3x3 inverse,
+    /// so it is cheaper than [`Matrix::inverse`]. </br>
+    /// 
+    /// # Panics
+    /// When the `use-assertion` feature is enabled, it will [`panic!`]
+    /// if the absolute value of the determinant of the linear part is less than or equal to [`f32::EPSILON`].
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn inverse(self) -> Self {
+        let matrix3 = self.matrix3.inverse();
+        let translation = -(matrix3 * self.translation);
+        Self { matrix3, translation }
+    }
+
+    /// Inverse of the transformation.
+    /// 
+    /// Returns `None` if the absolute value of the determinant of the linear part is less than or equal to [`f32::EPSILON`].
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn try_inverse(self) -> Option<Self> {
+        self.matrix3.try_inverse().map(|matrix3| {
+            let translation = -(matrix3 * self.translation);
+            Self { matrix3, translation }
+        })
+    }
+}
+
+impl Affine3 {
+    /// Returns `true` if the absolute difference of all elements is less than or equal to `max_abs_diff`.
+    #[inline]
+    #[must_use]
+    pub fn abs_diff_eq(self, rhs: Self, max_abs_diff: f32) -> bool {
+        near_eq::abs_diff_eq(&self.into_column_array(), &rhs.into_column_array(), max_abs_diff)
+    }
+
+    /// Returns `true` if all elements are equal within `max_abs_diff` or the relative tolerance `max_relative`.
+    /// 
+    /// ※ The relative tolerance is scaled by the larger absolute value of the two elements. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn relative_eq(self, rhs: Self, max_abs_diff: f32, max_relative: f32) -> bool {
+        near_eq::relative_eq(&self.into_column_array(), &rhs.into_column_array(), max_abs_diff, max_relative)
+    }
+
+    /// Returns `true` if all elements are equal within `max_abs_diff` or `max_ulps` representable values.
+    /// 
+    /// ※ Elements with different signs are equal only within `max_abs_diff`. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn ulps_eq(self, rhs: Self, max_abs_diff: f32, max_ulps: u32) -> bool {
+        near_eq::ulps_eq(&self.into_column_array(), &rhs.into_column_array(), max_abs_diff, max_ulps)
+    }
+}
+
+impl PartialEq for Affine3 {
+    /// Compares all elements exactly.
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.into_column_array() == other.into_column_array()
+    }
+}
+
+impl Default for Affine3 {
+    #[inline]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl From<Float3x4> for Affine3 {
+    #[inline]
+    fn from(value: Float3x4) -> Self {
+        Self::load_float3x4(value)
+    }
+}
+
+impl From<Affine3> for Float3x4 {
+    #[inline]
+    fn from(value: Affine3) -> Self {
+        value.store_float3x4()
+    }
+}
+
+impl From<[f32; 12]> for Affine3 {
+    #[inline]
+    fn from(value: [f32; 12]) -> Self {
+        Self::from_column_array(value)
+    }
+}
+
+impl From<Affine3> for [f32; 12] {
+    #[inline]
+    fn from(value: Affine3) -> Self {
+        value.into_column_array()
+    }
+}
+
+impl From<Matrix> for Affine3 {
+    #[inline]
+    fn from(value: Matrix) -> Self {
+        Self::from_matrix(value)
+    }
+}
+
+impl From<Affine3> for Matrix {
+    #[inline]
+    fn from(value: Affine3) -> Self {
+        value.into_matrix()
+    }
+}
+
+impl ops::Mul<Self> for Affine3 {
+    type Output = Self;
+    /// Composes two transformations. (`rhs` is applied first)
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self { 
+            matrix3: self.matrix3 * rhs.matrix3, 
+            translation: self.matrix3 * rhs.translation + self.translation 
+        }
+    }
+}
+
+impl ops::MulAssign<Self> for Affine3 {
+    /// Composes two transformations. (assign)
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs
+    }
+}
+
+impl ops::Mul<Matrix> for Affine3 {
+    type Output = Matrix;
+    /// Multiplies the transformation by a matrix.
+    #[inline]
+    fn mul(self, rhs: Matrix) -> Self::Output {
+        self.into_matrix() * rhs
+    }
+}
+
+impl ops::Mul<Affine3> for Matrix {
+    type Output = Matrix;
+    /// Multiplies the matrix by a transformation.
+    #[inline]
+    fn mul(self, rhs: Affine3) -> Self::Output {
+        self * rhs.into_matrix()
+    }
+}
+
+impl fmt::Debug for Affine3 {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(stringify!(Affine3))
+            .field("matrix3", &self.matrix3)
+            .field("translation", &self.translation)
+            .finish()
+    }
+}
@@ -0,0 +1,20 @@
+/// The range of the depth of the clip space used by projection matrices.
+///
+/// ※ `Direct3D`, `Metal` and `Vulkan` use [`DepthRange::ZeroToOne`]. </br>
+/// ※ `OpenGL` uses [`DepthRange::NegOneToOne`]. </br>
+///
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DepthRange {
+    /// The depth ranges from `0.0` to `1.0`.
+    ZeroToOne,
+    /// The depth ranges from `-1.0` to `1.0`.
+    NegOneToOne,
+}
+
+impl Default for DepthRange {
+    #[inline]
+    fn default() -> Self {
+        Self::ZeroToOne
+    }
+}
@@ -0,0 +1,716 @@
+use core::fmt;
+use core::ops;
+use crate::{ DVector, DQuaternion, Matrix, Double4x4 };
+use crate::near_eq;
+
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+
+
+
+/// This is a double-precision matrix data type built on [`DVector`].
+/// 
+/// It uses the instructions of [`DVector`].
+/// 
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union DMatrix {
+    /// member variables for constant variables.
+    arr: [f64; 16], 
+
+    pub(crate) columns: [DVector; 4], 
+}
+
+impl DMatrix {
+    /// All elements are zeros.
+    pub const ZERO: Self = Self { arr: [0.0; 16] };
+
+    /// Identity matrix.
+    pub const IDENTITY: Self = Self {
+        arr: [
+            1.0, 0.0, 0.0, 0.0, 
+            0.0, 1.0, 0.0, 0.0, 
+            0.0, 0.0, 1.0, 0.0, 
+            0.0, 0.0, 0.0, 1.0
+        ]
+    };
+}
+
+impl DMatrix {
+    /// Creates with given elements.
+    #[allow(clippy::too_many_arguments)]
+    #[inline]
+    #[must_use]
+    pub fn new(
+        m00: f64, m01: f64, m02: f64, m03: f64, 
+        m10: f64, m11: f64, m12: f64, m13: f64, 
+        m20: f64, m21: f64, m22: f64, m23: f64, 
+        m30: f64, m31: f64, m32: f64, m33: f64
+    ) -> Self {
+        Self::from_columns(
+            DVector::new(m00, m01, m02, m03), 
+            DVector::new(m10, m11, m12, m13), 
+            DVector::new(m20, m21, m22, m23), 
+            DVector::new(m30, m31, m32, m33)
+        )
+    }
+
+    /// Creates a diagonal matrix.
+    #[inline]
+    #[must_use]
+    pub fn diagonal(diagonal: DVector) -> Self {
+        Self::from_columns(
+            diagonal * DVector::X, 
+            diagonal * DVector::Y, 
+            diagonal * DVector::Z, 
+            diagonal * DVector::W
+        )
+    }
+
+    /// Creates with given column vectors.
+    #[inline]
+    #[must_use]
+    pub const fn from_columns(
+        x_axis: DVector, 
+        y_axis: DVector, 
+        z_axis: DVector, 
+        w_axis: DVector
+    ) -> Self {
+        Self { columns: [x_axis, y_axis, z_axis, w_axis] }
+    }
+
+    /// Creates from a given array.
+    #[inline]
+    #[must_use]
+    pub fn from_column_array(arr: [f64; 16]) -> Self {
+        Self::from_column_slice(&arr)
+    }
+
+    /// Stores the value in an array.
+    #[inline]
+    #[must_use]
+    pub fn into_column_array(self) -> [f64; 16] {
+        let mut arr = [0.0; 16];
+        for (dst, src) in arr.chunks_exact_mut(4).zip(unsafe { self.columns }) {
+            dst.copy_from_slice(&src.into_array());
+        }
+        arr
+    }
+
+    /// Creates from a given array of slice.
+    /// 
+    /// # Panics
+    /// When the `use-assertion` feature is enabled, it will [`panic!`]
+    /// if the array slice has less than sixteen elements.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn from_column_slice(slice: &[f64]) -> Self {
+        #[cfg(feature = "use-assertion")]
+        assert!(slice.len() >= 16, "The given array slice has less than sixteen elements!");
+        Self { columns: [
+            DVector::from_slice(&slice[0..4]), 
+            DVector::from_slice(&slice[4..8]), 
+            DVector::from_slice(&slice[8..12]), 
+            DVector::from_slice(&slice[12..16])
+        ] }
+    }
+
+    /// Loads a value from a given `Double4x4`.
+    #[inline]
+    #[must_use]
+    pub fn load_double4x4(val: Double4x4) -> Self {
+        Self::from_columns(
+            DVector::load_double4(val.x_axis), 
+            DVector::load_double4(val.y_axis), 
+            DVector::load_double4(val.z_axis), 
+            DVector::load_double4(val.w_axis)
+        )
+    }
+
+    /// Stores the value in a `Double4x4`.
+    #[inline]
+    #[must_use]
+    pub fn store_double4x4(self) -> Double4x4 {
+        Double4x4 {
+            x_axis: self.get_x_axis().store_double4(), 
+            y_axis: self.get_y_axis().store_double4(), 
+            z_axis: self.get_z_axis().store_double4(), 
+            w_axis: self.get_w_axis().store_double4()
+        }
+    }
+
+    /// Converts to a single-precision matrix.
+    /// 
+    /// ※ To keep the precision of large world positions, 
+    /// subtract the camera position with `DMatrix` or `DVector` before converting. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn as_f32(self) -> Matrix {
+        Matrix::from_columns(
+            self.get_x_axis().as_f32(), 
+            self.get_y_axis().as_f32(), 
+            self.get_z_axis().as_f32(), 
+            self.get_w_axis().as_f32()
+        )
+    }
+
+    /// Creates a matrix from a given quaternion.
+    /// 
+    /// # Panics
+    /// When the `use-assertion` feature is enabled, [`panic!`] will be called
+    /// if the quaternion is not a normalized quaternion.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn from_dquaternion(q: DQuaternion) -> Self {
+        q.into_dmatrix()
+    }
+
+    /// Creates a matrix from a given quaternion.
+    /// 
+    /// If the quaternion is not normalized, `None` is returned.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn try_from_dquaternion(q: DQuaternion) -> Option<Self> {
+        q.try_into_dmatrix()
+    }
+
+    /// Converts a matrix to a quaternion.
+    /// 
+    /// # Panics
+    /// When `use-assertion` feature is enabled, [`panic!`] will be called
+    /// if the length of each axis of the matrix is less than or equal to [`f64::EPSILON`].
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn into_dquaternion(self) -> DQuaternion {
+        DQuaternion::from_dmatrix(self)
+    }
+
+    /// Converts a matrix to a quaternion.
+    /// 
+    /// Returns `None` if the length of each axis of the matrix is less than or equal to [`f64::EPSILON`].
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn try_into_dquaternion(self) -> Option<DQuaternion> {
+        DQuaternion::try_from_dmatrix(self)
+    }
+
+    /// Create a matrix with the given `translation`.
+    #[inline]
+    #[must_use]
+    pub fn from_translation(mut translation: DVector) -> Self {
+        translation.set_w(1.0);
+        Self::from_columns(DVector::X, DVector::Y, DVector::Z, translation)
+    }
+
+    /// Creates a matrix with the given `rotation` and `translation`.
+    /// 
+    /// ※ The given `rotation` must be normalized.
+    /// 
+    /// # Panics
+    /// When the `use-assertion` feature is enabled, it will [`panic!`]
+    /// if the given quaternion is not a normalized quaternion.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn from_rotation_translation(
+        rotation: DQuaternion, 
+        mut translation: DVector
+    ) -> Self {
+        translation.set_w(1.0);
+        let (x_axis, y_axis, z_axis) = rotation.to_rotation_axes();
+        Self::from_columns(x_axis, y_axis, z_axis, translation)
+    }
+
+    /// Creates a matrix with the given `scale`, `rotation` and `translation`.
+    /// 
+    /// ※ The given `rotation` must be normalized.
+    /// 
+    /// # Panics
+    /// When the `use-assertion` feature is enabled, it will [`panic!`]
+    /// if the given quaternion is not a normalized quaternion.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn from_scale_rotation_translation(
+        scale: DVector, 
+        rotation: DQuaternion, 
+        mut translation: DVector
+    ) -> Self {
+        translation.set_w(1.0);
+        let (x_axis, y_axis, z_axis) = rotation.to_rotation_axes();
+        Self::from_columns(
+            x_axis * scale.get_x(), 
+            y_axis * scale.get_y(), 
+            z_axis * scale.get_z(), 
+            translation
+        )
+    }
+
+    /// Creates a matrix rotated by a given x-axis angle.
+    /// 
+    /// ※ The angles given are in radians.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn from_rotation_x(angle: f64) -> Self {
+        let (s, c) = angle.sin_cos();
+        Self::from_columns(
+            DVector::X, 
+            DVector::new(0.0, c, s, 0.0), 
+            DVector::new(0.0, -s, c, 0.0), 
+            DVector::W
+        )
+    }
+
+    /// Creates a matrix rotated by a given y-axis angle.
+    /// 
+    /// ※ The angles given are in radians.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn from_rotation_y(angle: f64) -> Self {
+        let (s, c) = angle.sin_cos();
+        Self::from_columns(
+            DVector::new(c, 0.0, -s, 0.0), 
+            DVector::Y, 
+            DVector::new(s, 0.0, c, 0.0), 
+            DVector::W
+        )
+    }
+
+    /// Creates a matrix rotated by a given z-axis angle.
+    /// 
+    /// ※ The angles given are in radians.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn from_rotation_z(angle: f64) -> Self {
+        let (s, c) = angle.sin_cos();
+        Self::from_columns(
+            DVector::new(c, s, 0.0, 0.0), 
+            DVector::new(-s, c, 0.0, 0.0), 
+            DVector::Z, 
+            DVector::W
+        )
+    }
+
+    /// Create a right-handed coordinate view matrix with the given `eye`, `dir`, and `up`.
+    /// 
+    /// ※ The given `dir` and `up` must be unit vectors.
+    /// 
+    /// # Panics
+    /// When the `use-assertion` feature is enabled, it will [`panic!`]
+    /// if the given `dir` and `up` is not unit vectors.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn look_to_rh(eye: DVector, dir: DVector, up: DVector) -> Self {
+        #[cfg(feature = "use-assertion")] {
+            assert!(dir.is_vec3_normalized(), "The given `dir` must be unit vector!");
+            assert!(up.is_vec3_normalized(), "The given `up` must be unit vector!");
+        }
+
+        let look = dir;
+        let right = look.vec3_cross(up).vec3_normalize();
+        let up = right.vec3_cross(look);
+
+        let mut row0 = right;
+        let mut row1 = up;
+        let mut row2 = -look;
+        row0.set_w(-eye.vec3_dot_into(right));
+        row1.set_w(-eye.vec3_dot_into(up));
+        row2.set_w(eye.vec3_dot_into(look));
+
+        Self::from_columns(row0, row1, row2, DVector::W)
+            .transpose()
+    }
+
+    /// Create a left-handed coordinate view matrix with the given `eye`, `dir`, and `up`.
+    /// 
+    /// ※ The given `dir` and `up` must be unit vectors.
+    /// 
+    /// # Panics
+    /// When the `use-assertion` feature is enabled, it will [`panic!`]
+    /// if the given `dir` and `up` is not unit vectors.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn look_to_lh(eye: DVector, dir: DVector, up: DVector) -> Self {
+        Self::look_to_rh(eye, -dir, up)
+    }
+
+    /// Create a right-handed coordinate view matrix with the given `eye`, `at`, and `up`.
+    /// 
+    /// ※ The given position of `eye` and `at` must be different.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn look_at_rh(eye: DVector, at: DVector, up: DVector) -> Self {
+        Self::look_to_rh(eye, (at - eye).vec3_normalize(), up)
+    }
+
+    /// Create a left-handed coordinate view matrix with the given `eye`, `at`, and `up`.
+    /// 
+    /// ※ The given position of `eye` and `at` must be different.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn look_at_lh(eye: DVector, at: DVector, up: DVector) -> Self {
+        Self::look_to_lh(eye, (at - eye).vec3_normalize(), up)
+    }
+}
+
+impl DMatrix {
+    /// Get the x-axis of a matrix.
+    #[inline]
+    #[must_use]
+    pub fn get_x_axis(&self) -> &DVector {
+        unsafe { self.columns.get_unchecked(0) }
+    }
+
+    /// Set the x-axis of a matrix.
+    #[inline]
+    pub fn set_x_axis(&mut self, v: DVector) {
+        unsafe { *self.columns.get_unchecked_mut(0) = v }
+    }
+
+    /// Get the y-axis of a matrix.
+    #[inline]
+    #[must_use]
+    pub fn get_y_axis(&self) -> &DVector {
+        unsafe { self.columns.get_unchecked(1) }
+    }
+
+    /// Set the y-axis of a matrix.
+    #[inline]
+    pub fn set_y_axis(&mut self, v: DVector) {
+        unsafe { *self.columns.get_unchecked_mut(1) = v }
+    }
+
+    /// Get the z-axis of a matrix.
+    #[inline]
+    #[must_use]
+    pub fn get_z_axis(&self) -> &DVector {
+        unsafe { self.columns.get_unchecked(2) }
+    }
+
+    /// Set the z-axis of a matrix.
+    #[inline]
+    pub fn set_z_axis(&mut self, v: DVector) {
+        unsafe { *self.columns.get_unchecked_mut(2) = v }
+    }
+
+    /// Get the w-axis of a matrix.
+    #[inline]
+    #[must_use]
+    pub fn get_w_axis(&self) -> &DVector {
+        unsafe { self.columns.get_unchecked(3) }
+    }
+
+    /// Set the w-axis of a matrix.
+    #[inline]
+    pub fn set_w_axis(&mut self, v: DVector) {
+        unsafe { *self.columns.get_unchecked_mut(3) = v }
+    }
+
+    /// Transpose of a matrix.
+    #[must_use]
+    pub fn transpose(self) -> Self {
+        let m = self.into_column_array();
+        Self::from_column_array([
+            m[0], m[4], m[8], m[12], 
+            m[1], m[5], m[9], m[13], 
+            m[2], m[6], m[10], m[14], 
+            m[3], m[7], m[11], m[15]
+        ])
+    }
+
+    /// Returns the adjugate matrix and the determinant.
+    fn adjugate(self) -> (Self, f64) {
+        // Reference: glm/detail/func_matrix.inl
+        let [
+            m00, m01, m02, m03, 
+            m10, m11, m12, m13, 
+            m20, m21, m22, m23, 
+            m30, m31, m32, m33
+        ] = self.into_column_array();
+
+        let fac0 = DVector::new(m22 * m33 - m32 * m23, m22 * m33 - m32 * m23, m12 * m33 - m32 * m13, m12 * m23 - m22 * m13);
+        let fac1 = DVector::new(m21 * m33 - m31 * m23, m21 * m33 - m31 * m23, m11 * m33 - m31 * m13, m11 * m23 - m21 * m13);
+        let fac2 = DVector::new(m21 * m32 - m31 * m22, m21 * m32 - m31 * m22, m11 * m32 - m31 * m12, m11 * m22 - m21 * m12);
+        let fac3 = DVector::new(m20 * m33 - m30 * m23, m20 * m33 - m30 * m23, m10 * m33 - m30 * m13, m10 * m23 - m20 * m13);
+        let fac4 = DVector::new(m20 * m32 - m30 * m22, m20 * m32 - m30 * m22, m10 * m32 - m30 * m12, m10 * m22 - m20 * m12);
+        let fac5 = DVector::new(m20 * m31 - m30 * m21, m20 * m31 - m30 * m21, m10 * m31 - m30 * m11, m10 * m21 - m20 * m11);
+
+        let vec0 = DVector::new(m10, m00, m00, m00);
+        let vec1 = DVector::new(m11, m01, m01, m01);
+        let vec2 = DVector::new(m12, m02, m02, m02);
+        let vec3 = DVector::new(m13, m03, m03, m03);
+
+        let inv0 = vec1 * fac0 - vec2 * fac1 + vec3 * fac2;
+        let inv1 = vec0 * fac0 - vec2 * fac3 + vec3 * fac4;
+        let inv2 = vec0 * fac1 - vec1 * fac3 + vec3 * fac5;
+        let inv3 = vec0 * fac2 - vec1 * fac4 + vec2 * fac5;
+
+        let one_neg_one_neg = DVector::new(1.0, -1.0, 1.0, -1.0);
+        let neg_one_neg_one = DVector::new(-1.0, 1.0, -1.0, 1.0);
+        let adjugate = Self::from_columns(
+            inv0 * one_neg_one_neg, 
+            inv1 * neg_one_neg_one, 
+            inv2 * one_neg_one_neg, 
+            inv3 * neg_one_neg_one
+        );
+
+        let row0 = DVector::new(
+            adjugate.get_x_axis().get_x(), 
+            adjugate.get_y_axis().get_x(), 
+            adjugate.get_z_axis().get_x(), 
+            adjugate.get_w_axis().get_x()
+        );
+        let det = self.get_x_axis().vec4_dot_into(row0);
+        (adjugate, det)
+    }
+
+    /// Determinant of a matrix.
+    #[inline]
+    #[must_use]
+    pub fn determinant(self) -> DVector {
+        DVector::fill(self.determinant_into())
+    }
+
+    /// Determinant of a matrix.
+    #[inline]
+    #[must_use]
+    pub fn determinant_into(self) -> f64 {
+        self.adjugate().1
+    }
+
+    /// Inverse of a matrix.
+    /// 
+    /// # Panics
+    /// When the `use-assertion` feature is enabled, it will [`panic!`]
+    /// if the absolute value of the determinant is less than or equal to [`f64::EPSILON`].
+    /// 
+    #[must_use]
+    pub fn inverse(self) -> Self {
+        let (adjugate, det) = self.adjugate();
+        #[cfg(feature = "use-assertion")]
+        assert!(det.abs() > f64::EPSILON, "The determinant of the matrix is less than or equal to `f64::EPSILON`!");
+        adjugate * det.recip()
+    }
+
+    /// Inverse of a matrix.
+    /// 
+    /// Returns `None` if the absolute value of the determinant is less than or equal to [`f64::EPSILON`].
+    /// 
+    #[must_use]
+    pub fn try_inverse(self) -> Option<Self> {
+        let (adjugate, det) = self.adjugate();
+        if det.abs() <= f64::EPSILON {
+            return None;
+        }
+        Some(adjugate * det.recip())
+    }
+}
+
+impl DMatrix {
+    /// Returns `true` if the absolute difference of all elements is less than or equal to `max_abs_diff`.
+    #[inline]
+    #[must_use]
+    pub fn abs_diff_eq(self, rhs: Self, max_abs_diff: f64) -> bool {
+        near_eq::abs_diff_eq(&self.into_column_array(), &rhs.into_column_array(), max_abs_diff)
+    }
+
+    /// Returns `true` if all elements are equal within `max_abs_diff` or the relative tolerance `max_relative`.
+    /// 
+    /// ※ The relative tolerance is scaled by the larger absolute value of the two elements. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn relative_eq(self, rhs: Self, max_abs_diff: f64, max_relative: f64) -> bool {
+        near_eq::relative_eq(&self.into_column_array(), &rhs.into_column_array(), max_abs_diff, max_relative)
+    }
+
+    /// Returns `true` if all elements are equal within `max_abs_diff` or `max_ulps` representable values.
+    /// 
+    /// ※ Elements with different signs are equal only within `max_abs_diff`. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn ulps_eq(self, rhs: Self, max_abs_diff: f64, max_ulps: u32) -> bool {
+        near_eq::ulps_eq(&self.into_column_array(), &rhs.into_column_array(), max_abs_diff, max_ulps)
+    }
+}
+
+impl PartialEq for DMatrix {
+    /// Compares all elements exactly.
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.into_column_array() == other.into_column_array()
+    }
+}
+
+impl Default for DMatrix {
+    #[inline]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl From<Double4x4> for DMatrix {
+    #[inline]
+    fn from(value: Double4x4) -> Self {
+        Self::load_double4x4(value)
+    }
+}
+
+impl From<DMatrix> for Double4x4 {
+    #[inline]
+    fn from(value: DMatrix) -> Self {
+        value.store_double4x4()
+    }
+}
+
+impl From<[f64; 16]> for DMatrix {
+    #[inline]
+    fn from(value: [f64; 16]) -> Self {
+        Self::from_column_array(value)
+    }
+}
+
+impl From<DMatrix> for [f64; 16] {
+    #[inline]
+    fn from(value: DMatrix) -> Self {
+        value.into_column_array()
+    }
+}
+
+impl ops::Add<Self> for DMatrix {
+    type Output = Self;
+    /// Adds two matrices.
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::from_columns(
+            *self.get_x_axis() + *rhs.get_x_axis(), 
+            *self.get_y_axis() + *rhs.get_y_axis(), 
+            *self.get_z_axis() + *rhs.get_z_axis(), 
+            *self.get_w_axis() + *rhs.get_w_axis()
+        )
+    }
+}
+
+impl ops::AddAssign<Self> for DMatrix {
+    /// Adds two matrices. (assign)
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+impl ops::Sub<Self> for DMatrix {
+    type Output = Self;
+    /// Subtracts two matrices.
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::from_columns(
+            *self.get_x_axis() - *rhs.get_x_axis(), 
+            *self.get_y_axis() - *rhs.get_y_axis(), 
+            *self.get_z_axis() - *rhs.get_z_axis(), 
+            *self.get_w_axis() - *rhs.get_w_axis()
+        )
+    }
+}
+
+impl ops::SubAssign<Self> for DMatrix {
+    /// Subtracts two matrices. (assign)
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs
+    }
+}
+
+impl ops::Neg for DMatrix {
+    type Output = Self;
+    /// Nagative.
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self::from_columns(
+            -*self.get_x_axis(), 
+            -*self.get_y_axis(), 
+            -*self.get_z_axis(), 
+            -*self.get_w_axis()
+        )
+    }
+}
+
+impl ops::Mul<DMatrix> for f64 {
+    type Output = DMatrix;
+    /// Multiplies each element of a matrix by a scalar value.
+    #[inline]
+    fn mul(self, rhs: DMatrix) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl ops::Mul<f64> for DMatrix {
+    type Output = Self;
+    /// Multiplies each element of a matrix by a scalar value.
+    #[inline]
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self::from_columns(
+            *self.get_x_axis() * rhs, 
+            *self.get_y_axis() * rhs, 
+            *self.get_z_axis() * rhs, 
+            *self.get_w_axis() * rhs
+        )
+    }
+}
+
+impl ops::Mul<DVector> for DMatrix {
+    type Output = DVector;
+    /// Transformation of the vector.
+    #[inline]
+    fn mul(self, rhs: DVector) -> Self::Output {
+        *self.get_x_axis() * rhs.get_x()
+            + *self.get_y_axis() * rhs.get_y()
+            + *self.get_z_axis() * rhs.get_z()
+            + *self.get_w_axis() * rhs.get_w()
+    }
+}
+
+impl ops::Mul<Self> for DMatrix {
+    type Output = Self;
+    /// Multiplies two matrices.
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::from_columns(
+            self * *rhs.get_x_axis(), 
+            self * *rhs.get_y_axis(), 
+            self * *rhs.get_z_axis(), 
+            self * *rhs.get_w_axis()
+        )
+    }
+}
+
+impl ops::MulAssign<Self> for DMatrix {
+    /// Multiplies two matrices. (assign)
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs
+    }
+}
+
+impl fmt::Debug for DMatrix {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple(stringify!(DMatrix))
+            .field(unsafe { &self.columns })
+            .finish()
+    }
+}
@@ -0,0 +1,706 @@
+use core::fmt;
+use core::ops;
+use crate::{ DMatrix, DVector, Quaternion, Double3, Double4 };
+use crate::near_eq;
+
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+
+
+
+/// This is a double-precision quaternion data type built on [`DVector`].
+/// 
+/// It uses the instructions of [`DVector`].
+/// 
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+pub struct DQuaternion {
+    pub(crate) inner: DVector, 
+}
+
+impl DQuaternion {
+    /// All elements are zeros.
+    pub const ZERO: Self = Self { inner: DVector::ZERO };
+
+    /// Identity quaternion.
+    pub const IDENTITY: Self = Self { inner: DVector::W };
+}
+
+impl DQuaternion {
+    /// Creates with given elements.
+    #[inline]
+    #[must_use]
+    pub fn new(x: f64, y: f64, z: f64, w: f64) -> Self {
+        DVector::new(x, y, z, w).into_dquaternion()
+    }
+
+    /// Fills all elements with the given values.
+    #[inline]
+    #[must_use]
+    pub fn fill(v: f64) -> Self {
+        DVector::fill(v).into_dquaternion()
+    }
+
+    /// Creates from a given array.
+    #[inline]
+    #[must_use]
+    pub fn from_array(arr: [f64; 4]) -> Self {
+        DVector::from_array(arr).into_dquaternion()
+    }
+
+    /// Stores the value in an array.
+    #[inline]
+    #[must_use]
+    pub fn into_array(self) -> [f64; 4] {
+        self.into_dvector().into_array()
+    }
+
+    /// Creates from a given array of slice.
+    /// 
+    /// # Panics
+    /// When the `use-assertion` feature is enabled, it will [`panic!`]
+    /// if the array slice has less than four elements.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn from_slice(slice: &[f64]) -> Self {
+        DVector::from_slice(slice).into_dquaternion()
+    }
+
+    /// Loads a value from a given `Double4`.
+    #[inline]
+    #[must_use]
+    pub fn load_double4(val: Double4) -> Self {
+        DVector::load_double4(val).into_dquaternion()
+    }
+
+    /// Stores the value in a `Double4`.
+    #[inline]
+    #[must_use]
+    pub fn store_double4(self) -> Double4 {
+        self.into_dvector().store_double4()
+    }
+
+    /// Creates a quaternion from a given vector.
+    #[inline]
+    #[must_use]
+    pub fn from_dvector(v: DVector) -> Self {
+        Self { inner: v }
+    }
+
+    /// Converts quaternions to vectors.
+    #[inline]
+    #[must_use]
+    pub fn into_dvector(self) -> DVector {
+        self.inner
+    }
+
+    /// Converts to a single-precision quaternion.
+    #[inline]
+    #[must_use]
+    pub fn as_f32(self) -> Quaternion {
+        Quaternion::from_vector(self.into_dvector().as_f32())
+    }
+
+    /// Creates a quaternion rotated by a given x-axis angle.
+    /// 
+    /// ※ The angles given are in radians.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn from_rotation_x(angle: f64) -> Self {
+        let (s, c) = (0.5 * angle).sin_cos();
+        Self::new(s, 0.0, 0.0, c)
+    }
+
+    /// Creates a quaternion rotated by a given y-axis angle.
+    /// 
+    /// ※ The angles given are in radians.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn from_rotation_y(angle: f64) -> Self {
+        let (s, c) = (0.5 * angle).sin_cos();
+        Self::new(0.0, s, 0.0, c)
+    }
+
+    /// Creates a quaternion rotated by a given z-axis angle.
+    /// 
+    /// ※ The angles given are in radians.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn from_rotation_z(angle: f64) -> Self {
+        let (s, c) = (0.5 * angle).sin_cos();
+        Self::new(0.0, 0.0, s, c)
+    }
+
+    /// Creates a quaternion rotated about a given `axis` by a given `angle`.
+    /// 
+    /// ※ The angles given are in radians. </br>
+    /// ※ The given axis must be a unit vector. </br>
+    /// 
+    /// # Panics
+    /// When the `use-assertion` feature is enabled, [`panic!`] will be called
+    /// if the given axis is not a unit vector.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn from_axis_angle(axis: DVector, angle: f64) -> Self {
+        #[cfg(feature = "use-assertion")]
+        assert!(axis.is_vec3_normalized(), "The given axis must be a unit vector!");
+
+        let (s, c) = (0.5 * angle).sin_cos();
+        let mut v = axis * s;
+        v.set_w(c);
+        v.into()
+    }
+
+    /// Create a quaternion from each axis.
+    /// 
+    /// ※ Each axis must be a unit vector.
+    /// 
+    /// # Panics
+    /// If `use-assertion` is enabled
+    /// and the given axis is not a unit vector, it will call [`panic!`].
+    /// 
+    #[must_use]
+    pub fn from_rotation_axes(x_axis: DVector, y_axis: DVector, z_axis: DVector) -> Self {
+        #[cfg(feature = "use-assertion")] {
+            let validate = x_axis.is_vec3_normalized()
+                & y_axis.is_vec3_normalized()
+                & z_axis.is_vec3_normalized();
+            assert!(validate, "The given axis must be a unit vector!");
+        }
+        let x_axis: Double3 = x_axis.into();
+        let y_axis: Double3 = y_axis.into();
+        let z_axis: Double3 = z_axis.into();
+
+        // Reference: DirectXMath/Inc/DirectXMathMise.inl
+        let (m00, m01, m02) = x_axis.into();
+        let (m10, m11, m12) = y_axis.into();
+        let (m20, m21, m22) = z_axis.into();
+        if m22 <= 0.0 {
+            let dif10  = m11 - m00;
+            let omr22 = 1.0 - m22;
+            if dif10 <= 0.0 {
+                let four_x_sqr = omr22 - dif10;
+                let inv4x = 0.5 / four_x_sqr.sqrt();
+                Self::new(
+                    four_x_sqr * inv4x, 
+                    (m01 + m10) * inv4x, 
+                    (m02 + m20) * inv4x, 
+                    (m12 - m21) * inv4x
+                )
+            } else {
+                let four_y_sqr = omr22 + dif10;
+                let inv4y = 0.5 / four_y_sqr.sqrt();
+                Self::new(
+                    (m01 + m10) * inv4y, 
+                    four_y_sqr * inv4y, 
+                    (m12 + m21) * inv4y, 
+                    (m20 - m02) * inv4y
+                )
+            }
+        } else {
+            let sum10 = m11 + m00;
+            let opr22 = 1.0 + m22;
+            if sum10 <= 0.0 {
+                let four_z_sqr = opr22 - sum10;
+                let inv4z = 0.5 / four_z_sqr.sqrt();
+                Self::new(
+                    (m02 + m20) * inv4z, 
+                    (m12 + m21) * inv4z, 
+                    four_z_sqr * inv4z, 
+                    (m01 - m10) * inv4z
+                )
+            } else {
+                let four_w_sqr = opr22 + sum10;
+                let inv4w = 0.5 / four_w_sqr.sqrt();
+                Self::new(
+                    (m12 - m21) * inv4w, 
+                    (m20 - m02) * inv4w, 
+                    (m01 - m10) * inv4w, 
+                    four_w_sqr * inv4w
+                )
+            }
+        }
+    }
+
+    /// Convert quaternions to each axis.
+    /// 
+    /// ※ The quaternion must be a normalized quaternion.
+    /// 
+    /// # Panics
+    /// If `use-assertion` is enabled
+    /// and the given quaternion is not a normalized quaternion, it will call [`panic!`].
+    /// 
+    #[must_use]
+    pub fn to_rotation_axes(self) -> (DVector, DVector, DVector) {
+        #[cfg(feature = "use-assertion")]
+        assert!(self.is_normalized(), "The quaternion must be normalized!");
+
+        let quat: Double4 = self.into();
+        let x2 = quat.x + quat.x;
+        let y2 = quat.y + quat.y;
+        let z2 = quat.z + quat.z;
+        let xx = quat.x * x2;
+        let xy = quat.x * y2;
+        let xz = quat.x * z2;
+        let yy = quat.y * y2;
+        let yz = quat.y * z2;
+        let zz = quat.z * z2;
+        let wx = quat.w * x2;
+        let wy = quat.w * y2;
+        let wz = quat.w * z2;
+
+        let x_axis = DVector::new(1.0 - (yy + zz), xy + wz, xz - wy, 0.0);
+        let y_axis = DVector::new(xy - wz, 1.0 - (xx + zz), yz + wx, 0.0);
+        let z_axis = DVector::new(xz + wy, yz - wx, 1.0 - (xx + yy), 0.0);
+
+        (x_axis, y_axis, z_axis)
+    }
+
+    /// Creates from a given matrix.
+    /// 
+    /// # Panics
+    /// When `use-assertion` feature is enabled, [`panic!`] will be called
+    /// if the length of each axis of the matrix is less than or equal to [`f64::EPSILON`].
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn from_dmatrix(matrix: DMatrix) -> Self {
+        Self::from_rotation_axes(
+            matrix.get_x_axis().vec3_normalize(), 
+            matrix.get_y_axis().vec3_normalize(), 
+            matrix.get_z_axis().vec3_normalize()
+        )
+    }
+
+    /// Creates from a given matrix.
+    /// 
+    /// Returns `None` if the length of each axis of the matrix is less than or equal to [`f64::EPSILON`].
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn try_from_dmatrix(matrix: DMatrix) -> Option<Self> {
+        let x_axis = matrix.get_x_axis().try_vec3_normalize()?;
+        let y_axis = matrix.get_y_axis().try_vec3_normalize()?;
+        let z_axis = matrix.get_z_axis().try_vec3_normalize()?;
+        Some(Self::from_rotation_axes(x_axis, y_axis, z_axis))
+    }
+
+    /// Stores the value in a matrix.
+    /// 
+    /// # Panics
+    /// When the `use-assertion` feature is enabled, [`panic!`] will be called
+    /// if the quaternion is not a normalized quaternion.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn into_dmatrix(self) -> DMatrix {
+        let (x_axis, y_axis, z_axis) = self.to_rotation_axes();
+        DMatrix::from_columns(x_axis, y_axis, z_axis, DVector::W)
+    }
+
+    /// Stores the value in a matrix.
+    /// 
+    /// Returns `None` if the quaternion is not a normalized quaternion.
+    /// 
+    #[must_use]
+    pub fn try_into_dmatrix(self) -> Option<DMatrix> {
+        if !self.is_normalized() {
+            return None;
+        }
+        Some(self.into_dmatrix())
+    }
+}
+
+impl DQuaternion {
+    /// Get the `x` element of a quaternion.
+    #[inline]
+    #[must_use]
+    pub fn get_x(&self) -> f64 {
+        self.into_dvector().get_x()
+    }
+
+    /// Set the `x` element of a quaternion.
+    #[inline]
+    pub fn set_x(&mut self, v: f64) {
+        let mut vector = self.into_dvector();
+        vector.set_x(v);
+        *self = vector.into_dquaternion()
+    }
+
+    /// Get the `y` element of a quaternion.
+    #[inline]
+    #[must_use]
+    pub fn get_y(&self) -> f64 {
+        self.into_dvector().get_y()
+    }
+
+    /// Set the `y` element of a quaternion.
+    #[inline]
+    pub fn set_y(&mut self, v: f64) {
+        let mut vector = self.into_dvector();
+        vector.set_y(v);
+        *self = vector.into_dquaternion()
+    }
+
+    /// Get the `z` element of a quaternion.
+    #[inline]
+    #[must_use]
+    pub fn get_z(&self) -> f64 {
+        self.into_dvector().get_z()
+    }
+
+    /// Set the `z` element of a quaternion.
+    #[inline]
+    pub fn set_z(&mut self, v: f64) {
+        let mut vector = self.into_dvector();
+        vector.set_z(v);
+        *self = vector.into_dquaternion()
+    }
+
+    /// Get the `w` element of a quaternion.
+    #[inline]
+    #[must_use]
+    pub fn get_w(&self) -> f64 {
+        self.into_dvector().get_w()
+    }
+
+    /// Set the `w` element of a quaternion.
+    #[inline]
+    pub fn set_w(&mut self, v: f64) {
+        let mut vector = self.into_dvector();
+        vector.set_w(v);
+        *self = vector.into_dquaternion()
+    }
+
+    /// Returns a vector filled with the dot products of the quaternions.
+    #[inline]
+    #[must_use]
+    pub fn dot(self, rhs: Self) -> DVector {
+        self.into_dvector().vec4_dot(rhs.into_dvector())
+    }
+
+    /// Dot product of the quaternions.
+    #[inline]
+    #[must_use]
+    pub fn dot_into(self, rhs: Self) -> f64 {
+        self.into_dvector().vec4_dot_into(rhs.into_dvector())
+    }
+
+    /// Length squared of a quaternion.
+    #[inline]
+    #[must_use]
+    pub fn len_sq(self) -> f64 {
+        self.dot_into(self)
+    }
+
+    /// Length of a quaternion.
+    #[inline]
+    #[must_use]
+    pub fn len(self) -> f64 {
+        self.len_sq().sqrt()
+    }
+
+    /// Returns `true` if it is a normalized quaternion.
+    /// 
+    /// ※ The length is compared with a tolerance of `1e-12`, since [`f64::EPSILON`]
+    /// is smaller than the rounding error of the length calculation. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn is_normalized(self) -> bool {
+        (self.len() - 1.0).abs() <= 1e-12
+    }
+
+    /// Normalizes a quaternion.
+    /// 
+    /// Undefined behavior may occur if the length of the quaternion is less than or equal to [`f64::EPSILON`].
+    /// 
+    /// # Panics
+    /// When `use-assertion` is enabled, [`panic!`] will be called
+    /// if the length of the quaternion is less than or equal to [`f64::EPSILON`].
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn normalize(self) -> Self {
+        #[cfg(feature = "use-assertion")]
+        assert!(self.len() > f64::EPSILON, "The length of the quaternion is less than or equal to `f64::EPSILON`!");
+        self * self.len().recip()
+    }
+
+    /// Normalizes a quaternion.
+    /// 
+    /// Returns `None` if the length of the quaternion is less than or equal to [`f64::EPSILON`].
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn try_normalize(self) -> Option<Self> {
+        let length = self.len();
+        if length <= f64::EPSILON {
+            return None;
+        }
+        Some(self * length.recip())
+    }
+
+    /// Returns the conjugate of the quaternion.
+    #[inline]
+    #[must_use]
+    pub fn conjugate(self) -> Self {
+        (self.into_dvector() * DVector::new(-1.0, -1.0, -1.0, 1.0)).into_dquaternion()
+    }
+
+    /// Returns the inverse of the quaternion.
+    /// 
+    /// Undefined behavior may occur if the length of the quaternion is less than or equal to [`f64::EPSILON`].
+    /// 
+    /// # Panics
+    /// When `use-assertion` feature is enabled, [`panic!`] will be called
+    /// if the length of the quaternion is less than or equal to [`f64::EPSILON`].
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn inverse(self) -> Self {
+        self.normalize().conjugate()
+    }
+
+    /// Returns the inverse of the quaternion.
+    /// 
+    /// If the quaternion cannot be normalized, `None` is returned.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn try_inverse(self) -> Option<Self> {
+        self.try_normalize().map(|q| q.conjugate())
+    }
+
+    /// Returns a vector rotated by a quaternion.
+    /// 
+    /// # Panics
+    /// When `use-assertion` feature is enabled, [`panic!`] will be called
+    /// if the quaternion is not normalized.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn transform_vector(self, v: DVector) -> DVector {
+        (self * v.into_dquaternion() * self.inverse()).into_dvector()
+    }
+
+    /// Returns a quaternion that is a linear interpolation of two quaternion.
+    /// 
+    /// The given `t` must be in the range zero to one.
+    /// 
+    /// The closer `t` is to one, the more it becomes equal to the given `rhs`.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn lerp(self, rhs: Self, t: f64) -> Self {
+        self * (1.0 - t) + rhs * t
+    }
+
+    /// Returns a quaternion that is a normalized linear interpolation of two quaternions.
+    /// 
+    /// The given `t` must be in the range zero to one.
+    /// 
+    /// The closer `t` is to one, the more it becomes equal to the given `rhs`.
+    /// 
+    /// ※ It takes the shortest path, so the sign of `rhs` does not affect the rotation. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn nlerp(self, rhs: Self, t: f64) -> Self {
+        let rhs = match self.dot_into(rhs) < 0.0 {
+            true => rhs * -1.0, 
+            false => rhs
+        };
+        self.lerp(rhs, t).normalize()
+    }
+
+    /// Returns a quaternion that is a spherical linear interpolation of two quaternions.
+    /// 
+    /// The given `t` must be in the range zero to one.
+    /// 
+    /// The closer `t` is to one, the more it becomes equal to the given `rhs`.
+    /// 
+    /// ※ The given quaternions must be normalized. </br>
+    /// ※ It takes the shortest path, so the sign of `rhs` does not affect the rotation. </br>
+    /// ※ If the angle between the two quaternions is very small, [`DQuaternion::nlerp`] is used instead. </br>
+    /// 
+    #[must_use]
+    pub fn slerp(self, rhs: Self, t: f64) -> Self {
+        const DOT_THRESHOLD: f64 = 1.0 - f64::EPSILON;
+        let (rhs, dot) = match self.dot_into(rhs) {
+            dot if dot < 0.0 => (rhs * -1.0, -dot), 
+            dot => (rhs, dot)
+        };
+
+        if dot > DOT_THRESHOLD {
+            return self.lerp(rhs, t).normalize();
+        }
+
+        let theta = dot.acos();
+        (self * (theta * (1.0 - t)).sin() + rhs * (theta * t).sin()) * theta.sin().recip()
+    }
+}
+
+impl DQuaternion {
+    /// Returns `true` if the absolute difference of all elements is less than or equal to `max_abs_diff`.
+    #[inline]
+    #[must_use]
+    pub fn abs_diff_eq(self, rhs: Self, max_abs_diff: f64) -> bool {
+        near_eq::abs_diff_eq(&self.into_array(), &rhs.into_array(), max_abs_diff)
+    }
+
+    /// Returns `true` if all elements are equal within `max_abs_diff` or the relative tolerance `max_relative`.
+    /// 
+    /// ※ The relative tolerance is scaled by the larger absolute value of the two elements. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn relative_eq(self, rhs: Self, max_abs_diff: f64, max_relative: f64) -> bool {
+        near_eq::relative_eq(&self.into_array(), &rhs.into_array(), max_abs_diff, max_relative)
+    }
+
+    /// Returns `true` if all elements are equal within `max_abs_diff` or `max_ulps` representable values.
+    /// 
+    /// ※ Elements with different signs are equal only within `max_abs_diff`. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn ulps_eq(self, rhs: Self, max_abs_diff: f64, max_ulps: u32) -> bool {
+        near_eq::ulps_eq(&self.into_array(), &rhs.into_array(), max_abs_diff, max_ulps)
+    }
+}
+
+impl PartialEq for DQuaternion {
+    /// Compares all elements exactly.
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.into_array() == other.into_array()
+    }
+}
+
+impl Default for DQuaternion {
+    #[inline]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl From<[f64; 4]> for DQuaternion {
+    #[inline]
+    fn from(value: [f64; 4]) -> Self {
+        Self::from_array(value)
+    }
+}
+
+impl From<DQuaternion> for [f64; 4] {
+    #[inline]
+    fn from(value: DQuaternion) -> Self {
+        value.into_array()
+    }
+}
+
+impl From<DVector> for DQuaternion {
+    #[inline]
+    fn from(value: DVector) -> Self {
+        Self::from_dvector(value)
+    }
+}
+
+impl From<DQuaternion> for DVector {
+    #[inline]
+    fn from(value: DQuaternion) -> Self {
+        value.into_dvector()
+    }
+}
+
+impl From<Double4> for DQuaternion {
+    #[inline]
+    fn from(value: Double4) -> Self {
+        Self::load_double4(value)
+    }
+}
+
+impl From<DQuaternion> for Double4 {
+    #[inline]
+    fn from(value: DQuaternion) -> Self {
+        value.store_double4()
+    }
+}
+
+impl ops::Add<Self> for DQuaternion {
+    type Output = Self;
+    /// Element-wise addition of two quaternions.
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        (self.into_dvector() + rhs.into_dvector()).into_dquaternion()
+    }
+}
+
+impl ops::AddAssign<Self> for DQuaternion {
+    /// Element-wise addition of two quaternions.
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+impl ops::Mul<DQuaternion> for f64 {
+    type Output = DQuaternion;
+    /// Scalar multiplication of a quaternion.
+    #[inline]
+    fn mul(self, rhs: DQuaternion) -> Self::Output {
+        (self * rhs.into_dvector()).into_dquaternion()
+    }
+}
+
+impl ops::Mul<f64> for DQuaternion {
+    type Output = Self;
+    /// Scalar multiplication of a quaternion.
+    #[inline]
+    fn mul(self, rhs: f64) -> Self::Output {
+        (self.into_dvector() * rhs).into_dquaternion()
+    }
+}
+
+impl ops::Mul<Self> for DQuaternion {
+    type Output = Self;
+    /// Multiplies two quaternions.
+    fn mul(self, rhs: Self) -> Self::Output {
+        // self: a, rhs: b
+        // i: aw*bx + ax*bw + ay*bz - az*by
+        // j: aw*by - ax*bz + ay*bw + az*bx
+        // k: aw*bz + ax*by - ay*bx + az*bw
+        // w: aw*bw - ax*bx - ay*by - az*bz
+        //
+        let a: Double4 = self.into();
+        let b: Double4 = rhs.into();
+        Self::new(
+            a.w * b.x + a.x * b.w + a.y * b.z - a.z * b.y, 
+            a.w * b.y - a.x * b.z + a.y * b.w + a.z * b.x, 
+            a.w * b.z + a.x * b.y - a.y * b.x + a.z * b.w, 
+            a.w * b.w - a.x * b.x - a.y * b.y - a.z * b.z
+        )
+    }
+}
+
+impl ops::MulAssign<Self> for DQuaternion {
+    /// Multiplies two quaternions. (assign)
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs
+    }
+}
+
+impl fmt::Debug for DQuaternion {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple(stringify!(DQuaternion))
+            .field(&self.into_array())
+            .finish()
+    }
+}
@@ -0,0 +1,67 @@
+/// The order of rotations used by Euler angles.
+///
+/// ※ All orders are intrinsic, so each rotation is applied about the axes of the rotated frame. </br>
+/// ※ For example, [`EulerRot::XYZ`] rotates about the x-axis, then the new y-axis, then the new z-axis. </br>
+/// ※ The first six orders are Tait-Bryan angles and the last six orders are proper Euler angles. </br>
+///
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EulerRot {
+    /// Rotates about the x-axis, then the y-axis, then the z-axis.
+    XYZ,
+    /// Rotates about the x-axis, then the z-axis, then the y-axis.
+    XZY,
+    /// Rotates about the y-axis, then the x-axis, then the z-axis.
+    YXZ,
+    /// Rotates about the y-axis, then the z-axis, then the x-axis.
+    YZX,
+    /// Rotates about the z-axis, then the x-axis, then the y-axis.
+    ZXY,
+    /// Rotates about the z-axis, then the y-axis, then the x-axis.
+    ZYX,
+    /// Rotates about the x-axis, then the y-axis, then the x-axis.
+    XYX,
+    /// Rotates about the x-axis, then the z-axis, then the x-axis.
+    XZX,
+    /// Rotates about the y-axis, then the x-axis, then the y-axis.
+    YXY,
+    /// Rotates about the y-axis, then the z-axis, then the y-axis.
+    YZY,
+    /// Rotates about the z-axis, then the x-axis, then the z-axis.
+    ZXZ,
+    /// Rotates about the z-axis, then the y-axis, then the z-axis.
+    ZYZ,
+}
+
+impl EulerRot {
+    /// Returns the indices of the axes in the order of rotation.
+    ///
+    /// ※ `0`, `1` and `2` are the x-axis, y-axis and z-axis respectively. </br>
+    ///
+    #[inline]
+    #[must_use]
+    pub const fn axes(self) -> [usize; 3] {
+        match self {
+            Self::XYZ => [0, 1, 2],
+            Self::XZY => [0, 2, 1],
+            Self::YXZ => [1, 0, 2],
+            Self::YZX => [1, 2, 0],
+            Self::ZXY => [2, 0, 1],
+            Self::ZYX => [2, 1, 0],
+            Self::XYX => [0, 1, 0],
+            Self::XZX => [0, 2, 0],
+            Self::YXY => [1, 0, 1],
+            Self::YZY => [1, 2, 1],
+            Self::ZXZ => [2, 0, 2],
+            Self::ZYZ => [2, 1, 2],
+        }
+    }
+
+    /// Returns `true` if the first and last axes are the same. (proper Euler angles)
+    #[inline]
+    #[must_use]
+    pub const fn is_proper_euler(self) -> bool {
+        let axes = self.axes();
+        axes[0] == axes[2]
+    }
+}
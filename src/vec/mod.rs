@@ -11,6 +11,27 @@
 //! - `aarch64` - Supports SIMD operations using `neon`.
 //! - `x86`, `x86_64` - Supports SIMD operations using `sse2`.
 //! 
+//! The `sse2` backend uses `sse3`, `ssse3` and `sse4.1` instructions when the target features are enabled.
+//! The results are identical to the `sse2` instructions.
+//! 
+//! The `sse2` backend also uses `fma` instructions when the target feature is enabled.
+//! A fused multiply-add is rounded only once, so the results can differ slightly from the `sse2` instructions.
+//! 
+
+mod depth_range;
+pub use self::depth_range::DepthRange;
+
+mod euler;
+pub use self::euler::EulerRot;
+
+mod affine3;
+pub use self::affine3::Affine3;
+
+mod dmatrix;
+pub use self::dmatrix::DMatrix;
+
+mod dquaternion;
+pub use self::dquaternion::DQuaternion;
 
 #[cfg(any(feature = "scalar-math", not(any(target_feature = "neon", target_feature = "sse2"))))]
 mod scalar;
@@ -29,3 +50,5 @@ mod sse2;
 
 #[cfg(all(target_feature = "sse2", not(feature = "scalar-math")))]
 pub use self::sse2::*;
+
+mod swizzle;
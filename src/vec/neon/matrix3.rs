@@ -0,0 +1,661 @@
+use core::fmt;
+use core::ops;
+use core::arch::aarch64::*;
+use crate::{ EulerRot, Vector, Matrix, Quaternion, Float3x3 };
+use crate::near_eq;
+
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+
+
+
+/// This is a 3x3 matrix data type that uses the `SIMD` instruction.
+/// 
+/// Using the `arm neon` instruction.
+/// 
+/// ※ Each column is stored in a vector and the w element of each column is always zero. </br>
+/// 
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union Matrix3 {
+    /// member variables for constant variables.
+    arr: [f32; 12], 
+
+    pub(crate) columns: [Vector; 3], 
+
+    pub(crate) inner: float32x4x3_t, 
+}
+
+impl Matrix3 {
+    /// All elements are zeros.
+    pub const ZERO: Self = Self { arr: [0.0; 12] };
+
+    /// Identity matrix.
+    pub const IDENTITY: Self = Self { 
+        arr: [
+            1.0, 0.0, 0.0, 0.0, 
+            0.0, 1.0, 0.0, 0.0, 
+            0.0, 0.0, 1.0, 0.0 
+        ] 
+    };
+}
+
+impl Matrix3 {
+    /// Creates with given elements.
+    #[allow(clippy::too_many_arguments)]
+    #[inline]
+    #[must_use]
+    pub fn new(
+        m00: f32, m01: f32, m02: f32, 
+        m10: f32, m11: f32, m12: f32, 
+        m20: f32, m21: f32, m22: f32 
+    ) -> Self {
+        Self::from_columns(
+            Vector::new(m00, m01, m02, 0.0), 
+            Vector::new(m10, m11, m12, 0.0), 
+            Vector::new(m20, m21, m22, 0.0) 
+        )
+    }
+
+    /// Creates a diagonal matrix.
+    #[inline]
+    #[must_use]
+    pub fn diagonal(diagonal: Vector) -> Self {
+        Self::new(
+            diagonal.get_x(), 0.0, 0.0, 
+            0.0, diagonal.get_y(), 0.0, 
+            0.0, 0.0, diagonal.get_z() 
+        )
+    }
+
+    /// Creates with given column vectors.
+    /// 
+    /// ※ The w element of each column vector is set to zero. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn from_columns(
+        mut x_axis: Vector, 
+        mut y_axis: Vector, 
+        mut z_axis: Vector 
+    ) -> Self {
+        x_axis.set_w(0.0);
+        y_axis.set_w(0.0);
+        z_axis.set_w(0.0);
+        Self { columns: [x_axis, y_axis, z_axis] }
+    }
+
+    /// Creates from a given array.
+    #[inline]
+    #[must_use]
+    pub fn from_column_array(arr: [f32; 9]) -> Self {
+        Self::from_column_slice(&arr)
+    }
+
+    /// Stores the value in an array.
+    #[inline]
+    #[must_use]
+    pub fn into_column_array(self) -> [f32; 9] {
+        self.store_float3x3().into()
+    }
+
+    /// Creates from a given array of slice.
+    /// 
+    /// # Panics
+    /// When the `use-assertion` feature is enabled, it will [`panic!`]
+    /// if the array slice has less than nine elements.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn from_column_slice(slice: &[f32]) -> Self {
+        #[cfg(feature = "use-assertion")]
+        assert!(slice.len() >= 9, "The given array slice has less than nine elements!");
+        Self::new(
+            slice[0], slice[1], slice[2], 
+            slice[3], slice[4], slice[5], 
+            slice[6], slice[7], slice[8] 
+        )
+    }
+
+    /// Loads a value from a given `Float3x3`.
+    #[inline]
+    #[must_use]
+    pub fn load_float3x3(val: Float3x3) -> Self {
+        Self::from_columns(
+            Vector::load_float3(val.x_axis), 
+            Vector::load_float3(val.y_axis), 
+            Vector::load_float3(val.z_axis) 
+        )
+    }
+
+    /// Stores the value in a `Float3x3`.
+    #[inline]
+    #[must_use]
+    pub fn store_float3x3(self) -> Float3x3 {
+        Float3x3 {
+            x_axis: unsafe { self.columns[0].store_float3() }, 
+            y_axis: unsafe { self.columns[1].store_float3() }, 
+            z_axis: unsafe { self.columns[2].store_float3() }, 
+        }
+    }
+
+    /// Creates from the upper-left 3x3 elements of a given `Matrix`.
+    #[inline]
+    #[must_use]
+    pub fn from_matrix(m: Matrix) -> Self {
+        Self::from_columns(*m.get_x_axis(), *m.get_y_axis(), *m.get_z_axis())
+    }
+
+    /// Converts to a `Matrix`. 
+    /// 
+    /// ※ The w-axis of the matrix is set to [`Vector::W`]. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn into_matrix(self) -> Matrix {
+        Matrix::from_columns(*self.get_x_axis(), *self.get_y_axis(), *self.get_z_axis(), Vector::W)
+    }
+
+    /// Creates a matrix from a given quaternion.
+    /// 
+    /// # Panics
+    /// When the `use-assertion` feature is enabled, [`panic!`] will be called 
+    /// if the quaternion is not a normalized quaternion.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn from_quaternion(q: Quaternion) -> Self {
+        let (x_axis, y_axis, z_axis) = q.to_rotation_axes();
+        Self::from_columns(x_axis, y_axis, z_axis)
+    }
+
+    /// Creates a matrix from a given quaternion.
+    /// 
+    /// If the quaternion is not normalized, `None` is returned.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn try_from_quaternion(q: Quaternion) -> Option<Self> {
+        match q.is_normalized() {
+            true => Some(Self::from_quaternion(q)), 
+            false => None
+        }
+    }
+
+    /// Converts a matrix to a quaternion.
+    /// 
+    /// # Panics
+    /// When `use-assertion` feature is enabled, [`panic!`] will be called 
+    /// if the length of each axis of the matrix is less than or equal to [`f32::EPSILON`].
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn into_quaternion(self) -> Quaternion {
+        Quaternion::from_rotation_axes(
+            self.get_x_axis().vec3_normalize(), 
+            self.get_y_axis().vec3_normalize(), 
+            self.get_z_axis().vec3_normalize() 
+        )
+    }
+
+    /// Converts a matrix to a quaternion.
+    /// 
+    /// Returns `None` if the length of each axis of the matrix is less than or equal to [`f32::EPSILON`].
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn try_into_quaternion(self) -> Option<Quaternion> {
+        self.get_x_axis().try_vec3_normalize()
+            .and_then(|x_axis| self.get_y_axis().try_vec3_normalize()
+                .and_then(|y_axis| self.get_z_axis().try_vec3_normalize()
+                    .map(|z_axis| Quaternion::from_rotation_axes(x_axis, y_axis, z_axis))
+                )
+            )
+    }
+
+    /// Creates a rotation matrix from the given Euler angles.
+    /// 
+    /// ※ The angles given are in radians. </br>
+    /// ※ The rotations are intrinsic and applied in the given `order`. (See [`Quaternion::from_euler`]) </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn from_euler(order: EulerRot, a: f32, b: f32, c: f32) -> Self {
+        Self::from_quaternion(Quaternion::from_euler(order, a, b, c))
+    }
+
+    /// Converts the rotation of the matrix to Euler angles in the given `order`.
+    /// 
+    /// ※ The scale of each axis is removed before converting. </br>
+    /// ※ The range of the angles and the gimbal lock are handled in the same way as [`Quaternion::to_euler`]. </br>
+    /// 
+    /// # Panics
+    /// When `use-assertion` feature is enabled, [`panic!`] will be called 
+    /// if the length of each axis of the matrix is less than or equal to [`f32::EPSILON`].
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn to_euler_angles(self, order: EulerRot) -> (f32, f32, f32) {
+        self.into_quaternion().to_euler(order)
+    }
+
+    /// Creates a matrix rotated by a given angle about a given axis.
+    /// 
+    /// ※ The angles given are in radians. </br>
+    /// ※ The given `axis` must be a unit vector. </br>
+    /// 
+    /// # Panics
+    /// When the `use-assertion` feature is enabled, [`panic!`] will be called
+    /// if the given axis is not a unit vector.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn from_axis_angle(axis: Vector, angle: f32) -> Self {
+        Self::from_quaternion(Quaternion::from_axis_angle(axis, angle))
+    }
+
+    /// Creates a matrix rotated by a given x-axis angle.
+    /// 
+    /// ※ The angles given are in radians.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn from_rotation_x(angle: f32) -> Self {
+        let (s, c) = angle.sin_cos();
+        Self::from_columns(
+            Vector::X, 
+            Vector::new(0.0, c, s, 0.0), 
+            Vector::new(0.0, -s, c, 0.0) 
+        )
+    }
+
+    /// Creates a matrix rotated by a given y-axis angle.
+    /// 
+    /// ※ The angles given are in radians.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn from_rotation_y(angle: f32) -> Self {
+        let (s, c) = angle.sin_cos();
+        Self::from_columns(
+            Vector::new(c, 0.0, -s, 0.0), 
+            Vector::Y, 
+            Vector::new(s, 0.0, c, 0.0) 
+        )
+    }
+
+    /// Creates a matrix rotated by a given z-axis angle.
+    /// 
+    /// ※ The angles given are in radians.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn from_rotation_z(angle: f32) -> Self {
+        let (s, c) = angle.sin_cos();
+        Self::from_columns(
+            Vector::new(c, s, 0.0, 0.0), 
+            Vector::new(-s, c, 0.0, 0.0), 
+            Vector::Z 
+        )
+    }
+}
+
+impl Matrix3 {
+    /// Get the x-axis of a matrix.
+    #[inline]
+    #[must_use]
+    pub fn get_x_axis(&self) -> &Vector {
+        unsafe { self.columns.get_unchecked(0) }
+    }
+
+    /// Set the x-axis of a matrix.
+    /// 
+    /// ※ The w element of the given vector is set to zero. </br>
+    /// 
+    #[inline]
+    pub fn set_x_axis(&mut self, mut v: Vector) {
+        v.set_w(0.0);
+        unsafe { *self.columns.get_unchecked_mut(0) = v }
+    }
+
+    /// Get the y-axis of a matrix.
+    #[inline]
+    #[must_use]
+    pub fn get_y_axis(&self) -> &Vector {
+        unsafe { self.columns.get_unchecked(1) }
+    }
+
+    /// Set the y-axis of a matrix.
+    /// 
+    /// ※ The w element of the given vector is set to zero. </br>
+    /// 
+    #[inline]
+    pub fn set_y_axis(&mut self, mut v: Vector) {
+        v.set_w(0.0);
+        unsafe { *self.columns.get_unchecked_mut(1) = v }
+    }
+
+    /// Get the z-axis of a matrix.
+    #[inline]
+    #[must_use]
+    pub fn get_z_axis(&self) -> &Vector {
+        unsafe { self.columns.get_unchecked(2) }
+    }
+
+    /// Set the z-axis of a matrix.
+    /// 
+    /// ※ The w element of the given vector is set to zero. </br>
+    /// 
+    #[inline]
+    pub fn set_z_axis(&mut self, mut v: Vector) {
+        v.set_w(0.0);
+        unsafe { *self.columns.get_unchecked_mut(2) = v }
+    }
+
+    /// Transpose of a matrix.
+    #[must_use]
+    pub fn transpose(self) -> Self {
+        // Origin:
+        // m00 m01 m02 
+        // m10 m11 m12 
+        // m20 m21 m22 
+        // 
+        unsafe {
+            let zero = vdupq_n_f32(0.0);
+            let m00_m10_m01_m11 = vzip1q_f32(self.inner.0, self.inner.1);
+            let m20_0_m21_0 = vzip1q_f32(self.inner.2, zero);
+            let m02_m12_0_0 = vzip2q_f32(self.inner.0, self.inner.1);
+            let m22_0_0_0 = vzip2q_f32(self.inner.2, zero);
+
+            let col0 = vcombine_f32(vget_low_f32(m00_m10_m01_m11), vget_low_f32(m20_0_m21_0));
+            let col1 = vcombine_f32(vget_high_f32(m00_m10_m01_m11), vget_high_f32(m20_0_m21_0));
+            let col2 = vcombine_f32(vget_low_f32(m02_m12_0_0), vget_low_f32(m22_0_0_0));
+
+            Matrix3 { columns: [
+                Vector { inner: col0 }, 
+                Vector { inner: col1 }, 
+                Vector { inner: col2 }
+            ] }
+        }
+    }
+
+    /// Determinant of a matrix.
+    /// 
+    /// ※ All elements of the returned vector are the determinant. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn determinant(self) -> Vector {
+        let x_axis = *self.get_x_axis();
+        let y_axis = *self.get_y_axis();
+        let z_axis = *self.get_z_axis();
+        z_axis.vec3_dot(x_axis.vec3_cross(y_axis))
+    }
+
+    /// Determinant of a matrix.
+    #[inline]
+    #[must_use]
+    pub fn determinant_into(self) -> f32 {
+        self.determinant().get_x()
+    }
+
+    /// Inverse of a matrix.
+    /// 
+    /// # Panics
+    /// When the `use-assertion` feature is enabled, it will [`panic!`]
+    /// if the absolute value of the determinant of a matrix is less than or equal to [`f32::EPSILON`].
+    /// 
+    #[must_use]
+    pub fn inverse(self) -> Self {
+        let x_axis = *self.get_x_axis();
+        let y_axis = *self.get_y_axis();
+        let z_axis = *self.get_z_axis();
+
+        let tmp0 = y_axis.vec3_cross(z_axis);
+        let tmp1 = z_axis.vec3_cross(x_axis);
+        let tmp2 = x_axis.vec3_cross(y_axis);
+        let det = z_axis.vec3_dot(tmp2);
+
+        #[cfg(feature = "use-assertion")]
+        assert!(det.get_x().abs() > f32::EPSILON, "The determinant of a matrix is less than or equal to `f32::EPSILON`!");
+
+        let recip_det = det.recip();
+        Self { columns: [tmp0 * recip_det, tmp1 * recip_det, tmp2 * recip_det] }.transpose()
+    }
+
+    /// Inverse of a matrix.
+    /// 
+    /// Returns `None` if the absolute value of the determinant of a matrix is less than or equal to [`f32::EPSILON`].
+    /// 
+    #[must_use]
+    pub fn try_inverse(self) -> Option<Self> {
+        if self.determinant_into().abs() <= f32::EPSILON {
+            return None;
+        }
+        Some(self.inverse())
+    }
+}
+
+impl Matrix3 {
+    /// Returns `true` if the absolute difference of all elements is less than or equal to `max_abs_diff`.
+    #[inline]
+    #[must_use]
+    pub fn abs_diff_eq(self, rhs: Self, max_abs_diff: f32) -> bool {
+        near_eq::abs_diff_eq(&self.into_column_array(), &rhs.into_column_array(), max_abs_diff)
+    }
+
+    /// Returns `true` if all elements are equal within `max_abs_diff` or the relative tolerance `max_relative`.
+    /// 
+    /// ※ The relative tolerance is scaled by the larger absolute value of the two elements. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn relative_eq(self, rhs: Self, max_abs_diff: f32, max_relative: f32) -> bool {
+        near_eq::relative_eq(&self.into_column_array(), &rhs.into_column_array(), max_abs_diff, max_relative)
+    }
+
+    /// Returns `true` if all elements are equal within `max_abs_diff` or `max_ulps` representable values.
+    /// 
+    /// ※ Elements with different signs are equal only within `max_abs_diff`. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn ulps_eq(self, rhs: Self, max_abs_diff: f32, max_ulps: u32) -> bool {
+        near_eq::ulps_eq(&self.into_column_array(), &rhs.into_column_array(), max_abs_diff, max_ulps)
+    }
+}
+
+impl PartialEq for Matrix3 {
+    /// Compares all elements exactly.
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.into_column_array() == other.into_column_array()
+    }
+}
+
+impl Default for Matrix3 {
+    #[inline]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl From<Float3x3> for Matrix3 {
+    #[inline]
+    fn from(value: Float3x3) -> Self {
+        Self::load_float3x3(value)
+    }
+}
+
+impl From<Matrix3> for Float3x3 {
+    #[inline]
+    fn from(value: Matrix3) -> Self {
+        value.store_float3x3()
+    }
+}
+
+impl From<[f32; 9]> for Matrix3 {
+    #[inline]
+    fn from(value: [f32; 9]) -> Self {
+        Self::from_column_array(value)
+    }
+}
+
+impl From<Matrix3> for [f32; 9] {
+    #[inline]
+    fn from(value: Matrix3) -> Self {
+        value.into_column_array()
+    }
+}
+
+impl From<Matrix> for Matrix3 {
+    #[inline]
+    fn from(value: Matrix) -> Self {
+        Self::from_matrix(value)
+    }
+}
+
+impl From<Matrix3> for Matrix {
+    #[inline]
+    fn from(value: Matrix3) -> Self {
+        value.into_matrix()
+    }
+}
+
+impl ops::Add<Self> for Matrix3 {
+    type Output = Self;
+    /// Adds two matrices.
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            columns: [
+                unsafe { self.columns[0] + rhs.columns[0] }, 
+                unsafe { self.columns[1] + rhs.columns[1] }, 
+                unsafe { self.columns[2] + rhs.columns[2] } 
+            ]
+        }
+    }
+}
+
+impl ops::AddAssign<Self> for Matrix3 {
+    /// Adds two matrices. (assign)
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+impl ops::Sub<Self> for Matrix3 {
+    type Output = Self;
+    /// Subtracts two matrices.
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            columns: [
+                unsafe { self.columns[0] - rhs.columns[0] }, 
+                unsafe { self.columns[1] - rhs.columns[1] }, 
+                unsafe { self.columns[2] - rhs.columns[2] } 
+            ]
+        }
+    }
+}
+
+impl ops::SubAssign<Self> for Matrix3 {
+    /// Subtracts two matrices. (assign)
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs
+    }
+}
+
+impl ops::Neg for Matrix3 {
+    type Output = Self;
+    /// Nagative.
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self {
+            columns: [
+                unsafe { -self.columns[0] }, 
+                unsafe { -self.columns[1] }, 
+                unsafe { -self.columns[2] } 
+            ]
+        }
+    }
+}
+
+impl ops::Mul<Matrix3> for f32 {
+    type Output = Matrix3;
+    /// Multiplies each element of a matrix by a scalar value.
+    #[inline]
+    fn mul(self, rhs: Matrix3) -> Self::Output {
+        Matrix3 {
+            columns: [
+                unsafe { self * rhs.columns[0] }, 
+                unsafe { self * rhs.columns[1] }, 
+                unsafe { self * rhs.columns[2] } 
+            ]
+        }
+    }
+}
+
+impl ops::Mul<f32> for Matrix3 {
+    type Output = Self;
+    /// Multiplies each element of a matrix by a scalar value.
+    #[inline]
+    fn mul(self, rhs: f32) -> Self::Output {
+        Self {
+            columns: [
+                unsafe { self.columns[0] * rhs }, 
+                unsafe { self.columns[1] * rhs }, 
+                unsafe { self.columns[2] * rhs } 
+            ]
+        }
+    }
+}
+
+impl ops::Mul<Vector> for Matrix3 {
+    type Output = Vector;
+    /// Transformation of the vector.
+    /// 
+    /// ※ The w element of the given vector is ignored. </br>
+    /// 
+    #[inline]
+    fn mul(self, rhs: Vector) -> Self::Output {
+        let x = *self.get_x_axis() * rhs.splat_x();
+        let y = *self.get_y_axis() * rhs.splat_y();
+        let z = *self.get_z_axis() * rhs.splat_z();
+        x + y + z
+    }
+}
+
+impl ops::Mul<Self> for Matrix3 {
+    type Output = Self;
+    /// Multiplies two matrices.
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self {
+            columns: [
+                self * *rhs.get_x_axis(), 
+                self * *rhs.get_y_axis(), 
+                self * *rhs.get_z_axis() 
+            ]
+        }
+    }
+}
+
+impl ops::MulAssign<Self> for Matrix3 {
+    /// Multiplies two matrices. (assign)
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs
+    }
+}
+
+impl fmt::Debug for Matrix3 {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple(stringify!(Matrix3))
+            .field(unsafe { &self.columns })
+            .finish()
+    }
+}
@@ -1,11 +1,35 @@
+/// Returns the byte table of `vqtbl*q_u8` that selects the given element indices.
+#[inline]
+const fn shuffle_table(x: usize, y: usize, z: usize, w: usize) -> [u8; 16] {
+    let (x, y, z, w) = (((x & 7) * 4) as u8, ((y & 7) * 4) as u8, ((z & 7) * 4) as u8, ((w & 7) * 4) as u8);
+    [
+        x, x + 1, x + 2, x + 3, 
+        y, y + 1, y + 2, y + 3, 
+        z, z + 1, z + 2, z + 3, 
+        w, w + 1, w + 2, w + 3
+    ]
+}
+
+mod dvector;
+pub use self::dvector::DVector;
+
 mod matrix;
 pub use self::matrix::Matrix;
 
+mod matrix3;
+pub use self::matrix3::Matrix3;
+
 mod quaternion;
 pub use self::quaternion::Quaternion;
 
 mod vector;
 pub use self::vector::Vector;
 
+mod vector3x4;
+pub use self::vector3x4::Vector3x4;
+
+mod vector4x4;
+pub use self::vector4x4::Vector4x4;
+
 mod vector_int;
 pub use self::vector_int::VectorInt;
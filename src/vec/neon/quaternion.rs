@@ -1,7 +1,11 @@
 use core::fmt;
 use core::ops;
 use core::arch::aarch64::*;
-use crate::{ Vector, Matrix, VectorInt, Float3, Float4 };
+use crate::{ DQuaternion, EulerRot, Vector, Matrix, VectorInt, Float3, Float4 };
+use crate::near_eq;
+
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
 
 
 
@@ -230,7 +234,7 @@ impl Quaternion {
     #[must_use]
     pub fn to_rotation_axes(self) -> (Vector, Vector, Vector) {
         #[cfg(feature = "use-assertion")]
-        assert!(self.is_normalize(), "The quaternion must be normalized!");
+        assert!(self.is_normalized(), "The quaternion must be normalized!");
 
         let quat: Float4 = self.into();
         let x2 = quat.x + quat.x;
@@ -267,6 +271,13 @@ impl Quaternion {
         Vector { inner: unsafe { self.inner } }
     }
 
+    /// Converts to a double-precision quaternion.
+    #[inline]
+    #[must_use]
+    pub fn as_f64(self) -> DQuaternion {
+        DQuaternion::from_dvector(self.into_vector().as_f64())
+    }
+
     /// Creates from a given matrix.
     /// 
     /// # Panics
@@ -321,6 +332,77 @@ impl Quaternion {
         }
         Some(self.into_matrix())
     }
+
+    /// Creates a quaternion from the given Euler angles.
+    /// 
+    /// ※ The angles given are in radians. </br>
+    /// ※ The rotations are intrinsic and applied in the given `order`.
+    /// For example, [`EulerRot::XYZ`] rotates about the x-axis by `a`, then the new y-axis by `b`, then the new z-axis by `c`. </br>
+    /// 
+    #[must_use]
+    pub fn from_euler(order: EulerRot, a: f32, b: f32, c: f32) -> Self {
+        let rotation = |axis: usize, angle: f32| match axis {
+            0 => Self::from_rotation_x(angle), 
+            1 => Self::from_rotation_y(angle), 
+            _ => Self::from_rotation_z(angle)
+        };
+        let [i, j, k] = order.axes();
+        rotation(i, a) * rotation(j, b) * rotation(k, c)
+    }
+
+    /// Converts the quaternion to Euler angles in the given `order`.
+    /// 
+    /// ※ The quaternion must be normalized. </br>
+    /// ※ The returned angles are in radians and in the range `-π` to `π`.
+    /// The second angle is in the range `-π/2` to `π/2` for Tait-Bryan orders, and `0` to `π` for proper Euler orders. </br>
+    /// ※ In gimbal lock, where the first and third axes are aligned, 
+    /// the first angle is set to zero and the whole rotation about the aligned axis is returned as the third angle. </br>
+    /// 
+    #[must_use]
+    pub fn to_euler(self, order: EulerRot) -> (f32, f32, f32) {
+        // Reference: Bernardes, E. and Viollet, S. (2022). 
+        // Quaternion to Euler angles conversion: A direct, general and computationally efficient method.
+        use core::f32::consts::{ PI, FRAC_PI_2 };
+        const GIMBAL_LOCK_EPSILON: f32 = 1.0e-6;
+
+        // The method is for extrinsic rotations, so the axes of the intrinsic order are reversed.
+        let [k, j, i] = order.axes();
+        let proper = i == k;
+        let k = if proper { 3 - i - j } else { k };
+        let sign = ((i as i32 - j as i32) * (j as i32 - k as i32) * (k as i32 - i as i32) / 2) as f32;
+
+        let q = self.into_array();
+        let (a, b, c, d) = match proper {
+            true => (q[3], q[i], q[j], q[k] * sign), 
+            false => (q[3] - q[j], q[i] + q[k] * sign, q[j] + q[3], q[k] * sign - q[i])
+        };
+
+        let atan = Vector::new((c * c + d * d).sqrt(), b, d, 0.0)
+            .atan2(Vector::new((a * a + b * b).sqrt(), a, c, 0.0));
+        let second = 2.0 * atan.get_x();
+        let half_sum = atan.get_y();
+        let half_diff = atan.get_z();
+
+        let (first, third) = if second.abs() <= GIMBAL_LOCK_EPSILON {
+            (0.0, 2.0 * half_sum)
+        } else if (second - PI).abs() <= GIMBAL_LOCK_EPSILON {
+            (0.0, -2.0 * half_diff)
+        } else {
+            (half_sum + half_diff, half_sum - half_diff)
+        };
+
+        let (first, second) = match proper {
+            true => (first, second), 
+            false => (first * sign, second - FRAC_PI_2)
+        };
+
+        let wrap = |angle: f32| match angle {
+            angle if angle < -PI => angle + 2.0 * PI, 
+            angle if angle > PI => angle - 2.0 * PI, 
+            angle => angle
+        };
+        (wrap(first), wrap(second), wrap(third))
+    }
 }
 
 impl Quaternion {
@@ -513,6 +595,20 @@ impl Quaternion {
         (self * v.into_quaternion() * self.inverse()).into_vector()
     }
 
+    /// Rotates the vectors of the `src` slice and stores them in the `dst` slice.
+    /// 
+    /// ※ The quaternion is converted to a rotation matrix once, and the vectors are transformed four at a time. </br>
+    /// ※ If the lengths are different, only the shorter length is rotated. </br>
+    /// 
+    /// # Panics
+    /// When `use-assertion` feature is enabled, [`panic!`] will be called 
+    /// if the quaternion is not normalized or the `src` and `dst` slices have different lengths.
+    /// 
+    #[inline]
+    pub fn rotate_slice(self, src: &[Float3], dst: &mut [Float3]) {
+        self.into_matrix().transform_vector3_slice(src, dst)
+    }
+
     /// Returns a quaternion that is a linear interpolation of two quaternion.
     /// 
     /// The given `t` must be in the range zero to one.
@@ -529,6 +625,149 @@ impl Quaternion {
     pub fn lerp(self, rhs: Self, t: f32) -> Self {
         self * (1.0 - t) + rhs * t
     }
+
+    /// Returns a quaternion that is a normalized linear interpolation of two quaternions.
+    /// 
+    /// The given `t` must be in the range zero to one.
+    ///  
+    /// The closer `t` is to one, the more it becomes equal to the given `rhs`.
+    /// 
+    /// ※ It takes the shortest path, so the sign of `rhs` does not affect the rotation. </br>
+    /// ※ It is faster than [`Quaternion::slerp`], but the angular velocity is not constant. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn nlerp(self, rhs: Self, t: f32) -> Self {
+        let rhs = match self.dot_into(rhs) < 0.0 {
+            true => rhs * -1.0, 
+            false => rhs
+        };
+        self.lerp(rhs, t).normalize()
+    }
+
+    /// Returns a quaternion that is a spherical linear interpolation of two quaternions.
+    /// 
+    /// The given `t` must be in the range zero to one.
+    ///  
+    /// The closer `t` is to one, the more it becomes equal to the given `rhs`.
+    /// 
+    /// ※ The given quaternions must be normalized. </br>
+    /// ※ It takes the shortest path, so the sign of `rhs` does not affect the rotation. </br>
+    /// ※ If the angle between the two quaternions is very small, [`Quaternion::nlerp`] is used instead. </br>
+    /// 
+    #[must_use]
+    pub fn slerp(self, rhs: Self, t: f32) -> Self {
+        const DOT_THRESHOLD: f32 = 0.9995;
+        let (rhs, dot) = match self.dot_into(rhs) {
+            dot if dot < 0.0 => (rhs * -1.0, -dot), 
+            dot => (rhs, dot)
+        };
+
+        if dot > DOT_THRESHOLD {
+            return self.lerp(rhs, t).normalize();
+        }
+
+        let theta = Vector::fill(dot).acos().get_x();
+        let sin = Vector::new(theta * (1.0 - t), theta * t, theta, 0.0).sin();
+        (self * sin.get_x() + rhs * sin.get_y()) * sin.get_z().recip()
+    }
+
+    /// Returns a quaternion that is a spherical quadrangle interpolation of two quaternions.
+    /// 
+    /// The given `t` must be in the range zero to one.
+    /// 
+    /// ※ `a` and `b` are the control points of `self` and `rhs`, 
+    /// which can be computed with [`Quaternion::squad_control_point`]. </br>
+    /// ※ The given quaternions must be normalized. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn squad(self, a: Self, b: Self, rhs: Self, t: f32) -> Self {
+        self.slerp(rhs, t).slerp(a.slerp(b, t), 2.0 * t * (1.0 - t))
+    }
+
+    /// Returns the control point of `curr` used by [`Quaternion::squad`].
+    /// 
+    /// `prev` and `next` are the quaternions before and after `curr` in the sequence.
+    /// 
+    /// ※ The given quaternions must be normalized. </br>
+    /// ※ `prev` and `next` are moved to the same hemisphere as `curr` before computing. </br>
+    /// 
+    #[must_use]
+    pub fn squad_control_point(prev: Self, curr: Self, next: Self) -> Self {
+        let prev = match curr.dot_into(prev) < 0.0 {
+            true => prev * -1.0, 
+            false => prev
+        };
+        let next = match curr.dot_into(next) < 0.0 {
+            true => next * -1.0, 
+            false => next
+        };
+
+        // s = curr * exp(-(ln(curr⁻¹ * prev) + ln(curr⁻¹ * next)) / 4)
+        let inv = curr.conjugate();
+        let sum = unit_ln(inv * prev) + unit_ln(inv * next);
+        (curr * pure_exp(sum * -0.25)).normalize()
+    }
+}
+
+/// Returns the logarithm of a normalized quaternion as a vector with zero `w` element.
+fn unit_ln(q: Quaternion) -> Vector {
+    let v = q.into_vector() * Vector::new(1.0, 1.0, 1.0, 0.0);
+    let sin = v.vec3_len();
+    if sin <= f32::EPSILON {
+        return v;
+    }
+    let theta = Vector::fill(sin).atan2(Vector::fill(q.get_w())).get_x();
+    v * (theta / sin)
+}
+
+/// Returns the exponential of a quaternion with zero `w` element.
+fn pure_exp(v: Vector) -> Quaternion {
+    let theta = v.vec3_len();
+    let (sin, cos) = Vector::fill(theta).sin_cos();
+    let scale = match theta <= f32::EPSILON {
+        true => 1.0, 
+        false => sin.get_x() / theta
+    };
+    Quaternion::from_vector(v * scale + Vector::W * cos.get_x())
+}
+
+impl Quaternion {
+    /// Returns `true` if the absolute difference of all elements is less than or equal to `max_abs_diff`.
+    #[inline]
+    #[must_use]
+    pub fn abs_diff_eq(self, rhs: Self, max_abs_diff: f32) -> bool {
+        near_eq::abs_diff_eq(&self.into_array(), &rhs.into_array(), max_abs_diff)
+    }
+
+    /// Returns `true` if all elements are equal within `max_abs_diff` or the relative tolerance `max_relative`.
+    /// 
+    /// ※ The relative tolerance is scaled by the larger absolute value of the two elements. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn relative_eq(self, rhs: Self, max_abs_diff: f32, max_relative: f32) -> bool {
+        near_eq::relative_eq(&self.into_array(), &rhs.into_array(), max_abs_diff, max_relative)
+    }
+
+    /// Returns `true` if all elements are equal within `max_abs_diff` or `max_ulps` representable values.
+    /// 
+    /// ※ Elements with different signs are equal only within `max_abs_diff`. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn ulps_eq(self, rhs: Self, max_abs_diff: f32, max_ulps: u32) -> bool {
+        near_eq::ulps_eq(&self.into_array(), &rhs.into_array(), max_abs_diff, max_ulps)
+    }
+}
+
+impl PartialEq for Quaternion {
+    /// Compares all elements exactly.
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.into_array() == other.into_array()
+    }
 }
 
 impl Default for Quaternion {
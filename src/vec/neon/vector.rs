@@ -1,9 +1,15 @@
 use core::fmt;
 use core::ops;
 use core::arch::aarch64::*;
-use crate::{ VectorInt, Float2, Float3, Float4 };
+use crate::{ DVector, VectorInt, Float2, Float3, Float4, Half4, 
+    UByteN4, ByteN4, ShortN2, UShortN4, UDecN4, Float3PK, Rgb9e5 
+};
+use crate::near_eq;
 
-use super::Quaternion;
+use super::{ Quaternion, shuffle_table };
+
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
 
 
 
@@ -182,6 +188,232 @@ impl Vector {
             val
         }
     }
+
+    /// Loads a value from a given `Half4`.
+    #[inline]
+    #[must_use]
+    pub fn load_half4(val: Half4) -> Self {
+        Self::load_float4(val.to_float4())
+    }
+
+    /// Stores the value in a `Half4`.
+    /// 
+    /// ※ Each element is rounded to the nearest representable value, ties to even. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn store_half4(self) -> Half4 {
+        Half4::from_float4(self.store_float4())
+    }
+
+    /// Loads a value from a given `UByteN4`.
+    #[inline]
+    #[must_use]
+    pub fn load_ubyten4(val: UByteN4) -> Self {
+        Self::new(val.x as f32, val.y as f32, val.z as f32, val.w as f32) / 255.0
+    }
+
+    /// Stores the value in a `UByteN4`.
+    /// 
+    /// ※ Each element is saturated to the range `[0, 1]` and rounded to the nearest integer. (NaN becomes zero) </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn store_ubyten4(self) -> UByteN4 {
+        let [x, y, z, w] = (self.max(Self::ZERO).min(Self::ONE) * 255.0).round().into_array();
+        UByteN4 { x: x as u8, y: y as u8, z: z as u8, w: w as u8 }
+    }
+
+    /// Loads a value from a given `ByteN4`.
+    /// 
+    /// ※ Both `i8::MIN` and `-i8::MAX` become negative one. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn load_byten4(val: ByteN4) -> Self {
+        (Self::new(val.x as f32, val.y as f32, val.z as f32, val.w as f32) / 127.0).max(Self::NEG_ONE)
+    }
+
+    /// Stores the value in a `ByteN4`.
+    /// 
+    /// ※ Each element is saturated to the range `[-1, 1]` and rounded to the nearest integer. (NaN becomes zero) </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn store_byten4(self) -> ByteN4 {
+        let v = Self::select(self.eq(self), self, Self::ZERO);
+        let [x, y, z, w] = (v.max(Self::NEG_ONE).min(Self::ONE) * 127.0).round().into_array();
+        ByteN4 { x: x as i8, y: y as i8, z: z as i8, w: w as i8 }
+    }
+
+    /// Loads a value from a given `ShortN2`.
+    /// 
+    /// ※ Both `i16::MIN` and `-i16::MAX` become negative one. </br>
+    /// ※ The `z` and `w` elements are zero. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn load_shortn2(val: ShortN2) -> Self {
+        (Self::new(val.x as f32, val.y as f32, 0.0, 0.0) / 32767.0).max(Self::NEG_ONE)
+    }
+
+    /// Stores the `x` and `y` elements in a `ShortN2`.
+    /// 
+    /// ※ Each element is saturated to the range `[-1, 1]` and rounded to the nearest integer. (NaN becomes zero) </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn store_shortn2(self) -> ShortN2 {
+        let v = Self::select(self.eq(self), self, Self::ZERO);
+        let [x, y, _, _] = (v.max(Self::NEG_ONE).min(Self::ONE) * 32767.0).round().into_array();
+        ShortN2 { x: x as i16, y: y as i16 }
+    }
+
+    /// Loads a value from a given `UShortN4`.
+    #[inline]
+    #[must_use]
+    pub fn load_ushortn4(val: UShortN4) -> Self {
+        Self::new(val.x as f32, val.y as f32, val.z as f32, val.w as f32) / 65535.0
+    }
+
+    /// Stores the value in a `UShortN4`.
+    /// 
+    /// ※ Each element is saturated to the range `[0, 1]` and rounded to the nearest integer. (NaN becomes zero) </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn store_ushortn4(self) -> UShortN4 {
+        let [x, y, z, w] = (self.max(Self::ZERO).min(Self::ONE) * 65535.0).round().into_array();
+        UShortN4 { x: x as u16, y: y as u16, z: z as u16, w: w as u16 }
+    }
+
+    /// Loads a value from a given `UDecN4`.
+    #[inline]
+    #[must_use]
+    pub fn load_udecn4(val: UDecN4) -> Self {
+        let v = Self::new(val.x() as f32, val.y() as f32, val.z() as f32, val.w() as f32);
+        v / Self::new(1023.0, 1023.0, 1023.0, 3.0)
+    }
+
+    /// Stores the value in a `UDecN4`.
+    /// 
+    /// ※ Each element is saturated to the range `[0, 1]` and rounded to the nearest integer. (NaN becomes zero) </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn store_udecn4(self) -> UDecN4 {
+        let v = self.max(Self::ZERO).min(Self::ONE) * Self::new(1023.0, 1023.0, 1023.0, 3.0);
+        let [x, y, z, w] = v.round().into_array();
+        UDecN4::new(x as u32, y as u32, z as u32, w as u32)
+    }
+
+    /// Loads a value from a given `Float3PK`.
+    /// 
+    /// ※ The `w` element is zero. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn load_float3pk(val: Float3PK) -> Self {
+        Self::load_float3(val.to_float3())
+    }
+
+    /// Stores the `x`, `y` and `z` elements in a `Float3PK`.
+    /// 
+    /// ※ Each element is rounded to the nearest representable value, ties to even. </br>
+    /// ※ Negative values become zero and finite values that are too large become the largest finite value. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn store_float3pk(self) -> Float3PK {
+        Float3PK::from_float3(self.store_float3())
+    }
+
+    /// Loads a value from a given `Rgb9e5`.
+    /// 
+    /// ※ The `w` element is zero. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn load_rgb9e5(val: Rgb9e5) -> Self {
+        Self::load_float3(val.to_float3())
+    }
+
+    /// Stores the `x`, `y` and `z` elements in a `Rgb9e5`.
+    /// 
+    /// ※ Each element is clamped to the range `[0, 65408]`. (NaN becomes zero) </br>
+    /// ※ The shared exponent is chosen from the largest element, and the mantissas are rounded to the nearest integer. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn store_rgb9e5(self) -> Rgb9e5 {
+        Rgb9e5::from_float3(self.store_float3())
+    }
+
+    /// Encodes the `x`, `y` and `z` elements of a linear color in the range `[0, range]` in `RGBM` format.
+    /// 
+    /// ※ The `w` element of the result is the shared multiplier, rounded up to a multiple of `1/255` so that it can be stored in 8 bits. </br>
+    /// ※ All elements of the result are in the range `[0, 1]`, and the decoded color is `rgb * w * range`. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn encode_rgbm(self, range: f32) -> Self {
+        let color = self / range;
+        let m = color.get_x().max(color.get_y()).max(color.get_z()).clamp(1e-6, 1.0);
+        let m = (m * 255.0).ceil() / 255.0;
+        let mut rgbm = (color / m).max(Self::ZERO).min(Self::ONE);
+        rgbm.set_w(m);
+        rgbm
+    }
+
+    /// Decodes a color in `RGBM` format with the given range.
+    /// 
+    /// ※ The `w` element of the result is zero. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn decode_rgbm(self, range: f32) -> Self {
+        let mut color = self * (self.get_w() * range);
+        color.set_w(0.0);
+        color
+    }
+
+    /// Encodes the `x`, `y` and `z` elements of a linear color in the range `[0, range]` in `RGBD` format.
+    /// 
+    /// ※ The `w` element of the result is the shared divisor, a multiple of `1/255` so that it can be stored in 8 bits. </br>
+    /// ※ All elements of the result are in the range `[0, 1]`, and the decoded color is `rgb * range / (w * 255)`. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn encode_rgbd(self, range: f32) -> Self {
+        let max = self.get_x().max(self.get_y()).max(self.get_z());
+        let d = (range / max).floor().clamp(1.0, 255.0);
+        let mut rgbd = (self * (d / range)).max(Self::ZERO).min(Self::ONE);
+        rgbd.set_w(d / 255.0);
+        rgbd
+    }
+
+    /// Decodes a color in `RGBD` format with the given range.
+    /// 
+    /// ※ The `w` element of the result is zero. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn decode_rgbd(self, range: f32) -> Self {
+        let mut color = self * (range / (self.get_w() * 255.0));
+        color.set_w(0.0);
+        color
+    }
+
+    /// Converts to a double-precision vector.
+    #[inline]
+    #[must_use]
+    pub fn as_f64(self) -> DVector {
+        unsafe {
+            let lo = vcvt_f64_f32(vget_low_f32(self.inner));
+            let hi = vcvt_f64_f32(vget_high_f32(self.inner));
+            DVector { inner: float64x2x2_t(lo, hi) }
+        }
+    }
 }
 
 impl Vector {
@@ -319,6 +551,30 @@ impl Vector {
         !self.eq(rhs)
     }
 
+    /// Selects the elements of `a` where the `mask` is set, otherwise the elements of `b`.
+    /// 
+    /// ※ The selection is done bit by bit, so the `mask` is usually the result of a comparison. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn select(mask: VectorInt, a: Self, b: Self) -> Self {
+        unsafe { Vector { inner: vbslq_f32(vreinterpretq_u32_s32(mask.inner), a.inner, b.inner) } }
+    }
+
+    /// Creates a vector by reinterpreting the bits of the given integer vector.
+    #[inline]
+    #[must_use]
+    pub fn from_bits(bits: VectorInt) -> Self {
+        unsafe { Vector { inner: vreinterpretq_f32_s32(bits.inner) } }
+    }
+
+    /// Reinterprets the bits of the vector as an integer vector.
+    #[inline]
+    #[must_use]
+    pub fn to_bits(self) -> VectorInt {
+        VectorInt::from(self)
+    }
+
     /// Absolute value on vector elements.
     #[inline]
     #[must_use]
@@ -613,6 +869,702 @@ impl Vector {
     pub fn lerp(self, rhs: Self, t: f32) -> Self {
         self * (1.0 - t) + rhs * t
     }
+    /// Computes `(self * a) + b` for each element of the vector.
+    /// 
+    /// ※ Using the `fmla` instruction, it is computed with only one rounding error. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn mul_add(self, a: Self, b: Self) -> Self {
+        unsafe { Vector { inner: vfmaq_f32(b.inner, self.inner, a.inner) } }
+    }
+}
+
+impl Vector {
+    /// Returns a vector with the elements rearranged by the given indices.
+    /// 
+    /// ※ Each index must be in the range `0` to `3` and selects the `x`, `y`, `z` or `w` element. </br>
+    /// ※ For example, `shuffle::<2, 1, 0, 3>()` returns `(z, y, x, w)`. </br>
+    /// 
+    /// # Panics
+    /// When the `use-assertion` feature is enabled, it will [`panic!`]
+    /// if any of the indices is greater than three.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn shuffle<const X: usize, const Y: usize, const Z: usize, const W: usize>(self) -> Self {
+        #[cfg(feature = "use-assertion")]
+        assert!(X < 4 && Y < 4 && Z < 4 && W < 4, "The index of the element is out of range!");
+        self.shuffle_lanes::<X, Y, Z, W>()
+    }
+
+    /// Rearranges the elements by the lower two bits of the given indices.
+    /// 
+    /// ※ The common patterns use the matching NEON instructions, and the others use the table lookup. </br>
+    /// 
+    #[inline]
+    fn shuffle_lanes<const X: usize, const Y: usize, const Z: usize, const W: usize>(self) -> Self {
+        unsafe {
+            let inner = match (X & 3, Y & 3, Z & 3, W & 3) {
+                (0, 1, 2, 3) => self.inner, 
+                (0, 0, 0, 0) => vdupq_laneq_f32::<0b00>(self.inner), 
+                (1, 1, 1, 1) => vdupq_laneq_f32::<0b01>(self.inner), 
+                (2, 2, 2, 2) => vdupq_laneq_f32::<0b10>(self.inner), 
+                (3, 3, 3, 3) => vdupq_laneq_f32::<0b11>(self.inner), 
+                (0, 0, 1, 1) => vzip1q_f32(self.inner, self.inner), 
+                (2, 2, 3, 3) => vzip2q_f32(self.inner, self.inner), 
+                (0, 2, 0, 2) => vuzp1q_f32(self.inner, self.inner), 
+                (1, 3, 1, 3) => vuzp2q_f32(self.inner, self.inner), 
+                (0, 0, 2, 2) => vtrn1q_f32(self.inner, self.inner), 
+                (1, 1, 3, 3) => vtrn2q_f32(self.inner, self.inner), 
+                (1, 2, 3, 0) => vextq_f32::<1>(self.inner, self.inner), 
+                (2, 3, 0, 1) => vextq_f32::<2>(self.inner, self.inner), 
+                (3, 0, 1, 2) => vextq_f32::<3>(self.inner, self.inner), 
+                (1, 0, 3, 2) => vrev64q_f32(self.inner), 
+                (0, 1, 0, 1) => vcombine_f32(vget_low_f32(self.inner), vget_low_f32(self.inner)), 
+                (2, 3, 2, 3) => vcombine_f32(vget_high_f32(self.inner), vget_high_f32(self.inner)), 
+                _ => {
+                    let table = shuffle_table(X & 3, Y & 3, Z & 3, W & 3);
+                    let bytes = vqtbl1q_u8(vreinterpretq_u8_f32(self.inner), vld1q_u8(table.as_ptr()));
+                    vreinterpretq_f32_u8(bytes)
+                }
+            };
+            Vector { inner }
+        }
+    }
+
+    /// Returns a vector with the elements selected from the two vectors by the given indices.
+    /// 
+    /// ※ Indices `0` to `3` select the `x`, `y`, `z` or `w` element of `self`,
+    /// and indices `4` to `7` select the `x`, `y`, `z` or `w` element of `rhs`. </br>
+    /// ※ For example, `permute::<0, 4, 1, 5>(rhs)` returns `(self.x, rhs.x, self.y, rhs.y)`. </br>
+    /// 
+    /// # Panics
+    /// When the `use-assertion` feature is enabled, it will [`panic!`]
+    /// if any of the indices is greater than seven.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn permute<const X: usize, const Y: usize, const Z: usize, const W: usize>(self, rhs: Self) -> Self {
+        #[cfg(feature = "use-assertion")]
+        assert!(X < 8 && Y < 8 && Z < 8 && W < 8, "The index of the element is out of range!");
+        unsafe {
+            let inner = match (X, Y, Z, W) {
+                (0, 4, 1, 5) => vzip1q_f32(self.inner, rhs.inner), 
+                (2, 6, 3, 7) => vzip2q_f32(self.inner, rhs.inner), 
+                (4, 0, 5, 1) => vzip1q_f32(rhs.inner, self.inner), 
+                (6, 2, 7, 3) => vzip2q_f32(rhs.inner, self.inner), 
+                (0, 2, 4, 6) => vuzp1q_f32(self.inner, rhs.inner), 
+                (1, 3, 5, 7) => vuzp2q_f32(self.inner, rhs.inner), 
+                (4, 6, 0, 2) => vuzp1q_f32(rhs.inner, self.inner), 
+                (5, 7, 1, 3) => vuzp2q_f32(rhs.inner, self.inner), 
+                (0, 4, 2, 6) => vtrn1q_f32(self.inner, rhs.inner), 
+                (1, 5, 3, 7) => vtrn2q_f32(self.inner, rhs.inner), 
+                (4, 0, 6, 2) => vtrn1q_f32(rhs.inner, self.inner), 
+                (5, 1, 7, 3) => vtrn2q_f32(rhs.inner, self.inner), 
+                (1, 2, 3, 4) => vextq_f32::<1>(self.inner, rhs.inner), 
+                (2, 3, 4, 5) => vextq_f32::<2>(self.inner, rhs.inner), 
+                (3, 4, 5, 6) => vextq_f32::<3>(self.inner, rhs.inner), 
+                (5, 6, 7, 0) => vextq_f32::<1>(rhs.inner, self.inner), 
+                (6, 7, 0, 1) => vextq_f32::<2>(rhs.inner, self.inner), 
+                (7, 0, 1, 2) => vextq_f32::<3>(rhs.inner, self.inner), 
+                (0, 1, 4, 5) => vcombine_f32(vget_low_f32(self.inner), vget_low_f32(rhs.inner)), 
+                (2, 3, 6, 7) => vcombine_f32(vget_high_f32(self.inner), vget_high_f32(rhs.inner)), 
+                (0, 1, 6, 7) => vcombine_f32(vget_low_f32(self.inner), vget_high_f32(rhs.inner)), 
+                (4, 5, 0, 1) => vcombine_f32(vget_low_f32(rhs.inner), vget_low_f32(self.inner)), 
+                (6, 7, 2, 3) => vcombine_f32(vget_high_f32(rhs.inner), vget_high_f32(self.inner)), 
+                (4, 5, 2, 3) => vcombine_f32(vget_low_f32(rhs.inner), vget_high_f32(self.inner)), 
+                _ if X < 4 && Y < 4 && Z < 4 && W < 4 => self.shuffle_lanes::<X, Y, Z, W>().inner, 
+                _ if X >= 4 && Y >= 4 && Z >= 4 && W >= 4 => rhs.shuffle_lanes::<X, Y, Z, W>().inner, 
+                _ => {
+                    let table = shuffle_table(X, Y, Z, W);
+                    let src = uint8x16x2_t(vreinterpretq_u8_f32(self.inner), vreinterpretq_u8_f32(rhs.inner));
+                    let bytes = vqtbl2q_u8(src, vld1q_u8(table.as_ptr()));
+                    vreinterpretq_f32_u8(bytes)
+                }
+            };
+            Vector { inner }
+        }
+    }
+
+    /// Fills all elements with the `x` element of the vector.
+    #[inline]
+    #[must_use]
+    pub fn splat_x(self) -> Self {
+        unsafe { Vector { inner: vdupq_laneq_f32::<0b00>(self.inner) } }
+    }
+
+    /// Fills all elements with the `y` element of the vector.
+    #[inline]
+    #[must_use]
+    pub fn splat_y(self) -> Self {
+        unsafe { Vector { inner: vdupq_laneq_f32::<0b01>(self.inner) } }
+    }
+
+    /// Fills all elements with the `z` element of the vector.
+    #[inline]
+    #[must_use]
+    pub fn splat_z(self) -> Self {
+        unsafe { Vector { inner: vdupq_laneq_f32::<0b10>(self.inner) } }
+    }
+
+    /// Fills all elements with the `w` element of the vector.
+    #[inline]
+    #[must_use]
+    pub fn splat_w(self) -> Self {
+        unsafe { Vector { inner: vdupq_laneq_f32::<0b11>(self.inner) } }
+    }
+
+    /// Interleaves the `x` and `y` elements of the two vectors.
+    /// 
+    /// ※ Returns `(self.x, rhs.x, self.y, rhs.y)`. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn merge_xy(self, rhs: Self) -> Self {
+        unsafe { Vector { inner: vzip1q_f32(self.inner, rhs.inner) } }
+    }
+
+    /// Interleaves the `z` and `w` elements of the two vectors.
+    /// 
+    /// ※ Returns `(self.z, rhs.z, self.w, rhs.w)`. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn merge_zw(self, rhs: Self) -> Self {
+        unsafe { Vector { inner: vzip2q_f32(self.inner, rhs.inner) } }
+    }
+}
+
+impl Vector {
+    /// Returns a vector containing the square root of each element of the vector.
+    #[inline]
+    #[must_use]
+    pub fn sqrt(self) -> Self {
+        unsafe { Vector { inner: vsqrtq_f32(self.inner) } }
+    }
+
+    /// Returns a vector containing the estimated square root of each element of the vector.
+    ///
+    /// ※ The relative error is less than `4.0e-4`. </br>
+    /// ※ Zero and infinity elements are returned as they are.
+    ///
+    #[inline]
+    #[must_use]
+    pub fn sqrt_est(self) -> Self {
+        unsafe {
+            let est = vmulq_f32(self.inner, self.rsqrt_est().inner);
+            let exact = vorrq_u32(
+                vceqq_f32(self.inner, vdupq_n_f32(0.0)), 
+                vceqq_f32(self.inner, vdupq_n_f32(f32::INFINITY))
+            );
+            Vector { inner: vbslq_f32(exact, self.inner, est) }
+        }
+    }
+
+    /// Returns a vector containing the reciprocal square root of each element of the vector.
+    #[inline]
+    #[must_use]
+    pub fn rsqrt(self) -> Self {
+        unsafe { Vector { inner: vdivq_f32(vdupq_n_f32(1.0), vsqrtq_f32(self.inner)) } }
+    }
+
+    /// Returns a vector containing the estimated reciprocal square root of each element of the vector.
+    ///
+    /// ※ The relative error is less than `4.0e-4`.
+    ///
+    #[inline]
+    #[must_use]
+    pub fn rsqrt_est(self) -> Self {
+        // The hardware estimate is refined once with the Newton-Raphson method
+        // to have the same precision as the `sse2` backend.
+        unsafe {
+            let est = vrsqrteq_f32(self.inner);
+            let est = vmulq_f32(est, vrsqrtsq_f32(self.inner, vmulq_f32(est, est)));
+            Vector { inner: est }
+        }
+    }
+
+    /// Returns a vector containing the reciprocal of each element of the vector.
+    #[inline]
+    #[must_use]
+    pub fn recip(self) -> Self {
+        unsafe { Vector { inner: vdivq_f32(vdupq_n_f32(1.0), self.inner) } }
+    }
+
+    /// Returns a vector containing the estimated reciprocal of each element of the vector.
+    ///
+    /// ※ The relative error is less than `4.0e-4`.
+    ///
+    #[inline]
+    #[must_use]
+    pub fn recip_est(self) -> Self {
+        // The hardware estimate is refined once with the Newton-Raphson method
+        // to have the same precision as the `sse2` backend.
+        unsafe {
+            let est = vrecpeq_f32(self.inner);
+            let est = vmulq_f32(est, vrecpsq_f32(self.inner, est));
+            Vector { inner: est }
+        }
+    }
+
+    /// Estimated length of a two-element vector.
+    ///
+    /// ※ The relative error is less than `4.0e-4`.
+    ///
+    #[inline]
+    #[must_use]
+    pub fn vec2_len_est(self) -> f32 {
+        self.vec2_dot(self).sqrt_est().get_x()
+    }
+
+    /// Estimated length of a three-element vector.
+    ///
+    /// ※ The relative error is less than `4.0e-4`.
+    ///
+    #[inline]
+    #[must_use]
+    pub fn vec3_len_est(self) -> f32 {
+        self.vec3_dot(self).sqrt_est().get_x()
+    }
+
+    /// Estimated length of a four-element vector.
+    ///
+    /// ※ The relative error is less than `4.0e-4`.
+    ///
+    #[inline]
+    #[must_use]
+    pub fn vec4_len_est(self) -> f32 {
+        self.vec4_dot(self).sqrt_est().get_x()
+    }
+
+    /// Normalizes a two-element vector using the estimated reciprocal square root.
+    ///
+    /// ※ The relative error of each element is less than `4.0e-4`. </br>
+    /// Undefined behavior may occur if the length of the vector is less than or equal to [`f32::EPSILON`].
+    ///
+    /// # Panics
+    /// When `use-assertion` feature is enabled, [`panic!`] will be called 
+    /// if the length of the vector is less than or equal to [`f32::EPSILON`].
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn vec2_normalize_est(self) -> Self {
+        #[cfg(feature = "use-assertion")]
+        assert!(self.vec2_len() > f32::EPSILON, "The length of the vector is less than or equal to `f32::EPSILON`!");
+
+        const MASK_XY: Vector = Vector { arr: [1.0, 1.0, 0.0, 0.0] };
+        self * self.vec2_dot(self).rsqrt_est() * MASK_XY
+    }
+
+    /// Normalizes a three-element vector using the estimated reciprocal square root.
+    ///
+    /// ※ The relative error of each element is less than `4.0e-4`. </br>
+    /// Undefined behavior may occur if the length of the vector is less than or equal to [`f32::EPSILON`].
+    ///
+    /// # Panics
+    /// When `use-assertion` feature is enabled, [`panic!`] will be called 
+    /// if the length of the vector is less than or equal to [`f32::EPSILON`].
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn vec3_normalize_est(self) -> Self {
+        #[cfg(feature = "use-assertion")]
+        assert!(self.vec3_len() > f32::EPSILON, "The length of the vector is less than or equal to `f32::EPSILON`!");
+
+        const MASK_XYZ: Vector = Vector { arr: [1.0, 1.0, 1.0, 0.0] };
+        self * self.vec3_dot(self).rsqrt_est() * MASK_XYZ
+    }
+
+    /// Normalizes a four-element vector using the estimated reciprocal square root.
+    ///
+    /// ※ The relative error of each element is less than `4.0e-4`. </br>
+    /// Undefined behavior may occur if the length of the vector is less than or equal to [`f32::EPSILON`].
+    ///
+    /// # Panics
+    /// When `use-assertion` feature is enabled, [`panic!`] will be called 
+    /// if the length of the vector is less than or equal to [`f32::EPSILON`].
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn vec4_normalize_est(self) -> Self {
+        #[cfg(feature = "use-assertion")]
+        assert!(self.vec4_len() > f32::EPSILON, "The length of the vector is less than or equal to `f32::EPSILON`!");
+        self * self.vec4_dot(self).rsqrt_est()
+    }
+}
+
+impl Vector {
+    /// Returns a vector containing the sine of each element of the vector.
+    ///
+    /// ※ The angles given are in radians. </br>
+    /// ※ It uses an 11-degree minimax polynomial approximation.
+    /// The absolute error is less than `4.0e-7` in the range `-4π` to `4π`
+    /// and grows with the magnitude of the angle outside of it.
+    ///
+    #[inline]
+    #[must_use]
+    pub fn sin(self) -> Self {
+        self.sin_cos().0
+    }
+
+    /// Returns a vector containing the cosine of each element of the vector.
+    ///
+    /// ※ The angles given are in radians. </br>
+    /// ※ It uses a 10-degree minimax polynomial approximation.
+    /// The absolute error is less than `4.0e-7` in the range `-4π` to `4π`
+    /// and grows with the magnitude of the angle outside of it.
+    ///
+    #[inline]
+    #[must_use]
+    pub fn cos(self) -> Self {
+        self.sin_cos().1
+    }
+
+    /// Returns the sine and cosine of each element of the vector at the same time.
+    ///
+    /// ※ The angles given are in radians. </br>
+    /// ※ The error bounds are the same as [`Vector::sin`] and [`Vector::cos`].
+    ///
+    #[must_use]
+    pub fn sin_cos(self) -> (Self, Self) {
+        // Reference: DirectXMath/Inc/DirectXMathVector.inl (XMVectorSinCos)
+        const FRAC_1_TAU: f32 = 0.15915494;
+        const TAU_HI: f32 = 6.28125;
+        const TAU_LO: f32 = 1.9353072e-3;
+        unsafe {
+            // Reduce the angle to the range -π to π.
+            let quotient = vrndnq_f32(vmulq_n_f32(self.inner, FRAC_1_TAU));
+            let x = vsubq_f32(self.inner, vmulq_n_f32(quotient, TAU_HI));
+            let x = vsubq_f32(x, vmulq_n_f32(quotient, TAU_LO));
+
+            // Map the angle to the range -π/2 to π/2 with sin(y) = sin(x).
+            let sign = vandq_u32(vreinterpretq_u32_f32(x), vdupq_n_u32(0x80000000));
+            let c = vreinterpretq_f32_u32(vorrq_u32(vreinterpretq_u32_f32(vdupq_n_f32(core::f32::consts::PI)), sign));
+            let rflx = vsubq_f32(c, x);
+            let comp = vcleq_f32(vabsq_f32(x), vdupq_n_f32(core::f32::consts::FRAC_PI_2));
+            let x = vbslq_f32(comp, x, rflx);
+            let x2 = vmulq_f32(x, x);
+
+            // Compute polynomial approximation of sine.
+            let s = vaddq_f32(vmulq_n_f32(x2, -2.3889859e-8), vdupq_n_f32(2.7525562e-6));
+            let s = vaddq_f32(vmulq_f32(s, x2), vdupq_n_f32(-1.9840874e-4));
+            let s = vaddq_f32(vmulq_f32(s, x2), vdupq_n_f32(8.333331e-3));
+            let s = vaddq_f32(vmulq_f32(s, x2), vdupq_n_f32(-1.6666667e-1));
+            let s = vaddq_f32(vmulq_f32(s, x2), vdupq_n_f32(1.0));
+            let s = vmulq_f32(s, x);
+
+            // Compute polynomial approximation of cosine.
+            let sign = vbslq_f32(comp, vdupq_n_f32(1.0), vdupq_n_f32(-1.0));
+            let c = vaddq_f32(vmulq_n_f32(x2, -2.6051615e-7), vdupq_n_f32(2.4760495e-5));
+            let c = vaddq_f32(vmulq_f32(c, x2), vdupq_n_f32(-1.3888378e-3));
+            let c = vaddq_f32(vmulq_f32(c, x2), vdupq_n_f32(4.1666638e-2));
+            let c = vaddq_f32(vmulq_f32(c, x2), vdupq_n_f32(-0.5));
+            let c = vaddq_f32(vmulq_f32(c, x2), vdupq_n_f32(1.0));
+            let c = vmulq_f32(c, sign);
+
+            (Vector { inner: s }, Vector { inner: c })
+        }
+    }
+
+    /// Returns a vector containing the tangent of each element of the vector.
+    ///
+    /// ※ The angles given are in radians. </br>
+    /// ※ It is computed as the quotient of [`Vector::sin_cos`].
+    /// In the range `-4π` to `4π`, where the absolute value of the cosine is greater than `0.1`,
+    /// the error is less than `4.0e-6` (absolute if the result is less than one, otherwise relative).
+    ///
+    #[inline]
+    #[must_use]
+    pub fn tan(self) -> Self {
+        let (s, c) = self.sin_cos();
+        s / c
+    }
+
+    /// Returns a vector containing the arcsine of each element of the vector.
+    ///
+    /// ※ The given elements must be in the range `-1.0` to `1.0`. </br>
+    /// ※ It uses a 7-degree minimax polynomial approximation.
+    /// The absolute error is less than `5.0e-7`.
+    ///
+    #[inline]
+    #[must_use]
+    pub fn asin(self) -> Self {
+        unsafe { Vector { inner: vsubq_f32(vdupq_n_f32(core::f32::consts::FRAC_PI_2), self.acos().inner) } }
+    }
+
+    /// Returns a vector containing the arccosine of each element of the vector.
+    ///
+    /// ※ The given elements must be in the range `-1.0` to `1.0`. </br>
+    /// ※ It uses a 7-degree minimax polynomial approximation.
+    /// The absolute error is less than `5.0e-7`.
+    ///
+    #[must_use]
+    pub fn acos(self) -> Self {
+        // Reference: DirectXMath/Inc/DirectXMathVector.inl (XMVectorACos)
+        unsafe {
+            let non_negative = vcgeq_f32(self.inner, vdupq_n_f32(0.0));
+            let x = vabsq_f32(self.inner);
+            let root = vsqrtq_f32(vmaxq_f32(vdupq_n_f32(0.0), vsubq_f32(vdupq_n_f32(1.0), x)));
+
+            let t = vaddq_f32(vmulq_n_f32(x, -1.2624911e-3), vdupq_n_f32(6.67009e-3));
+            let t = vaddq_f32(vmulq_f32(t, x), vdupq_n_f32(-1.7088126e-2));
+            let t = vaddq_f32(vmulq_f32(t, x), vdupq_n_f32(3.089188e-2));
+            let t = vaddq_f32(vmulq_f32(t, x), vdupq_n_f32(-5.0174303e-2));
+            let t = vaddq_f32(vmulq_f32(t, x), vdupq_n_f32(8.897899e-2));
+            let t = vaddq_f32(vmulq_f32(t, x), vdupq_n_f32(-2.145988e-1));
+            let t = vaddq_f32(vmulq_f32(t, x), vdupq_n_f32(core::f32::consts::FRAC_PI_2));
+            let t = vmulq_f32(t, root);
+
+            let rflx = vsubq_f32(vdupq_n_f32(core::f32::consts::PI), t);
+            Vector { inner: vbslq_f32(non_negative, t, rflx) }
+        }
+    }
+
+    /// Returns a vector containing the arctangent of each element of the vector.
+    ///
+    /// ※ It uses a 9-degree polynomial approximation after reducing the argument
+    /// to the range `-tan(π/8)` to `tan(π/8)`.
+    /// The absolute error is less than `2.0e-7`.
+    ///
+    #[must_use]
+    pub fn atan(self) -> Self {
+        // Reference: Cephes Math Library (atanf.c)
+        unsafe {
+            let sign = vandq_u32(vreinterpretq_u32_f32(self.inner), vdupq_n_u32(0x80000000));
+            let x = vabsq_f32(self.inner);
+
+            let big = vcgtq_f32(x, vdupq_n_f32(2.4142137));
+            let mid = vbicq_u32(vcgtq_f32(x, vdupq_n_f32(0.41421357)), big);
+            let y = vbslq_f32(big, vdupq_n_f32(core::f32::consts::FRAC_PI_2), vdupq_n_f32(0.0));
+            let y = vbslq_f32(mid, vdupq_n_f32(core::f32::consts::FRAC_PI_4), y);
+
+            let x_big = vdivq_f32(vdupq_n_f32(-1.0), x);
+            let x_mid = vdivq_f32(vsubq_f32(x, vdupq_n_f32(1.0)), vaddq_f32(x, vdupq_n_f32(1.0)));
+            let x = vbslq_f32(big, x_big, x);
+            let x = vbslq_f32(mid, x_mid, x);
+            let z = vmulq_f32(x, x);
+
+            let p = vaddq_f32(vmulq_n_f32(z, 8.0537446e-2), vdupq_n_f32(-1.3877685e-1));
+            let p = vaddq_f32(vmulq_f32(p, z), vdupq_n_f32(1.9977711e-1));
+            let p = vaddq_f32(vmulq_f32(p, z), vdupq_n_f32(-3.333295e-1));
+            let p = vaddq_f32(vmulq_f32(vmulq_f32(p, z), x), x);
+
+            let result = vreinterpretq_u32_f32(vaddq_f32(y, p));
+            Vector { inner: vreinterpretq_f32_u32(veorq_u32(result, sign)) }
+        }
+    }
+
+    /// Returns a vector containing the four quadrant arctangent of `self` (`y`) and `x`.
+    ///
+    /// ※ The absolute error is less than `4.0e-7`. </br>
+    /// ※ Signed zeros and infinities are handled in the same way as [`f32::atan2`].
+    ///
+    #[must_use]
+    pub fn atan2(self, x: Self) -> Self {
+        unsafe {
+            let y = self.inner;
+            let x = x.inner;
+            let sign_mask = vdupq_n_u32(0x80000000);
+            let y_sign = vandq_u32(vreinterpretq_u32_f32(y), sign_mask);
+            let x_neg = vreinterpretq_u32_s32(vshrq_n_s32::<31>(vreinterpretq_s32_f32(x)));
+
+            // When `x` is negative, the result is moved to the opposite quadrant.
+            let atan = Vector { inner: vdivq_f32(y, x) }.atan().inner;
+            let pi = vreinterpretq_f32_u32(vorrq_u32(vreinterpretq_u32_f32(vdupq_n_f32(core::f32::consts::PI)), y_sign));
+            let result = vbslq_f32(x_neg, vaddq_f32(atan, pi), atan);
+
+            // When `y` is zero, the result is zero or π.
+            let y_zero = vceqq_f32(y, vdupq_n_f32(0.0));
+            let zero = vreinterpretq_f32_u32(vorrq_u32(vandq_u32(x_neg, vreinterpretq_u32_f32(vdupq_n_f32(core::f32::consts::PI))), y_sign));
+            let result = vbslq_f32(y_zero, zero, result);
+
+            // When both `x` and `y` are infinite, the result is π/4 or 3π/4.
+            let inf = vdupq_n_f32(f32::INFINITY);
+            let both_inf = vandq_u32(vceqq_f32(vabsq_f32(y), inf), vceqq_f32(vabsq_f32(x), inf));
+            let quarter = vbslq_f32(x_neg, vdupq_n_f32(3.0 * core::f32::consts::FRAC_PI_4), vdupq_n_f32(core::f32::consts::FRAC_PI_4));
+            let quarter = vreinterpretq_f32_u32(vorrq_u32(vreinterpretq_u32_f32(quarter), y_sign));
+            let result = vbslq_f32(both_inf, quarter, result);
+
+            // When either `x` or `y` is `NaN`, the result is `NaN`.
+            let ordered = vandq_u32(vceqq_f32(x, x), vceqq_f32(y, y));
+            Vector { inner: vbslq_f32(ordered, result, vdupq_n_f32(f32::NAN)) }
+        }
+    }
+
+    /// Returns a vector containing `e^(self)` for each element of the vector.
+    ///
+    /// ※ It uses a 7-degree polynomial approximation after reducing the argument
+    /// to the range `-ln(2)/2` to `ln(2)/2`.
+    /// The relative error is less than `2.0e-7`.
+    ///
+    #[must_use]
+    pub fn exp(self) -> Self {
+        unsafe {
+            // Clamp the range so that the exponent of the result does not overflow.
+            let x = vmaxq_f32(vdupq_n_f32(-104.0), self.inner);
+            let x = vminq_f32(vdupq_n_f32(89.0), x);
+
+            // e^x = 2^n * e^r
+            let n = vcvtnq_s32_f32(vmulq_n_f32(x, core::f32::consts::LOG2_E));
+            let nf = vcvtq_f32_s32(n);
+            let r = vsubq_f32(x, vmulq_n_f32(nf, 0.6933594));
+            let r = vsubq_f32(r, vmulq_n_f32(nf, -2.1219444e-4));
+            Self::exp_reduced(n, r)
+        }
+    }
+
+    /// Returns a vector containing `2^(self)` for each element of the vector.
+    ///
+    /// ※ The error bounds are the same as [`Vector::exp`].
+    ///
+    #[must_use]
+    pub fn exp2(self) -> Self {
+        unsafe {
+            let x = vmaxq_f32(vdupq_n_f32(-150.0), self.inner);
+            let x = vminq_f32(vdupq_n_f32(129.0), x);
+
+            // 2^x = 2^n * e^(r * ln(2))
+            let n = vcvtnq_s32_f32(x);
+            let r = vsubq_f32(x, vcvtq_f32_s32(n));
+            let r = vmulq_n_f32(r, core::f32::consts::LN_2);
+            Self::exp_reduced(n, r)
+        }
+    }
+
+    /// Computes `2^n * e^r` where `r` is in the range `-ln(2)/2` to `ln(2)/2`.
+    #[inline]
+    unsafe fn exp_reduced(n: int32x4_t, r: float32x4_t) -> Self {
+        // Reference: Cephes Math Library (expf.c)
+        let r2 = vmulq_f32(r, r);
+        let p = vaddq_f32(vmulq_n_f32(r, 1.9875691e-4), vdupq_n_f32(1.3981999e-3));
+        let p = vaddq_f32(vmulq_f32(p, r), vdupq_n_f32(8.333452e-3));
+        let p = vaddq_f32(vmulq_f32(p, r), vdupq_n_f32(4.1665796e-2));
+        let p = vaddq_f32(vmulq_f32(p, r), vdupq_n_f32(1.6666666e-1));
+        let p = vaddq_f32(vmulq_f32(p, r), vdupq_n_f32(0.5));
+        let p = vaddq_f32(vaddq_f32(vmulq_f32(p, r2), r), vdupq_n_f32(1.0));
+
+        // 2^n is split into two factors so that each exponent stays in range.
+        let n0 = vshrq_n_s32::<1>(n);
+        let n1 = vsubq_s32(n, n0);
+        let bias = vdupq_n_s32(127);
+        let pow0 = vreinterpretq_f32_s32(vshlq_n_s32::<23>(vaddq_s32(n0, bias)));
+        let pow1 = vreinterpretq_f32_s32(vshlq_n_s32::<23>(vaddq_s32(n1, bias)));
+        Vector { inner: vmulq_f32(vmulq_f32(p, pow0), pow1) }
+    }
+
+    /// Returns a vector containing the natural logarithm of each element of the vector.
+    ///
+    /// ※ It uses a 9-degree polynomial approximation after splitting the mantissa
+    /// into the range `sqrt(0.5)` to `sqrt(2.0)`.
+    /// The error is less than the larger of `5.0e-7` absolute and `2.0e-7` relative. </br>
+    /// ※ Returns `NaN` for negative elements and negative infinity for zeros.
+    ///
+    #[must_use]
+    pub fn ln(self) -> Self {
+        unsafe {
+            let (e, m) = self.log_reduced();
+            let result = vaddq_f32(m, vmulq_n_f32(e, -2.1219444e-4));
+            let result = vaddq_f32(result, vmulq_n_f32(e, 0.6933594));
+            self.log_special_cases(result)
+        }
+    }
+
+    /// Returns a vector containing the base 2 logarithm of each element of the vector.
+    ///
+    /// ※ The error is less than the larger of `1.0e-6` absolute and `2.0e-7` relative. </br>
+    /// ※ Returns `NaN` for negative elements and negative infinity for zeros.
+    ///
+    #[must_use]
+    pub fn log2(self) -> Self {
+        unsafe {
+            let (e, m) = self.log_reduced();
+            let result = vaddq_f32(vmulq_n_f32(m, core::f32::consts::LOG2_E), e);
+            self.log_special_cases(result)
+        }
+    }
+
+    /// Splits each element into `2^e * m` and returns `e` and `ln(m)`.
+    #[inline]
+    unsafe fn log_reduced(self) -> (float32x4_t, float32x4_t) {
+        // Reference: Cephes Math Library (logf.c)
+        // Scales subnormal numbers to the normal range.
+        let subnormal = vcltq_f32(self.inner, vdupq_n_f32(f32::MIN_POSITIVE));
+        let x = vbslq_f32(subnormal, vmulq_n_f32(self.inner, 8388608.0), self.inner);
+        let bias = vbslq_s32(subnormal, vdupq_n_s32(149), vdupq_n_s32(126));
+
+        // x = m * 2^e (0.5 <= m < 1.0)
+        let bits = vreinterpretq_u32_f32(x);
+        let e = vcvtq_f32_s32(vsubq_s32(vreinterpretq_s32_u32(vshrq_n_u32::<23>(bits)), bias));
+        let m = vandq_u32(bits, vdupq_n_u32(0x007FFFFF));
+        let m = vreinterpretq_f32_u32(vorrq_u32(m, vdupq_n_u32(0x3F000000)));
+
+        // If m < sqrt(0.5), then use 2m - 1 and e - 1.
+        let small = vcltq_f32(m, vdupq_n_f32(core::f32::consts::FRAC_1_SQRT_2));
+        let e = vbslq_f32(small, vsubq_f32(e, vdupq_n_f32(1.0)), e);
+        let m = vbslq_f32(small, vsubq_f32(vaddq_f32(m, m), vdupq_n_f32(1.0)), vsubq_f32(m, vdupq_n_f32(1.0)));
+        let m2 = vmulq_f32(m, m);
+
+        let p = vaddq_f32(vmulq_n_f32(m, 7.0376836e-2), vdupq_n_f32(-1.151461e-1));
+        let p = vaddq_f32(vmulq_f32(p, m), vdupq_n_f32(1.16769984e-1));
+        let p = vaddq_f32(vmulq_f32(p, m), vdupq_n_f32(-1.2420141e-1));
+        let p = vaddq_f32(vmulq_f32(p, m), vdupq_n_f32(1.4249323e-1));
+        let p = vaddq_f32(vmulq_f32(p, m), vdupq_n_f32(-1.6668057e-1));
+        let p = vaddq_f32(vmulq_f32(p, m), vdupq_n_f32(2.0000714e-1));
+        let p = vaddq_f32(vmulq_f32(p, m), vdupq_n_f32(-2.4999994e-1));
+        let p = vaddq_f32(vmulq_f32(p, m), vdupq_n_f32(3.333333e-1));
+        let p = vmulq_f32(vmulq_f32(p, m), m2);
+        let p = vsubq_f32(p, vmulq_n_f32(m2, 0.5));
+        (e, vaddq_f32(m, p))
+    }
+
+    /// Replaces the logarithm of zero, negative, infinity and `NaN` elements.
+    #[inline]
+    unsafe fn log_special_cases(self, result: float32x4_t) -> Self {
+        let zero = vceqq_f32(self.inner, vdupq_n_f32(0.0));
+        let inf = vceqq_f32(self.inner, vdupq_n_f32(f32::INFINITY));
+        let valid = vcgeq_f32(self.inner, vdupq_n_f32(0.0));
+        let result = vbslq_f32(zero, vdupq_n_f32(f32::NEG_INFINITY), result);
+        let result = vbslq_f32(inf, self.inner, result);
+        Vector { inner: vbslq_f32(valid, result, vdupq_n_f32(f32::NAN)) }
+    }
+}
+
+impl Vector {
+    /// Returns `true` if the absolute difference of all elements is less than or equal to `max_abs_diff`.
+    #[inline]
+    #[must_use]
+    pub fn abs_diff_eq(self, rhs: Self, max_abs_diff: f32) -> bool {
+        near_eq::abs_diff_eq(&self.into_array(), &rhs.into_array(), max_abs_diff)
+    }
+
+    /// Returns `true` if all elements are equal within `max_abs_diff` or the relative tolerance `max_relative`.
+    /// 
+    /// ※ The relative tolerance is scaled by the larger absolute value of the two elements. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn relative_eq(self, rhs: Self, max_abs_diff: f32, max_relative: f32) -> bool {
+        near_eq::relative_eq(&self.into_array(), &rhs.into_array(), max_abs_diff, max_relative)
+    }
+
+    /// Returns `true` if all elements are equal within `max_abs_diff` or `max_ulps` representable values.
+    /// 
+    /// ※ Elements with different signs are equal only within `max_abs_diff`. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn ulps_eq(self, rhs: Self, max_abs_diff: f32, max_ulps: u32) -> bool {
+        near_eq::ulps_eq(&self.into_array(), &rhs.into_array(), max_abs_diff, max_ulps)
+    }
+}
+
+impl PartialEq for Vector {
+    /// Compares all elements exactly.
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.into_array() == other.into_array()
+    }
 }
 
 impl Default for Vector {
@@ -622,6 +1574,13 @@ impl Default for Vector {
     }
 }
 
+impl From<VectorInt> for Vector {
+    #[inline]
+    fn from(value: VectorInt) -> Self {
+        Self::from_bits(value)
+    }
+}
+
 impl From<[f32; 4]> for Vector {
     #[inline]
     fn from(value: [f32; 4]) -> Self {
@@ -0,0 +1,287 @@
+use core::fmt;
+use core::ops;
+use core::arch::aarch64::*;
+use crate::{ Vector, Vector4x4, VectorInt, Float3 };
+
+
+
+/// This is a structure-of-arrays data type that stores four three-dimensional vectors.
+///
+/// Each register holds one component of four vectors, so the operations are lane-parallel. </br>
+/// Using the `arm neon` instruction.
+///
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Vector3x4 {
+    /// The `x` components of four vectors.
+    pub x: Vector,
+
+    /// The `y` components of four vectors.
+    pub y: Vector,
+
+    /// The `z` components of four vectors.
+    pub z: Vector,
+}
+
+impl Vector3x4 {
+    /// All elements are zeros.
+    pub const ZERO: Self = Self::new(Vector::ZERO, Vector::ZERO, Vector::ZERO);
+}
+
+impl Vector3x4 {
+    /// Creates with given component vectors.
+    #[inline]
+    #[must_use]
+    pub const fn new(x: Vector, y: Vector, z: Vector) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Fills all four vectors with the `x`, `y`, `z` elements of the given vector.
+    #[inline]
+    #[must_use]
+    pub fn splat(v: Vector) -> Self {
+        Self { x: v.splat_x(), y: v.splat_y(), z: v.splat_z() }
+    }
+
+    /// Creates from four vectors. (transposition)
+    ///
+    /// ※ The `w` element of the given vectors is ignored. </br>
+    ///
+    #[inline]
+    #[must_use]
+    pub fn from_vectors(arr: [Vector; 4]) -> Self {
+        let v = Vector4x4::from_vectors(arr);
+        Self { x: v.x, y: v.y, z: v.z }
+    }
+
+    /// Stores the value in four vectors. (transposition)
+    ///
+    /// ※ The `w` element of the vectors is zero. </br>
+    ///
+    #[inline]
+    #[must_use]
+    pub fn into_vectors(self) -> [Vector; 4] {
+        Vector4x4::new(self.x, self.y, self.z, Vector::ZERO).into_vectors()
+    }
+
+    /// Loads a value from four given `Float3`. (transposition)
+    #[inline]
+    #[must_use]
+    pub fn load_float3_array(arr: [Float3; 4]) -> Self {
+        unsafe {
+            let v = vld3q_f32(arr.as_ptr() as *const f32);
+            Self { x: Vector { inner: v.0 }, y: Vector { inner: v.1 }, z: Vector { inner: v.2 } }
+        }
+    }
+
+    /// Stores the value in four `Float3`. (transposition)
+    #[inline]
+    #[must_use]
+    pub fn store_float3_array(self) -> [Float3; 4] {
+        let mut arr = [Float3::ZERO; 4];
+        unsafe {
+            let v = float32x4x3_t(self.x.inner, self.y.inner, self.z.inner);
+            vst3q_f32(arr.as_mut_ptr() as *mut f32, v);
+        }
+        arr
+    }
+
+    /// Dot product of each of the four vectors.
+    #[inline]
+    #[must_use]
+    pub fn dot(self, rhs: Self) -> Vector {
+        self.x.mul_add(rhs.x, self.y.mul_add(rhs.y, self.z * rhs.z))
+    }
+
+    /// Cross product of each of the four vectors.
+    #[inline]
+    #[must_use]
+    pub fn cross(self, rhs: Self) -> Self {
+        Self {
+            x: self.y * rhs.z - self.z * rhs.y,
+            y: self.z * rhs.x - self.x * rhs.z,
+            z: self.x * rhs.y - self.y * rhs.x
+        }
+    }
+
+    /// Returns the length squared of each of the four vectors.
+    #[inline]
+    #[must_use]
+    pub fn len_sq(self) -> Vector {
+        self.dot(self)
+    }
+
+    /// Returns the length of each of the four vectors.
+    #[inline]
+    #[must_use]
+    pub fn len(self) -> Vector {
+        self.len_sq().sqrt()
+    }
+
+    /// Normalizes each of the four vectors.
+    ///
+    /// Undefined behavior may occur if the length of the vector is less than or equal to [`f32::EPSILON`].
+    ///
+    #[inline]
+    #[must_use]
+    pub fn normalize(self) -> Self {
+        self * self.len().recip()
+    }
+
+    /// Linearly interpolates each of the four vectors.
+    #[inline]
+    #[must_use]
+    pub fn lerp(self, rhs: Self, t: f32) -> Self {
+        Self { x: self.x.lerp(rhs.x, t), y: self.y.lerp(rhs.y, t), z: self.z.lerp(rhs.z, t) }
+    }
+
+    /// Selects the vectors of `a` where the `mask` lane is set, otherwise the vectors of `b`.
+    ///
+    /// ※ The selection is done bit by bit, so the `mask` is usually the result of a comparison. </br>
+    ///
+    #[inline]
+    #[must_use]
+    pub fn select(mask: VectorInt, a: Self, b: Self) -> Self {
+        Self {
+            x: Vector::select(mask, a.x, b.x),
+            y: Vector::select(mask, a.y, b.y),
+            z: Vector::select(mask, a.z, b.z)
+        }
+    }
+
+    /// Returns `true` if the absolute difference of all elements is less than or equal to `max_abs_diff`.
+    #[inline]
+    #[must_use]
+    pub fn abs_diff_eq(self, rhs: Self, max_abs_diff: f32) -> bool {
+        self.x.abs_diff_eq(rhs.x, max_abs_diff) && 
+        self.y.abs_diff_eq(rhs.y, max_abs_diff) && 
+        self.z.abs_diff_eq(rhs.z, max_abs_diff)
+    }
+
+    /// Returns `true` if all elements are equal within `max_abs_diff` or the relative tolerance `max_relative`.
+    #[inline]
+    #[must_use]
+    pub fn relative_eq(self, rhs: Self, max_abs_diff: f32, max_relative: f32) -> bool {
+        self.x.relative_eq(rhs.x, max_abs_diff, max_relative) && 
+        self.y.relative_eq(rhs.y, max_abs_diff, max_relative) && 
+        self.z.relative_eq(rhs.z, max_abs_diff, max_relative)
+    }
+
+    /// Returns `true` if all elements are equal within `max_abs_diff` or `max_ulps` representable values.
+    #[inline]
+    #[must_use]
+    pub fn ulps_eq(self, rhs: Self, max_abs_diff: f32, max_ulps: u32) -> bool {
+        self.x.ulps_eq(rhs.x, max_abs_diff, max_ulps) && 
+        self.y.ulps_eq(rhs.y, max_abs_diff, max_ulps) && 
+        self.z.ulps_eq(rhs.z, max_abs_diff, max_ulps)
+    }
+}
+
+impl PartialEq for Vector3x4 {
+    /// Compares all elements exactly.
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.x == other.x && self.y == other.y && self.z == other.z
+    }
+}
+
+impl Default for Vector3x4 {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl From<[Float3; 4]> for Vector3x4 {
+    #[inline]
+    fn from(value: [Float3; 4]) -> Self {
+        Self::load_float3_array(value)
+    }
+}
+
+impl From<Vector3x4> for [Float3; 4] {
+    #[inline]
+    fn from(value: Vector3x4) -> Self {
+        value.store_float3_array()
+    }
+}
+
+impl ops::Add<Self> for Vector3x4 {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self { x: self.x + rhs.x, y: self.y + rhs.y, z: self.z + rhs.z }
+    }
+}
+
+impl ops::AddAssign<Self> for Vector3x4 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+impl ops::Sub<Self> for Vector3x4 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self { x: self.x - rhs.x, y: self.y - rhs.y, z: self.z - rhs.z }
+    }
+}
+
+impl ops::SubAssign<Self> for Vector3x4 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs
+    }
+}
+
+impl ops::Neg for Vector3x4 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self { x: -self.x, y: -self.y, z: -self.z }
+    }
+}
+
+impl ops::Mul<Vector> for Vector3x4 {
+    type Output = Self;
+    /// Scales each of the four vectors by the corresponding lane.
+    #[inline]
+    fn mul(self, rhs: Vector) -> Self::Output {
+        Self { x: self.x * rhs, y: self.y * rhs, z: self.z * rhs }
+    }
+}
+
+impl ops::MulAssign<Vector> for Vector3x4 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Vector) {
+        *self = *self * rhs
+    }
+}
+
+impl ops::Mul<f32> for Vector3x4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: f32) -> Self::Output {
+        self * Vector::fill(rhs)
+    }
+}
+
+impl ops::MulAssign<f32> for Vector3x4 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: f32) {
+        *self = *self * rhs
+    }
+}
+
+impl fmt::Debug for Vector3x4 {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(stringify!(Vector3x4))
+            .field("x", &self.x)
+            .field("y", &self.y)
+            .field("z", &self.z)
+            .finish()
+    }
+}
@@ -331,6 +331,16 @@ impl VectorInt {
         !self.eq(rhs)
     }
 
+    /// Selects the elements of `a` where the `mask` is set, otherwise the elements of `b`.
+    /// 
+    /// ※ The selection is done bit by bit, so the `mask` is usually the result of a comparison. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn select(mask: VectorInt, a: Self, b: Self) -> Self {
+        unsafe { VectorInt { inner: vbslq_s32(vreinterpretq_u32_s32(mask.inner), a.inner, b.inner) } }
+    }
+
     /// Absolute value on vector elements.
     #[inline]
     #[must_use]
@@ -410,6 +420,91 @@ impl VectorInt {
     }
 }
 
+impl VectorInt {
+    /// Returns a vector with the elements rearranged by the given indices.
+    /// 
+    /// ※ Each index must be in the range `0` to `3` and selects the `x`, `y`, `z` or `w` element. </br>
+    /// ※ For example, `shuffle::<2, 1, 0, 3>()` returns `(z, y, x, w)`. </br>
+    /// 
+    /// # Panics
+    /// When the `use-assertion` feature is enabled, it will [`panic!`]
+    /// if any of the indices is greater than three.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn shuffle<const X: usize, const Y: usize, const Z: usize, const W: usize>(self) -> Self {
+        #[cfg(feature = "use-assertion")]
+        assert!(X < 4 && Y < 4 && Z < 4 && W < 4, "The index of the element is out of range!");
+        Vector::from_bits(self).shuffle::<X, Y, Z, W>().to_bits()
+    }
+
+    /// Returns a vector with the elements selected from the two vectors by the given indices.
+    /// 
+    /// ※ Indices `0` to `3` select the `x`, `y`, `z` or `w` element of `self`,
+    /// and indices `4` to `7` select the `x`, `y`, `z` or `w` element of `rhs`. </br>
+    /// ※ For example, `permute::<0, 4, 1, 5>(rhs)` returns `(self.x, rhs.x, self.y, rhs.y)`. </br>
+    /// 
+    /// # Panics
+    /// When the `use-assertion` feature is enabled, it will [`panic!`]
+    /// if any of the indices is greater than seven.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn permute<const X: usize, const Y: usize, const Z: usize, const W: usize>(self, rhs: Self) -> Self {
+        #[cfg(feature = "use-assertion")]
+        assert!(X < 8 && Y < 8 && Z < 8 && W < 8, "The index of the element is out of range!");
+        Vector::from_bits(self).permute::<X, Y, Z, W>(Vector::from_bits(rhs)).to_bits()
+    }
+
+    /// Fills all elements with the `x` element of the vector.
+    #[inline]
+    #[must_use]
+    pub fn splat_x(self) -> Self {
+        unsafe { VectorInt { inner: vdupq_laneq_s32::<0b00>(self.inner) } }
+    }
+
+    /// Fills all elements with the `y` element of the vector.
+    #[inline]
+    #[must_use]
+    pub fn splat_y(self) -> Self {
+        unsafe { VectorInt { inner: vdupq_laneq_s32::<0b01>(self.inner) } }
+    }
+
+    /// Fills all elements with the `z` element of the vector.
+    #[inline]
+    #[must_use]
+    pub fn splat_z(self) -> Self {
+        unsafe { VectorInt { inner: vdupq_laneq_s32::<0b10>(self.inner) } }
+    }
+
+    /// Fills all elements with the `w` element of the vector.
+    #[inline]
+    #[must_use]
+    pub fn splat_w(self) -> Self {
+        unsafe { VectorInt { inner: vdupq_laneq_s32::<0b11>(self.inner) } }
+    }
+
+    /// Interleaves the `x` and `y` elements of the two vectors.
+    /// 
+    /// ※ Returns `(self.x, rhs.x, self.y, rhs.y)`. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn merge_xy(self, rhs: Self) -> Self {
+        unsafe { VectorInt { inner: vzip1q_s32(self.inner, rhs.inner) } }
+    }
+
+    /// Interleaves the `z` and `w` elements of the two vectors.
+    /// 
+    /// ※ Returns `(self.z, rhs.z, self.w, rhs.w)`. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn merge_zw(self, rhs: Self) -> Self {
+        unsafe { VectorInt { inner: vzip2q_s32(self.inner, rhs.inner) } }
+    }
+}
+
 impl Default for VectorInt {
     #[inline]
     fn default() -> Self {
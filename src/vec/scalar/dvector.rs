@@ -0,0 +1,888 @@
+use core::fmt;
+use core::ops;
+use crate::{ DQuaternion, Vector, Double2, Double3, Double4 };
+use crate::near_eq;
+
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
+
+
+
+/// This is a double-precision vector data type that uses the `Scalar` instruction.
+/// 
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct DVector {
+    pub(crate) arr: [f64; 4]
+}
+
+impl DVector {
+    /// All elements are zeros.
+    pub const ZERO: Self = Self { arr: [0.0; 4] };
+
+    /// All elements are one.
+    pub const ONE: Self = Self { arr: [1.0; 4] };
+
+    /// All elements are negative one.
+    pub const NEG_ONE: Self = Self { arr: [-1.0; 4] };
+
+    /// All elements are [`f64::MIN`].
+    pub const MIN: Self = Self { arr: [f64::MIN; 4] };
+
+    /// All elements are [`f64::MAX`].
+    pub const MAX: Self = Self { arr: [f64::MAX; 4] };
+
+    /// All elements are [`f64::INFINITY`].
+    pub const INFINITY: Self = Self { arr: [f64::INFINITY; 4] };
+
+    /// All elements are [`f64::NEG_INFINITY`].
+    pub const NEG_INFINITY: Self = Self { arr: [f64::NEG_INFINITY; 4] };
+
+    /// positive unit vector on x-axis.
+    pub const X: Self = Self { arr: [1.0, 0.0, 0.0, 0.0] };
+
+    /// positive unit vector on y-axis.
+    pub const Y: Self = Self { arr: [0.0, 1.0, 0.0, 0.0] };
+
+    /// positive unit vector on z-axis.
+    pub const Z: Self = Self { arr: [0.0, 0.0, 1.0, 0.0] };
+
+    /// positive unit vector on w-axis.
+    pub const W: Self = Self { arr: [0.0, 0.0, 0.0, 1.0] };
+
+    /// negative unit vector on x-axis.
+    pub const NEG_X: Self = Self { arr: [-1.0, 0.0, 0.0, 0.0] };
+
+    /// negative unit vector on y-axis.
+    pub const NEG_Y: Self = Self { arr: [0.0, -1.0, 0.0, 0.0] };
+
+    /// negative unit vector on z-axis.
+    pub const NEG_Z: Self = Self { arr: [0.0, 0.0, -1.0, 0.0] };
+
+    /// negative unit vector on w-axis.
+    pub const NEG_W: Self = Self { arr: [0.0, 0.0, 0.0, -1.0] };
+}
+
+impl DVector {
+    /// Creates with given elements.
+    #[inline]
+    #[must_use]
+    pub fn new(x: f64, y: f64, z: f64, w: f64) -> Self {
+        Self { arr: [x, y, z, w] }
+    }
+
+    /// Fills all elements with the given values.
+    #[inline]
+    #[must_use]
+    pub fn fill(v: f64) -> Self {
+        Self { arr: [v; 4] }
+    }
+
+    /// Creates from a given array.
+    #[inline]
+    #[must_use]
+    pub fn from_array(arr: [f64; 4]) -> Self {
+        Self { arr }
+    }
+
+    /// Stores the value in an array.
+    #[inline]
+    #[must_use]
+    pub fn into_array(self) -> [f64; 4] {
+        self.arr
+    }
+
+    /// Creates from a given array of slice.
+    /// 
+    /// # Panics
+    /// When the `use-assertion` feature is enabled, it will [`panic!`]
+    /// if the array slice has less than four elements.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn from_slice(slice: &[f64]) -> Self {
+        #[cfg(feature = "use-assertion")]
+        assert!(slice.len() >= 4, "The given array slice has less than four elements!");
+        let mut arr = [0.0; 4];
+        for (i, e) in arr.iter_mut().enumerate() {
+            *e = slice[i]
+        }
+        Self { arr }
+    }
+
+    /// Converts a given quaternion to a vector.
+    #[inline]
+    #[must_use]
+    pub fn from_dquaternion(q: DQuaternion) -> Self {
+        q.into_dvector()
+    }
+
+    /// Converts a vector to a quaternion.
+    #[inline]
+    #[must_use]
+    pub fn into_dquaternion(self) -> DQuaternion {
+        DQuaternion::from_dvector(self)
+    }
+
+    /// Loads a value from a given `Double2`.
+    #[inline]
+    #[must_use]
+    pub fn load_double2(val: Double2) -> Self {
+        Self::load_double4(val.into())
+    }
+
+    /// Stores the value in a `Double2`.
+    #[inline]
+    #[must_use]
+    pub fn store_double2(self) -> Double2 {
+        self.store_double4().xy()
+    }
+
+    /// Loads a value from a given `Double3`.
+    #[inline]
+    #[must_use]
+    pub fn load_double3(val: Double3) -> Self {
+        Self::load_double4(val.into())
+    }
+
+    /// Stores the value in a `Double3`.
+    #[inline]
+    #[must_use]
+    pub fn store_double3(self) -> Double3 {
+        self.store_double4().xyz()
+    }
+
+    /// Loads a value from a given `Double4`.
+    #[inline]
+    #[must_use]
+    pub fn load_double4(val: Double4) -> Self {
+        Self { arr: val.to_array() }
+    }
+
+    /// Stores the value in a `Double4`.
+    #[inline]
+    #[must_use]
+    pub fn store_double4(self) -> Double4 {
+        Double4::from_array(self.arr)
+    }
+
+    /// Converts to a single-precision vector.
+    /// 
+    /// ※ Elements that cannot be represented in `f32` are rounded or become infinity. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn as_f32(self) -> Vector {
+        Vector::new(
+            self.get_x() as f32, 
+            self.get_y() as f32, 
+            self.get_z() as f32, 
+            self.get_w() as f32
+        )
+    }
+}
+
+impl DVector {
+    /// Get the `x` element of a vector.
+    #[inline]
+    #[must_use]
+    pub fn get_x(&self) -> f64 {
+        self.arr[0]
+    }
+
+    /// Set the `x` element of a vector.
+    #[inline]
+    pub fn set_x(&mut self, v: f64) {
+        self.arr[0] = v
+    }
+
+    /// Get the `y` element of a vector.
+    #[inline]
+    #[must_use]
+    pub fn get_y(&self) -> f64 {
+        self.arr[1]
+    }
+
+    /// Set the `y` element of a vector.
+    #[inline]
+    pub fn set_y(&mut self, v: f64) {
+        self.arr[1] = v
+    }
+
+    /// Get the `z` element of a vector.
+    #[inline]
+    #[must_use]
+    pub fn get_z(&self) -> f64 {
+        self.arr[2]
+    }
+
+    /// Set the `z` element of a vector.
+    #[inline]
+    pub fn set_z(&mut self, v: f64) {
+        self.arr[2] = v
+    }
+
+    /// Get the `w` element of a vector.
+    #[inline]
+    #[must_use]
+    pub fn get_w(&self) -> f64 {
+        self.arr[3]
+    }
+
+    /// Set the `w` element of a vector.
+    #[inline]
+    pub fn set_w(&mut self, v: f64) {
+        self.arr[3] = v
+    }
+
+    /// Takes the smaller of the elements of the two vectors.
+    #[inline]
+    #[must_use]
+    pub fn min(self, rhs: Self) -> Self {
+        Self { arr: [
+            self.get_x().min(rhs.get_x()), 
+            self.get_y().min(rhs.get_y()), 
+            self.get_z().min(rhs.get_z()), 
+            self.get_w().min(rhs.get_w())
+        ] }
+    }
+
+    /// Takes the larger of the elements of the two vectors.
+    #[inline]
+    #[must_use]
+    pub fn max(self, rhs: Self) -> Self {
+        Self { arr: [
+            self.get_x().max(rhs.get_x()), 
+            self.get_y().max(rhs.get_y()), 
+            self.get_z().max(rhs.get_z()), 
+            self.get_w().max(rhs.get_w())
+        ] }
+    }
+
+    /// Absolute value on vector elements.
+    #[inline]
+    #[must_use]
+    pub fn abs(self) -> Self {
+        Self { arr: [
+            self.get_x().abs(), 
+            self.get_y().abs(), 
+            self.get_z().abs(), 
+            self.get_w().abs()
+        ] }
+    }
+
+    /// Return a vector filled by adding all the elements of the vector.
+    #[inline]
+    #[must_use]
+    pub fn sum(self) -> Self {
+        Self { arr: [self.arr.into_iter().sum(); 4] }
+    }
+
+    /// Returns the sum of all elements in a vector.
+    #[inline]
+    #[must_use]
+    pub fn sum_into(self) -> f64 {
+        self.sum().get_x()
+    }
+
+    /// Returns a vector filled with the dot products of the two-element vectors.
+    #[inline]
+    #[must_use]
+    pub fn vec2_dot(self, rhs: Self) -> DVector {
+        let v = self * rhs;
+        Self { arr: [v.get_x() + v.get_y(); 4] }
+    }
+
+    /// Dot product of the two-element vectors.
+    #[inline]
+    #[must_use]
+    pub fn vec2_dot_into(self, rhs: Self) -> f64 {
+        self.vec2_dot(rhs).get_x()
+    }
+
+    /// Returns a vector filled with the dot products of the three-element vectors.
+    #[inline]
+    #[must_use]
+    pub fn vec3_dot(self, rhs: Self) -> DVector {
+        let v = self * rhs;
+        Self { arr: [v.get_x() + v.get_y() + v.get_z(); 4] }
+    }
+
+    /// Dot product of the three-element vectors.
+    #[inline]
+    #[must_use]
+    pub fn vec3_dot_into(self, rhs: Self) -> f64 {
+        self.vec3_dot(rhs).get_x()
+    }
+
+    /// Returns a vector filled with the dot products of the four-element vectors.
+    #[inline]
+    #[must_use]
+    pub fn vec4_dot(self, rhs: Self) -> DVector {
+        let v = self * rhs;
+        Self { arr: [v.get_x() + v.get_y() + v.get_z() + v.get_w(); 4] }
+    }
+
+    /// Dot product of the four-element vectors.
+    #[inline]
+    #[must_use]
+    pub fn vec4_dot_into(self, rhs: Self) -> f64 {
+        self.vec4_dot(rhs).get_x()
+    }
+
+    /// Cross product of two three-element vectors.
+    #[inline]
+    #[must_use]
+    pub fn vec3_cross(self, rhs: Self) -> DVector {
+        Self { arr: [
+            self.get_y() * rhs.get_z() - self.get_z() * rhs.get_y(), 
+            self.get_z() * rhs.get_x() - self.get_x() * rhs.get_z(), 
+            self.get_x() * rhs.get_y() - self.get_y() * rhs.get_x(), 
+            0.0
+        ] }
+    }
+
+    /// Length squared of a two-element vector.
+    #[inline]
+    #[must_use]
+    pub fn vec2_len_sq(self) -> f64 {
+        self.vec2_dot_into(self)
+    }
+
+    /// Length of a two-element vector.
+    #[inline]
+    #[must_use]
+    pub fn vec2_len(self) -> f64 {
+        self.vec2_len_sq().sqrt()
+    }
+
+    /// Length squared of a three-element vector.
+    #[inline]
+    #[must_use]
+    pub fn vec3_len_sq(self) -> f64 {
+        self.vec3_dot_into(self)
+    }
+
+    /// Length of a three-element vector.
+    #[inline]
+    #[must_use]
+    pub fn vec3_len(self) -> f64 {
+        self.vec3_len_sq().sqrt()
+    }
+
+    /// Length squared of a four-element vector.
+    #[inline]
+    #[must_use]
+    pub fn vec4_len_sq(self) -> f64 {
+        self.vec4_dot_into(self)
+    }
+
+    /// Length of a four-element vector.
+    #[inline]
+    #[must_use]
+    pub fn vec4_len(self) -> f64 {
+        self.vec4_len_sq().sqrt()
+    }
+
+    /// Returns `true` if it is a unit vector.
+    #[inline]
+    #[must_use]
+    pub fn is_vec2_normalized(self) -> bool {
+        (self.vec2_len() - 1.0).abs() <= f64::EPSILON
+    }
+
+    /// Normalizes a two-element vector.
+    /// 
+    /// Undefined behavior may occur if the length of the vector is less than or equal to [`f64::EPSILON`].
+    /// 
+    /// # Panics
+    /// When `use-assertion` feature is enabled, [`panic!`] will be called
+    /// if the length of the vector is less than or equal to [`f64::EPSILON`].
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn vec2_normalize(self) -> Self {
+        #[cfg(feature = "use-assertion")]
+        assert!(self.vec2_len() > f64::EPSILON, "The length of the vector is less than or equal to `f64::EPSILON`!");
+
+        const MASK_XY: DVector = DVector { arr: [1.0, 1.0, 0.0, 0.0] };
+        self * self.vec2_len().recip() * MASK_XY
+    }
+
+    /// Normalizes a two-element vector.
+    /// 
+    /// Returns `None` if the length of the vector is less than or equal to [`f64::EPSILON`].
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn try_vec2_normalize(self) -> Option<Self> {
+        const MASK_XY: DVector = DVector { arr: [1.0, 1.0, 0.0, 0.0] };
+        let length = self.vec2_len();
+        if length <= f64::EPSILON {
+            return None;
+        }
+        Some(self * length.recip() * MASK_XY)
+    }
+
+    /// Returns `true` if it is a unit vector.
+    #[inline]
+    #[must_use]
+    pub fn is_vec3_normalized(self) -> bool {
+        (self.vec3_len() - 1.0).abs() <= f64::EPSILON
+    }
+
+    /// Normalizes a three-element vector.
+    /// 
+    /// Undefined behavior may occur if the length of the vector is less than or equal to [`f64::EPSILON`].
+    /// 
+    /// # Panics
+    /// When `use-assertion` feature is enabled, [`panic!`] will be called
+    /// if the length of the vector is less than or equal to [`f64::EPSILON`].
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn vec3_normalize(self) -> Self {
+        #[cfg(feature = "use-assertion")]
+        assert!(self.vec3_len() > f64::EPSILON, "The length of the vector is less than or equal to `f64::EPSILON`!");
+
+        const MASK_XYZ: DVector = DVector { arr: [1.0, 1.0, 1.0, 0.0] };
+        self * self.vec3_len().recip() * MASK_XYZ
+    }
+
+    /// Normalizes a three-element vector.
+    /// 
+    /// Returns `None` if the length of the vector is less than or equal to [`f64::EPSILON`].
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn try_vec3_normalize(self) -> Option<Self> {
+        const MASK_XYZ: DVector = DVector { arr: [1.0, 1.0, 1.0, 0.0] };
+        let length = self.vec3_len();
+        if length <= f64::EPSILON {
+            return None;
+        }
+        Some(self * length.recip() * MASK_XYZ)
+    }
+
+    /// Returns `true` if it is a unit vector.
+    #[inline]
+    #[must_use]
+    pub fn is_vec4_normalized(self) -> bool {
+        (self.vec4_len() - 1.0).abs() <= f64::EPSILON
+    }
+
+    /// Normalizes a four-element vector.
+    /// 
+    /// Undefined behavior may occur if the length of the vector is less than or equal to [`f64::EPSILON`].
+    /// 
+    /// # Panics
+    /// When `use-assertion` feature is enabled, [`panic!`] will be called
+    /// if the length of the vector is less than or equal to [`f64::EPSILON`].
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn vec4_normalize(self) -> Self {
+        #[cfg(feature = "use-assertion")]
+        assert!(self.vec4_len() > f64::EPSILON, "The length of the vector is less than or equal to `f64::EPSILON`!");
+        self * self.vec4_len().recip()
+    }
+
+    /// Normalizes a four-element vector.
+    /// 
+    /// Returns `None` if the length of the vector is less than or equal to [`f64::EPSILON`].
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn try_vec4_normalize(self) -> Option<Self> {
+        let length = self.vec4_len();
+        if length <= f64::EPSILON {
+            return None;
+        }
+        Some(self * length.recip())
+    }
+
+    /// Returns a vector containing the smallest integer less than or equal to the number for each element of the vector.
+    #[inline]
+    #[must_use]
+    pub fn floor(self) -> Self {
+        Self { arr: [
+            self.get_x().floor(), 
+            self.get_y().floor(), 
+            self.get_z().floor(), 
+            self.get_w().floor()
+        ] }
+    }
+
+    /// Returns a vector containing the smallest integer greater than or equal to the number for each element of the vector.
+    #[inline]
+    #[must_use]
+    pub fn ceil(self) -> Self {
+        Self { arr: [
+            self.get_x().ceil(), 
+            self.get_y().ceil(), 
+            self.get_z().ceil(), 
+            self.get_w().ceil()
+        ] }
+    }
+
+    /// Returns a vector with the numbers for each element of the vector rounded to the nearest integer.
+    /// 
+    /// ※ Half-way cases are rounded away from zero. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn round(self) -> Self {
+        Self { arr: [
+            self.get_x().round(), 
+            self.get_y().round(), 
+            self.get_z().round(), 
+            self.get_w().round()
+        ] }
+    }
+
+    /// Returns the square root of each element of the vector.
+    #[inline]
+    #[must_use]
+    pub fn sqrt(self) -> Self {
+        Self { arr: [
+            self.get_x().sqrt(), 
+            self.get_y().sqrt(), 
+            self.get_z().sqrt(), 
+            self.get_w().sqrt()
+        ] }
+    }
+
+    /// Returns the reciprocal of each element of the vector.
+    #[inline]
+    #[must_use]
+    pub fn recip(self) -> Self {
+        Self::ONE / self
+    }
+
+    /// Returns a vector that is a linear interpolation of two vectors.
+    /// 
+    /// The given `t` must be in the range zero to one.
+    /// 
+    /// The closer `t` is to one, the more it becomes equal to the given `rhs`.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn lerp(self, rhs: Self, t: f64) -> Self {
+        self * (1.0 - t) + rhs * t
+    }
+}
+
+impl DVector {
+    /// Returns `true` if the absolute difference of all elements is less than or equal to `max_abs_diff`.
+    #[inline]
+    #[must_use]
+    pub fn abs_diff_eq(self, rhs: Self, max_abs_diff: f64) -> bool {
+        near_eq::abs_diff_eq(&self.into_array(), &rhs.into_array(), max_abs_diff)
+    }
+
+    /// Returns `true` if all elements are equal within `max_abs_diff` or the relative tolerance `max_relative`.
+    /// 
+    /// ※ The relative tolerance is scaled by the larger absolute value of the two elements. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn relative_eq(self, rhs: Self, max_abs_diff: f64, max_relative: f64) -> bool {
+        near_eq::relative_eq(&self.into_array(), &rhs.into_array(), max_abs_diff, max_relative)
+    }
+
+    /// Returns `true` if all elements are equal within `max_abs_diff` or `max_ulps` representable values.
+    /// 
+    /// ※ Elements with different signs are equal only within `max_abs_diff`. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn ulps_eq(self, rhs: Self, max_abs_diff: f64, max_ulps: u32) -> bool {
+        near_eq::ulps_eq(&self.into_array(), &rhs.into_array(), max_abs_diff, max_ulps)
+    }
+}
+
+impl PartialEq for DVector {
+    /// Compares all elements exactly.
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.into_array() == other.into_array()
+    }
+}
+
+impl Default for DVector {
+    #[inline]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl From<[f64; 4]> for DVector {
+    #[inline]
+    fn from(value: [f64; 4]) -> Self {
+        Self::from_array(value)
+    }
+}
+
+impl From<DVector> for [f64; 4] {
+    #[inline]
+    fn from(value: DVector) -> Self {
+        value.into_array()
+    }
+}
+
+impl From<Double2> for DVector {
+    #[inline]
+    fn from(value: Double2) -> Self {
+        Self::load_double2(value)
+    }
+}
+
+impl From<DVector> for Double2 {
+    #[inline]
+    fn from(value: DVector) -> Self {
+        value.store_double2()
+    }
+}
+
+impl From<Double3> for DVector {
+    #[inline]
+    fn from(value: Double3) -> Self {
+        Self::load_double3(value)
+    }
+}
+
+impl From<DVector> for Double3 {
+    #[inline]
+    fn from(value: DVector) -> Self {
+        value.store_double3()
+    }
+}
+
+impl From<Double4> for DVector {
+    #[inline]
+    fn from(value: Double4) -> Self {
+        Self::load_double4(value)
+    }
+}
+
+impl From<DVector> for Double4 {
+    #[inline]
+    fn from(value: DVector) -> Self {
+        value.store_double4()
+    }
+}
+
+impl fmt::Debug for DVector {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple(stringify!(DVector))
+            .field(&self.arr)
+            .finish()
+    }
+}
+
+impl ops::Add<DVector> for f64 {
+    type Output = DVector;
+    /// Adds a scalar value to each element of a vector.
+    #[inline]
+    fn add(self, rhs: DVector) -> Self::Output {
+        DVector::fill(self) + rhs
+    }
+}
+
+impl ops::Add<f64> for DVector {
+    type Output = DVector;
+    /// Adds a scalar value to each element of a vector.
+    #[inline]
+    fn add(self, rhs: f64) -> Self::Output {
+        self + Self::fill(rhs)
+    }
+}
+
+impl ops::AddAssign<f64> for DVector {
+    /// Adds a scalar value to each element of a vector.
+    #[inline]
+    fn add_assign(&mut self, rhs: f64) {
+        *self = *self + rhs
+    }
+}
+
+impl ops::Add<DVector> for DVector {
+    type Output = DVector;
+    /// Adds two vectors.
+    #[inline]
+    fn add(self, rhs: DVector) -> Self::Output {
+        Self { arr: [
+            self.get_x() + rhs.get_x(), 
+            self.get_y() + rhs.get_y(), 
+            self.get_z() + rhs.get_z(), 
+            self.get_w() + rhs.get_w()
+        ] }
+    }
+}
+
+impl ops::AddAssign<DVector> for DVector {
+    /// Adds two vectors.
+    #[inline]
+    fn add_assign(&mut self, rhs: DVector) {
+        *self = *self + rhs
+    }
+}
+
+impl ops::Sub<DVector> for f64 {
+    type Output = DVector;
+    /// Subtracts a scalar value to each element of a vector.
+    #[inline]
+    fn sub(self, rhs: DVector) -> Self::Output {
+        DVector::fill(self) - rhs
+    }
+}
+
+impl ops::Sub<f64> for DVector {
+    type Output = DVector;
+    /// Subtracts a scalar value to each element of a vector.
+    #[inline]
+    fn sub(self, rhs: f64) -> Self::Output {
+        self - Self::fill(rhs)
+    }
+}
+
+impl ops::SubAssign<f64> for DVector {
+    /// Subtracts a scalar value to each element of a vector.
+    #[inline]
+    fn sub_assign(&mut self, rhs: f64) {
+        *self = *self - rhs
+    }
+}
+
+impl ops::Sub<DVector> for DVector {
+    type Output = DVector;
+    /// Subtracts two vectors.
+    #[inline]
+    fn sub(self, rhs: DVector) -> Self::Output {
+        Self { arr: [
+            self.get_x() - rhs.get_x(), 
+            self.get_y() - rhs.get_y(), 
+            self.get_z() - rhs.get_z(), 
+            self.get_w() - rhs.get_w()
+        ] }
+    }
+}
+
+impl ops::SubAssign<DVector> for DVector {
+    /// Subtracts two vectors.
+    #[inline]
+    fn sub_assign(&mut self, rhs: DVector) {
+        *self = *self - rhs
+    }
+}
+
+impl ops::Neg for DVector {
+    type Output = DVector;
+    /// Nagative.
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self { arr: [
+            -self.get_x(), 
+            -self.get_y(), 
+            -self.get_z(), 
+            -self.get_w()
+        ] }
+    }
+}
+
+impl ops::Mul<DVector> for f64 {
+    type Output = DVector;
+    /// Multiplies each element of a vector by a scalar value.
+    #[inline]
+    fn mul(self, rhs: DVector) -> Self::Output {
+        DVector::fill(self) * rhs
+    }
+}
+
+impl ops::Mul<f64> for DVector {
+    type Output = DVector;
+    /// Multiplies each element of a vector by a scalar value.
+    #[inline]
+    fn mul(self, rhs: f64) -> Self::Output {
+        self * Self::fill(rhs)
+    }
+}
+
+impl ops::MulAssign<f64> for DVector {
+    /// Multiplies each element of a vector by a scalar value.
+    #[inline]
+    fn mul_assign(&mut self, rhs: f64) {
+        *self = *self * rhs
+    }
+}
+
+impl ops::Mul<DVector> for DVector {
+    type Output = DVector;
+    /// Element-wise multiplication of two vectors.
+    #[inline]
+    fn mul(self, rhs: DVector) -> Self::Output {
+        Self { arr: [
+            self.get_x() * rhs.get_x(), 
+            self.get_y() * rhs.get_y(), 
+            self.get_z() * rhs.get_z(), 
+            self.get_w() * rhs.get_w()
+        ] }
+    }
+}
+
+impl ops::MulAssign<DVector> for DVector {
+    /// Element-wise multiplication of two vectors.
+    #[inline]
+    fn mul_assign(&mut self, rhs: DVector) {
+        *self = *self * rhs
+    }
+}
+
+impl ops::Div<DVector> for f64 {
+    type Output = DVector;
+    /// Divides each element of a vector by a scalar value.
+    #[inline]
+    fn div(self, rhs: DVector) -> Self::Output {
+        DVector::fill(self) / rhs
+    }
+}
+
+impl ops::Div<f64> for DVector {
+    type Output = DVector;
+    /// Divides each element of a vector by a scalar value.
+    #[inline]
+    fn div(self, rhs: f64) -> Self::Output {
+        self / Self::fill(rhs)
+    }
+}
+
+impl ops::DivAssign<f64> for DVector {
+    /// Divides each element of a vector by a scalar value.
+    #[inline]
+    fn div_assign(&mut self, rhs: f64) {
+        *self = *self / rhs
+    }
+}
+
+impl ops::Div<DVector> for DVector {
+    type Output = DVector;
+    /// Element-wise division of two vectors.
+    #[inline]
+    fn div(self, rhs: DVector) -> Self::Output {
+        Self { arr: [
+            self.get_x() / rhs.get_x(), 
+            self.get_y() / rhs.get_y(), 
+            self.get_z() / rhs.get_z(), 
+            self.get_w() / rhs.get_w()
+        ] }
+    }
+}
+
+impl ops::DivAssign<DVector> for DVector {
+    /// Element-wise division of two vectors.
+    #[inline]
+    fn div_assign(&mut self, rhs: DVector) {
+        *self = *self / rhs
+    }
+}
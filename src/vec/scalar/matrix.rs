@@ -1,6 +1,10 @@
 use core::fmt;
 use core::ops;
-use crate::{ Vector, Quaternion, Float3x3, Float4x4 };
+use crate::{ DMatrix, DepthRange, EulerRot, Plane, Vector, Vector3x4, Vector4x4, Quaternion, Float3, Float4, Float3x3, Float4x4 };
+use crate::near_eq;
+
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
 
 
 
@@ -125,8 +129,8 @@ impl Matrix {
     pub fn load_float3x3(val: Float3x3) -> Self {
         Self::from_columns(
             Vector::load_float3(val.x_axis), 
-            Vector::load_float3(val.x_axis), 
-            Vector::load_float3(val.x_axis), 
+            Vector::load_float3(val.y_axis), 
+            Vector::load_float3(val.z_axis), 
             Vector::W
         )
     }
@@ -156,6 +160,18 @@ impl Matrix {
         Float4x4::from_column_array(self.into_column_array())
     }
 
+    /// Converts to a double-precision matrix.
+    #[inline]
+    #[must_use]
+    pub fn as_f64(self) -> DMatrix {
+        DMatrix::from_columns(
+            self.get_x_axis().as_f64(),
+            self.get_y_axis().as_f64(),
+            self.get_z_axis().as_f64(),
+            self.get_w_axis().as_f64()
+        )
+    }
+
     /// Creates a matrix from a given quaternion.
     /// 
     /// # Panics
@@ -200,6 +216,102 @@ impl Matrix {
         Quaternion::try_from_matrix(self)
     }
 
+    /// Creates a rotation matrix from the given Euler angles.
+    /// 
+    /// ※ The angles given are in radians. </br>
+    /// ※ The rotations are intrinsic and applied in the given `order`. (See [`Quaternion::from_euler`]) </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn from_euler(order: EulerRot, a: f32, b: f32, c: f32) -> Self {
+        Quaternion::from_euler(order, a, b, c).into_matrix()
+    }
+
+    /// Converts the rotation of the matrix to Euler angles in the given `order`.
+    /// 
+    /// ※ The scale of each axis is removed before converting. </br>
+    /// ※ The range of the angles and the gimbal lock are handled in the same way as [`Quaternion::to_euler`]. </br>
+    /// 
+    /// # Panics
+    /// When `use-assertion` feature is enabled, [`panic!`] will be called 
+    /// if the length of each axis of the matrix is less than or equal to [`f32::EPSILON`].
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn to_euler_angles(self, order: EulerRot) -> (f32, f32, f32) {
+        Quaternion::from_matrix(self).to_euler(order)
+    }
+
+    /// Decomposes the matrix into `scale`, `rotation` and `translation`.
+    /// 
+    /// ※ The matrix must be a transformation matrix created from a scale, a rotation and a translation. </br>
+    /// ※ If the determinant of the matrix is negative (reflection), the x element of the scale is negative. </br>
+    /// 
+    /// # Panics
+    /// When `use-assertion` feature is enabled, [`panic!`] will be called 
+    /// if the length of each axis of the matrix is less than or equal to [`f32::EPSILON`].
+    /// 
+    #[must_use]
+    pub fn to_scale_rotation_translation(self) -> (Vector, Quaternion, Vector) {
+        let x_axis = *self.get_x_axis();
+        let y_axis = *self.get_y_axis();
+        let z_axis = *self.get_z_axis();
+        let sign = z_axis.vec3_dot_into(x_axis.vec3_cross(y_axis)).signum();
+        let scale = Vector::new(sign * x_axis.vec3_len(), y_axis.vec3_len(), z_axis.vec3_len(), 0.0);
+
+        #[cfg(feature = "use-assertion")]
+        assert!(
+            scale.get_x().abs() > f32::EPSILON && scale.get_y() > f32::EPSILON && scale.get_z() > f32::EPSILON, 
+            "The length of each axis of the matrix is less than or equal to `f32::EPSILON`!"
+        );
+
+        let rotation = Quaternion::from_rotation_axes(
+            x_axis * scale.get_x().recip(), 
+            y_axis * scale.get_y().recip(), 
+            z_axis * scale.get_z().recip() 
+        );
+
+        let mut translation = *self.get_w_axis();
+        translation.set_w(0.0);
+        (scale, rotation, translation)
+    }
+
+    /// Decomposes the matrix into `scale`, `rotation` and `translation`.
+    /// 
+    /// Returns `None` if the matrix cannot be decomposed. 
+    /// That is, if the length of an axis is less than or equal to [`f32::EPSILON`], 
+    /// the axes are not orthogonal (shear), or the last row of the matrix is not `(0, 0, 0, 1)`.
+    /// 
+    /// ※ If the determinant of the matrix is negative (reflection), the x element of the scale is negative. </br>
+    /// 
+    #[must_use]
+    pub fn try_decompose(self) -> Option<(Vector, Quaternion, Vector)> {
+        const DECOMPOSE_EPSILON: f32 = 1.0e-4;
+        let x_axis = *self.get_x_axis();
+        let y_axis = *self.get_y_axis();
+        let z_axis = *self.get_z_axis();
+        let w_axis = *self.get_w_axis();
+
+        let last_row = [x_axis.get_w(), y_axis.get_w(), z_axis.get_w(), w_axis.get_w() - 1.0];
+        if last_row.iter().any(|e| e.abs() > DECOMPOSE_EPSILON) {
+            return None;
+        }
+
+        let x_axis = x_axis.try_vec3_normalize()?;
+        let y_axis = y_axis.try_vec3_normalize()?;
+        let z_axis = z_axis.try_vec3_normalize()?;
+        let dots = [
+            x_axis.vec3_dot_into(y_axis), 
+            y_axis.vec3_dot_into(z_axis), 
+            z_axis.vec3_dot_into(x_axis) 
+        ];
+        if dots.iter().any(|e| e.abs() > DECOMPOSE_EPSILON) {
+            return None;
+        }
+
+        Some(self.to_scale_rotation_translation())
+    }
+
     /// Create a matrix with the given `translation`.
     #[inline]
     #[must_use]
@@ -300,6 +412,46 @@ impl Matrix {
         )
     }
 
+    /// Creates a matrix that reflects points and vectors about the given plane.
+    /// 
+    /// ※ The plane is normalized before use. </br>
+    /// ※ This has a similar purpose to `XMMatrixReflect` in the `DirectXMath` library. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn reflect(plane: Plane) -> Self {
+        let plane = plane.normalize();
+        let (p, s) = (plane.into_vector(), plane.normal() * -2.0);
+        Self::from_columns(
+            s.mul_add(p.splat_x(), Vector::X), 
+            s.mul_add(p.splat_y(), Vector::Y), 
+            s.mul_add(p.splat_z(), Vector::Z), 
+            s.mul_add(p.splat_w(), Vector::W)
+        )
+    }
+
+    /// Creates a matrix that projects points onto the given plane along the rays from the given light.
+    /// 
+    /// ※ If the `w` element of the light is zero, it is a directional light along the direction `(x, y, z)`. 
+    /// If the `w` element of the light is one, it is a point light at the position `(x, y, z)`. </br>
+    /// ※ The plane is normalized before use. </br>
+    /// ※ The `w` element of the transformed point is not one, so it must be divided by `w`. </br>
+    /// ※ This has a similar purpose to `XMMatrixShadow` in the `DirectXMath` library. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn shadow(plane: Plane, light: Vector) -> Self {
+        let p = plane.normalize().into_vector();
+        let dot = Vector::fill(p.vec4_dot_into(light));
+        let l = -light;
+        Self::from_columns(
+            l.mul_add(p.splat_x(), Vector::X * dot), 
+            l.mul_add(p.splat_y(), Vector::Y * dot), 
+            l.mul_add(p.splat_z(), Vector::Z * dot), 
+            l.mul_add(p.splat_w(), Vector::W * dot)
+        )
+    }
+
     /// Create a right-handed coordinate view matrix with the given `eye`, `dir`, and `up`.
     /// 
     /// ※ The given `dir` and `up` must be unit vectors.
@@ -389,6 +541,39 @@ impl Matrix {
     #[inline]
     #[must_use]
     pub fn perspective_rh(fov_y: f32, aspect_ratio: f32, z_near: f32, z_far: f32) -> Self {
+        Self::perspective_depth_range_rh(fov_y, aspect_ratio, z_near, z_far, DepthRange::ZeroToOne)
+    }
+
+    /// Create a left-handed coordinate perspective projection matrix
+    /// with the given `fov_y`, `aspect_ratio`, `z_near`, `z_far`.
+    /// 
+    /// ※ The depth of the created frustum ranges from `0.0` to `1.0`. </br>
+    /// ※ The given `fov_y` is in radians. </br>
+    /// ※ The given value of `z_near` and `z_far` must be different.
+    /// 
+    /// # Panics
+    /// When the `use-assertion` feature is enabled, it will [`panic!`]
+    /// if the given `z_near` and `z_far` are equal.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn perspective_lh(fov_y: f32, aspect_ratio: f32, z_near: f32, z_far: f32) -> Self {
+        Self::perspective_depth_range_lh(fov_y, aspect_ratio, z_near, z_far, DepthRange::ZeroToOne)
+    }
+
+    /// Create a right-handed coordinate perspective projection matrix
+    /// with the given `fov_y`, `aspect_ratio`, `z_near`, `z_far` and `depth_range`.
+    /// 
+    /// ※ The depth of the created frustum ranges in the given `depth_range`. </br>
+    /// ※ The given `fov_y` is in radians. </br>
+    /// ※ The given value of `z_near` and `z_far` must be different.
+    /// 
+    /// # Panics
+    /// When the `use-assertion` feature is enabled, it will [`panic!`]
+    /// if the given `z_near` and `z_far` are equal.
+    /// 
+    #[must_use]
+    pub fn perspective_depth_range_rh(fov_y: f32, aspect_ratio: f32, z_near: f32, z_far: f32, depth_range: DepthRange) -> Self {
         #[cfg(feature = "use-assertion")] {
             let invalidate = (z_far - z_near).abs() <= f32::EPSILON;
             assert!(!invalidate, "The given value of 'z_near' and 'z_far' must be different!");
@@ -397,20 +582,124 @@ impl Matrix {
         let (s, c) = (0.5 * fov_y).sin_cos();
         let h = c / s;
         let w = h / aspect_ratio;
-        let r = z_far / (z_near - z_far);
+        let (a, b) = match depth_range {
+            DepthRange::ZeroToOne => {
+                let r = z_far / (z_near - z_far);
+                (r, r * z_near)
+            },
+            DepthRange::NegOneToOne => {
+                let recip_depth = 1.0 / (z_near - z_far);
+                ((z_far + z_near) * recip_depth, 2.0 * z_far * z_near * recip_depth)
+            }
+        };
         Self::new(
             w, 0.0, 0.0, 0.0, 
             0.0, h, 0.0, 0.0, 
-            0.0, 0.0, r, -1.0, 
-            0.0, 0.0, r * z_near, 0.0
+            0.0, 0.0, a, -1.0, 
+            0.0, 0.0, b, 0.0
         )
     }
 
     /// Create a left-handed coordinate perspective projection matrix
-    /// with the given `fov_y`, `aspect_ratio`, `z_near`, `z_far`.
+    /// with the given `fov_y`, `aspect_ratio`, `z_near`, `z_far` and `depth_range`.
+    /// 
+    /// ※ The depth of the created frustum ranges in the given `depth_range`. </br>
+    /// ※ The given `fov_y` is in radians. </br>
+    /// ※ The given value of `z_near` and `z_far` must be different.
+    /// 
+    /// # Panics
+    /// When the `use-assertion` feature is enabled, it will [`panic!`]
+    /// if the given `z_near` and `z_far` are equal.
+    /// 
+    #[must_use]
+    pub fn perspective_depth_range_lh(fov_y: f32, aspect_ratio: f32, z_near: f32, z_far: f32, depth_range: DepthRange) -> Self {
+        #[cfg(feature = "use-assertion")] {
+            let invalidate = (z_far - z_near).abs() <= f32::EPSILON;
+            assert!(!invalidate, "The given value of 'z_near' and 'z_far' must be different!");
+        }
+
+        let (s, c) = (0.5 * fov_y).sin_cos();
+        let h = c / s;
+        let w = h / aspect_ratio;
+        let (a, b) = match depth_range {
+            DepthRange::ZeroToOne => {
+                let r = z_far / (z_far - z_near);
+                (r, -r * z_near)
+            },
+            DepthRange::NegOneToOne => {
+                let recip_depth = 1.0 / (z_far - z_near);
+                ((z_far + z_near) * recip_depth, -2.0 * z_far * z_near * recip_depth)
+            }
+        };
+        Self::new(
+            w, 0.0, 0.0, 0.0, 
+            0.0, h, 0.0, 0.0, 
+            0.0, 0.0, a, 1.0, 
+            0.0, 0.0, b, 0.0
+        )
+    }
+
+    /// Create a right-handed coordinate perspective projection matrix
+    /// with the given `fov_y`, `aspect_ratio`, `z_near` and an infinite far plane.
+    /// 
+    /// ※ The depth of the created frustum ranges from `0.0` to `1.0`. </br>
+    /// ※ The given `fov_y` is in radians. </br>
+    /// ※ The given value of `z_near` must be greater than zero.
+    /// 
+    /// # Panics
+    /// When the `use-assertion` feature is enabled, it will [`panic!`]
+    /// if the given `z_near` is less than or equal to zero.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn perspective_infinite_rh(fov_y: f32, aspect_ratio: f32, z_near: f32) -> Self {
+        #[cfg(feature = "use-assertion")]
+        assert!(z_near > 0.0, "The given value of 'z_near' must be greater than zero!");
+
+        let (s, c) = (0.5 * fov_y).sin_cos();
+        let h = c / s;
+        let w = h / aspect_ratio;
+        Self::new(
+            w, 0.0, 0.0, 0.0, 
+            0.0, h, 0.0, 0.0, 
+            0.0, 0.0, -1.0, -1.0, 
+            0.0, 0.0, -z_near, 0.0
+        )
+    }
+
+    /// Create a left-handed coordinate perspective projection matrix
+    /// with the given `fov_y`, `aspect_ratio`, `z_near` and an infinite far plane.
     /// 
     /// ※ The depth of the created frustum ranges from `0.0` to `1.0`. </br>
     /// ※ The given `fov_y` is in radians. </br>
+    /// ※ The given value of `z_near` must be greater than zero.
+    /// 
+    /// # Panics
+    /// When the `use-assertion` feature is enabled, it will [`panic!`]
+    /// if the given `z_near` is less than or equal to zero.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn perspective_infinite_lh(fov_y: f32, aspect_ratio: f32, z_near: f32) -> Self {
+        #[cfg(feature = "use-assertion")]
+        assert!(z_near > 0.0, "The given value of 'z_near' must be greater than zero!");
+
+        let (s, c) = (0.5 * fov_y).sin_cos();
+        let h = c / s;
+        let w = h / aspect_ratio;
+        Self::new(
+            w, 0.0, 0.0, 0.0, 
+            0.0, h, 0.0, 0.0, 
+            0.0, 0.0, 1.0, 1.0, 
+            0.0, 0.0, -z_near, 0.0
+        )
+    }
+
+    /// Create a right-handed coordinate perspective projection matrix
+    /// with the given `fov_y`, `aspect_ratio`, `z_near`, `z_far` and a reversed depth.
+    /// 
+    /// ※ The depth of the created frustum ranges from `1.0` (near) to `0.0` (far). </br>
+    /// ※ The given `fov_y` is in radians. </br>
     /// ※ The given value of `z_near` and `z_far` must be different.
     /// 
     /// # Panics
@@ -419,7 +708,7 @@ impl Matrix {
     /// 
     #[inline]
     #[must_use]
-    pub fn perspective_lh(fov_y: f32, aspect_ratio: f32, z_near: f32, z_far: f32) -> Self {
+    pub fn perspective_reverse_rh(fov_y: f32, aspect_ratio: f32, z_near: f32, z_far: f32) -> Self {
         #[cfg(feature = "use-assertion")] {
             let invalidate = (z_far - z_near).abs() <= f32::EPSILON;
             assert!(!invalidate, "The given value of 'z_near' and 'z_far' must be different!");
@@ -428,12 +717,99 @@ impl Matrix {
         let (s, c) = (0.5 * fov_y).sin_cos();
         let h = c / s;
         let w = h / aspect_ratio;
-        let r = z_far / (z_far - z_near);
+        let r = z_near / (z_far - z_near);
         Self::new(
             w, 0.0, 0.0, 0.0, 
             0.0, h, 0.0, 0.0, 
-            0.0, 0.0, r, 1.0, 
-            0.0, 0.0, -r * z_near, 0.0
+            0.0, 0.0, r, -1.0, 
+            0.0, 0.0, r * z_far, 0.0
+        )
+    }
+
+    /// Create a left-handed coordinate perspective projection matrix
+    /// with the given `fov_y`, `aspect_ratio`, `z_near`, `z_far` and a reversed depth.
+    /// 
+    /// ※ The depth of the created frustum ranges from `1.0` (near) to `0.0` (far). </br>
+    /// ※ The given `fov_y` is in radians. </br>
+    /// ※ The given value of `z_near` and `z_far` must be different.
+    /// 
+    /// # Panics
+    /// When the `use-assertion` feature is enabled, it will [`panic!`]
+    /// if the given `z_near` and `z_far` are equal.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn perspective_reverse_lh(fov_y: f32, aspect_ratio: f32, z_near: f32, z_far: f32) -> Self {
+        #[cfg(feature = "use-assertion")] {
+            let invalidate = (z_far - z_near).abs() <= f32::EPSILON;
+            assert!(!invalidate, "The given value of 'z_near' and 'z_far' must be different!");
+        }
+
+        let (s, c) = (0.5 * fov_y).sin_cos();
+        let h = c / s;
+        let w = h / aspect_ratio;
+        let r = z_near / (z_far - z_near);
+        Self::new(
+            w, 0.0, 0.0, 0.0, 
+            0.0, h, 0.0, 0.0, 
+            0.0, 0.0, -r, 1.0, 
+            0.0, 0.0, r * z_far, 0.0
+        )
+    }
+
+    /// Create a right-handed coordinate perspective projection matrix
+    /// with the given `fov_y`, `aspect_ratio`, `z_near`, an infinite far plane and a reversed depth.
+    /// 
+    /// ※ The depth of the created frustum ranges from `1.0` (near) to `0.0` (infinity). </br>
+    /// ※ The given `fov_y` is in radians. </br>
+    /// ※ The given value of `z_near` must be greater than zero.
+    /// 
+    /// # Panics
+    /// When the `use-assertion` feature is enabled, it will [`panic!`]
+    /// if the given `z_near` is less than or equal to zero.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn perspective_infinite_reverse_rh(fov_y: f32, aspect_ratio: f32, z_near: f32) -> Self {
+        #[cfg(feature = "use-assertion")]
+        assert!(z_near > 0.0, "The given value of 'z_near' must be greater than zero!");
+
+        let (s, c) = (0.5 * fov_y).sin_cos();
+        let h = c / s;
+        let w = h / aspect_ratio;
+        Self::new(
+            w, 0.0, 0.0, 0.0, 
+            0.0, h, 0.0, 0.0, 
+            0.0, 0.0, 0.0, -1.0, 
+            0.0, 0.0, z_near, 0.0
+        )
+    }
+
+    /// Create a left-handed coordinate perspective projection matrix
+    /// with the given `fov_y`, `aspect_ratio`, `z_near`, an infinite far plane and a reversed depth.
+    /// 
+    /// ※ The depth of the created frustum ranges from `1.0` (near) to `0.0` (infinity). </br>
+    /// ※ The given `fov_y` is in radians. </br>
+    /// ※ The given value of `z_near` must be greater than zero.
+    /// 
+    /// # Panics
+    /// When the `use-assertion` feature is enabled, it will [`panic!`]
+    /// if the given `z_near` is less than or equal to zero.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn perspective_infinite_reverse_lh(fov_y: f32, aspect_ratio: f32, z_near: f32) -> Self {
+        #[cfg(feature = "use-assertion")]
+        assert!(z_near > 0.0, "The given value of 'z_near' must be greater than zero!");
+
+        let (s, c) = (0.5 * fov_y).sin_cos();
+        let h = c / s;
+        let w = h / aspect_ratio;
+        Self::new(
+            w, 0.0, 0.0, 0.0, 
+            0.0, h, 0.0, 0.0, 
+            0.0, 0.0, 0.0, 1.0, 
+            0.0, 0.0, z_near, 0.0
         )
     }
 
@@ -454,6 +830,46 @@ impl Matrix {
     #[inline]
     #[must_use]
     pub fn orthographic_rh(left: f32, right: f32, bottom: f32, top: f32, near: f32, far: f32) -> Self {
+        Self::orthographic_depth_range_rh(left, right, bottom, top, near, far, DepthRange::ZeroToOne)
+    }
+
+    /// Create a left-handed coordinate orthographic projection matrix
+    /// with the given `left`, `right`, `bottom`, `top`, `near`, `far`.
+    /// 
+    /// ※ The depth of the created frustum ranges from `0.0` to `1.0`. </br>
+    /// ※ The given value of `left` and `right` must be different. </br>
+    /// ※ The given value of `bottom` and `top` must be different. </br>
+    /// ※ The given value of `near` and `far` must be different. </br>
+    /// 
+    /// # Panics
+    /// When the `use-assertion` feature is enabled, it will [`panic!`]
+    /// if the given `left` and `right` are equal 
+    /// or `bottom` and `top` are equal
+    /// or `near` and `far` are equal.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn orthographic_lh(left: f32, right: f32, bottom: f32, top: f32, near: f32, far: f32) -> Self {
+        Self::orthographic_depth_range_lh(left, right, bottom, top, near, far, DepthRange::ZeroToOne)
+    }
+
+    /// Create a right-handed coordinate orthographic projection matrix
+    /// with the given `left`, `right`, `bottom`, `top`, `near`, `far` and `depth_range`.
+    /// 
+    /// ※ The depth of the created frustum ranges in the given `depth_range`. </br>
+    /// ※ The given value of `left` and `right` must be different. </br>
+    /// ※ The given value of `bottom` and `top` must be different. </br>
+    /// ※ The given value of `near` and `far` must be different. </br>
+    /// 
+    /// # Panics
+    /// When the `use-assertion` feature is enabled, it will [`panic!`]
+    /// if the given `left` and `right` are equal 
+    /// or `bottom` and `top` are equal
+    /// or `near` and `far` are equal.
+    /// 
+    #[allow(clippy::too_many_arguments)]
+    #[must_use]
+    pub fn orthographic_depth_range_rh(left: f32, right: f32, bottom: f32, top: f32, near: f32, far: f32, depth_range: DepthRange) -> Self {
         #[cfg(feature = "use-assertion")] {
             let invalidate = (left - right).abs() <= f32::EPSILON;
             assert!(!invalidate, "The given value of 'left' and 'right' must be different!");
@@ -466,21 +882,25 @@ impl Matrix {
         let recip_width = 1.0 / (right - left);
         let recip_height = 1.0 / (top - bottom);
         let recip_depth = 1.0 / (near - far);
+        let (a, b) = match depth_range {
+            DepthRange::ZeroToOne => (recip_depth, near * recip_depth), 
+            DepthRange::NegOneToOne => (2.0 * recip_depth, (near + far) * recip_depth)
+        };
         Self::new(
             2.0 * recip_width, 0.0, 0.0, 0.0, 
             0.0, 2.0 * recip_height, 0.0, 0.0, 
-            0.0, 0.0, recip_depth, 0.0, 
+            0.0, 0.0, a, 0.0, 
             -(left + right) * recip_width, 
             -(bottom + top) * recip_height, 
-            near * recip_depth, 
+            b, 
             1.0
         )
     }
 
     /// Create a left-handed coordinate orthographic projection matrix
-    /// with the given `left`, `right`, `bottom`, `top`, `near`, `far`.
+    /// with the given `left`, `right`, `bottom`, `top`, `near`, `far` and `depth_range`.
     /// 
-    /// ※ The depth of the created frustum ranges from `0.0` to `1.0`. </br>
+    /// ※ The depth of the created frustum ranges in the given `depth_range`. </br>
     /// ※ The given value of `left` and `right` must be different. </br>
     /// ※ The given value of `bottom` and `top` must be different. </br>
     /// ※ The given value of `near` and `far` must be different. </br>
@@ -491,9 +911,9 @@ impl Matrix {
     /// or `bottom` and `top` are equal
     /// or `near` and `far` are equal.
     /// 
-    #[inline]
+    #[allow(clippy::too_many_arguments)]
     #[must_use]
-    pub fn orthographic_lh(left: f32, right: f32, bottom: f32, top: f32, near: f32, far: f32) -> Self {
+    pub fn orthographic_depth_range_lh(left: f32, right: f32, bottom: f32, top: f32, near: f32, far: f32, depth_range: DepthRange) -> Self {
         #[cfg(feature = "use-assertion")] {
             let invalidate = (left - right).abs() <= f32::EPSILON;
             assert!(!invalidate, "The given value of 'left' and 'right' must be different!");
@@ -506,13 +926,17 @@ impl Matrix {
         let recip_width = 1.0 / (right - left);
         let recip_height = 1.0 / (top - bottom);
         let recip_depth = 1.0 / (far - near);
+        let (a, b) = match depth_range {
+            DepthRange::ZeroToOne => (recip_depth, -near * recip_depth), 
+            DepthRange::NegOneToOne => (2.0 * recip_depth, -(near + far) * recip_depth)
+        };
         Self::new(
             2.0 * recip_width, 0.0, 0.0, 0.0, 
             0.0, 2.0 * recip_height, 0.0, 0.0, 
-            0.0, 0.0, recip_depth, 0.0, 
+            0.0, 0.0, a, 0.0, 
             -(left + right) * recip_width, 
             -(bottom + top) * recip_height, 
-            -near * recip_depth, 
+            b, 
             1.0
         )
     }
@@ -736,15 +1160,169 @@ impl Matrix {
 
     /// Inverse of a matrix.
     /// 
-    /// Returns `None` if the determinant of a matrix is less than or equal to [`f32::EPSILON`].
+    /// Returns `None` if the absolute value of the determinant of a matrix is less than or equal to [`f32::EPSILON`].
     /// 
     #[must_use]
     pub fn try_inverse(self) -> Option<Self> {
-        let det = self.determinant_into();
-        if det <= f32::EPSILON {
+        if self.determinant_into().abs() <= f32::EPSILON {
             return None;
         }
-        Some(self * det.recip())
+        Some(self.inverse())
+    }
+
+    /// Transforms four points at once. (`w` is one)
+    #[inline]
+    #[must_use]
+    pub fn transform_point3x4(self, v: Vector3x4) -> Vector3x4 {
+        let (c0, c1, c2, c3) = (*self.get_x_axis(), *self.get_y_axis(), *self.get_z_axis(), *self.get_w_axis());
+        Vector3x4 {
+            x: v.x.mul_add(c0.splat_x(), v.y.mul_add(c1.splat_x(), v.z.mul_add(c2.splat_x(), c3.splat_x()))), 
+            y: v.x.mul_add(c0.splat_y(), v.y.mul_add(c1.splat_y(), v.z.mul_add(c2.splat_y(), c3.splat_y()))), 
+            z: v.x.mul_add(c0.splat_z(), v.y.mul_add(c1.splat_z(), v.z.mul_add(c2.splat_z(), c3.splat_z())))
+        }
+    }
+
+    /// Transforms four vectors at once. (`w` is zero)
+    #[inline]
+    #[must_use]
+    pub fn transform_vector3x4(self, v: Vector3x4) -> Vector3x4 {
+        let (c0, c1, c2) = (*self.get_x_axis(), *self.get_y_axis(), *self.get_z_axis());
+        Vector3x4 {
+            x: v.x.mul_add(c0.splat_x(), v.y.mul_add(c1.splat_x(), v.z * c2.splat_x())), 
+            y: v.x.mul_add(c0.splat_y(), v.y.mul_add(c1.splat_y(), v.z * c2.splat_y())), 
+            z: v.x.mul_add(c0.splat_z(), v.y.mul_add(c1.splat_z(), v.z * c2.splat_z()))
+        }
+    }
+
+    /// Transforms four four-dimensional vectors at once.
+    #[inline]
+    #[must_use]
+    pub fn transform_vector4x4(self, v: Vector4x4) -> Vector4x4 {
+        let (c0, c1, c2, c3) = (*self.get_x_axis(), *self.get_y_axis(), *self.get_z_axis(), *self.get_w_axis());
+        Vector4x4 {
+            x: v.x.mul_add(c0.splat_x(), v.y.mul_add(c1.splat_x(), v.z.mul_add(c2.splat_x(), v.w * c3.splat_x()))), 
+            y: v.x.mul_add(c0.splat_y(), v.y.mul_add(c1.splat_y(), v.z.mul_add(c2.splat_y(), v.w * c3.splat_y()))), 
+            z: v.x.mul_add(c0.splat_z(), v.y.mul_add(c1.splat_z(), v.z.mul_add(c2.splat_z(), v.w * c3.splat_z()))), 
+            w: v.x.mul_add(c0.splat_w(), v.y.mul_add(c1.splat_w(), v.z.mul_add(c2.splat_w(), v.w * c3.splat_w())))
+        }
+    }
+
+    /// Transforms the points of the `src` slice and stores them in the `dst` slice. (`w` is one)
+    /// 
+    /// ※ Four points are transformed at a time using [`Vector3x4`]. </br>
+    /// ※ If the lengths are different, only the shorter length is transformed. </br>
+    /// 
+    /// # Panics
+    /// When the `use-assertion` feature is enabled, it will [`panic!`]
+    /// if the `src` and `dst` slices have different lengths.
+    /// 
+    #[inline]
+    pub fn transform_point3_slice(self, src: &[Float3], dst: &mut [Float3]) {
+        transform_float3_slice(src, dst, |v| self.transform_point3x4(v))
+    }
+
+    /// Transforms the vectors of the `src` slice and stores them in the `dst` slice. (`w` is zero)
+    /// 
+    /// ※ Four vectors are transformed at a time using [`Vector3x4`]. </br>
+    /// ※ If the lengths are different, only the shorter length is transformed. </br>
+    /// 
+    /// # Panics
+    /// When the `use-assertion` feature is enabled, it will [`panic!`]
+    /// if the `src` and `dst` slices have different lengths.
+    /// 
+    #[inline]
+    pub fn transform_vector3_slice(self, src: &[Float3], dst: &mut [Float3]) {
+        transform_float3_slice(src, dst, |v| self.transform_vector3x4(v))
+    }
+
+    /// Transforms the four-dimensional vectors of the `src` slice and stores them in the `dst` slice.
+    /// 
+    /// ※ Four vectors are transformed at a time using [`Vector4x4`]. </br>
+    /// ※ If the lengths are different, only the shorter length is transformed. </br>
+    /// 
+    /// # Panics
+    /// When the `use-assertion` feature is enabled, it will [`panic!`]
+    /// if the `src` and `dst` slices have different lengths.
+    /// 
+    #[inline]
+    pub fn transform_float4_slice(self, src: &[Float4], dst: &mut [Float4]) {
+        transform_float4_slice(src, dst, |v| self.transform_vector4x4(v))
+    }
+
+    /// Transforms `count` points of the interleaved `src` buffer and stores them in the interleaved `dst` buffer. (`w` is one)
+    /// 
+    /// ※ The strides are the number of `f32` elements between the starts of two consecutive points. </br>
+    /// ※ Only the first three elements of each stride are read and written. </br>
+    /// 
+    /// # Panics
+    /// It will [`panic!`] if a buffer is too short to hold `count` elements with the given stride.
+    /// 
+    #[inline]
+    pub fn transform_point3_slice_strided(self, src: &[f32], src_stride: usize, dst: &mut [f32], dst_stride: usize, count: usize) {
+        transform_float3_strided(src, src_stride, dst, dst_stride, count, |v| self.transform_point3x4(v))
+    }
+
+    /// Transforms `count` vectors of the interleaved `src` buffer and stores them in the interleaved `dst` buffer. (`w` is zero)
+    /// 
+    /// ※ The strides are the number of `f32` elements between the starts of two consecutive vectors. </br>
+    /// ※ Only the first three elements of each stride are read and written. </br>
+    /// 
+    /// # Panics
+    /// It will [`panic!`] if a buffer is too short to hold `count` elements with the given stride.
+    /// 
+    #[inline]
+    pub fn transform_vector3_slice_strided(self, src: &[f32], src_stride: usize, dst: &mut [f32], dst_stride: usize, count: usize) {
+        transform_float3_strided(src, src_stride, dst, dst_stride, count, |v| self.transform_vector3x4(v))
+    }
+
+    /// Transforms `count` four-dimensional vectors of the interleaved `src` buffer and stores them in the interleaved `dst` buffer.
+    /// 
+    /// ※ The strides are the number of `f32` elements between the starts of two consecutive vectors. </br>
+    /// ※ Only the first four elements of each stride are read and written. </br>
+    /// 
+    /// # Panics
+    /// It will [`panic!`] if a buffer is too short to hold `count` elements with the given stride.
+    /// 
+    #[inline]
+    pub fn transform_float4_slice_strided(self, src: &[f32], src_stride: usize, dst: &mut [f32], dst_stride: usize, count: usize) {
+        transform_float4_strided(src, src_stride, dst, dst_stride, count, |v| self.transform_vector4x4(v))
+    }
+}
+
+impl Matrix {
+    /// Returns `true` if the absolute difference of all elements is less than or equal to `max_abs_diff`.
+    #[inline]
+    #[must_use]
+    pub fn abs_diff_eq(self, rhs: Self, max_abs_diff: f32) -> bool {
+        near_eq::abs_diff_eq(&self.into_column_array(), &rhs.into_column_array(), max_abs_diff)
+    }
+
+    /// Returns `true` if all elements are equal within `max_abs_diff` or the relative tolerance `max_relative`.
+    /// 
+    /// ※ The relative tolerance is scaled by the larger absolute value of the two elements. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn relative_eq(self, rhs: Self, max_abs_diff: f32, max_relative: f32) -> bool {
+        near_eq::relative_eq(&self.into_column_array(), &rhs.into_column_array(), max_abs_diff, max_relative)
+    }
+
+    /// Returns `true` if all elements are equal within `max_abs_diff` or `max_ulps` representable values.
+    /// 
+    /// ※ Elements with different signs are equal only within `max_abs_diff`. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn ulps_eq(self, rhs: Self, max_abs_diff: f32, max_ulps: u32) -> bool {
+        near_eq::ulps_eq(&self.into_column_array(), &rhs.into_column_array(), max_abs_diff, max_ulps)
+    }
+}
+
+impl PartialEq for Matrix {
+    /// Compares all elements exactly.
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.into_column_array() == other.into_column_array()
     }
 }
 
@@ -898,14 +1476,23 @@ impl ops::Mul<Vector> for Matrix {
     /// Transformation of the vector.
     fn mul(self, rhs: Vector) -> Self::Output {
         Vector::new(
-            self.get_x_axis().get_x() * rhs.get_x() + self.get_x_axis().get_y() * rhs.get_y() + self.get_x_axis().get_z() * rhs.get_z() + self.get_x_axis().get_w() * rhs.get_w(), 
-            self.get_y_axis().get_x() * rhs.get_x() + self.get_y_axis().get_y() * rhs.get_y() + self.get_y_axis().get_z() * rhs.get_z() + self.get_y_axis().get_w() * rhs.get_w(), 
-            self.get_z_axis().get_x() * rhs.get_x() + self.get_z_axis().get_y() * rhs.get_y() + self.get_z_axis().get_z() * rhs.get_z() + self.get_z_axis().get_w() * rhs.get_w(), 
-            self.get_w_axis().get_x() * rhs.get_x() + self.get_w_axis().get_y() * rhs.get_y() + self.get_w_axis().get_z() * rhs.get_z() + self.get_w_axis().get_w() * rhs.get_w() 
+            self.get_x_axis().get_x() * rhs.get_x() + self.get_y_axis().get_x() * rhs.get_y() + self.get_z_axis().get_x() * rhs.get_z() + self.get_w_axis().get_x() * rhs.get_w(), 
+            self.get_x_axis().get_y() * rhs.get_x() + self.get_y_axis().get_y() * rhs.get_y() + self.get_z_axis().get_y() * rhs.get_z() + self.get_w_axis().get_y() * rhs.get_w(), 
+            self.get_x_axis().get_z() * rhs.get_x() + self.get_y_axis().get_z() * rhs.get_y() + self.get_z_axis().get_z() * rhs.get_z() + self.get_w_axis().get_z() * rhs.get_w(), 
+            self.get_x_axis().get_w() * rhs.get_x() + self.get_y_axis().get_w() * rhs.get_y() + self.get_z_axis().get_w() * rhs.get_z() + self.get_w_axis().get_w() * rhs.get_w() 
         )
     }
 }
 
+impl ops::Mul<Vector4x4> for Matrix {
+    type Output = Vector4x4;
+    /// Transformation of the four vectors.
+    #[inline]
+    fn mul(self, rhs: Vector4x4) -> Self::Output {
+        self.transform_vector4x4(rhs)
+    }
+}
+
 impl ops::Mul<Self> for Matrix {
     type Output = Self;
     /// Multiplies two matrices.
@@ -942,3 +1529,81 @@ impl fmt::Debug for Matrix {
             .finish()
     }
 }
+
+/// Applies the given batch function to the `src` slice four elements at a time and stores the results in the `dst` slice.
+/// 
+/// ※ The remaining elements are padded with zeros, so every element goes through the same operations. </br>
+/// 
+#[inline]
+fn transform_float3_slice<F: Fn(Vector3x4) -> Vector3x4>(src: &[Float3], dst: &mut [Float3], f: F) {
+    #[cfg(feature = "use-assertion")]
+    assert!(src.len() == dst.len(), "The lengths of the `src` and `dst` slices are different!");
+
+    let len = src.len().min(dst.len());
+    let src_chunks = src[..len].chunks(4);
+    let dst_chunks = dst[..len].chunks_mut(4);
+    for (s, d) in src_chunks.zip(dst_chunks) {
+        let mut arr = [Float3::ZERO; 4];
+        arr[..s.len()].copy_from_slice(s);
+        let arr = f(Vector3x4::load_float3_array(arr)).store_float3_array();
+        d.copy_from_slice(&arr[..d.len()]);
+    }
+}
+
+/// Applies the given batch function to the `src` slice four elements at a time and stores the results in the `dst` slice.
+/// 
+/// ※ The remaining elements are padded with zeros, so every element goes through the same operations. </br>
+/// 
+#[inline]
+fn transform_float4_slice<F: Fn(Vector4x4) -> Vector4x4>(src: &[Float4], dst: &mut [Float4], f: F) {
+    #[cfg(feature = "use-assertion")]
+    assert!(src.len() == dst.len(), "The lengths of the `src` and `dst` slices are different!");
+
+    let len = src.len().min(dst.len());
+    let src_chunks = src[..len].chunks(4);
+    let dst_chunks = dst[..len].chunks_mut(4);
+    for (s, d) in src_chunks.zip(dst_chunks) {
+        let mut arr = [Float4::ZERO; 4];
+        arr[..s.len()].copy_from_slice(s);
+        let arr = f(Vector4x4::load_float4_array(arr)).store_float4_array();
+        d.copy_from_slice(&arr[..d.len()]);
+    }
+}
+
+/// Gathers `count` elements of the interleaved `src` buffer four at a time,
+/// applies the given batch function and scatters the results to the interleaved `dst` buffer.
+#[inline]
+fn transform_float3_strided<F: Fn(Vector3x4) -> Vector3x4>(src: &[f32], src_stride: usize, dst: &mut [f32], dst_stride: usize, count: usize, f: F) {
+    for base in (0..count).step_by(4) {
+        let num = (count - base).min(4);
+        let mut arr = [Float3::ZERO; 4];
+        for (i, e) in arr.iter_mut().enumerate().take(num) {
+            let offset = (base + i) * src_stride;
+            *e = Float3::from_slice(&src[offset..offset + 3]);
+        }
+        let arr = f(Vector3x4::load_float3_array(arr)).store_float3_array();
+        for (i, e) in arr.iter().enumerate().take(num) {
+            let offset = (base + i) * dst_stride;
+            dst[offset..offset + 3].copy_from_slice(&[e.x, e.y, e.z]);
+        }
+    }
+}
+
+/// Gathers `count` elements of the interleaved `src` buffer four at a time,
+/// applies the given batch function and scatters the results to the interleaved `dst` buffer.
+#[inline]
+fn transform_float4_strided<F: Fn(Vector4x4) -> Vector4x4>(src: &[f32], src_stride: usize, dst: &mut [f32], dst_stride: usize, count: usize, f: F) {
+    for base in (0..count).step_by(4) {
+        let num = (count - base).min(4);
+        let mut arr = [Float4::ZERO; 4];
+        for (i, e) in arr.iter_mut().enumerate().take(num) {
+            let offset = (base + i) * src_stride;
+            *e = Float4::from_slice(&src[offset..offset + 4]);
+        }
+        let arr = f(Vector4x4::load_float4_array(arr)).store_float4_array();
+        for (i, e) in arr.iter().enumerate().take(num) {
+            let offset = (base + i) * dst_stride;
+            dst[offset..offset + 4].copy_from_slice(&[e.x, e.y, e.z, e.w]);
+        }
+    }
+}
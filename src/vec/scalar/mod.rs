@@ -1,11 +1,23 @@
+mod dvector;
+pub use self::dvector::DVector;
+
 mod matrix;
 pub use self::matrix::Matrix;
 
+mod matrix3;
+pub use self::matrix3::Matrix3;
+
 mod quaternion;
 pub use self::quaternion::Quaternion;
 
 mod vector;
 pub use self::vector::Vector;
 
+mod vector3x4;
+pub use self::vector3x4::Vector3x4;
+
+mod vector4x4;
+pub use self::vector4x4::Vector4x4;
+
 mod vector_int;
 pub use self::vector_int::VectorInt;
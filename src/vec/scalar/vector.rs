@@ -1,6 +1,12 @@
 use core::fmt;
 use core::ops;
-use crate::{ Quaternion, VectorInt, Float2, Float3, Float4 };
+use crate::{ DVector, Quaternion, VectorInt, Float2, Float3, Float4, Half4, 
+    UByteN4, ByteN4, ShortN2, UShortN4, UDecN4, Float3PK, Rgb9e5 
+};
+use crate::near_eq;
+
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
 
 
 
@@ -167,6 +173,233 @@ impl Vector {
     pub fn store_float4(self) -> Float4 {
         Float4::from_array(self.into_array())
     }
+
+    /// Loads a value from a given `Half4`.
+    #[inline]
+    #[must_use]
+    pub fn load_half4(val: Half4) -> Self {
+        Self::load_float4(val.to_float4())
+    }
+
+    /// Stores the value in a `Half4`.
+    /// 
+    /// ※ Each element is rounded to the nearest representable value, ties to even. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn store_half4(self) -> Half4 {
+        Half4::from_float4(self.store_float4())
+    }
+
+    /// Loads a value from a given `UByteN4`.
+    #[inline]
+    #[must_use]
+    pub fn load_ubyten4(val: UByteN4) -> Self {
+        Self::new(val.x as f32, val.y as f32, val.z as f32, val.w as f32) / 255.0
+    }
+
+    /// Stores the value in a `UByteN4`.
+    /// 
+    /// ※ Each element is saturated to the range `[0, 1]` and rounded to the nearest integer. (NaN becomes zero) </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn store_ubyten4(self) -> UByteN4 {
+        let [x, y, z, w] = (self.max(Self::ZERO).min(Self::ONE) * 255.0).round().into_array();
+        UByteN4 { x: x as u8, y: y as u8, z: z as u8, w: w as u8 }
+    }
+
+    /// Loads a value from a given `ByteN4`.
+    /// 
+    /// ※ Both `i8::MIN` and `-i8::MAX` become negative one. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn load_byten4(val: ByteN4) -> Self {
+        (Self::new(val.x as f32, val.y as f32, val.z as f32, val.w as f32) / 127.0).max(Self::NEG_ONE)
+    }
+
+    /// Stores the value in a `ByteN4`.
+    /// 
+    /// ※ Each element is saturated to the range `[-1, 1]` and rounded to the nearest integer. (NaN becomes zero) </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn store_byten4(self) -> ByteN4 {
+        let v = Self::select(self.eq(self), self, Self::ZERO);
+        let [x, y, z, w] = (v.max(Self::NEG_ONE).min(Self::ONE) * 127.0).round().into_array();
+        ByteN4 { x: x as i8, y: y as i8, z: z as i8, w: w as i8 }
+    }
+
+    /// Loads a value from a given `ShortN2`.
+    /// 
+    /// ※ Both `i16::MIN` and `-i16::MAX` become negative one. </br>
+    /// ※ The `z` and `w` elements are zero. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn load_shortn2(val: ShortN2) -> Self {
+        (Self::new(val.x as f32, val.y as f32, 0.0, 0.0) / 32767.0).max(Self::NEG_ONE)
+    }
+
+    /// Stores the `x` and `y` elements in a `ShortN2`.
+    /// 
+    /// ※ Each element is saturated to the range `[-1, 1]` and rounded to the nearest integer. (NaN becomes zero) </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn store_shortn2(self) -> ShortN2 {
+        let v = Self::select(self.eq(self), self, Self::ZERO);
+        let [x, y, _, _] = (v.max(Self::NEG_ONE).min(Self::ONE) * 32767.0).round().into_array();
+        ShortN2 { x: x as i16, y: y as i16 }
+    }
+
+    /// Loads a value from a given `UShortN4`.
+    #[inline]
+    #[must_use]
+    pub fn load_ushortn4(val: UShortN4) -> Self {
+        Self::new(val.x as f32, val.y as f32, val.z as f32, val.w as f32) / 65535.0
+    }
+
+    /// Stores the value in a `UShortN4`.
+    /// 
+    /// ※ Each element is saturated to the range `[0, 1]` and rounded to the nearest integer. (NaN becomes zero) </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn store_ushortn4(self) -> UShortN4 {
+        let [x, y, z, w] = (self.max(Self::ZERO).min(Self::ONE) * 65535.0).round().into_array();
+        UShortN4 { x: x as u16, y: y as u16, z: z as u16, w: w as u16 }
+    }
+
+    /// Loads a value from a given `UDecN4`.
+    #[inline]
+    #[must_use]
+    pub fn load_udecn4(val: UDecN4) -> Self {
+        let v = Self::new(val.x() as f32, val.y() as f32, val.z() as f32, val.w() as f32);
+        v / Self::new(1023.0, 1023.0, 1023.0, 3.0)
+    }
+
+    /// Stores the value in a `UDecN4`.
+    /// 
+    /// ※ Each element is saturated to the range `[0, 1]` and rounded to the nearest integer. (NaN becomes zero) </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn store_udecn4(self) -> UDecN4 {
+        let v = self.max(Self::ZERO).min(Self::ONE) * Self::new(1023.0, 1023.0, 1023.0, 3.0);
+        let [x, y, z, w] = v.round().into_array();
+        UDecN4::new(x as u32, y as u32, z as u32, w as u32)
+    }
+
+    /// Loads a value from a given `Float3PK`.
+    /// 
+    /// ※ The `w` element is zero. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn load_float3pk(val: Float3PK) -> Self {
+        Self::load_float3(val.to_float3())
+    }
+
+    /// Stores the `x`, `y` and `z` elements in a `Float3PK`.
+    /// 
+    /// ※ Each element is rounded to the nearest representable value, ties to even. </br>
+    /// ※ Negative values become zero and finite values that are too large become the largest finite value. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn store_float3pk(self) -> Float3PK {
+        Float3PK::from_float3(self.store_float3())
+    }
+
+    /// Loads a value from a given `Rgb9e5`.
+    /// 
+    /// ※ The `w` element is zero. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn load_rgb9e5(val: Rgb9e5) -> Self {
+        Self::load_float3(val.to_float3())
+    }
+
+    /// Stores the `x`, `y` and `z` elements in a `Rgb9e5`.
+    /// 
+    /// ※ Each element is clamped to the range `[0, 65408]`. (NaN becomes zero) </br>
+    /// ※ The shared exponent is chosen from the largest element, and the mantissas are rounded to the nearest integer. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn store_rgb9e5(self) -> Rgb9e5 {
+        Rgb9e5::from_float3(self.store_float3())
+    }
+
+    /// Encodes the `x`, `y` and `z` elements of a linear color in the range `[0, range]` in `RGBM` format.
+    /// 
+    /// ※ The `w` element of the result is the shared multiplier, rounded up to a multiple of `1/255` so that it can be stored in 8 bits. </br>
+    /// ※ All elements of the result are in the range `[0, 1]`, and the decoded color is `rgb * w * range`. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn encode_rgbm(self, range: f32) -> Self {
+        let color = self / range;
+        let m = color.get_x().max(color.get_y()).max(color.get_z()).clamp(1e-6, 1.0);
+        let m = (m * 255.0).ceil() / 255.0;
+        let mut rgbm = (color / m).max(Self::ZERO).min(Self::ONE);
+        rgbm.set_w(m);
+        rgbm
+    }
+
+    /// Decodes a color in `RGBM` format with the given range.
+    /// 
+    /// ※ The `w` element of the result is zero. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn decode_rgbm(self, range: f32) -> Self {
+        let mut color = self * (self.get_w() * range);
+        color.set_w(0.0);
+        color
+    }
+
+    /// Encodes the `x`, `y` and `z` elements of a linear color in the range `[0, range]` in `RGBD` format.
+    /// 
+    /// ※ The `w` element of the result is the shared divisor, a multiple of `1/255` so that it can be stored in 8 bits. </br>
+    /// ※ All elements of the result are in the range `[0, 1]`, and the decoded color is `rgb * range / (w * 255)`. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn encode_rgbd(self, range: f32) -> Self {
+        let max = self.get_x().max(self.get_y()).max(self.get_z());
+        let d = (range / max).floor().clamp(1.0, 255.0);
+        let mut rgbd = (self * (d / range)).max(Self::ZERO).min(Self::ONE);
+        rgbd.set_w(d / 255.0);
+        rgbd
+    }
+
+    /// Decodes a color in `RGBD` format with the given range.
+    /// 
+    /// ※ The `w` element of the result is zero. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn decode_rgbd(self, range: f32) -> Self {
+        let mut color = self * (range / (self.get_w() * 255.0));
+        color.set_w(0.0);
+        color
+    }
+
+    /// Converts to a double-precision vector.
+    #[inline]
+    #[must_use]
+    pub fn as_f64(self) -> DVector {
+        DVector::new(
+            self.arr[0] as f64,
+            self.arr[1] as f64,
+            self.arr[2] as f64,
+            self.arr[3] as f64
+        )
+    }
 }
 
 impl Vector {
@@ -322,6 +555,43 @@ impl Vector {
     pub fn ne(self, rhs: Self) -> VectorInt {
         !self.eq(rhs)
     }
+
+    /// Selects the elements of `a` where the `mask` is set, otherwise the elements of `b`.
+    /// 
+    /// ※ The selection is done bit by bit, so the `mask` is usually the result of a comparison. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn select(mask: VectorInt, a: Self, b: Self) -> Self {
+        let mask = mask.into_array();
+        let a = a.to_bits().into_array();
+        let b = b.to_bits().into_array();
+        let mut arr = [0; 4];
+        for (i, e) in arr.iter_mut().enumerate() {
+            *e = (a[i] & mask[i]) | (b[i] & !mask[i]);
+        }
+        Self::from_bits(VectorInt::from_array(arr))
+    }
+
+    /// Creates a vector by reinterpreting the bits of the given integer vector.
+    #[inline]
+    #[must_use]
+    pub fn from_bits(bits: VectorInt) -> Self {
+        let bits = bits.into_array();
+        Self { arr: [
+            f32::from_bits(bits[0] as u32), 
+            f32::from_bits(bits[1] as u32), 
+            f32::from_bits(bits[2] as u32), 
+            f32::from_bits(bits[3] as u32) 
+        ] }
+    }
+
+    /// Reinterprets the bits of the vector as an integer vector.
+    #[inline]
+    #[must_use]
+    pub fn to_bits(self) -> VectorInt {
+        VectorInt::from(self)
+    }
     
     /// Absolute value on vector elements.
     #[inline]
@@ -612,6 +882,695 @@ impl Vector {
     pub fn lerp(self, rhs: Self, t: f32) -> Self {
         self * (1.0 - t) + rhs * t
     }
+    /// Computes `(self * a) + b` for each element of the vector.
+    /// 
+    /// ※ It is computed as a separate multiply and add. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn mul_add(self, a: Self, b: Self) -> Self {
+        Self { arr: [
+            self.arr[0] * a.arr[0] + b.arr[0], 
+            self.arr[1] * a.arr[1] + b.arr[1], 
+            self.arr[2] * a.arr[2] + b.arr[2], 
+            self.arr[3] * a.arr[3] + b.arr[3]
+        ] }
+    }
+}
+
+impl Vector {
+    /// Returns a vector with the elements rearranged by the given indices.
+    /// 
+    /// ※ Each index must be in the range `0` to `3` and selects the `x`, `y`, `z` or `w` element. </br>
+    /// ※ For example, `shuffle::<2, 1, 0, 3>()` returns `(z, y, x, w)`. </br>
+    /// 
+    /// # Panics
+    /// When the `use-assertion` feature is enabled, it will [`panic!`]
+    /// if any of the indices is greater than three.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn shuffle<const X: usize, const Y: usize, const Z: usize, const W: usize>(self) -> Self {
+        #[cfg(feature = "use-assertion")]
+        assert!(X < 4 && Y < 4 && Z < 4 && W < 4, "The index of the element is out of range!");
+        Self { arr: [self.arr[X & 3], self.arr[Y & 3], self.arr[Z & 3], self.arr[W & 3]] }
+    }
+
+    /// Returns a vector with the elements selected from the two vectors by the given indices.
+    /// 
+    /// ※ Indices `0` to `3` select the `x`, `y`, `z` or `w` element of `self`,
+    /// and indices `4` to `7` select the `x`, `y`, `z` or `w` element of `rhs`. </br>
+    /// ※ For example, `permute::<0, 4, 1, 5>(rhs)` returns `(self.x, rhs.x, self.y, rhs.y)`. </br>
+    /// 
+    /// # Panics
+    /// When the `use-assertion` feature is enabled, it will [`panic!`]
+    /// if any of the indices is greater than seven.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn permute<const X: usize, const Y: usize, const Z: usize, const W: usize>(self, rhs: Self) -> Self {
+        #[cfg(feature = "use-assertion")]
+        assert!(X < 8 && Y < 8 && Z < 8 && W < 8, "The index of the element is out of range!");
+        let arr = [
+            self.get_x(), self.get_y(), self.get_z(), self.get_w(), 
+            rhs.get_x(), rhs.get_y(), rhs.get_z(), rhs.get_w() 
+        ];
+        Self { arr: [arr[X & 7], arr[Y & 7], arr[Z & 7], arr[W & 7]] }
+    }
+
+    /// Fills all elements with the `x` element of the vector.
+    #[inline]
+    #[must_use]
+    pub fn splat_x(self) -> Self {
+        Self { arr: [self.get_x(); 4] }
+    }
+
+    /// Fills all elements with the `y` element of the vector.
+    #[inline]
+    #[must_use]
+    pub fn splat_y(self) -> Self {
+        Self { arr: [self.get_y(); 4] }
+    }
+
+    /// Fills all elements with the `z` element of the vector.
+    #[inline]
+    #[must_use]
+    pub fn splat_z(self) -> Self {
+        Self { arr: [self.get_z(); 4] }
+    }
+
+    /// Fills all elements with the `w` element of the vector.
+    #[inline]
+    #[must_use]
+    pub fn splat_w(self) -> Self {
+        Self { arr: [self.get_w(); 4] }
+    }
+
+    /// Interleaves the `x` and `y` elements of the two vectors.
+    /// 
+    /// ※ Returns `(self.x, rhs.x, self.y, rhs.y)`. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn merge_xy(self, rhs: Self) -> Self {
+        Self { arr: [self.get_x(), rhs.get_x(), self.get_y(), rhs.get_y()] }
+    }
+
+    /// Interleaves the `z` and `w` elements of the two vectors.
+    /// 
+    /// ※ Returns `(self.z, rhs.z, self.w, rhs.w)`. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn merge_zw(self, rhs: Self) -> Self {
+        Self { arr: [self.get_z(), rhs.get_z(), self.get_w(), rhs.get_w()] }
+    }
+}
+
+impl Vector {
+    /// Returns a vector containing the square root of each element of the vector.
+    #[inline]
+    #[must_use]
+    pub fn sqrt(self) -> Self {
+        Self { arr: [
+            self.get_x().sqrt(), 
+            self.get_y().sqrt(), 
+            self.get_z().sqrt(), 
+            self.get_w().sqrt() 
+        ] }
+    }
+
+    /// Returns a vector containing the estimated square root of each element of the vector.
+    ///
+    /// ※ The relative error is less than `4.0e-4`. </br>
+    /// ※ Zero and infinity elements are returned as they are.
+    ///
+    #[inline]
+    #[must_use]
+    pub fn sqrt_est(self) -> Self {
+        // There is no estimate instruction, so it is the same as `sqrt`.
+        self.sqrt()
+    }
+
+    /// Returns a vector containing the reciprocal square root of each element of the vector.
+    #[inline]
+    #[must_use]
+    pub fn rsqrt(self) -> Self {
+        Self { arr: [
+            self.get_x().sqrt().recip(), 
+            self.get_y().sqrt().recip(), 
+            self.get_z().sqrt().recip(), 
+            self.get_w().sqrt().recip() 
+        ] }
+    }
+
+    /// Returns a vector containing the estimated reciprocal square root of each element of the vector.
+    ///
+    /// ※ The relative error is less than `4.0e-4`.
+    ///
+    #[inline]
+    #[must_use]
+    pub fn rsqrt_est(self) -> Self {
+        // There is no estimate instruction, so it is the same as `rsqrt`.
+        self.rsqrt()
+    }
+
+    /// Returns a vector containing the reciprocal of each element of the vector.
+    #[inline]
+    #[must_use]
+    pub fn recip(self) -> Self {
+        Self { arr: [
+            self.get_x().recip(), 
+            self.get_y().recip(), 
+            self.get_z().recip(), 
+            self.get_w().recip() 
+        ] }
+    }
+
+    /// Returns a vector containing the estimated reciprocal of each element of the vector.
+    ///
+    /// ※ The relative error is less than `4.0e-4`.
+    ///
+    #[inline]
+    #[must_use]
+    pub fn recip_est(self) -> Self {
+        // There is no estimate instruction, so it is the same as `recip`.
+        self.recip()
+    }
+
+    /// Estimated length of a two-element vector.
+    ///
+    /// ※ The relative error is less than `4.0e-4`.
+    ///
+    #[inline]
+    #[must_use]
+    pub fn vec2_len_est(self) -> f32 {
+        self.vec2_dot(self).sqrt_est().get_x()
+    }
+
+    /// Estimated length of a three-element vector.
+    ///
+    /// ※ The relative error is less than `4.0e-4`.
+    ///
+    #[inline]
+    #[must_use]
+    pub fn vec3_len_est(self) -> f32 {
+        self.vec3_dot(self).sqrt_est().get_x()
+    }
+
+    /// Estimated length of a four-element vector.
+    ///
+    /// ※ The relative error is less than `4.0e-4`.
+    ///
+    #[inline]
+    #[must_use]
+    pub fn vec4_len_est(self) -> f32 {
+        self.vec4_dot(self).sqrt_est().get_x()
+    }
+
+    /// Normalizes a two-element vector using the estimated reciprocal square root.
+    ///
+    /// ※ The relative error of each element is less than `4.0e-4`. </br>
+    /// Undefined behavior may occur if the length of the vector is less than or equal to [`f32::EPSILON`].
+    ///
+    /// # Panics
+    /// When `use-assertion` feature is enabled, [`panic!`] will be called 
+    /// if the length of the vector is less than or equal to [`f32::EPSILON`].
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn vec2_normalize_est(self) -> Self {
+        #[cfg(feature = "use-assertion")]
+        assert!(self.vec2_len() > f32::EPSILON, "The length of the vector is less than or equal to `f32::EPSILON`!");
+
+        const MASK_XY: Vector = Vector { arr: [1.0, 1.0, 0.0, 0.0] };
+        self * self.vec2_dot(self).rsqrt_est() * MASK_XY
+    }
+
+    /// Normalizes a three-element vector using the estimated reciprocal square root.
+    ///
+    /// ※ The relative error of each element is less than `4.0e-4`. </br>
+    /// Undefined behavior may occur if the length of the vector is less than or equal to [`f32::EPSILON`].
+    ///
+    /// # Panics
+    /// When `use-assertion` feature is enabled, [`panic!`] will be called 
+    /// if the length of the vector is less than or equal to [`f32::EPSILON`].
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn vec3_normalize_est(self) -> Self {
+        #[cfg(feature = "use-assertion")]
+        assert!(self.vec3_len() > f32::EPSILON, "The length of the vector is less than or equal to `f32::EPSILON`!");
+
+        const MASK_XYZ: Vector = Vector { arr: [1.0, 1.0, 1.0, 0.0] };
+        self * self.vec3_dot(self).rsqrt_est() * MASK_XYZ
+    }
+
+    /// Normalizes a four-element vector using the estimated reciprocal square root.
+    ///
+    /// ※ The relative error of each element is less than `4.0e-4`. </br>
+    /// Undefined behavior may occur if the length of the vector is less than or equal to [`f32::EPSILON`].
+    ///
+    /// # Panics
+    /// When `use-assertion` feature is enabled, [`panic!`] will be called 
+    /// if the length of the vector is less than or equal to [`f32::EPSILON`].
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn vec4_normalize_est(self) -> Self {
+        #[cfg(feature = "use-assertion")]
+        assert!(self.vec4_len() > f32::EPSILON, "The length of the vector is less than or equal to `f32::EPSILON`!");
+        self * self.vec4_dot(self).rsqrt_est()
+    }
+}
+
+impl Vector {
+    /// Returns a vector containing the sine of each element of the vector.
+    ///
+    /// ※ The angles given are in radians. </br>
+    /// ※ It uses an 11-degree minimax polynomial approximation.
+    /// The absolute error is less than `4.0e-7` in the range `-4π` to `4π`
+    /// and grows with the magnitude of the angle outside of it.
+    ///
+    #[inline]
+    #[must_use]
+    pub fn sin(self) -> Self {
+        self.sin_cos().0
+    }
+
+    /// Returns a vector containing the cosine of each element of the vector.
+    ///
+    /// ※ The angles given are in radians. </br>
+    /// ※ It uses a 10-degree minimax polynomial approximation.
+    /// The absolute error is less than `4.0e-7` in the range `-4π` to `4π`
+    /// and grows with the magnitude of the angle outside of it.
+    ///
+    #[inline]
+    #[must_use]
+    pub fn cos(self) -> Self {
+        self.sin_cos().1
+    }
+
+    /// Returns the sine and cosine of each element of the vector at the same time.
+    ///
+    /// ※ The angles given are in radians. </br>
+    /// ※ The error bounds are the same as [`Vector::sin`] and [`Vector::cos`].
+    ///
+    #[must_use]
+    pub fn sin_cos(self) -> (Self, Self) {
+        let (sx, cx) = scalar_sin_cos(self.get_x());
+        let (sy, cy) = scalar_sin_cos(self.get_y());
+        let (sz, cz) = scalar_sin_cos(self.get_z());
+        let (sw, cw) = scalar_sin_cos(self.get_w());
+        (Self { arr: [sx, sy, sz, sw] }, Self { arr: [cx, cy, cz, cw] })
+    }
+
+    /// Returns a vector containing the tangent of each element of the vector.
+    ///
+    /// ※ The angles given are in radians. </br>
+    /// ※ It is computed as the quotient of [`Vector::sin_cos`].
+    /// In the range `-4π` to `4π`, where the absolute value of the cosine is greater than `0.1`,
+    /// the error is less than `4.0e-6` (absolute if the result is less than one, otherwise relative).
+    ///
+    #[inline]
+    #[must_use]
+    pub fn tan(self) -> Self {
+        let (s, c) = self.sin_cos();
+        s / c
+    }
+
+    /// Returns a vector containing the arcsine of each element of the vector.
+    ///
+    /// ※ The given elements must be in the range `-1.0` to `1.0`. </br>
+    /// ※ It uses a 7-degree minimax polynomial approximation.
+    /// The absolute error is less than `5.0e-7`.
+    ///
+    #[inline]
+    #[must_use]
+    pub fn asin(self) -> Self {
+        core::f32::consts::FRAC_PI_2 - self.acos()
+    }
+
+    /// Returns a vector containing the arccosine of each element of the vector.
+    ///
+    /// ※ The given elements must be in the range `-1.0` to `1.0`. </br>
+    /// ※ It uses a 7-degree minimax polynomial approximation.
+    /// The absolute error is less than `5.0e-7`.
+    ///
+    #[inline]
+    #[must_use]
+    pub fn acos(self) -> Self {
+        Self { arr: [
+            scalar_acos(self.get_x()), 
+            scalar_acos(self.get_y()), 
+            scalar_acos(self.get_z()), 
+            scalar_acos(self.get_w()) 
+        ] }
+    }
+
+    /// Returns a vector containing the arctangent of each element of the vector.
+    ///
+    /// ※ It uses a 9-degree polynomial approximation after reducing the argument
+    /// to the range `-tan(π/8)` to `tan(π/8)`.
+    /// The absolute error is less than `2.0e-7`.
+    ///
+    #[inline]
+    #[must_use]
+    pub fn atan(self) -> Self {
+        Self { arr: [
+            scalar_atan(self.get_x()), 
+            scalar_atan(self.get_y()), 
+            scalar_atan(self.get_z()), 
+            scalar_atan(self.get_w()) 
+        ] }
+    }
+
+    /// Returns a vector containing the four quadrant arctangent of `self` (`y`) and `x`.
+    ///
+    /// ※ The absolute error is less than `4.0e-7`. </br>
+    /// ※ Signed zeros and infinities are handled in the same way as [`f32::atan2`].
+    ///
+    #[inline]
+    #[must_use]
+    pub fn atan2(self, x: Self) -> Self {
+        Self { arr: [
+            scalar_atan2(self.get_x(), x.get_x()), 
+            scalar_atan2(self.get_y(), x.get_y()), 
+            scalar_atan2(self.get_z(), x.get_z()), 
+            scalar_atan2(self.get_w(), x.get_w()) 
+        ] }
+    }
+
+    /// Returns a vector containing `e^(self)` for each element of the vector.
+    ///
+    /// ※ It uses a 7-degree polynomial approximation after reducing the argument
+    /// to the range `-ln(2)/2` to `ln(2)/2`.
+    /// The relative error is less than `2.0e-7`.
+    ///
+    #[inline]
+    #[must_use]
+    pub fn exp(self) -> Self {
+        Self { arr: [
+            scalar_exp(self.get_x()), 
+            scalar_exp(self.get_y()), 
+            scalar_exp(self.get_z()), 
+            scalar_exp(self.get_w()) 
+        ] }
+    }
+
+    /// Returns a vector containing `2^(self)` for each element of the vector.
+    ///
+    /// ※ The error bounds are the same as [`Vector::exp`].
+    ///
+    #[inline]
+    #[must_use]
+    pub fn exp2(self) -> Self {
+        Self { arr: [
+            scalar_exp2(self.get_x()), 
+            scalar_exp2(self.get_y()), 
+            scalar_exp2(self.get_z()), 
+            scalar_exp2(self.get_w()) 
+        ] }
+    }
+
+    /// Returns a vector containing the natural logarithm of each element of the vector.
+    ///
+    /// ※ It uses a 9-degree polynomial approximation after splitting the mantissa
+    /// into the range `sqrt(0.5)` to `sqrt(2.0)`.
+    /// The error is less than the larger of `5.0e-7` absolute and `2.0e-7` relative. </br>
+    /// ※ Returns `NaN` for negative elements and negative infinity for zeros.
+    ///
+    #[inline]
+    #[must_use]
+    pub fn ln(self) -> Self {
+        Self { arr: [
+            scalar_ln(self.get_x()), 
+            scalar_ln(self.get_y()), 
+            scalar_ln(self.get_z()), 
+            scalar_ln(self.get_w()) 
+        ] }
+    }
+
+    /// Returns a vector containing the base 2 logarithm of each element of the vector.
+    ///
+    /// ※ The error is less than the larger of `1.0e-6` absolute and `2.0e-7` relative. </br>
+    /// ※ Returns `NaN` for negative elements and negative infinity for zeros.
+    ///
+    #[inline]
+    #[must_use]
+    pub fn log2(self) -> Self {
+        Self { arr: [
+            scalar_log2(self.get_x()), 
+            scalar_log2(self.get_y()), 
+            scalar_log2(self.get_z()), 
+            scalar_log2(self.get_w()) 
+        ] }
+    }
+}
+
+/// Returns the sine and cosine of the given angle.
+fn scalar_sin_cos(angle: f32) -> (f32, f32) {
+    // Reference: DirectXMath/Inc/DirectXMathMisc.inl (XMScalarSinCos)
+    const FRAC_1_TAU: f32 = 0.15915494;
+    const TAU_HI: f32 = 6.28125;
+    const TAU_LO: f32 = 1.9353072e-3;
+
+    // Reduce the angle to the range -π to π.
+    let quotient = (angle * FRAC_1_TAU).round();
+    let x = angle - quotient * TAU_HI - quotient * TAU_LO;
+
+    // Map the angle to the range -π/2 to π/2 with sin(y) = sin(x).
+    let (x, sign) = if x > core::f32::consts::FRAC_PI_2 {
+        (core::f32::consts::PI - x, -1.0)
+    } else if x < -core::f32::consts::FRAC_PI_2 {
+        (-core::f32::consts::PI - x, -1.0)
+    } else {
+        (x, 1.0)
+    };
+    let x2 = x * x;
+
+    let s = -2.3889859e-8 * x2 + 2.7525562e-6;
+    let s = s * x2 - 1.9840874e-4;
+    let s = s * x2 + 8.333331e-3;
+    let s = s * x2 - 1.6666667e-1;
+    let s = (s * x2 + 1.0) * x;
+
+    let c = -2.6051615e-7 * x2 + 2.4760495e-5;
+    let c = c * x2 - 1.3888378e-3;
+    let c = c * x2 + 4.1666638e-2;
+    let c = c * x2 - 0.5;
+    let c = (c * x2 + 1.0) * sign;
+
+    (s, c)
+}
+
+/// Returns the arccosine of the given value.
+fn scalar_acos(value: f32) -> f32 {
+    // Reference: DirectXMath/Inc/DirectXMathMisc.inl (XMScalarACos)
+    let x = value.abs();
+    let root = (1.0 - x).max(0.0).sqrt();
+
+    let t = -1.2624911e-3 * x + 6.67009e-3;
+    let t = t * x - 1.7088126e-2;
+    let t = t * x + 3.089188e-2;
+    let t = t * x - 5.0174303e-2;
+    let t = t * x + 8.897899e-2;
+    let t = t * x - 2.145988e-1;
+    let t = (t * x + core::f32::consts::FRAC_PI_2) * root;
+
+    match value >= 0.0 {
+        true => t, 
+        false => core::f32::consts::PI - t
+    }
+}
+
+/// Returns the arctangent of the given value.
+fn scalar_atan(value: f32) -> f32 {
+    // Reference: Cephes Math Library (atanf.c)
+    let x = value.abs();
+    let (y, x) = if x > 2.4142137 {
+        (core::f32::consts::FRAC_PI_2, -1.0 / x)
+    } else if x > 0.41421357 {
+        (core::f32::consts::FRAC_PI_4, (x - 1.0) / (x + 1.0))
+    } else {
+        (0.0, x)
+    };
+    let z = x * x;
+
+    let p = 8.0537446e-2 * z - 1.3877685e-1;
+    let p = p * z + 1.9977711e-1;
+    let p = p * z - 3.333295e-1;
+    let p = p * z * x + x;
+
+    (y + p).copysign(value)
+}
+
+/// Returns the four quadrant arctangent of the given `y` and `x`.
+fn scalar_atan2(y: f32, x: f32) -> f32 {
+    if x.is_nan() || y.is_nan() {
+        return f32::NAN;
+    }
+
+    let x_neg = x.is_sign_negative();
+    if y == 0.0 {
+        let zero = if x_neg { core::f32::consts::PI } else { 0.0 };
+        return zero.copysign(y);
+    }
+
+    if x.is_infinite() && y.is_infinite() {
+        let quarter = if x_neg { 3.0 * core::f32::consts::FRAC_PI_4 } else { core::f32::consts::FRAC_PI_4 };
+        return quarter.copysign(y);
+    }
+
+    let result = scalar_atan(y / x);
+    match x_neg {
+        true => result + core::f32::consts::PI.copysign(y), 
+        false => result
+    }
+}
+
+/// Returns `e^(value)`.
+fn scalar_exp(value: f32) -> f32 {
+    // Clamp the range so that the exponent of the result does not overflow.
+    let x = value.clamp(-104.0, 89.0);
+
+    // e^x = 2^n * e^r
+    let n = (x * core::f32::consts::LOG2_E).round();
+    let r = x - n * 0.6933594 + n * 2.1219444e-4;
+    scalar_exp_reduced(n as i32, r)
+}
+
+/// Returns `2^(value)`.
+fn scalar_exp2(value: f32) -> f32 {
+    let x = value.clamp(-150.0, 129.0);
+
+    // 2^x = 2^n * e^(r * ln(2))
+    let n = x.round();
+    let r = (x - n) * core::f32::consts::LN_2;
+    scalar_exp_reduced(n as i32, r)
+}
+
+/// Computes `2^n * e^r` where `r` is in the range `-ln(2)/2` to `ln(2)/2`.
+fn scalar_exp_reduced(n: i32, r: f32) -> f32 {
+    // Reference: Cephes Math Library (expf.c)
+    let p = 1.9875691e-4 * r + 1.3981999e-3;
+    let p = p * r + 8.333452e-3;
+    let p = p * r + 4.1665796e-2;
+    let p = p * r + 1.6666666e-1;
+    let p = p * r + 0.5;
+    let p = p * r * r + r + 1.0;
+
+    // 2^n is split into two factors so that each exponent stays in range.
+    let n0 = n >> 1;
+    let n1 = n - n0;
+    let pow0 = f32::from_bits(((n0 + 127) as u32) << 23);
+    let pow1 = f32::from_bits(((n1 + 127) as u32) << 23);
+    p * pow0 * pow1
+}
+
+/// Returns the natural logarithm of the given value.
+fn scalar_ln(value: f32) -> f32 {
+    match scalar_log_special_cases(value) {
+        Some(result) => result, 
+        None => {
+            let (e, m) = scalar_log_reduced(value);
+            m + e * -2.1219444e-4 + e * 0.6933594
+        }
+    }
+}
+
+/// Returns the base 2 logarithm of the given value.
+fn scalar_log2(value: f32) -> f32 {
+    match scalar_log_special_cases(value) {
+        Some(result) => result, 
+        None => {
+            let (e, m) = scalar_log_reduced(value);
+            m * core::f32::consts::LOG2_E + e
+        }
+    }
+}
+
+/// Splits the given value into `2^e * m` and returns `e` and `ln(m)`.
+fn scalar_log_reduced(value: f32) -> (f32, f32) {
+    // Reference: Cephes Math Library (logf.c)
+    // Scales subnormal numbers to the normal range.
+    let (x, bias) = match value < f32::MIN_POSITIVE {
+        true => (value * 8388608.0, 149), 
+        false => (value, 126)
+    };
+
+    // x = m * 2^e (0.5 <= m < 1.0)
+    let bits = x.to_bits();
+    let e = ((bits >> 23) as i32 - bias) as f32;
+    let m = f32::from_bits((bits & 0x007FFFFF) | 0x3F000000);
+
+    // If m < sqrt(0.5), then use 2m - 1 and e - 1.
+    let (e, m) = match m < core::f32::consts::FRAC_1_SQRT_2 {
+        true => (e - 1.0, m + m - 1.0), 
+        false => (e, m - 1.0)
+    };
+    let m2 = m * m;
+
+    let p = 7.0376836e-2 * m - 1.151461e-1;
+    let p = p * m + 1.16769984e-1;
+    let p = p * m - 1.2420141e-1;
+    let p = p * m + 1.4249323e-1;
+    let p = p * m - 1.6668057e-1;
+    let p = p * m + 2.0000714e-1;
+    let p = p * m - 2.4999994e-1;
+    let p = p * m + 3.333333e-1;
+    let p = p * m * m2 - m2 * 0.5;
+    (e, m + p)
+}
+
+/// Returns the logarithm of zero, negative, infinity and `NaN` values.
+fn scalar_log_special_cases(value: f32) -> Option<f32> {
+    if value.is_nan() || value < 0.0 {
+        Some(f32::NAN)
+    } else if value == 0.0 {
+        Some(f32::NEG_INFINITY)
+    } else if value == f32::INFINITY {
+        Some(f32::INFINITY)
+    } else {
+        None
+    }
+}
+
+impl Vector {
+    /// Returns `true` if the absolute difference of all elements is less than or equal to `max_abs_diff`.
+    #[inline]
+    #[must_use]
+    pub fn abs_diff_eq(self, rhs: Self, max_abs_diff: f32) -> bool {
+        near_eq::abs_diff_eq(&self.into_array(), &rhs.into_array(), max_abs_diff)
+    }
+
+    /// Returns `true` if all elements are equal within `max_abs_diff` or the relative tolerance `max_relative`.
+    /// 
+    /// ※ The relative tolerance is scaled by the larger absolute value of the two elements. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn relative_eq(self, rhs: Self, max_abs_diff: f32, max_relative: f32) -> bool {
+        near_eq::relative_eq(&self.into_array(), &rhs.into_array(), max_abs_diff, max_relative)
+    }
+
+    /// Returns `true` if all elements are equal within `max_abs_diff` or `max_ulps` representable values.
+    /// 
+    /// ※ Elements with different signs are equal only within `max_abs_diff`. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn ulps_eq(self, rhs: Self, max_abs_diff: f32, max_ulps: u32) -> bool {
+        near_eq::ulps_eq(&self.into_array(), &rhs.into_array(), max_abs_diff, max_ulps)
+    }
+}
+
+impl PartialEq for Vector {
+    /// Compares all elements exactly.
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.into_array() == other.into_array()
+    }
 }
 
 impl Default for Vector {
@@ -621,6 +1580,13 @@ impl Default for Vector {
     }
 }
 
+impl From<VectorInt> for Vector {
+    #[inline]
+    fn from(value: VectorInt) -> Self {
+        Self::from_bits(value)
+    }
+}
+
 impl From<[f32; 4]> for Vector {
     #[inline]
     fn from(value: [f32; 4]) -> Self {
@@ -344,15 +344,25 @@ impl VectorInt {
         !self.eq(rhs)
     }
 
+    /// Selects the elements of `a` where the `mask` is set, otherwise the elements of `b`.
+    /// 
+    /// ※ The selection is done bit by bit, so the `mask` is usually the result of a comparison. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn select(mask: VectorInt, a: Self, b: Self) -> Self {
+        (mask & a) | (!mask & b)
+    }
+
     /// Absolute value on vector elements.
     #[inline]
     #[must_use]
     pub fn abs(self) -> Self {
         VectorInt { arr: [
-            self.get_x().abs(), 
-            self.get_y().abs(), 
-            self.get_z().abs(), 
-            self.get_w().abs() 
+            self.get_x().wrapping_abs(), 
+            self.get_y().wrapping_abs(), 
+            self.get_z().wrapping_abs(), 
+            self.get_w().wrapping_abs() 
         ] }
     }
     
@@ -415,6 +425,95 @@ impl VectorInt {
     }
 }
 
+impl VectorInt {
+    /// Returns a vector with the elements rearranged by the given indices.
+    /// 
+    /// ※ Each index must be in the range `0` to `3` and selects the `x`, `y`, `z` or `w` element. </br>
+    /// ※ For example, `shuffle::<2, 1, 0, 3>()` returns `(z, y, x, w)`. </br>
+    /// 
+    /// # Panics
+    /// When the `use-assertion` feature is enabled, it will [`panic!`]
+    /// if any of the indices is greater than three.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn shuffle<const X: usize, const Y: usize, const Z: usize, const W: usize>(self) -> Self {
+        #[cfg(feature = "use-assertion")]
+        assert!(X < 4 && Y < 4 && Z < 4 && W < 4, "The index of the element is out of range!");
+        Self { arr: [self.arr[X & 3], self.arr[Y & 3], self.arr[Z & 3], self.arr[W & 3]] }
+    }
+
+    /// Returns a vector with the elements selected from the two vectors by the given indices.
+    /// 
+    /// ※ Indices `0` to `3` select the `x`, `y`, `z` or `w` element of `self`,
+    /// and indices `4` to `7` select the `x`, `y`, `z` or `w` element of `rhs`. </br>
+    /// ※ For example, `permute::<0, 4, 1, 5>(rhs)` returns `(self.x, rhs.x, self.y, rhs.y)`. </br>
+    /// 
+    /// # Panics
+    /// When the `use-assertion` feature is enabled, it will [`panic!`]
+    /// if any of the indices is greater than seven.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn permute<const X: usize, const Y: usize, const Z: usize, const W: usize>(self, rhs: Self) -> Self {
+        #[cfg(feature = "use-assertion")]
+        assert!(X < 8 && Y < 8 && Z < 8 && W < 8, "The index of the element is out of range!");
+        let arr = [
+            self.get_x(), self.get_y(), self.get_z(), self.get_w(), 
+            rhs.get_x(), rhs.get_y(), rhs.get_z(), rhs.get_w() 
+        ];
+        Self { arr: [arr[X & 7], arr[Y & 7], arr[Z & 7], arr[W & 7]] }
+    }
+
+    /// Fills all elements with the `x` element of the vector.
+    #[inline]
+    #[must_use]
+    pub fn splat_x(self) -> Self {
+        Self { arr: [self.get_x(); 4] }
+    }
+
+    /// Fills all elements with the `y` element of the vector.
+    #[inline]
+    #[must_use]
+    pub fn splat_y(self) -> Self {
+        Self { arr: [self.get_y(); 4] }
+    }
+
+    /// Fills all elements with the `z` element of the vector.
+    #[inline]
+    #[must_use]
+    pub fn splat_z(self) -> Self {
+        Self { arr: [self.get_z(); 4] }
+    }
+
+    /// Fills all elements with the `w` element of the vector.
+    #[inline]
+    #[must_use]
+    pub fn splat_w(self) -> Self {
+        Self { arr: [self.get_w(); 4] }
+    }
+
+    /// Interleaves the `x` and `y` elements of the two vectors.
+    /// 
+    /// ※ Returns `(self.x, rhs.x, self.y, rhs.y)`. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn merge_xy(self, rhs: Self) -> Self {
+        Self { arr: [self.get_x(), rhs.get_x(), self.get_y(), rhs.get_y()] }
+    }
+
+    /// Interleaves the `z` and `w` elements of the two vectors.
+    /// 
+    /// ※ Returns `(self.z, rhs.z, self.w, rhs.w)`. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn merge_zw(self, rhs: Self) -> Self {
+        Self { arr: [self.get_z(), rhs.get_z(), self.get_w(), rhs.get_w()] }
+    }
+}
+
 impl Default for VectorInt {
     #[inline]
     fn default() -> Self {
@@ -682,10 +781,10 @@ impl ops::Mul<VectorInt> for VectorInt {
     #[inline]
     fn mul(self, rhs: VectorInt) -> Self::Output {
         Self { arr: [
-            self.get_x() * rhs.get_x(), 
-            self.get_y() * rhs.get_y(), 
-            self.get_z() * rhs.get_z(), 
-            self.get_w() * rhs.get_w() 
+            self.get_x().wrapping_mul(rhs.get_x()), 
+            self.get_y().wrapping_mul(rhs.get_y()), 
+            self.get_z().wrapping_mul(rhs.get_z()), 
+            self.get_w().wrapping_mul(rhs.get_w()) 
         ] }
     }
 }
@@ -818,10 +917,10 @@ impl ops::Not for VectorInt {
     #[inline]
     fn not(self) -> Self::Output {
         Self { arr: [
-            self.get_x(), 
-            self.get_y(), 
-            self.get_z(), 
-            self.get_w() 
+            !self.get_x(), 
+            !self.get_y(), 
+            !self.get_z(), 
+            !self.get_w() 
         ] }
     }
 }
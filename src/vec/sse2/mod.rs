@@ -1,11 +1,126 @@
+#[cfg(target_pointer_width = "32")]
+use core::arch::x86::*;
+
+#[cfg(target_pointer_width = "64")]
+use core::arch::x86_64::*;
+
+/// Calls the given intrinsic with an 8-bit immediate value computed from const generics.
+/// 
+/// ※ Intrinsics that take an immediate value cannot be called with an expression
+/// of const generic parameters, so every possible value is matched. </br>
+/// ※ The match is removed at compile time because the given value is a constant. </br>
+/// 
+macro_rules! dispatch_imm8 {
+    ($imm:expr, $f:ident($($arg:expr),*)) => {
+        match $imm {
+            0 => $f::<0>($($arg),*), 1 => $f::<1>($($arg),*), 2 => $f::<2>($($arg),*), 3 => $f::<3>($($arg),*),
+            4 => $f::<4>($($arg),*), 5 => $f::<5>($($arg),*), 6 => $f::<6>($($arg),*), 7 => $f::<7>($($arg),*),
+            8 => $f::<8>($($arg),*), 9 => $f::<9>($($arg),*), 10 => $f::<10>($($arg),*), 11 => $f::<11>($($arg),*),
+            12 => $f::<12>($($arg),*), 13 => $f::<13>($($arg),*), 14 => $f::<14>($($arg),*), 15 => $f::<15>($($arg),*),
+            16 => $f::<16>($($arg),*), 17 => $f::<17>($($arg),*), 18 => $f::<18>($($arg),*), 19 => $f::<19>($($arg),*),
+            20 => $f::<20>($($arg),*), 21 => $f::<21>($($arg),*), 22 => $f::<22>($($arg),*), 23 => $f::<23>($($arg),*),
+            24 => $f::<24>($($arg),*), 25 => $f::<25>($($arg),*), 26 => $f::<26>($($arg),*), 27 => $f::<27>($($arg),*),
+            28 => $f::<28>($($arg),*), 29 => $f::<29>($($arg),*), 30 => $f::<30>($($arg),*), 31 => $f::<31>($($arg),*),
+            32 => $f::<32>($($arg),*), 33 => $f::<33>($($arg),*), 34 => $f::<34>($($arg),*), 35 => $f::<35>($($arg),*),
+            36 => $f::<36>($($arg),*), 37 => $f::<37>($($arg),*), 38 => $f::<38>($($arg),*), 39 => $f::<39>($($arg),*),
+            40 => $f::<40>($($arg),*), 41 => $f::<41>($($arg),*), 42 => $f::<42>($($arg),*), 43 => $f::<43>($($arg),*),
+            44 => $f::<44>($($arg),*), 45 => $f::<45>($($arg),*), 46 => $f::<46>($($arg),*), 47 => $f::<47>($($arg),*),
+            48 => $f::<48>($($arg),*), 49 => $f::<49>($($arg),*), 50 => $f::<50>($($arg),*), 51 => $f::<51>($($arg),*),
+            52 => $f::<52>($($arg),*), 53 => $f::<53>($($arg),*), 54 => $f::<54>($($arg),*), 55 => $f::<55>($($arg),*),
+            56 => $f::<56>($($arg),*), 57 => $f::<57>($($arg),*), 58 => $f::<58>($($arg),*), 59 => $f::<59>($($arg),*),
+            60 => $f::<60>($($arg),*), 61 => $f::<61>($($arg),*), 62 => $f::<62>($($arg),*), 63 => $f::<63>($($arg),*),
+            64 => $f::<64>($($arg),*), 65 => $f::<65>($($arg),*), 66 => $f::<66>($($arg),*), 67 => $f::<67>($($arg),*),
+            68 => $f::<68>($($arg),*), 69 => $f::<69>($($arg),*), 70 => $f::<70>($($arg),*), 71 => $f::<71>($($arg),*),
+            72 => $f::<72>($($arg),*), 73 => $f::<73>($($arg),*), 74 => $f::<74>($($arg),*), 75 => $f::<75>($($arg),*),
+            76 => $f::<76>($($arg),*), 77 => $f::<77>($($arg),*), 78 => $f::<78>($($arg),*), 79 => $f::<79>($($arg),*),
+            80 => $f::<80>($($arg),*), 81 => $f::<81>($($arg),*), 82 => $f::<82>($($arg),*), 83 => $f::<83>($($arg),*),
+            84 => $f::<84>($($arg),*), 85 => $f::<85>($($arg),*), 86 => $f::<86>($($arg),*), 87 => $f::<87>($($arg),*),
+            88 => $f::<88>($($arg),*), 89 => $f::<89>($($arg),*), 90 => $f::<90>($($arg),*), 91 => $f::<91>($($arg),*),
+            92 => $f::<92>($($arg),*), 93 => $f::<93>($($arg),*), 94 => $f::<94>($($arg),*), 95 => $f::<95>($($arg),*),
+            96 => $f::<96>($($arg),*), 97 => $f::<97>($($arg),*), 98 => $f::<98>($($arg),*), 99 => $f::<99>($($arg),*),
+            100 => $f::<100>($($arg),*), 101 => $f::<101>($($arg),*), 102 => $f::<102>($($arg),*), 103 => $f::<103>($($arg),*),
+            104 => $f::<104>($($arg),*), 105 => $f::<105>($($arg),*), 106 => $f::<106>($($arg),*), 107 => $f::<107>($($arg),*),
+            108 => $f::<108>($($arg),*), 109 => $f::<109>($($arg),*), 110 => $f::<110>($($arg),*), 111 => $f::<111>($($arg),*),
+            112 => $f::<112>($($arg),*), 113 => $f::<113>($($arg),*), 114 => $f::<114>($($arg),*), 115 => $f::<115>($($arg),*),
+            116 => $f::<116>($($arg),*), 117 => $f::<117>($($arg),*), 118 => $f::<118>($($arg),*), 119 => $f::<119>($($arg),*),
+            120 => $f::<120>($($arg),*), 121 => $f::<121>($($arg),*), 122 => $f::<122>($($arg),*), 123 => $f::<123>($($arg),*),
+            124 => $f::<124>($($arg),*), 125 => $f::<125>($($arg),*), 126 => $f::<126>($($arg),*), 127 => $f::<127>($($arg),*),
+            128 => $f::<128>($($arg),*), 129 => $f::<129>($($arg),*), 130 => $f::<130>($($arg),*), 131 => $f::<131>($($arg),*),
+            132 => $f::<132>($($arg),*), 133 => $f::<133>($($arg),*), 134 => $f::<134>($($arg),*), 135 => $f::<135>($($arg),*),
+            136 => $f::<136>($($arg),*), 137 => $f::<137>($($arg),*), 138 => $f::<138>($($arg),*), 139 => $f::<139>($($arg),*),
+            140 => $f::<140>($($arg),*), 141 => $f::<141>($($arg),*), 142 => $f::<142>($($arg),*), 143 => $f::<143>($($arg),*),
+            144 => $f::<144>($($arg),*), 145 => $f::<145>($($arg),*), 146 => $f::<146>($($arg),*), 147 => $f::<147>($($arg),*),
+            148 => $f::<148>($($arg),*), 149 => $f::<149>($($arg),*), 150 => $f::<150>($($arg),*), 151 => $f::<151>($($arg),*),
+            152 => $f::<152>($($arg),*), 153 => $f::<153>($($arg),*), 154 => $f::<154>($($arg),*), 155 => $f::<155>($($arg),*),
+            156 => $f::<156>($($arg),*), 157 => $f::<157>($($arg),*), 158 => $f::<158>($($arg),*), 159 => $f::<159>($($arg),*),
+            160 => $f::<160>($($arg),*), 161 => $f::<161>($($arg),*), 162 => $f::<162>($($arg),*), 163 => $f::<163>($($arg),*),
+            164 => $f::<164>($($arg),*), 165 => $f::<165>($($arg),*), 166 => $f::<166>($($arg),*), 167 => $f::<167>($($arg),*),
+            168 => $f::<168>($($arg),*), 169 => $f::<169>($($arg),*), 170 => $f::<170>($($arg),*), 171 => $f::<171>($($arg),*),
+            172 => $f::<172>($($arg),*), 173 => $f::<173>($($arg),*), 174 => $f::<174>($($arg),*), 175 => $f::<175>($($arg),*),
+            176 => $f::<176>($($arg),*), 177 => $f::<177>($($arg),*), 178 => $f::<178>($($arg),*), 179 => $f::<179>($($arg),*),
+            180 => $f::<180>($($arg),*), 181 => $f::<181>($($arg),*), 182 => $f::<182>($($arg),*), 183 => $f::<183>($($arg),*),
+            184 => $f::<184>($($arg),*), 185 => $f::<185>($($arg),*), 186 => $f::<186>($($arg),*), 187 => $f::<187>($($arg),*),
+            188 => $f::<188>($($arg),*), 189 => $f::<189>($($arg),*), 190 => $f::<190>($($arg),*), 191 => $f::<191>($($arg),*),
+            192 => $f::<192>($($arg),*), 193 => $f::<193>($($arg),*), 194 => $f::<194>($($arg),*), 195 => $f::<195>($($arg),*),
+            196 => $f::<196>($($arg),*), 197 => $f::<197>($($arg),*), 198 => $f::<198>($($arg),*), 199 => $f::<199>($($arg),*),
+            200 => $f::<200>($($arg),*), 201 => $f::<201>($($arg),*), 202 => $f::<202>($($arg),*), 203 => $f::<203>($($arg),*),
+            204 => $f::<204>($($arg),*), 205 => $f::<205>($($arg),*), 206 => $f::<206>($($arg),*), 207 => $f::<207>($($arg),*),
+            208 => $f::<208>($($arg),*), 209 => $f::<209>($($arg),*), 210 => $f::<210>($($arg),*), 211 => $f::<211>($($arg),*),
+            212 => $f::<212>($($arg),*), 213 => $f::<213>($($arg),*), 214 => $f::<214>($($arg),*), 215 => $f::<215>($($arg),*),
+            216 => $f::<216>($($arg),*), 217 => $f::<217>($($arg),*), 218 => $f::<218>($($arg),*), 219 => $f::<219>($($arg),*),
+            220 => $f::<220>($($arg),*), 221 => $f::<221>($($arg),*), 222 => $f::<222>($($arg),*), 223 => $f::<223>($($arg),*),
+            224 => $f::<224>($($arg),*), 225 => $f::<225>($($arg),*), 226 => $f::<226>($($arg),*), 227 => $f::<227>($($arg),*),
+            228 => $f::<228>($($arg),*), 229 => $f::<229>($($arg),*), 230 => $f::<230>($($arg),*), 231 => $f::<231>($($arg),*),
+            232 => $f::<232>($($arg),*), 233 => $f::<233>($($arg),*), 234 => $f::<234>($($arg),*), 235 => $f::<235>($($arg),*),
+            236 => $f::<236>($($arg),*), 237 => $f::<237>($($arg),*), 238 => $f::<238>($($arg),*), 239 => $f::<239>($($arg),*),
+            240 => $f::<240>($($arg),*), 241 => $f::<241>($($arg),*), 242 => $f::<242>($($arg),*), 243 => $f::<243>($($arg),*),
+            244 => $f::<244>($($arg),*), 245 => $f::<245>($($arg),*), 246 => $f::<246>($($arg),*), 247 => $f::<247>($($arg),*),
+            248 => $f::<248>($($arg),*), 249 => $f::<249>($($arg),*), 250 => $f::<250>($($arg),*), 251 => $f::<251>($($arg),*),
+            252 => $f::<252>($($arg),*), 253 => $f::<253>($($arg),*), 254 => $f::<254>($($arg),*), 255 => $f::<255>($($arg),*),
+            _ => unreachable!()
+        }
+    };
+}
+
+/// Selects the elements of `a` where the `mask` is set, otherwise the elements of `b`.
+/// 
+/// ※ Each element of the `mask` must be all ones or all zeros, such as the result of a comparison. </br>
+/// ※ When the `sse4.1` target feature is enabled, the `blendvps` instruction is used. </br>
+/// 
+#[inline]
+unsafe fn blend_ps(mask: __m128, a: __m128, b: __m128) -> __m128 {
+    match cfg!(target_feature = "sse4.1") {
+        true => _mm_blendv_ps(b, a, mask), 
+        false => _mm_or_ps(_mm_and_ps(mask, a), _mm_andnot_ps(mask, b))
+    }
+}
+
+/// Returns the immediate value of `_mm_shuffle_ps` that selects the given element indices.
+#[inline]
+const fn shuffle_mask(x: usize, y: usize, z: usize, w: usize) -> i32 {
+    (((w & 3) << 6) | ((z & 3) << 4) | ((y & 3) << 2) | (x & 3)) as i32
+}
+
+mod dvector;
+pub use self::dvector::DVector;
+
 mod matrix;
 pub use self::matrix::Matrix;
 
+mod matrix3;
+pub use self::matrix3::Matrix3;
+
 mod quaternion;
 pub use self::quaternion::Quaternion;
 
 mod vector;
 pub use self::vector::Vector;
 
+mod vector3x4;
+pub use self::vector3x4::Vector3x4;
+
+mod vector4x4;
+pub use self::vector4x4::Vector4x4;
+
 mod vector_int;
 pub use self::vector_int::VectorInt;
@@ -7,7 +7,15 @@ use core::arch::x86::*;
 #[cfg(target_pointer_width = "64")]
 use core::arch::x86_64::*;
 
-use crate::{ Quaternion, VectorInt, Float2, Float3, Float4 };
+use crate::{ DVector, Quaternion, VectorInt, Float2, Float3, Float4, Half4, 
+    UByteN4, ByteN4, ShortN2, UShortN4, UDecN4, Float3PK, Rgb9e5 
+};
+use crate::near_eq;
+
+use super::{ blend_ps, shuffle_mask };
+
+#[cfg(not(feature = "std"))]
+use crate::math::Float;
 
 
 
@@ -186,6 +194,256 @@ impl Vector {
             val
         }
     }
+
+    /// Loads a value from a given `Half4`.
+    /// 
+    /// ※ Uses the `f16c` instruction if the `target_feature` is enabled at compile time. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn load_half4(val: Half4) -> Self {
+        #[cfg(target_feature = "f16c")]
+        unsafe {
+            let bits = _mm_loadl_epi64(&val as *const _ as *const __m128i);
+            Self { inner: _mm_cvtph_ps(bits) }
+        }
+
+        #[cfg(not(target_feature = "f16c"))]
+        {
+            Self::load_float4(val.to_float4())
+        }
+    }
+
+    /// Stores the value in a `Half4`.
+    /// 
+    /// ※ Each element is rounded to the nearest representable value, ties to even. </br>
+    /// ※ Uses the `f16c` instruction if the `target_feature` is enabled at compile time. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn store_half4(self) -> Half4 {
+        #[cfg(target_feature = "f16c")]
+        unsafe {
+            let mut val = Half4::default();
+            let bits = _mm_cvtps_ph::<_MM_FROUND_TO_NEAREST_INT>(self.inner);
+            _mm_storel_epi64(&mut val as *mut _ as *mut __m128i, bits);
+            val
+        }
+
+        #[cfg(not(target_feature = "f16c"))]
+        {
+            Half4::from_float4(self.store_float4())
+        }
+    }
+
+    /// Loads a value from a given `UByteN4`.
+    #[inline]
+    #[must_use]
+    pub fn load_ubyten4(val: UByteN4) -> Self {
+        Self::new(val.x as f32, val.y as f32, val.z as f32, val.w as f32) / 255.0
+    }
+
+    /// Stores the value in a `UByteN4`.
+    /// 
+    /// ※ Each element is saturated to the range `[0, 1]` and rounded to the nearest integer. (NaN becomes zero) </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn store_ubyten4(self) -> UByteN4 {
+        let [x, y, z, w] = (self.max(Self::ZERO).min(Self::ONE) * 255.0).round().into_array();
+        UByteN4 { x: x as u8, y: y as u8, z: z as u8, w: w as u8 }
+    }
+
+    /// Loads a value from a given `ByteN4`.
+    /// 
+    /// ※ Both `i8::MIN` and `-i8::MAX` become negative one. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn load_byten4(val: ByteN4) -> Self {
+        (Self::new(val.x as f32, val.y as f32, val.z as f32, val.w as f32) / 127.0).max(Self::NEG_ONE)
+    }
+
+    /// Stores the value in a `ByteN4`.
+    /// 
+    /// ※ Each element is saturated to the range `[-1, 1]` and rounded to the nearest integer. (NaN becomes zero) </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn store_byten4(self) -> ByteN4 {
+        let v = Self::select(self.eq(self), self, Self::ZERO);
+        let [x, y, z, w] = (v.max(Self::NEG_ONE).min(Self::ONE) * 127.0).round().into_array();
+        ByteN4 { x: x as i8, y: y as i8, z: z as i8, w: w as i8 }
+    }
+
+    /// Loads a value from a given `ShortN2`.
+    /// 
+    /// ※ Both `i16::MIN` and `-i16::MAX` become negative one. </br>
+    /// ※ The `z` and `w` elements are zero. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn load_shortn2(val: ShortN2) -> Self {
+        (Self::new(val.x as f32, val.y as f32, 0.0, 0.0) / 32767.0).max(Self::NEG_ONE)
+    }
+
+    /// Stores the `x` and `y` elements in a `ShortN2`.
+    /// 
+    /// ※ Each element is saturated to the range `[-1, 1]` and rounded to the nearest integer. (NaN becomes zero) </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn store_shortn2(self) -> ShortN2 {
+        let v = Self::select(self.eq(self), self, Self::ZERO);
+        let [x, y, _, _] = (v.max(Self::NEG_ONE).min(Self::ONE) * 32767.0).round().into_array();
+        ShortN2 { x: x as i16, y: y as i16 }
+    }
+
+    /// Loads a value from a given `UShortN4`.
+    #[inline]
+    #[must_use]
+    pub fn load_ushortn4(val: UShortN4) -> Self {
+        Self::new(val.x as f32, val.y as f32, val.z as f32, val.w as f32) / 65535.0
+    }
+
+    /// Stores the value in a `UShortN4`.
+    /// 
+    /// ※ Each element is saturated to the range `[0, 1]` and rounded to the nearest integer. (NaN becomes zero) </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn store_ushortn4(self) -> UShortN4 {
+        let [x, y, z, w] = (self.max(Self::ZERO).min(Self::ONE) * 65535.0).round().into_array();
+        UShortN4 { x: x as u16, y: y as u16, z: z as u16, w: w as u16 }
+    }
+
+    /// Loads a value from a given `UDecN4`.
+    #[inline]
+    #[must_use]
+    pub fn load_udecn4(val: UDecN4) -> Self {
+        let v = Self::new(val.x() as f32, val.y() as f32, val.z() as f32, val.w() as f32);
+        v / Self::new(1023.0, 1023.0, 1023.0, 3.0)
+    }
+
+    /// Stores the value in a `UDecN4`.
+    /// 
+    /// ※ Each element is saturated to the range `[0, 1]` and rounded to the nearest integer. (NaN becomes zero) </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn store_udecn4(self) -> UDecN4 {
+        let v = self.max(Self::ZERO).min(Self::ONE) * Self::new(1023.0, 1023.0, 1023.0, 3.0);
+        let [x, y, z, w] = v.round().into_array();
+        UDecN4::new(x as u32, y as u32, z as u32, w as u32)
+    }
+
+    /// Loads a value from a given `Float3PK`.
+    /// 
+    /// ※ The `w` element is zero. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn load_float3pk(val: Float3PK) -> Self {
+        Self::load_float3(val.to_float3())
+    }
+
+    /// Stores the `x`, `y` and `z` elements in a `Float3PK`.
+    /// 
+    /// ※ Each element is rounded to the nearest representable value, ties to even. </br>
+    /// ※ Negative values become zero and finite values that are too large become the largest finite value. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn store_float3pk(self) -> Float3PK {
+        Float3PK::from_float3(self.store_float3())
+    }
+
+    /// Loads a value from a given `Rgb9e5`.
+    /// 
+    /// ※ The `w` element is zero. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn load_rgb9e5(val: Rgb9e5) -> Self {
+        Self::load_float3(val.to_float3())
+    }
+
+    /// Stores the `x`, `y` and `z` elements in a `Rgb9e5`.
+    /// 
+    /// ※ Each element is clamped to the range `[0, 65408]`. (NaN becomes zero) </br>
+    /// ※ The shared exponent is chosen from the largest element, and the mantissas are rounded to the nearest integer. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn store_rgb9e5(self) -> Rgb9e5 {
+        Rgb9e5::from_float3(self.store_float3())
+    }
+
+    /// Encodes the `x`, `y` and `z` elements of a linear color in the range `[0, range]` in `RGBM` format.
+    /// 
+    /// ※ The `w` element of the result is the shared multiplier, rounded up to a multiple of `1/255` so that it can be stored in 8 bits. </br>
+    /// ※ All elements of the result are in the range `[0, 1]`, and the decoded color is `rgb * w * range`. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn encode_rgbm(self, range: f32) -> Self {
+        let color = self / range;
+        let m = color.get_x().max(color.get_y()).max(color.get_z()).clamp(1e-6, 1.0);
+        let m = (m * 255.0).ceil() / 255.0;
+        let mut rgbm = (color / m).max(Self::ZERO).min(Self::ONE);
+        rgbm.set_w(m);
+        rgbm
+    }
+
+    /// Decodes a color in `RGBM` format with the given range.
+    /// 
+    /// ※ The `w` element of the result is zero. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn decode_rgbm(self, range: f32) -> Self {
+        let mut color = self * (self.get_w() * range);
+        color.set_w(0.0);
+        color
+    }
+
+    /// Encodes the `x`, `y` and `z` elements of a linear color in the range `[0, range]` in `RGBD` format.
+    /// 
+    /// ※ The `w` element of the result is the shared divisor, a multiple of `1/255` so that it can be stored in 8 bits. </br>
+    /// ※ All elements of the result are in the range `[0, 1]`, and the decoded color is `rgb * range / (w * 255)`. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn encode_rgbd(self, range: f32) -> Self {
+        let max = self.get_x().max(self.get_y()).max(self.get_z());
+        let d = (range / max).floor().clamp(1.0, 255.0);
+        let mut rgbd = (self * (d / range)).max(Self::ZERO).min(Self::ONE);
+        rgbd.set_w(d / 255.0);
+        rgbd
+    }
+
+    /// Decodes a color in `RGBD` format with the given range.
+    /// 
+    /// ※ The `w` element of the result is zero. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn decode_rgbd(self, range: f32) -> Self {
+        let mut color = self * (range / (self.get_w() * 255.0));
+        color.set_w(0.0);
+        color
+    }
+
+    /// Converts to a double-precision vector.
+    #[inline]
+    #[must_use]
+    pub fn as_f64(self) -> DVector {
+        unsafe {
+            let lo = _mm_cvtps_pd(self.inner);
+            let hi = _mm_cvtps_pd(_mm_movehl_ps(self.inner, self.inner));
+            DVector { inner: [lo, hi] }
+        }
+    }
 }
 
 impl Vector {
@@ -220,7 +478,7 @@ impl Vector {
     pub fn set_y(&mut self, v: f32) {
         unsafe {
             let v = _mm_set_ss(v);
-            self.inner = match is_x86_feature_detected!("sse4.1") {
+            self.inner = match cfg!(target_feature = "sse4.1") {
                 true => _mm_insert_ps::<0x10>(self.inner, v), 
                 false => {
                     let yxzw = _mm_shuffle_ps::<0b_11_10_00_01>(self.inner, self.inner);
@@ -246,7 +504,7 @@ impl Vector {
     pub fn set_z(&mut self, v: f32) {
         unsafe {
             let v = _mm_set_ss(v);
-            self.inner = match is_x86_feature_detected!("sse4.1") {
+            self.inner = match cfg!(target_feature = "sse4.1") {
                 true => _mm_insert_ps::<0x20>(self.inner, v), 
                 false => {
                     let zwxy = _mm_shuffle_ps::<0b_01_00_11_10>(self.inner, self.inner);
@@ -272,7 +530,7 @@ impl Vector {
     pub fn set_w(&mut self, v: f32) {
         unsafe {
             let v = _mm_set_ss(v);
-            self.inner = match is_x86_feature_detected!("sse4.1") {
+            self.inner = match cfg!(target_feature = "sse4.1") {
                 true => _mm_insert_ps::<0x30>(self.inner, v), 
                 false => {
                     let wyzx = _mm_shuffle_ps::<0b_00_10_01_11>(self.inner, self.inner);
@@ -356,6 +614,33 @@ impl Vector {
     pub fn ne(self, rhs: Self) -> VectorInt {
         !self.eq(rhs)
     }
+
+    /// Selects the elements of `a` where the `mask` is set, otherwise the elements of `b`.
+    /// 
+    /// ※ The selection is done bit by bit, so the `mask` is usually the result of a comparison. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn select(mask: VectorInt, a: Self, b: Self) -> Self {
+        unsafe {
+            let mask = _mm_castsi128_ps(mask.inner);
+            Vector { inner: _mm_or_ps(_mm_and_ps(mask, a.inner), _mm_andnot_ps(mask, b.inner)) }
+        }
+    }
+
+    /// Creates a vector by reinterpreting the bits of the given integer vector.
+    #[inline]
+    #[must_use]
+    pub fn from_bits(bits: VectorInt) -> Self {
+        unsafe { Vector { inner: _mm_castsi128_ps(bits.inner) } }
+    }
+
+    /// Reinterprets the bits of the vector as an integer vector.
+    #[inline]
+    #[must_use]
+    pub fn to_bits(self) -> VectorInt {
+        VectorInt::from(self)
+    }
     
     /// Absolute value on vector elements.
     #[inline]
@@ -369,17 +654,17 @@ impl Vector {
     #[must_use]
     pub fn sum(self) -> Self {
         unsafe {
-            let sum = match is_x86_feature_detected!("sse3") {
+            let sum = match cfg!(target_feature = "sse3") {
                 true => {
                     let temp = _mm_hadd_ps(self.inner, self.inner);
                     _mm_hadd_ps(temp, temp)
                 }, 
                 false => {
-                    let low = _mm_shuffle_ps::<0b_01_00_01_00>(self.inner, self.inner);
-                    let high = _mm_shuffle_ps::<0b_11_10_11_10>(self.inner, self.inner);
-                    let low = _mm_add_ps(low, high);
-                    let high = _mm_shuffle_ps::<0b_10_11_00_01>(low, low);
-                    _mm_add_ps(low, high)
+                    // Adds in the same order as `haddps`. ((x + y) + (z + w))
+                    let pair = _mm_shuffle_ps::<0b_10_11_00_01>(self.inner, self.inner);
+                    let pair = _mm_add_ps(self.inner, pair);
+                    let mix = _mm_shuffle_ps::<0b_01_00_11_10>(pair, pair);
+                    _mm_add_ps(pair, mix)
                 }
             };
             return Vector { inner: sum };
@@ -398,11 +683,22 @@ impl Vector {
     #[must_use]
     pub fn vec2_dot(self, rhs: Self) -> Vector {
         unsafe {
-            match is_x86_feature_detected!("sse4.1") {
+            // If `fma` is supported, the products are accumulated with one rounding error each.
+            if cfg!(target_feature = "fma") {
+                let y = _mm_mul_ps(
+                    _mm_shuffle_ps::<0b_01_01_01_01>(self.inner, self.inner), 
+                    _mm_shuffle_ps::<0b_01_01_01_01>(rhs.inner, rhs.inner)
+                );
+                let dot = _mm_fmadd_ps(self.inner, rhs.inner, y);
+                return Vector { inner: _mm_shuffle_ps::<0b_00_00_00_00>(dot, dot) };
+            }
+
+            match cfg!(target_feature = "sse4.1") {
                 true => Vector { inner: _mm_dp_ps(self.inner, rhs.inner, 0x3F) },
                 false => {
-                    const MASK_XY: Vector = Vector { arr: [1.0, 1.0, 0.0, 0.0] };
-                    (self * rhs * MASK_XY).sum()
+                    // The masked elements are cleared like `dpps`, not multiplied by zero.
+                    let mask = _mm_castsi128_ps(_mm_setr_epi32(-1, -1, 0, 0));
+                    Vector { inner: _mm_and_ps((self * rhs).inner, mask) }.sum()
                 }
             }
         }
@@ -420,11 +716,27 @@ impl Vector {
     #[must_use]
     pub fn vec3_dot(self, rhs: Self) -> Vector {
         unsafe {
-            match is_x86_feature_detected!("sse4.1") {
+            // If `fma` is supported, the products are accumulated with one rounding error each.
+            if cfg!(target_feature = "fma") {
+                let z = _mm_mul_ps(
+                    _mm_shuffle_ps::<0b_10_10_10_10>(self.inner, self.inner), 
+                    _mm_shuffle_ps::<0b_10_10_10_10>(rhs.inner, rhs.inner)
+                );
+                let yz = _mm_fmadd_ps(
+                    _mm_shuffle_ps::<0b_01_01_01_01>(self.inner, self.inner), 
+                    _mm_shuffle_ps::<0b_01_01_01_01>(rhs.inner, rhs.inner), 
+                    z
+                );
+                let dot = _mm_fmadd_ps(self.inner, rhs.inner, yz);
+                return Vector { inner: _mm_shuffle_ps::<0b_00_00_00_00>(dot, dot) };
+            }
+
+            match cfg!(target_feature = "sse4.1") {
                 true => Vector { inner: _mm_dp_ps(self.inner, rhs.inner, 0x7F) }, 
                 false => {
-                    const MASK_XYZ: Vector = Vector { arr: [1.0, 1.0, 1.0, 0.0] };
-                    (self * rhs * MASK_XYZ).sum()
+                    // The masked elements are cleared like `dpps`, not multiplied by zero.
+                    let mask = _mm_castsi128_ps(_mm_setr_epi32(-1, -1, -1, 0));
+                    Vector { inner: _mm_and_ps((self * rhs).inner, mask) }.sum()
                 }
             }
         }
@@ -442,7 +754,16 @@ impl Vector {
     #[must_use]
     pub fn vec4_dot(self, rhs: Self) -> Vector {
         unsafe { 
-            match is_x86_feature_detected!("sse4.1") {
+            // If `fma` is supported, the products are accumulated with one rounding error each.
+            if cfg!(target_feature = "fma") {
+                // (x * x' + z * z', y * y' + w * w', ...)
+                let zw = _mm_mul_ps(_mm_movehl_ps(self.inner, self.inner), _mm_movehl_ps(rhs.inner, rhs.inner));
+                let sum = _mm_fmadd_ps(self.inner, rhs.inner, zw);
+                let dot = _mm_add_ps(sum, _mm_shuffle_ps::<0b_01_01_01_01>(sum, sum));
+                return Vector { inner: _mm_shuffle_ps::<0b_00_00_00_00>(dot, dot) };
+            }
+
+            match cfg!(target_feature = "sse4.1") {
                 true => Vector { inner: _mm_dp_ps(self.inner, rhs.inner, 0xFF) }, 
                 false => (self * rhs).sum()
             }
@@ -637,7 +958,7 @@ impl Vector {
     pub fn floor(self) -> Self {
         // If `sse4.1`` is not supported, hardware acceleration is not used.
         unsafe {
-            match is_x86_feature_detected!("sse4.1") {
+            match cfg!(target_feature = "sse4.1") {
                 true => Vector { inner: _mm_floor_ps(self.inner) }, 
                 false => {
                     let mut arr = self.into_array();
@@ -656,7 +977,7 @@ impl Vector {
     pub fn ceil(self) -> Self {
         // If `sse4.1`` is not supported, hardware acceleration is not used.
         unsafe {
-            match is_x86_feature_detected!("sse4.1") {
+            match cfg!(target_feature = "sse4.1") {
                 true => Vector { inner: _mm_ceil_ps(self.inner) }, 
                 false => {
                     let mut arr = self.into_array();
@@ -675,10 +996,14 @@ impl Vector {
     pub fn round(self) -> Self {
         // If `sse4.1`` is not supported, hardware acceleration is not used.
         unsafe {
-            match is_x86_feature_detected!("sse4.1") {
+            match cfg!(target_feature = "sse4.1") {
                 true => {
-                    const FLAG: i32 = _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC;
-                    Vector { inner: _mm_round_ps::<FLAG>(self.inner) }
+                    // Rounds half-way cases away from zero like `f32::round`.
+                    // The largest number less than `0.5` is added, so `0.49999997` does not become `1.0`.
+                    const FLAG: i32 = _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC;
+                    let sign = _mm_and_ps(self.inner, _mm_set1_ps(-0.0));
+                    let half = _mm_or_ps(sign, _mm_set1_ps(0.49999997));
+                    Vector { inner: _mm_round_ps::<FLAG>(_mm_add_ps(self.inner, half)) }
                 }, 
                 false => {
                     let mut arr = self.into_array();
@@ -700,7 +1025,653 @@ impl Vector {
     #[inline]
     #[must_use]
     pub fn lerp(self, rhs: Self, t: f32) -> Self {
-        self * (1.0 - t) + rhs * t
+        rhs.mul_add(Vector::fill(t), self * (1.0 - t))
+    }
+
+    /// Computes `(self * a) + b` for each element of the vector.
+    /// 
+    /// ※ If the `fma` target feature is enabled, it is computed with only one rounding error. </br>
+    /// ※ Otherwise, it is computed as a separate multiply and add. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn mul_add(self, a: Self, b: Self) -> Self {
+        unsafe {
+            match cfg!(target_feature = "fma") {
+                true => Vector { inner: _mm_fmadd_ps(self.inner, a.inner, b.inner) }, 
+                false => Vector { inner: _mm_add_ps(_mm_mul_ps(self.inner, a.inner), b.inner) }
+            }
+        }
+    }
+}
+
+impl Vector {
+    /// Returns a vector with the elements rearranged by the given indices.
+    /// 
+    /// ※ Each index must be in the range `0` to `3` and selects the `x`, `y`, `z` or `w` element. </br>
+    /// ※ For example, `shuffle::<2, 1, 0, 3>()` returns `(z, y, x, w)`. </br>
+    /// 
+    /// # Panics
+    /// When the `use-assertion` feature is enabled, it will [`panic!`]
+    /// if any of the indices is greater than three.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn shuffle<const X: usize, const Y: usize, const Z: usize, const W: usize>(self) -> Self {
+        #[cfg(feature = "use-assertion")]
+        assert!(X < 4 && Y < 4 && Z < 4 && W < 4, "The index of the element is out of range!");
+        unsafe { Vector { inner: dispatch_imm8!(shuffle_mask(X, Y, Z, W), _mm_shuffle_ps(self.inner, self.inner)) } }
+    }
+
+    /// Returns a vector with the elements selected from the two vectors by the given indices.
+    /// 
+    /// ※ Indices `0` to `3` select the `x`, `y`, `z` or `w` element of `self`,
+    /// and indices `4` to `7` select the `x`, `y`, `z` or `w` element of `rhs`. </br>
+    /// ※ For example, `permute::<0, 4, 1, 5>(rhs)` returns `(self.x, rhs.x, self.y, rhs.y)`. </br>
+    /// 
+    /// # Panics
+    /// When the `use-assertion` feature is enabled, it will [`panic!`]
+    /// if any of the indices is greater than seven.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn permute<const X: usize, const Y: usize, const Z: usize, const W: usize>(self, rhs: Self) -> Self {
+        #[cfg(feature = "use-assertion")]
+        assert!(X < 8 && Y < 8 && Z < 8 && W < 8, "The index of the element is out of range!");
+        unsafe {
+            let inner = match (X < 4, Y < 4, Z < 4, W < 4) {
+                (true, true, true, true) => dispatch_imm8!(shuffle_mask(X, Y, Z, W), _mm_shuffle_ps(self.inner, self.inner)), 
+                (false, false, false, false) => dispatch_imm8!(shuffle_mask(X, Y, Z, W), _mm_shuffle_ps(rhs.inner, rhs.inner)), 
+                (true, true, false, false) => dispatch_imm8!(shuffle_mask(X, Y, Z, W), _mm_shuffle_ps(self.inner, rhs.inner)), 
+                (false, false, true, true) => dispatch_imm8!(shuffle_mask(X, Y, Z, W), _mm_shuffle_ps(rhs.inner, self.inner)), 
+                _ => {
+                    let a = dispatch_imm8!(shuffle_mask(X, Y, Z, W), _mm_shuffle_ps(self.inner, self.inner));
+                    let b = dispatch_imm8!(shuffle_mask(X, Y, Z, W), _mm_shuffle_ps(rhs.inner, rhs.inner));
+                    let mask = _mm_castsi128_ps(_mm_setr_epi32(
+                        -((X >= 4) as i32), 
+                        -((Y >= 4) as i32), 
+                        -((Z >= 4) as i32), 
+                        -((W >= 4) as i32)
+                    ));
+                    blend_ps(mask, b, a)
+                }
+            };
+            Vector { inner }
+        }
+    }
+
+    /// Fills all elements with the `x` element of the vector.
+    #[inline]
+    #[must_use]
+    pub fn splat_x(self) -> Self {
+        unsafe { Vector { inner: _mm_shuffle_ps::<0b_00_00_00_00>(self.inner, self.inner) } }
+    }
+
+    /// Fills all elements with the `y` element of the vector.
+    #[inline]
+    #[must_use]
+    pub fn splat_y(self) -> Self {
+        unsafe { Vector { inner: _mm_shuffle_ps::<0b_01_01_01_01>(self.inner, self.inner) } }
+    }
+
+    /// Fills all elements with the `z` element of the vector.
+    #[inline]
+    #[must_use]
+    pub fn splat_z(self) -> Self {
+        unsafe { Vector { inner: _mm_shuffle_ps::<0b_10_10_10_10>(self.inner, self.inner) } }
+    }
+
+    /// Fills all elements with the `w` element of the vector.
+    #[inline]
+    #[must_use]
+    pub fn splat_w(self) -> Self {
+        unsafe { Vector { inner: _mm_shuffle_ps::<0b_11_11_11_11>(self.inner, self.inner) } }
+    }
+
+    /// Interleaves the `x` and `y` elements of the two vectors.
+    /// 
+    /// ※ Returns `(self.x, rhs.x, self.y, rhs.y)`. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn merge_xy(self, rhs: Self) -> Self {
+        unsafe { Vector { inner: _mm_unpacklo_ps(self.inner, rhs.inner) } }
+    }
+
+    /// Interleaves the `z` and `w` elements of the two vectors.
+    /// 
+    /// ※ Returns `(self.z, rhs.z, self.w, rhs.w)`. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn merge_zw(self, rhs: Self) -> Self {
+        unsafe { Vector { inner: _mm_unpackhi_ps(self.inner, rhs.inner) } }
+    }
+}
+
+impl Vector {
+    /// Returns a vector containing the square root of each element of the vector.
+    #[inline]
+    #[must_use]
+    pub fn sqrt(self) -> Self {
+        unsafe { Vector { inner: _mm_sqrt_ps(self.inner) } }
+    }
+
+    /// Returns a vector containing the estimated square root of each element of the vector.
+    ///
+    /// ※ The relative error is less than `4.0e-4`. </br>
+    /// ※ Zero and infinity elements are returned as they are.
+    ///
+    #[inline]
+    #[must_use]
+    pub fn sqrt_est(self) -> Self {
+        unsafe {
+            let est = _mm_mul_ps(self.inner, _mm_rsqrt_ps(self.inner));
+            let exact = _mm_or_ps(
+                _mm_cmpeq_ps(self.inner, _mm_setzero_ps()), 
+                _mm_cmpeq_ps(self.inner, _mm_set1_ps(f32::INFINITY))
+            );
+            Vector { inner: blend_ps(exact, self.inner, est) }
+        }
+    }
+
+    /// Returns a vector containing the reciprocal square root of each element of the vector.
+    #[inline]
+    #[must_use]
+    pub fn rsqrt(self) -> Self {
+        unsafe { Vector { inner: _mm_div_ps(_mm_set1_ps(1.0), _mm_sqrt_ps(self.inner)) } }
+    }
+
+    /// Returns a vector containing the estimated reciprocal square root of each element of the vector.
+    ///
+    /// ※ The relative error is less than `4.0e-4`.
+    ///
+    #[inline]
+    #[must_use]
+    pub fn rsqrt_est(self) -> Self {
+        unsafe { Vector { inner: _mm_rsqrt_ps(self.inner) } }
+    }
+
+    /// Returns a vector containing the reciprocal of each element of the vector.
+    #[inline]
+    #[must_use]
+    pub fn recip(self) -> Self {
+        unsafe { Vector { inner: _mm_div_ps(_mm_set1_ps(1.0), self.inner) } }
+    }
+
+    /// Returns a vector containing the estimated reciprocal of each element of the vector.
+    ///
+    /// ※ The relative error is less than `4.0e-4`.
+    ///
+    #[inline]
+    #[must_use]
+    pub fn recip_est(self) -> Self {
+        unsafe { Vector { inner: _mm_rcp_ps(self.inner) } }
+    }
+
+    /// Estimated length of a two-element vector.
+    ///
+    /// ※ The relative error is less than `4.0e-4`.
+    ///
+    #[inline]
+    #[must_use]
+    pub fn vec2_len_est(self) -> f32 {
+        self.vec2_dot(self).sqrt_est().get_x()
+    }
+
+    /// Estimated length of a three-element vector.
+    ///
+    /// ※ The relative error is less than `4.0e-4`.
+    ///
+    #[inline]
+    #[must_use]
+    pub fn vec3_len_est(self) -> f32 {
+        self.vec3_dot(self).sqrt_est().get_x()
+    }
+
+    /// Estimated length of a four-element vector.
+    ///
+    /// ※ The relative error is less than `4.0e-4`.
+    ///
+    #[inline]
+    #[must_use]
+    pub fn vec4_len_est(self) -> f32 {
+        self.vec4_dot(self).sqrt_est().get_x()
+    }
+
+    /// Normalizes a two-element vector using the estimated reciprocal square root.
+    ///
+    /// ※ The relative error of each element is less than `4.0e-4`. </br>
+    /// Undefined behavior may occur if the length of the vector is less than or equal to [`f32::EPSILON`].
+    ///
+    /// # Panics
+    /// When `use-assertion` feature is enabled, [`panic!`] will be called 
+    /// if the length of the vector is less than or equal to [`f32::EPSILON`].
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn vec2_normalize_est(self) -> Self {
+        #[cfg(feature = "use-assertion")]
+        assert!(self.vec2_len() > f32::EPSILON, "The length of the vector is less than or equal to `f32::EPSILON`!");
+
+        const MASK_XY: Vector = Vector { arr: [1.0, 1.0, 0.0, 0.0] };
+        self * self.vec2_dot(self).rsqrt_est() * MASK_XY
+    }
+
+    /// Normalizes a three-element vector using the estimated reciprocal square root.
+    ///
+    /// ※ The relative error of each element is less than `4.0e-4`. </br>
+    /// Undefined behavior may occur if the length of the vector is less than or equal to [`f32::EPSILON`].
+    ///
+    /// # Panics
+    /// When `use-assertion` feature is enabled, [`panic!`] will be called 
+    /// if the length of the vector is less than or equal to [`f32::EPSILON`].
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn vec3_normalize_est(self) -> Self {
+        #[cfg(feature = "use-assertion")]
+        assert!(self.vec3_len() > f32::EPSILON, "The length of the vector is less than or equal to `f32::EPSILON`!");
+
+        const MASK_XYZ: Vector = Vector { arr: [1.0, 1.0, 1.0, 0.0] };
+        self * self.vec3_dot(self).rsqrt_est() * MASK_XYZ
+    }
+
+    /// Normalizes a four-element vector using the estimated reciprocal square root.
+    ///
+    /// ※ The relative error of each element is less than `4.0e-4`. </br>
+    /// Undefined behavior may occur if the length of the vector is less than or equal to [`f32::EPSILON`].
+    ///
+    /// # Panics
+    /// When `use-assertion` feature is enabled, [`panic!`] will be called 
+    /// if the length of the vector is less than or equal to [`f32::EPSILON`].
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn vec4_normalize_est(self) -> Self {
+        #[cfg(feature = "use-assertion")]
+        assert!(self.vec4_len() > f32::EPSILON, "The length of the vector is less than or equal to `f32::EPSILON`!");
+        self * self.vec4_dot(self).rsqrt_est()
+    }
+}
+
+impl Vector {
+    /// Returns a vector containing the sine of each element of the vector.
+    ///
+    /// ※ The angles given are in radians. </br>
+    /// ※ It uses an 11-degree minimax polynomial approximation.
+    /// The absolute error is less than `4.0e-7` in the range `-4π` to `4π`
+    /// and grows with the magnitude of the angle outside of it.
+    ///
+    #[inline]
+    #[must_use]
+    pub fn sin(self) -> Self {
+        self.sin_cos().0
+    }
+
+    /// Returns a vector containing the cosine of each element of the vector.
+    ///
+    /// ※ The angles given are in radians. </br>
+    /// ※ It uses a 10-degree minimax polynomial approximation.
+    /// The absolute error is less than `4.0e-7` in the range `-4π` to `4π`
+    /// and grows with the magnitude of the angle outside of it.
+    ///
+    #[inline]
+    #[must_use]
+    pub fn cos(self) -> Self {
+        self.sin_cos().1
+    }
+
+    /// Returns the sine and cosine of each element of the vector at the same time.
+    ///
+    /// ※ The angles given are in radians. </br>
+    /// ※ The error bounds are the same as [`Vector::sin`] and [`Vector::cos`].
+    ///
+    #[must_use]
+    pub fn sin_cos(self) -> (Self, Self) {
+        // Reference: DirectXMath/Inc/DirectXMathVector.inl (XMVectorSinCos)
+        const FRAC_1_TAU: f32 = 0.15915494;
+        const TAU_HI: f32 = 6.28125;
+        const TAU_LO: f32 = 1.9353072e-3;
+        unsafe {
+            // Reduce the angle to the range -π to π.
+            let quotient = _mm_cvtepi32_ps(_mm_cvtps_epi32(_mm_mul_ps(self.inner, _mm_set1_ps(FRAC_1_TAU))));
+            let x = _mm_sub_ps(self.inner, _mm_mul_ps(quotient, _mm_set1_ps(TAU_HI)));
+            let x = _mm_sub_ps(x, _mm_mul_ps(quotient, _mm_set1_ps(TAU_LO)));
+
+            // Map the angle to the range -π/2 to π/2 with sin(y) = sin(x).
+            let sign = _mm_and_ps(x, _mm_set1_ps(-0.0));
+            let c = _mm_or_ps(_mm_set1_ps(core::f32::consts::PI), sign);
+            let abs = _mm_andnot_ps(sign, x);
+            let rflx = _mm_sub_ps(c, x);
+            let comp = _mm_cmple_ps(abs, _mm_set1_ps(core::f32::consts::FRAC_PI_2));
+            let x = blend_ps(comp, x, rflx);
+            let x2 = _mm_mul_ps(x, x);
+
+            // Compute polynomial approximation of sine.
+            let s = _mm_add_ps(_mm_mul_ps(_mm_set1_ps(-2.3889859e-8), x2), _mm_set1_ps(2.7525562e-6));
+            let s = _mm_add_ps(_mm_mul_ps(s, x2), _mm_set1_ps(-1.9840874e-4));
+            let s = _mm_add_ps(_mm_mul_ps(s, x2), _mm_set1_ps(8.333331e-3));
+            let s = _mm_add_ps(_mm_mul_ps(s, x2), _mm_set1_ps(-1.6666667e-1));
+            let s = _mm_add_ps(_mm_mul_ps(s, x2), _mm_set1_ps(1.0));
+            let s = _mm_mul_ps(s, x);
+
+            // Compute polynomial approximation of cosine.
+            let sign = blend_ps(comp, _mm_set1_ps(1.0), _mm_set1_ps(-1.0));
+            let c = _mm_add_ps(_mm_mul_ps(_mm_set1_ps(-2.6051615e-7), x2), _mm_set1_ps(2.4760495e-5));
+            let c = _mm_add_ps(_mm_mul_ps(c, x2), _mm_set1_ps(-1.3888378e-3));
+            let c = _mm_add_ps(_mm_mul_ps(c, x2), _mm_set1_ps(4.1666638e-2));
+            let c = _mm_add_ps(_mm_mul_ps(c, x2), _mm_set1_ps(-0.5));
+            let c = _mm_add_ps(_mm_mul_ps(c, x2), _mm_set1_ps(1.0));
+            let c = _mm_mul_ps(c, sign);
+
+            (Vector { inner: s }, Vector { inner: c })
+        }
+    }
+
+    /// Returns a vector containing the tangent of each element of the vector.
+    ///
+    /// ※ The angles given are in radians. </br>
+    /// ※ It is computed as the quotient of [`Vector::sin_cos`].
+    /// In the range `-4π` to `4π`, where the absolute value of the cosine is greater than `0.1`,
+    /// the error is less than `4.0e-6` (absolute if the result is less than one, otherwise relative).
+    ///
+    #[inline]
+    #[must_use]
+    pub fn tan(self) -> Self {
+        let (s, c) = self.sin_cos();
+        s / c
+    }
+
+    /// Returns a vector containing the arcsine of each element of the vector.
+    ///
+    /// ※ The given elements must be in the range `-1.0` to `1.0`. </br>
+    /// ※ It uses a 7-degree minimax polynomial approximation.
+    /// The absolute error is less than `5.0e-7`.
+    ///
+    #[inline]
+    #[must_use]
+    pub fn asin(self) -> Self {
+        unsafe { Vector { inner: _mm_sub_ps(_mm_set1_ps(core::f32::consts::FRAC_PI_2), self.acos().inner) } }
+    }
+
+    /// Returns a vector containing the arccosine of each element of the vector.
+    ///
+    /// ※ The given elements must be in the range `-1.0` to `1.0`. </br>
+    /// ※ It uses a 7-degree minimax polynomial approximation.
+    /// The absolute error is less than `5.0e-7`.
+    ///
+    #[must_use]
+    pub fn acos(self) -> Self {
+        // Reference: DirectXMath/Inc/DirectXMathVector.inl (XMVectorACos)
+        unsafe {
+            let non_negative = _mm_cmpge_ps(self.inner, _mm_setzero_ps());
+            let x = _mm_andnot_ps(_mm_set1_ps(-0.0), self.inner);
+            let root = _mm_sqrt_ps(_mm_max_ps(_mm_setzero_ps(), _mm_sub_ps(_mm_set1_ps(1.0), x)));
+
+            let t = _mm_add_ps(_mm_mul_ps(_mm_set1_ps(-1.2624911e-3), x), _mm_set1_ps(6.67009e-3));
+            let t = _mm_add_ps(_mm_mul_ps(t, x), _mm_set1_ps(-1.7088126e-2));
+            let t = _mm_add_ps(_mm_mul_ps(t, x), _mm_set1_ps(3.089188e-2));
+            let t = _mm_add_ps(_mm_mul_ps(t, x), _mm_set1_ps(-5.0174303e-2));
+            let t = _mm_add_ps(_mm_mul_ps(t, x), _mm_set1_ps(8.897899e-2));
+            let t = _mm_add_ps(_mm_mul_ps(t, x), _mm_set1_ps(-2.145988e-1));
+            let t = _mm_add_ps(_mm_mul_ps(t, x), _mm_set1_ps(core::f32::consts::FRAC_PI_2));
+            let t = _mm_mul_ps(t, root);
+
+            let rflx = _mm_sub_ps(_mm_set1_ps(core::f32::consts::PI), t);
+            Vector { inner: blend_ps(non_negative, t, rflx) }
+        }
+    }
+
+    /// Returns a vector containing the arctangent of each element of the vector.
+    ///
+    /// ※ It uses a 9-degree polynomial approximation after reducing the argument
+    /// to the range `-tan(π/8)` to `tan(π/8)`.
+    /// The absolute error is less than `2.0e-7`.
+    ///
+    #[must_use]
+    pub fn atan(self) -> Self {
+        // Reference: Cephes Math Library (atanf.c)
+        unsafe {
+            let sign = _mm_and_ps(self.inner, _mm_set1_ps(-0.0));
+            let x = _mm_andnot_ps(sign, self.inner);
+
+            let big = _mm_cmpgt_ps(x, _mm_set1_ps(2.4142137));
+            let mid = _mm_andnot_ps(big, _mm_cmpgt_ps(x, _mm_set1_ps(0.41421357)));
+            let y = _mm_or_ps(
+                _mm_and_ps(big, _mm_set1_ps(core::f32::consts::FRAC_PI_2)),
+                _mm_and_ps(mid, _mm_set1_ps(core::f32::consts::FRAC_PI_4))
+            );
+
+            let x_big = _mm_div_ps(_mm_set1_ps(-1.0), x);
+            let x_mid = _mm_div_ps(_mm_sub_ps(x, _mm_set1_ps(1.0)), _mm_add_ps(x, _mm_set1_ps(1.0)));
+            let x = blend_ps(big, x_big, x);
+            let x = blend_ps(mid, x_mid, x);
+            let z = _mm_mul_ps(x, x);
+
+            let p = _mm_add_ps(_mm_mul_ps(_mm_set1_ps(8.0537446e-2), z), _mm_set1_ps(-1.3877685e-1));
+            let p = _mm_add_ps(_mm_mul_ps(p, z), _mm_set1_ps(1.9977711e-1));
+            let p = _mm_add_ps(_mm_mul_ps(p, z), _mm_set1_ps(-3.333295e-1));
+            let p = _mm_add_ps(_mm_mul_ps(_mm_mul_ps(p, z), x), x);
+
+            Vector { inner: _mm_xor_ps(_mm_add_ps(y, p), sign) }
+        }
+    }
+
+    /// Returns a vector containing the four quadrant arctangent of `self` (`y`) and `x`.
+    ///
+    /// ※ The absolute error is less than `4.0e-7`. </br>
+    /// ※ Signed zeros and infinities are handled in the same way as [`f32::atan2`].
+    ///
+    #[must_use]
+    pub fn atan2(self, x: Self) -> Self {
+        unsafe {
+            let y = self.inner;
+            let x = x.inner;
+            let sign_mask = _mm_set1_ps(-0.0);
+            let y_sign = _mm_and_ps(y, sign_mask);
+            let x_neg = _mm_castsi128_ps(_mm_srai_epi32::<31>(_mm_castps_si128(x)));
+
+            // When `x` is negative, the result is moved to the opposite quadrant.
+            let atan = Vector { inner: _mm_div_ps(y, x) }.atan().inner;
+            let offset = _mm_add_ps(atan, _mm_or_ps(_mm_set1_ps(core::f32::consts::PI), y_sign));
+            let result = blend_ps(x_neg, offset, atan);
+
+            // When `y` is zero, the result is zero or π.
+            let y_zero = _mm_cmpeq_ps(y, _mm_setzero_ps());
+            let zero = _mm_or_ps(_mm_and_ps(x_neg, _mm_set1_ps(core::f32::consts::PI)), y_sign);
+            let result = blend_ps(y_zero, zero, result);
+
+            // When both `x` and `y` are infinite, the result is π/4 or 3π/4.
+            let inf = _mm_set1_ps(f32::INFINITY);
+            let both_inf = _mm_and_ps(
+                _mm_cmpeq_ps(_mm_andnot_ps(sign_mask, y), inf),
+                _mm_cmpeq_ps(_mm_andnot_ps(sign_mask, x), inf)
+            );
+            let quarter = blend_ps(x_neg, _mm_set1_ps(3.0 * core::f32::consts::FRAC_PI_4), _mm_set1_ps(core::f32::consts::FRAC_PI_4));
+            let quarter = _mm_or_ps(quarter, y_sign);
+            let result = blend_ps(both_inf, quarter, result);
+            Vector { inner: _mm_or_ps(result, _mm_cmpunord_ps(x, y)) }
+        }
+    }
+
+    /// Returns a vector containing `e^(self)` for each element of the vector.
+    ///
+    /// ※ It uses a 7-degree polynomial approximation after reducing the argument
+    /// to the range `-ln(2)/2` to `ln(2)/2`.
+    /// The relative error is less than `2.0e-7`.
+    ///
+    #[must_use]
+    pub fn exp(self) -> Self {
+        unsafe {
+            // Clamp the range so that the exponent of the result does not overflow.
+            // `NaN` is propagated because it is the second operand.
+            let x = _mm_max_ps(_mm_set1_ps(-104.0), self.inner);
+            let x = _mm_min_ps(_mm_set1_ps(89.0), x);
+
+            // e^x = 2^n * e^r
+            let n = _mm_cvtps_epi32(_mm_mul_ps(x, _mm_set1_ps(core::f32::consts::LOG2_E)));
+            let nf = _mm_cvtepi32_ps(n);
+            let r = _mm_sub_ps(x, _mm_mul_ps(nf, _mm_set1_ps(0.6933594)));
+            let r = _mm_sub_ps(r, _mm_mul_ps(nf, _mm_set1_ps(-2.1219444e-4)));
+            Self::exp_reduced(n, r)
+        }
+    }
+
+    /// Returns a vector containing `2^(self)` for each element of the vector.
+    ///
+    /// ※ The error bounds are the same as [`Vector::exp`].
+    ///
+    #[must_use]
+    pub fn exp2(self) -> Self {
+        unsafe {
+            let x = _mm_max_ps(_mm_set1_ps(-150.0), self.inner);
+            let x = _mm_min_ps(_mm_set1_ps(129.0), x);
+
+            // 2^x = 2^n * e^(r * ln(2))
+            let n = _mm_cvtps_epi32(x);
+            let r = _mm_sub_ps(x, _mm_cvtepi32_ps(n));
+            let r = _mm_mul_ps(r, _mm_set1_ps(core::f32::consts::LN_2));
+            Self::exp_reduced(n, r)
+        }
+    }
+
+    /// Computes `2^n * e^r` where `r` is in the range `-ln(2)/2` to `ln(2)/2`.
+    #[inline]
+    unsafe fn exp_reduced(n: __m128i, r: __m128) -> Self {
+        // Reference: Cephes Math Library (expf.c)
+        let r2 = _mm_mul_ps(r, r);
+        let p = _mm_add_ps(_mm_mul_ps(_mm_set1_ps(1.9875691e-4), r), _mm_set1_ps(1.3981999e-3));
+        let p = _mm_add_ps(_mm_mul_ps(p, r), _mm_set1_ps(8.333452e-3));
+        let p = _mm_add_ps(_mm_mul_ps(p, r), _mm_set1_ps(4.1665796e-2));
+        let p = _mm_add_ps(_mm_mul_ps(p, r), _mm_set1_ps(1.6666666e-1));
+        let p = _mm_add_ps(_mm_mul_ps(p, r), _mm_set1_ps(0.5));
+        let p = _mm_add_ps(_mm_add_ps(_mm_mul_ps(p, r2), r), _mm_set1_ps(1.0));
+
+        // 2^n is split into two factors so that each exponent stays in range.
+        let n0 = _mm_srai_epi32::<1>(n);
+        let n1 = _mm_sub_epi32(n, n0);
+        let bias = _mm_set1_epi32(127);
+        let pow0 = _mm_castsi128_ps(_mm_slli_epi32::<23>(_mm_add_epi32(n0, bias)));
+        let pow1 = _mm_castsi128_ps(_mm_slli_epi32::<23>(_mm_add_epi32(n1, bias)));
+        Vector { inner: _mm_mul_ps(_mm_mul_ps(p, pow0), pow1) }
+    }
+
+    /// Returns a vector containing the natural logarithm of each element of the vector.
+    ///
+    /// ※ It uses a 9-degree polynomial approximation after splitting the mantissa
+    /// into the range `sqrt(0.5)` to `sqrt(2.0)`.
+    /// The error is less than the larger of `5.0e-7` absolute and `2.0e-7` relative. </br>
+    /// ※ Returns `NaN` for negative elements and negative infinity for zeros.
+    ///
+    #[must_use]
+    pub fn ln(self) -> Self {
+        unsafe {
+            let (e, m) = self.log_reduced();
+            let result = _mm_add_ps(m, _mm_mul_ps(e, _mm_set1_ps(-2.1219444e-4)));
+            let result = _mm_add_ps(result, _mm_mul_ps(e, _mm_set1_ps(0.6933594)));
+            self.log_special_cases(result)
+        }
+    }
+
+    /// Returns a vector containing the base 2 logarithm of each element of the vector.
+    ///
+    /// ※ The error is less than the larger of `1.0e-6` absolute and `2.0e-7` relative. </br>
+    /// ※ Returns `NaN` for negative elements and negative infinity for zeros.
+    ///
+    #[must_use]
+    pub fn log2(self) -> Self {
+        unsafe {
+            let (e, m) = self.log_reduced();
+            let result = _mm_add_ps(_mm_mul_ps(m, _mm_set1_ps(core::f32::consts::LOG2_E)), e);
+            self.log_special_cases(result)
+        }
+    }
+
+    /// Splits each element into `2^e * m` and returns `e` and `ln(m)`.
+    #[inline]
+    unsafe fn log_reduced(self) -> (__m128, __m128) {
+        // Reference: Cephes Math Library (logf.c)
+        // Scales subnormal numbers to the normal range.
+        let subnormal = _mm_cmplt_ps(self.inner, _mm_set1_ps(f32::MIN_POSITIVE));
+        let scaled = _mm_mul_ps(self.inner, _mm_set1_ps(8388608.0));
+        let x = blend_ps(subnormal, scaled, self.inner);
+        let bias = _mm_or_si128(
+            _mm_and_si128(_mm_castps_si128(subnormal), _mm_set1_epi32(149)),
+            _mm_andnot_si128(_mm_castps_si128(subnormal), _mm_set1_epi32(126))
+        );
+
+        // x = m * 2^e (0.5 <= m < 1.0)
+        let bits = _mm_castps_si128(x);
+        let e = _mm_cvtepi32_ps(_mm_sub_epi32(_mm_srli_epi32::<23>(bits), bias));
+        let m = _mm_and_si128(bits, _mm_set1_epi32(0x007FFFFF));
+        let m = _mm_castsi128_ps(_mm_or_si128(m, _mm_set1_epi32(0x3F000000)));
+
+        // If m < sqrt(0.5), then use 2m - 1 and e - 1.
+        let small = _mm_cmplt_ps(m, _mm_set1_ps(core::f32::consts::FRAC_1_SQRT_2));
+        let e = _mm_sub_ps(e, _mm_and_ps(small, _mm_set1_ps(1.0)));
+        let m = _mm_add_ps(_mm_sub_ps(m, _mm_set1_ps(1.0)), _mm_and_ps(small, m));
+        let m2 = _mm_mul_ps(m, m);
+
+        let p = _mm_add_ps(_mm_mul_ps(_mm_set1_ps(7.0376836e-2), m), _mm_set1_ps(-1.151461e-1));
+        let p = _mm_add_ps(_mm_mul_ps(p, m), _mm_set1_ps(1.16769984e-1));
+        let p = _mm_add_ps(_mm_mul_ps(p, m), _mm_set1_ps(-1.2420141e-1));
+        let p = _mm_add_ps(_mm_mul_ps(p, m), _mm_set1_ps(1.4249323e-1));
+        let p = _mm_add_ps(_mm_mul_ps(p, m), _mm_set1_ps(-1.6668057e-1));
+        let p = _mm_add_ps(_mm_mul_ps(p, m), _mm_set1_ps(2.0000714e-1));
+        let p = _mm_add_ps(_mm_mul_ps(p, m), _mm_set1_ps(-2.4999994e-1));
+        let p = _mm_add_ps(_mm_mul_ps(p, m), _mm_set1_ps(3.333333e-1));
+        let p = _mm_mul_ps(_mm_mul_ps(p, m), m2);
+        let p = _mm_sub_ps(p, _mm_mul_ps(m2, _mm_set1_ps(0.5)));
+        (e, _mm_add_ps(m, p))
+    }
+
+    /// Replaces the logarithm of zero, negative, infinity and `NaN` elements.
+    #[inline]
+    unsafe fn log_special_cases(self, result: __m128) -> Self {
+        let zero = _mm_cmpeq_ps(self.inner, _mm_setzero_ps());
+        let inf = _mm_cmpeq_ps(self.inner, _mm_set1_ps(f32::INFINITY));
+        let invalid = _mm_or_ps(_mm_cmplt_ps(self.inner, _mm_setzero_ps()), _mm_cmpunord_ps(self.inner, self.inner));
+        let result = blend_ps(zero, _mm_set1_ps(f32::NEG_INFINITY), result);
+        let result = blend_ps(inf, self.inner, result);
+        Vector { inner: _mm_or_ps(invalid, result) }
+    }
+}
+
+impl Vector {
+    /// Returns `true` if the absolute difference of all elements is less than or equal to `max_abs_diff`.
+    #[inline]
+    #[must_use]
+    pub fn abs_diff_eq(self, rhs: Self, max_abs_diff: f32) -> bool {
+        near_eq::abs_diff_eq(&self.into_array(), &rhs.into_array(), max_abs_diff)
+    }
+
+    /// Returns `true` if all elements are equal within `max_abs_diff` or the relative tolerance `max_relative`.
+    /// 
+    /// ※ The relative tolerance is scaled by the larger absolute value of the two elements. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn relative_eq(self, rhs: Self, max_abs_diff: f32, max_relative: f32) -> bool {
+        near_eq::relative_eq(&self.into_array(), &rhs.into_array(), max_abs_diff, max_relative)
+    }
+
+    /// Returns `true` if all elements are equal within `max_abs_diff` or `max_ulps` representable values.
+    /// 
+    /// ※ Elements with different signs are equal only within `max_abs_diff`. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn ulps_eq(self, rhs: Self, max_abs_diff: f32, max_ulps: u32) -> bool {
+        near_eq::ulps_eq(&self.into_array(), &rhs.into_array(), max_abs_diff, max_ulps)
+    }
+}
+
+impl PartialEq for Vector {
+    /// Compares all elements exactly.
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.into_array() == other.into_array()
     }
 }
 
@@ -711,6 +1682,13 @@ impl Default for Vector {
     }
 }
 
+impl From<VectorInt> for Vector {
+    #[inline]
+    fn from(value: VectorInt) -> Self {
+        Self::from_bits(value)
+    }
+}
+
 impl From<[f32; 4]> for Vector {
     #[inline]
     fn from(value: [f32; 4]) -> Self {
@@ -0,0 +1,314 @@
+use core::fmt;
+use core::ops;
+
+#[cfg(target_pointer_width = "32")]
+use core::arch::x86::*;
+
+#[cfg(target_pointer_width = "64")]
+use core::arch::x86_64::*;
+
+use crate::{ Vector, VectorInt, Float4 };
+
+
+
+/// This is a structure-of-arrays data type that stores four four-dimensional vectors.
+///
+/// Each register holds one component of four vectors, so the operations are lane-parallel. </br>
+/// Using the `sse2` instruction.
+///
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Vector4x4 {
+    /// The `x` components of four vectors.
+    pub x: Vector,
+
+    /// The `y` components of four vectors.
+    pub y: Vector,
+
+    /// The `z` components of four vectors.
+    pub z: Vector,
+
+    /// The `w` components of four vectors.
+    pub w: Vector,
+}
+
+impl Vector4x4 {
+    /// All elements are zeros.
+    pub const ZERO: Self = Self::new(Vector::ZERO, Vector::ZERO, Vector::ZERO, Vector::ZERO);
+}
+
+impl Vector4x4 {
+    /// Creates with given component vectors.
+    #[inline]
+    #[must_use]
+    pub const fn new(x: Vector, y: Vector, z: Vector, w: Vector) -> Self {
+        Self { x, y, z, w }
+    }
+
+    /// Fills all four vectors with the given vector.
+    #[inline]
+    #[must_use]
+    pub fn splat(v: Vector) -> Self {
+        Self { x: v.splat_x(), y: v.splat_y(), z: v.splat_z(), w: v.splat_w() }
+    }
+
+    /// Creates from four vectors. (transposition)
+    #[inline]
+    #[must_use]
+    pub fn from_vectors(arr: [Vector; 4]) -> Self {
+        unsafe {
+            let (x, y, z, w) = transpose(arr[0].inner, arr[1].inner, arr[2].inner, arr[3].inner);
+            Self { x: Vector { inner: x }, y: Vector { inner: y }, z: Vector { inner: z }, w: Vector { inner: w } }
+        }
+    }
+
+    /// Stores the value in four vectors. (transposition)
+    #[inline]
+    #[must_use]
+    pub fn into_vectors(self) -> [Vector; 4] {
+        unsafe {
+            let (a, b, c, d) = transpose(self.x.inner, self.y.inner, self.z.inner, self.w.inner);
+            [Vector { inner: a }, Vector { inner: b }, Vector { inner: c }, Vector { inner: d }]
+        }
+    }
+
+    /// Loads a value from four given `Float4`. (transposition)
+    #[inline]
+    #[must_use]
+    pub fn load_float4_array(arr: [Float4; 4]) -> Self {
+        unsafe {
+            let ptr = arr.as_ptr() as *const f32;
+            let (x, y, z, w) = transpose(
+                _mm_loadu_ps(ptr),
+                _mm_loadu_ps(ptr.add(4)),
+                _mm_loadu_ps(ptr.add(8)),
+                _mm_loadu_ps(ptr.add(12))
+            );
+            Self { x: Vector { inner: x }, y: Vector { inner: y }, z: Vector { inner: z }, w: Vector { inner: w } }
+        }
+    }
+
+    /// Stores the value in four `Float4`. (transposition)
+    #[inline]
+    #[must_use]
+    pub fn store_float4_array(self) -> [Float4; 4] {
+        let mut arr = [Float4::ZERO; 4];
+        unsafe {
+            let (a, b, c, d) = transpose(self.x.inner, self.y.inner, self.z.inner, self.w.inner);
+            let ptr = arr.as_mut_ptr() as *mut f32;
+            _mm_storeu_ps(ptr, a);
+            _mm_storeu_ps(ptr.add(4), b);
+            _mm_storeu_ps(ptr.add(8), c);
+            _mm_storeu_ps(ptr.add(12), d);
+        }
+        arr
+    }
+
+    /// Dot product of each of the four vectors.
+    #[inline]
+    #[must_use]
+    pub fn dot(self, rhs: Self) -> Vector {
+        self.x.mul_add(rhs.x, self.y.mul_add(rhs.y, self.z.mul_add(rhs.z, self.w * rhs.w)))
+    }
+
+    /// Returns the length squared of each of the four vectors.
+    #[inline]
+    #[must_use]
+    pub fn len_sq(self) -> Vector {
+        self.dot(self)
+    }
+
+    /// Returns the length of each of the four vectors.
+    #[inline]
+    #[must_use]
+    pub fn len(self) -> Vector {
+        self.len_sq().sqrt()
+    }
+
+    /// Normalizes each of the four vectors.
+    ///
+    /// Undefined behavior may occur if the length of the vector is less than or equal to [`f32::EPSILON`].
+    ///
+    #[inline]
+    #[must_use]
+    pub fn normalize(self) -> Self {
+        self * self.len().recip()
+    }
+
+    /// Linearly interpolates each of the four vectors.
+    #[inline]
+    #[must_use]
+    pub fn lerp(self, rhs: Self, t: f32) -> Self {
+        Self {
+            x: self.x.lerp(rhs.x, t),
+            y: self.y.lerp(rhs.y, t),
+            z: self.z.lerp(rhs.z, t),
+            w: self.w.lerp(rhs.w, t)
+        }
+    }
+
+    /// Selects the vectors of `a` where the `mask` lane is set, otherwise the vectors of `b`.
+    ///
+    /// ※ The selection is done bit by bit, so the `mask` is usually the result of a comparison. </br>
+    ///
+    #[inline]
+    #[must_use]
+    pub fn select(mask: VectorInt, a: Self, b: Self) -> Self {
+        Self {
+            x: Vector::select(mask, a.x, b.x),
+            y: Vector::select(mask, a.y, b.y),
+            z: Vector::select(mask, a.z, b.z),
+            w: Vector::select(mask, a.w, b.w)
+        }
+    }
+
+    /// Returns `true` if the absolute difference of all elements is less than or equal to `max_abs_diff`.
+    #[inline]
+    #[must_use]
+    pub fn abs_diff_eq(self, rhs: Self, max_abs_diff: f32) -> bool {
+        self.x.abs_diff_eq(rhs.x, max_abs_diff) && 
+        self.y.abs_diff_eq(rhs.y, max_abs_diff) && 
+        self.z.abs_diff_eq(rhs.z, max_abs_diff) && 
+        self.w.abs_diff_eq(rhs.w, max_abs_diff)
+    }
+
+    /// Returns `true` if all elements are equal within `max_abs_diff` or the relative tolerance `max_relative`.
+    #[inline]
+    #[must_use]
+    pub fn relative_eq(self, rhs: Self, max_abs_diff: f32, max_relative: f32) -> bool {
+        self.x.relative_eq(rhs.x, max_abs_diff, max_relative) && 
+        self.y.relative_eq(rhs.y, max_abs_diff, max_relative) && 
+        self.z.relative_eq(rhs.z, max_abs_diff, max_relative) && 
+        self.w.relative_eq(rhs.w, max_abs_diff, max_relative)
+    }
+
+    /// Returns `true` if all elements are equal within `max_abs_diff` or `max_ulps` representable values.
+    #[inline]
+    #[must_use]
+    pub fn ulps_eq(self, rhs: Self, max_abs_diff: f32, max_ulps: u32) -> bool {
+        self.x.ulps_eq(rhs.x, max_abs_diff, max_ulps) && 
+        self.y.ulps_eq(rhs.y, max_abs_diff, max_ulps) && 
+        self.z.ulps_eq(rhs.z, max_abs_diff, max_ulps) && 
+        self.w.ulps_eq(rhs.w, max_abs_diff, max_ulps)
+    }
+}
+
+/// Transposes four registers as the rows of a 4x4 matrix.
+#[inline]
+unsafe fn transpose(a: __m128, b: __m128, c: __m128, d: __m128) -> (__m128, __m128, __m128, __m128) {
+    let t0 = _mm_unpacklo_ps(a, b);
+    let t1 = _mm_unpacklo_ps(c, d);
+    let t2 = _mm_unpackhi_ps(a, b);
+    let t3 = _mm_unpackhi_ps(c, d);
+    (_mm_movelh_ps(t0, t1), _mm_movehl_ps(t1, t0), _mm_movelh_ps(t2, t3), _mm_movehl_ps(t3, t2))
+}
+
+impl PartialEq for Vector4x4 {
+    /// Compares all elements exactly.
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.x == other.x && self.y == other.y && self.z == other.z && self.w == other.w
+    }
+}
+
+impl Default for Vector4x4 {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl From<[Float4; 4]> for Vector4x4 {
+    #[inline]
+    fn from(value: [Float4; 4]) -> Self {
+        Self::load_float4_array(value)
+    }
+}
+
+impl From<Vector4x4> for [Float4; 4] {
+    #[inline]
+    fn from(value: Vector4x4) -> Self {
+        value.store_float4_array()
+    }
+}
+
+impl ops::Add<Self> for Vector4x4 {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self { x: self.x + rhs.x, y: self.y + rhs.y, z: self.z + rhs.z, w: self.w + rhs.w }
+    }
+}
+
+impl ops::AddAssign<Self> for Vector4x4 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+impl ops::Sub<Self> for Vector4x4 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self { x: self.x - rhs.x, y: self.y - rhs.y, z: self.z - rhs.z, w: self.w - rhs.w }
+    }
+}
+
+impl ops::SubAssign<Self> for Vector4x4 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs
+    }
+}
+
+impl ops::Neg for Vector4x4 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self { x: -self.x, y: -self.y, z: -self.z, w: -self.w }
+    }
+}
+
+impl ops::Mul<Vector> for Vector4x4 {
+    type Output = Self;
+    /// Scales each of the four vectors by the corresponding lane.
+    #[inline]
+    fn mul(self, rhs: Vector) -> Self::Output {
+        Self { x: self.x * rhs, y: self.y * rhs, z: self.z * rhs, w: self.w * rhs }
+    }
+}
+
+impl ops::MulAssign<Vector> for Vector4x4 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Vector) {
+        *self = *self * rhs
+    }
+}
+
+impl ops::Mul<f32> for Vector4x4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: f32) -> Self::Output {
+        self * Vector::fill(rhs)
+    }
+}
+
+impl ops::MulAssign<f32> for Vector4x4 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: f32) {
+        *self = *self * rhs
+    }
+}
+
+impl fmt::Debug for Vector4x4 {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(stringify!(Vector4x4))
+            .field("x", &self.x)
+            .field("y", &self.y)
+            .field("z", &self.z)
+            .field("w", &self.w)
+            .finish()
+    }
+}
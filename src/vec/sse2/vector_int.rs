@@ -13,6 +13,8 @@ use crate::{
     UInteger2, UInteger3, UInteger4, 
 };
 
+use super::shuffle_mask;
+
 
 
 /// This is a vector data type that uses the `SIMD` instruction.
@@ -221,7 +223,7 @@ impl VectorInt {
     pub fn set_x(&mut self, v: i32) {
         // If sse4.1 is not supported, hardware acceleration is not used.
         unsafe {
-            match is_x86_feature_detected!("sse4.1") {
+            match cfg!(target_feature = "sse4.1") {
                 true => self.inner = _mm_insert_epi32::<0b00>(self.inner, v), 
                 false => {
                     let mut arr = self.into_array();
@@ -237,7 +239,7 @@ impl VectorInt {
     #[must_use]
     pub fn get_y(&self) -> i32 {
         unsafe {
-            match is_x86_feature_detected!("sse4.1") {
+            match cfg!(target_feature = "sse4.1") {
                 true => _mm_extract_epi32::<0b01>(self.inner), 
                 false => _mm_cvtsi128_si32(_mm_shuffle_epi32::<0b_01_01_01_01>(self.inner))
             }
@@ -249,7 +251,7 @@ impl VectorInt {
     pub fn set_y(&mut self, v: i32) {
         // If sse4.1 is not supported, hardware acceleration is not used.
         unsafe {
-            match is_x86_feature_detected!("sse4.1") {
+            match cfg!(target_feature = "sse4.1") {
                 true => self.inner = _mm_insert_epi32::<0b01>(self.inner, v), 
                 false => {
                     let mut arr = self.into_array();
@@ -265,7 +267,7 @@ impl VectorInt {
     #[must_use]
     pub fn get_z(&self) -> i32 {
         unsafe {
-            match is_x86_feature_detected!("sse4.1") {
+            match cfg!(target_feature = "sse4.1") {
                 true => _mm_extract_epi32::<0b10>(self.inner), 
                 false => _mm_cvtsi128_si32(_mm_shuffle_epi32::<0b_10_10_10_10>(self.inner))
             }
@@ -277,7 +279,7 @@ impl VectorInt {
     pub fn set_z(&mut self, v: i32) {
         // If sse4.1 is not supported, hardware acceleration is not used.
         unsafe {
-            match is_x86_feature_detected!("sse4.1") {
+            match cfg!(target_feature = "sse4.1") {
                 true => self.inner = _mm_insert_epi32::<0b10>(self.inner, v), 
                 false => {
                     let mut arr = self.into_array();
@@ -293,7 +295,7 @@ impl VectorInt {
     #[must_use]
     pub fn get_w(&self) -> i32 {
         unsafe {
-            match is_x86_feature_detected!("sse4.1") {
+            match cfg!(target_feature = "sse4.1") {
                 true => _mm_extract_epi32::<0b11>(self.inner), 
                 false => _mm_cvtsi128_si32(_mm_shuffle_epi32::<0b_11_11_11_11>(self.inner))
             }
@@ -305,7 +307,7 @@ impl VectorInt {
     pub fn set_w(&mut self, v: i32) {
         // If sse4.1 is not supported, hardware acceleration is not used.
         unsafe {
-            match is_x86_feature_detected!("sse4.1") {
+            match cfg!(target_feature = "sse4.1") {
                 true => self.inner = _mm_insert_epi32::<0b11>(self.inner, v), 
                 false => {
                     let mut arr = self.into_array();
@@ -319,13 +321,23 @@ impl VectorInt {
     /// Takes the samller of the elements of the two vectors.
     #[inline]
     pub fn min(self, rhs: Self) -> Self {
-        unsafe { VectorInt { inner: _mm_min_epi32(self.inner, rhs.inner) } }
+        unsafe {
+            match cfg!(target_feature = "sse4.1") {
+                true => VectorInt { inner: _mm_min_epi32(self.inner, rhs.inner) }, 
+                false => VectorInt::select(self.lt(rhs), self, rhs)
+            }
+        }
     }
 
     /// Takes the larger of the elements of the two vectors.
     #[inline]
     pub fn max(self, rhs: Self) -> Self {
-        unsafe { VectorInt { inner: _mm_max_epi32(self.inner, rhs.inner) } }
+        unsafe {
+            match cfg!(target_feature = "sse4.1") {
+                true => VectorInt { inner: _mm_max_epi32(self.inner, rhs.inner) }, 
+                false => VectorInt::select(self.gt(rhs), self, rhs)
+            }
+        }
     }
 
     /// Checks if the elements of two vectors are less.
@@ -370,11 +382,30 @@ impl VectorInt {
         !self.eq(rhs)
     }
 
+    /// Selects the elements of `a` where the `mask` is set, otherwise the elements of `b`.
+    /// 
+    /// ※ The selection is done bit by bit, so the `mask` is usually the result of a comparison. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn select(mask: VectorInt, a: Self, b: Self) -> Self {
+        unsafe { VectorInt { inner: _mm_or_si128(_mm_and_si128(mask.inner, a.inner), _mm_andnot_si128(mask.inner, b.inner)) } }
+    }
+
     /// Absolute value on vector elements.
     #[inline]
     #[must_use]
     pub fn abs(self) -> Self {
-        self.max(-self)
+        unsafe {
+            match cfg!(target_feature = "ssse3") {
+                true => VectorInt { inner: _mm_abs_epi32(self.inner) }, 
+                false => {
+                    // (x ^ sign) - sign
+                    let sign = _mm_srai_epi32::<31>(self.inner);
+                    VectorInt { inner: _mm_sub_epi32(_mm_xor_si128(self.inner, sign), sign) }
+                }
+            }
+        }
     }
 
     /// Return a vector filled by adding all the elements of the vector.
@@ -382,7 +413,7 @@ impl VectorInt {
     #[must_use]
     pub fn sum(self) -> Self {
         unsafe {
-            let sum = match is_x86_feature_detected!("sse3") {
+            let sum = match cfg!(target_feature = "ssse3") {
                 true => {
                     let temp = _mm_hadd_epi32(self.inner, self.inner);
                     _mm_hadd_epi32(temp, temp)
@@ -451,6 +482,91 @@ impl VectorInt {
     }
 }
 
+impl VectorInt {
+    /// Returns a vector with the elements rearranged by the given indices.
+    /// 
+    /// ※ Each index must be in the range `0` to `3` and selects the `x`, `y`, `z` or `w` element. </br>
+    /// ※ For example, `shuffle::<2, 1, 0, 3>()` returns `(z, y, x, w)`. </br>
+    /// 
+    /// # Panics
+    /// When the `use-assertion` feature is enabled, it will [`panic!`]
+    /// if any of the indices is greater than three.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn shuffle<const X: usize, const Y: usize, const Z: usize, const W: usize>(self) -> Self {
+        #[cfg(feature = "use-assertion")]
+        assert!(X < 4 && Y < 4 && Z < 4 && W < 4, "The index of the element is out of range!");
+        unsafe { VectorInt { inner: dispatch_imm8!(shuffle_mask(X, Y, Z, W), _mm_shuffle_epi32(self.inner)) } }
+    }
+
+    /// Returns a vector with the elements selected from the two vectors by the given indices.
+    /// 
+    /// ※ Indices `0` to `3` select the `x`, `y`, `z` or `w` element of `self`,
+    /// and indices `4` to `7` select the `x`, `y`, `z` or `w` element of `rhs`. </br>
+    /// ※ For example, `permute::<0, 4, 1, 5>(rhs)` returns `(self.x, rhs.x, self.y, rhs.y)`. </br>
+    /// 
+    /// # Panics
+    /// When the `use-assertion` feature is enabled, it will [`panic!`]
+    /// if any of the indices is greater than seven.
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn permute<const X: usize, const Y: usize, const Z: usize, const W: usize>(self, rhs: Self) -> Self {
+        #[cfg(feature = "use-assertion")]
+        assert!(X < 8 && Y < 8 && Z < 8 && W < 8, "The index of the element is out of range!");
+        Vector::from_bits(self).permute::<X, Y, Z, W>(Vector::from_bits(rhs)).to_bits()
+    }
+
+    /// Fills all elements with the `x` element of the vector.
+    #[inline]
+    #[must_use]
+    pub fn splat_x(self) -> Self {
+        unsafe { VectorInt { inner: _mm_shuffle_epi32::<0b_00_00_00_00>(self.inner) } }
+    }
+
+    /// Fills all elements with the `y` element of the vector.
+    #[inline]
+    #[must_use]
+    pub fn splat_y(self) -> Self {
+        unsafe { VectorInt { inner: _mm_shuffle_epi32::<0b_01_01_01_01>(self.inner) } }
+    }
+
+    /// Fills all elements with the `z` element of the vector.
+    #[inline]
+    #[must_use]
+    pub fn splat_z(self) -> Self {
+        unsafe { VectorInt { inner: _mm_shuffle_epi32::<0b_10_10_10_10>(self.inner) } }
+    }
+
+    /// Fills all elements with the `w` element of the vector.
+    #[inline]
+    #[must_use]
+    pub fn splat_w(self) -> Self {
+        unsafe { VectorInt { inner: _mm_shuffle_epi32::<0b_11_11_11_11>(self.inner) } }
+    }
+
+    /// Interleaves the `x` and `y` elements of the two vectors.
+    /// 
+    /// ※ Returns `(self.x, rhs.x, self.y, rhs.y)`. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn merge_xy(self, rhs: Self) -> Self {
+        unsafe { VectorInt { inner: _mm_unpacklo_epi32(self.inner, rhs.inner) } }
+    }
+
+    /// Interleaves the `z` and `w` elements of the two vectors.
+    /// 
+    /// ※ Returns `(self.z, rhs.z, self.w, rhs.w)`. </br>
+    /// 
+    #[inline]
+    #[must_use]
+    pub fn merge_zw(self, rhs: Self) -> Self {
+        unsafe { VectorInt { inner: _mm_unpackhi_epi32(self.inner, rhs.inner) } }
+    }
+}
+
 impl Default for VectorInt {
     #[inline]
     fn default() -> Self {
@@ -697,7 +813,19 @@ impl ops::Mul<VectorInt> for VectorInt {
     /// Element-wise multiplication of two vectors.
     #[inline]
     fn mul(self, rhs: VectorInt) -> Self::Output {
-        unsafe { VectorInt { inner: _mm_mul_epi32(self.inner, rhs.inner) } }
+        unsafe {
+            match cfg!(target_feature = "sse4.1") {
+                true => VectorInt { inner: _mm_mullo_epi32(self.inner, rhs.inner) }, 
+                false => {
+                    // Multiplies the even and odd elements separately, and keeps the lower 32 bits.
+                    let even = _mm_mul_epu32(self.inner, rhs.inner);
+                    let odd = _mm_mul_epu32(_mm_srli_epi64::<32>(self.inner), _mm_srli_epi64::<32>(rhs.inner));
+                    let even = _mm_shuffle_epi32::<0b_00_00_10_00>(even);
+                    let odd = _mm_shuffle_epi32::<0b_00_00_10_00>(odd);
+                    VectorInt { inner: _mm_unpacklo_epi32(even, odd) }
+                }
+            }
+        }
     }
 }
 
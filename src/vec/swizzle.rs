@@ -0,0 +1,3078 @@
+use super::{ Vector, VectorInt };
+
+
+impl Vector {
+    #[inline]
+    #[must_use]
+    pub fn xxxx(self) -> Self {
+        self.shuffle::<0, 0, 0, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xxxy(self) -> Self {
+        self.shuffle::<0, 0, 0, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xxxz(self) -> Self {
+        self.shuffle::<0, 0, 0, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xxxw(self) -> Self {
+        self.shuffle::<0, 0, 0, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xxyx(self) -> Self {
+        self.shuffle::<0, 0, 1, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xxyy(self) -> Self {
+        self.shuffle::<0, 0, 1, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xxyz(self) -> Self {
+        self.shuffle::<0, 0, 1, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xxyw(self) -> Self {
+        self.shuffle::<0, 0, 1, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xxzx(self) -> Self {
+        self.shuffle::<0, 0, 2, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xxzy(self) -> Self {
+        self.shuffle::<0, 0, 2, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xxzz(self) -> Self {
+        self.shuffle::<0, 0, 2, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xxzw(self) -> Self {
+        self.shuffle::<0, 0, 2, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xxwx(self) -> Self {
+        self.shuffle::<0, 0, 3, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xxwy(self) -> Self {
+        self.shuffle::<0, 0, 3, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xxwz(self) -> Self {
+        self.shuffle::<0, 0, 3, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xxww(self) -> Self {
+        self.shuffle::<0, 0, 3, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xyxx(self) -> Self {
+        self.shuffle::<0, 1, 0, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xyxy(self) -> Self {
+        self.shuffle::<0, 1, 0, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xyxz(self) -> Self {
+        self.shuffle::<0, 1, 0, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xyxw(self) -> Self {
+        self.shuffle::<0, 1, 0, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xyyx(self) -> Self {
+        self.shuffle::<0, 1, 1, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xyyy(self) -> Self {
+        self.shuffle::<0, 1, 1, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xyyz(self) -> Self {
+        self.shuffle::<0, 1, 1, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xyyw(self) -> Self {
+        self.shuffle::<0, 1, 1, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xyzx(self) -> Self {
+        self.shuffle::<0, 1, 2, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xyzy(self) -> Self {
+        self.shuffle::<0, 1, 2, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xyzz(self) -> Self {
+        self.shuffle::<0, 1, 2, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xyzw(self) -> Self {
+        self.shuffle::<0, 1, 2, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xywx(self) -> Self {
+        self.shuffle::<0, 1, 3, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xywy(self) -> Self {
+        self.shuffle::<0, 1, 3, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xywz(self) -> Self {
+        self.shuffle::<0, 1, 3, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xyww(self) -> Self {
+        self.shuffle::<0, 1, 3, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xzxx(self) -> Self {
+        self.shuffle::<0, 2, 0, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xzxy(self) -> Self {
+        self.shuffle::<0, 2, 0, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xzxz(self) -> Self {
+        self.shuffle::<0, 2, 0, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xzxw(self) -> Self {
+        self.shuffle::<0, 2, 0, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xzyx(self) -> Self {
+        self.shuffle::<0, 2, 1, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xzyy(self) -> Self {
+        self.shuffle::<0, 2, 1, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xzyz(self) -> Self {
+        self.shuffle::<0, 2, 1, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xzyw(self) -> Self {
+        self.shuffle::<0, 2, 1, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xzzx(self) -> Self {
+        self.shuffle::<0, 2, 2, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xzzy(self) -> Self {
+        self.shuffle::<0, 2, 2, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xzzz(self) -> Self {
+        self.shuffle::<0, 2, 2, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xzzw(self) -> Self {
+        self.shuffle::<0, 2, 2, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xzwx(self) -> Self {
+        self.shuffle::<0, 2, 3, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xzwy(self) -> Self {
+        self.shuffle::<0, 2, 3, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xzwz(self) -> Self {
+        self.shuffle::<0, 2, 3, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xzww(self) -> Self {
+        self.shuffle::<0, 2, 3, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xwxx(self) -> Self {
+        self.shuffle::<0, 3, 0, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xwxy(self) -> Self {
+        self.shuffle::<0, 3, 0, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xwxz(self) -> Self {
+        self.shuffle::<0, 3, 0, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xwxw(self) -> Self {
+        self.shuffle::<0, 3, 0, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xwyx(self) -> Self {
+        self.shuffle::<0, 3, 1, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xwyy(self) -> Self {
+        self.shuffle::<0, 3, 1, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xwyz(self) -> Self {
+        self.shuffle::<0, 3, 1, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xwyw(self) -> Self {
+        self.shuffle::<0, 3, 1, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xwzx(self) -> Self {
+        self.shuffle::<0, 3, 2, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xwzy(self) -> Self {
+        self.shuffle::<0, 3, 2, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xwzz(self) -> Self {
+        self.shuffle::<0, 3, 2, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xwzw(self) -> Self {
+        self.shuffle::<0, 3, 2, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xwwx(self) -> Self {
+        self.shuffle::<0, 3, 3, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xwwy(self) -> Self {
+        self.shuffle::<0, 3, 3, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xwwz(self) -> Self {
+        self.shuffle::<0, 3, 3, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xwww(self) -> Self {
+        self.shuffle::<0, 3, 3, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yxxx(self) -> Self {
+        self.shuffle::<1, 0, 0, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yxxy(self) -> Self {
+        self.shuffle::<1, 0, 0, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yxxz(self) -> Self {
+        self.shuffle::<1, 0, 0, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yxxw(self) -> Self {
+        self.shuffle::<1, 0, 0, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yxyx(self) -> Self {
+        self.shuffle::<1, 0, 1, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yxyy(self) -> Self {
+        self.shuffle::<1, 0, 1, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yxyz(self) -> Self {
+        self.shuffle::<1, 0, 1, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yxyw(self) -> Self {
+        self.shuffle::<1, 0, 1, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yxzx(self) -> Self {
+        self.shuffle::<1, 0, 2, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yxzy(self) -> Self {
+        self.shuffle::<1, 0, 2, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yxzz(self) -> Self {
+        self.shuffle::<1, 0, 2, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yxzw(self) -> Self {
+        self.shuffle::<1, 0, 2, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yxwx(self) -> Self {
+        self.shuffle::<1, 0, 3, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yxwy(self) -> Self {
+        self.shuffle::<1, 0, 3, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yxwz(self) -> Self {
+        self.shuffle::<1, 0, 3, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yxww(self) -> Self {
+        self.shuffle::<1, 0, 3, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yyxx(self) -> Self {
+        self.shuffle::<1, 1, 0, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yyxy(self) -> Self {
+        self.shuffle::<1, 1, 0, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yyxz(self) -> Self {
+        self.shuffle::<1, 1, 0, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yyxw(self) -> Self {
+        self.shuffle::<1, 1, 0, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yyyx(self) -> Self {
+        self.shuffle::<1, 1, 1, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yyyy(self) -> Self {
+        self.shuffle::<1, 1, 1, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yyyz(self) -> Self {
+        self.shuffle::<1, 1, 1, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yyyw(self) -> Self {
+        self.shuffle::<1, 1, 1, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yyzx(self) -> Self {
+        self.shuffle::<1, 1, 2, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yyzy(self) -> Self {
+        self.shuffle::<1, 1, 2, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yyzz(self) -> Self {
+        self.shuffle::<1, 1, 2, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yyzw(self) -> Self {
+        self.shuffle::<1, 1, 2, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yywx(self) -> Self {
+        self.shuffle::<1, 1, 3, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yywy(self) -> Self {
+        self.shuffle::<1, 1, 3, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yywz(self) -> Self {
+        self.shuffle::<1, 1, 3, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yyww(self) -> Self {
+        self.shuffle::<1, 1, 3, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yzxx(self) -> Self {
+        self.shuffle::<1, 2, 0, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yzxy(self) -> Self {
+        self.shuffle::<1, 2, 0, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yzxz(self) -> Self {
+        self.shuffle::<1, 2, 0, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yzxw(self) -> Self {
+        self.shuffle::<1, 2, 0, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yzyx(self) -> Self {
+        self.shuffle::<1, 2, 1, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yzyy(self) -> Self {
+        self.shuffle::<1, 2, 1, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yzyz(self) -> Self {
+        self.shuffle::<1, 2, 1, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yzyw(self) -> Self {
+        self.shuffle::<1, 2, 1, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yzzx(self) -> Self {
+        self.shuffle::<1, 2, 2, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yzzy(self) -> Self {
+        self.shuffle::<1, 2, 2, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yzzz(self) -> Self {
+        self.shuffle::<1, 2, 2, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yzzw(self) -> Self {
+        self.shuffle::<1, 2, 2, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yzwx(self) -> Self {
+        self.shuffle::<1, 2, 3, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yzwy(self) -> Self {
+        self.shuffle::<1, 2, 3, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yzwz(self) -> Self {
+        self.shuffle::<1, 2, 3, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yzww(self) -> Self {
+        self.shuffle::<1, 2, 3, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn ywxx(self) -> Self {
+        self.shuffle::<1, 3, 0, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn ywxy(self) -> Self {
+        self.shuffle::<1, 3, 0, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn ywxz(self) -> Self {
+        self.shuffle::<1, 3, 0, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn ywxw(self) -> Self {
+        self.shuffle::<1, 3, 0, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn ywyx(self) -> Self {
+        self.shuffle::<1, 3, 1, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn ywyy(self) -> Self {
+        self.shuffle::<1, 3, 1, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn ywyz(self) -> Self {
+        self.shuffle::<1, 3, 1, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn ywyw(self) -> Self {
+        self.shuffle::<1, 3, 1, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn ywzx(self) -> Self {
+        self.shuffle::<1, 3, 2, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn ywzy(self) -> Self {
+        self.shuffle::<1, 3, 2, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn ywzz(self) -> Self {
+        self.shuffle::<1, 3, 2, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn ywzw(self) -> Self {
+        self.shuffle::<1, 3, 2, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn ywwx(self) -> Self {
+        self.shuffle::<1, 3, 3, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn ywwy(self) -> Self {
+        self.shuffle::<1, 3, 3, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn ywwz(self) -> Self {
+        self.shuffle::<1, 3, 3, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn ywww(self) -> Self {
+        self.shuffle::<1, 3, 3, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zxxx(self) -> Self {
+        self.shuffle::<2, 0, 0, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zxxy(self) -> Self {
+        self.shuffle::<2, 0, 0, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zxxz(self) -> Self {
+        self.shuffle::<2, 0, 0, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zxxw(self) -> Self {
+        self.shuffle::<2, 0, 0, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zxyx(self) -> Self {
+        self.shuffle::<2, 0, 1, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zxyy(self) -> Self {
+        self.shuffle::<2, 0, 1, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zxyz(self) -> Self {
+        self.shuffle::<2, 0, 1, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zxyw(self) -> Self {
+        self.shuffle::<2, 0, 1, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zxzx(self) -> Self {
+        self.shuffle::<2, 0, 2, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zxzy(self) -> Self {
+        self.shuffle::<2, 0, 2, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zxzz(self) -> Self {
+        self.shuffle::<2, 0, 2, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zxzw(self) -> Self {
+        self.shuffle::<2, 0, 2, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zxwx(self) -> Self {
+        self.shuffle::<2, 0, 3, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zxwy(self) -> Self {
+        self.shuffle::<2, 0, 3, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zxwz(self) -> Self {
+        self.shuffle::<2, 0, 3, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zxww(self) -> Self {
+        self.shuffle::<2, 0, 3, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zyxx(self) -> Self {
+        self.shuffle::<2, 1, 0, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zyxy(self) -> Self {
+        self.shuffle::<2, 1, 0, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zyxz(self) -> Self {
+        self.shuffle::<2, 1, 0, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zyxw(self) -> Self {
+        self.shuffle::<2, 1, 0, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zyyx(self) -> Self {
+        self.shuffle::<2, 1, 1, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zyyy(self) -> Self {
+        self.shuffle::<2, 1, 1, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zyyz(self) -> Self {
+        self.shuffle::<2, 1, 1, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zyyw(self) -> Self {
+        self.shuffle::<2, 1, 1, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zyzx(self) -> Self {
+        self.shuffle::<2, 1, 2, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zyzy(self) -> Self {
+        self.shuffle::<2, 1, 2, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zyzz(self) -> Self {
+        self.shuffle::<2, 1, 2, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zyzw(self) -> Self {
+        self.shuffle::<2, 1, 2, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zywx(self) -> Self {
+        self.shuffle::<2, 1, 3, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zywy(self) -> Self {
+        self.shuffle::<2, 1, 3, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zywz(self) -> Self {
+        self.shuffle::<2, 1, 3, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zyww(self) -> Self {
+        self.shuffle::<2, 1, 3, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zzxx(self) -> Self {
+        self.shuffle::<2, 2, 0, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zzxy(self) -> Self {
+        self.shuffle::<2, 2, 0, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zzxz(self) -> Self {
+        self.shuffle::<2, 2, 0, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zzxw(self) -> Self {
+        self.shuffle::<2, 2, 0, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zzyx(self) -> Self {
+        self.shuffle::<2, 2, 1, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zzyy(self) -> Self {
+        self.shuffle::<2, 2, 1, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zzyz(self) -> Self {
+        self.shuffle::<2, 2, 1, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zzyw(self) -> Self {
+        self.shuffle::<2, 2, 1, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zzzx(self) -> Self {
+        self.shuffle::<2, 2, 2, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zzzy(self) -> Self {
+        self.shuffle::<2, 2, 2, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zzzz(self) -> Self {
+        self.shuffle::<2, 2, 2, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zzzw(self) -> Self {
+        self.shuffle::<2, 2, 2, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zzwx(self) -> Self {
+        self.shuffle::<2, 2, 3, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zzwy(self) -> Self {
+        self.shuffle::<2, 2, 3, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zzwz(self) -> Self {
+        self.shuffle::<2, 2, 3, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zzww(self) -> Self {
+        self.shuffle::<2, 2, 3, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zwxx(self) -> Self {
+        self.shuffle::<2, 3, 0, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zwxy(self) -> Self {
+        self.shuffle::<2, 3, 0, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zwxz(self) -> Self {
+        self.shuffle::<2, 3, 0, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zwxw(self) -> Self {
+        self.shuffle::<2, 3, 0, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zwyx(self) -> Self {
+        self.shuffle::<2, 3, 1, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zwyy(self) -> Self {
+        self.shuffle::<2, 3, 1, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zwyz(self) -> Self {
+        self.shuffle::<2, 3, 1, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zwyw(self) -> Self {
+        self.shuffle::<2, 3, 1, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zwzx(self) -> Self {
+        self.shuffle::<2, 3, 2, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zwzy(self) -> Self {
+        self.shuffle::<2, 3, 2, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zwzz(self) -> Self {
+        self.shuffle::<2, 3, 2, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zwzw(self) -> Self {
+        self.shuffle::<2, 3, 2, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zwwx(self) -> Self {
+        self.shuffle::<2, 3, 3, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zwwy(self) -> Self {
+        self.shuffle::<2, 3, 3, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zwwz(self) -> Self {
+        self.shuffle::<2, 3, 3, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zwww(self) -> Self {
+        self.shuffle::<2, 3, 3, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wxxx(self) -> Self {
+        self.shuffle::<3, 0, 0, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wxxy(self) -> Self {
+        self.shuffle::<3, 0, 0, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wxxz(self) -> Self {
+        self.shuffle::<3, 0, 0, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wxxw(self) -> Self {
+        self.shuffle::<3, 0, 0, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wxyx(self) -> Self {
+        self.shuffle::<3, 0, 1, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wxyy(self) -> Self {
+        self.shuffle::<3, 0, 1, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wxyz(self) -> Self {
+        self.shuffle::<3, 0, 1, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wxyw(self) -> Self {
+        self.shuffle::<3, 0, 1, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wxzx(self) -> Self {
+        self.shuffle::<3, 0, 2, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wxzy(self) -> Self {
+        self.shuffle::<3, 0, 2, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wxzz(self) -> Self {
+        self.shuffle::<3, 0, 2, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wxzw(self) -> Self {
+        self.shuffle::<3, 0, 2, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wxwx(self) -> Self {
+        self.shuffle::<3, 0, 3, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wxwy(self) -> Self {
+        self.shuffle::<3, 0, 3, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wxwz(self) -> Self {
+        self.shuffle::<3, 0, 3, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wxww(self) -> Self {
+        self.shuffle::<3, 0, 3, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wyxx(self) -> Self {
+        self.shuffle::<3, 1, 0, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wyxy(self) -> Self {
+        self.shuffle::<3, 1, 0, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wyxz(self) -> Self {
+        self.shuffle::<3, 1, 0, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wyxw(self) -> Self {
+        self.shuffle::<3, 1, 0, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wyyx(self) -> Self {
+        self.shuffle::<3, 1, 1, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wyyy(self) -> Self {
+        self.shuffle::<3, 1, 1, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wyyz(self) -> Self {
+        self.shuffle::<3, 1, 1, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wyyw(self) -> Self {
+        self.shuffle::<3, 1, 1, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wyzx(self) -> Self {
+        self.shuffle::<3, 1, 2, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wyzy(self) -> Self {
+        self.shuffle::<3, 1, 2, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wyzz(self) -> Self {
+        self.shuffle::<3, 1, 2, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wyzw(self) -> Self {
+        self.shuffle::<3, 1, 2, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wywx(self) -> Self {
+        self.shuffle::<3, 1, 3, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wywy(self) -> Self {
+        self.shuffle::<3, 1, 3, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wywz(self) -> Self {
+        self.shuffle::<3, 1, 3, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wyww(self) -> Self {
+        self.shuffle::<3, 1, 3, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wzxx(self) -> Self {
+        self.shuffle::<3, 2, 0, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wzxy(self) -> Self {
+        self.shuffle::<3, 2, 0, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wzxz(self) -> Self {
+        self.shuffle::<3, 2, 0, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wzxw(self) -> Self {
+        self.shuffle::<3, 2, 0, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wzyx(self) -> Self {
+        self.shuffle::<3, 2, 1, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wzyy(self) -> Self {
+        self.shuffle::<3, 2, 1, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wzyz(self) -> Self {
+        self.shuffle::<3, 2, 1, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wzyw(self) -> Self {
+        self.shuffle::<3, 2, 1, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wzzx(self) -> Self {
+        self.shuffle::<3, 2, 2, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wzzy(self) -> Self {
+        self.shuffle::<3, 2, 2, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wzzz(self) -> Self {
+        self.shuffle::<3, 2, 2, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wzzw(self) -> Self {
+        self.shuffle::<3, 2, 2, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wzwx(self) -> Self {
+        self.shuffle::<3, 2, 3, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wzwy(self) -> Self {
+        self.shuffle::<3, 2, 3, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wzwz(self) -> Self {
+        self.shuffle::<3, 2, 3, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wzww(self) -> Self {
+        self.shuffle::<3, 2, 3, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wwxx(self) -> Self {
+        self.shuffle::<3, 3, 0, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wwxy(self) -> Self {
+        self.shuffle::<3, 3, 0, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wwxz(self) -> Self {
+        self.shuffle::<3, 3, 0, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wwxw(self) -> Self {
+        self.shuffle::<3, 3, 0, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wwyx(self) -> Self {
+        self.shuffle::<3, 3, 1, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wwyy(self) -> Self {
+        self.shuffle::<3, 3, 1, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wwyz(self) -> Self {
+        self.shuffle::<3, 3, 1, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wwyw(self) -> Self {
+        self.shuffle::<3, 3, 1, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wwzx(self) -> Self {
+        self.shuffle::<3, 3, 2, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wwzy(self) -> Self {
+        self.shuffle::<3, 3, 2, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wwzz(self) -> Self {
+        self.shuffle::<3, 3, 2, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wwzw(self) -> Self {
+        self.shuffle::<3, 3, 2, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wwwx(self) -> Self {
+        self.shuffle::<3, 3, 3, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wwwy(self) -> Self {
+        self.shuffle::<3, 3, 3, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wwwz(self) -> Self {
+        self.shuffle::<3, 3, 3, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wwww(self) -> Self {
+        self.shuffle::<3, 3, 3, 3>()
+    }
+}
+
+impl VectorInt {
+    #[inline]
+    #[must_use]
+    pub fn xxxx(self) -> Self {
+        self.shuffle::<0, 0, 0, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xxxy(self) -> Self {
+        self.shuffle::<0, 0, 0, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xxxz(self) -> Self {
+        self.shuffle::<0, 0, 0, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xxxw(self) -> Self {
+        self.shuffle::<0, 0, 0, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xxyx(self) -> Self {
+        self.shuffle::<0, 0, 1, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xxyy(self) -> Self {
+        self.shuffle::<0, 0, 1, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xxyz(self) -> Self {
+        self.shuffle::<0, 0, 1, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xxyw(self) -> Self {
+        self.shuffle::<0, 0, 1, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xxzx(self) -> Self {
+        self.shuffle::<0, 0, 2, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xxzy(self) -> Self {
+        self.shuffle::<0, 0, 2, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xxzz(self) -> Self {
+        self.shuffle::<0, 0, 2, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xxzw(self) -> Self {
+        self.shuffle::<0, 0, 2, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xxwx(self) -> Self {
+        self.shuffle::<0, 0, 3, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xxwy(self) -> Self {
+        self.shuffle::<0, 0, 3, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xxwz(self) -> Self {
+        self.shuffle::<0, 0, 3, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xxww(self) -> Self {
+        self.shuffle::<0, 0, 3, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xyxx(self) -> Self {
+        self.shuffle::<0, 1, 0, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xyxy(self) -> Self {
+        self.shuffle::<0, 1, 0, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xyxz(self) -> Self {
+        self.shuffle::<0, 1, 0, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xyxw(self) -> Self {
+        self.shuffle::<0, 1, 0, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xyyx(self) -> Self {
+        self.shuffle::<0, 1, 1, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xyyy(self) -> Self {
+        self.shuffle::<0, 1, 1, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xyyz(self) -> Self {
+        self.shuffle::<0, 1, 1, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xyyw(self) -> Self {
+        self.shuffle::<0, 1, 1, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xyzx(self) -> Self {
+        self.shuffle::<0, 1, 2, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xyzy(self) -> Self {
+        self.shuffle::<0, 1, 2, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xyzz(self) -> Self {
+        self.shuffle::<0, 1, 2, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xyzw(self) -> Self {
+        self.shuffle::<0, 1, 2, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xywx(self) -> Self {
+        self.shuffle::<0, 1, 3, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xywy(self) -> Self {
+        self.shuffle::<0, 1, 3, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xywz(self) -> Self {
+        self.shuffle::<0, 1, 3, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xyww(self) -> Self {
+        self.shuffle::<0, 1, 3, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xzxx(self) -> Self {
+        self.shuffle::<0, 2, 0, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xzxy(self) -> Self {
+        self.shuffle::<0, 2, 0, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xzxz(self) -> Self {
+        self.shuffle::<0, 2, 0, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xzxw(self) -> Self {
+        self.shuffle::<0, 2, 0, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xzyx(self) -> Self {
+        self.shuffle::<0, 2, 1, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xzyy(self) -> Self {
+        self.shuffle::<0, 2, 1, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xzyz(self) -> Self {
+        self.shuffle::<0, 2, 1, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xzyw(self) -> Self {
+        self.shuffle::<0, 2, 1, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xzzx(self) -> Self {
+        self.shuffle::<0, 2, 2, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xzzy(self) -> Self {
+        self.shuffle::<0, 2, 2, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xzzz(self) -> Self {
+        self.shuffle::<0, 2, 2, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xzzw(self) -> Self {
+        self.shuffle::<0, 2, 2, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xzwx(self) -> Self {
+        self.shuffle::<0, 2, 3, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xzwy(self) -> Self {
+        self.shuffle::<0, 2, 3, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xzwz(self) -> Self {
+        self.shuffle::<0, 2, 3, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xzww(self) -> Self {
+        self.shuffle::<0, 2, 3, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xwxx(self) -> Self {
+        self.shuffle::<0, 3, 0, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xwxy(self) -> Self {
+        self.shuffle::<0, 3, 0, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xwxz(self) -> Self {
+        self.shuffle::<0, 3, 0, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xwxw(self) -> Self {
+        self.shuffle::<0, 3, 0, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xwyx(self) -> Self {
+        self.shuffle::<0, 3, 1, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xwyy(self) -> Self {
+        self.shuffle::<0, 3, 1, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xwyz(self) -> Self {
+        self.shuffle::<0, 3, 1, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xwyw(self) -> Self {
+        self.shuffle::<0, 3, 1, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xwzx(self) -> Self {
+        self.shuffle::<0, 3, 2, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xwzy(self) -> Self {
+        self.shuffle::<0, 3, 2, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xwzz(self) -> Self {
+        self.shuffle::<0, 3, 2, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xwzw(self) -> Self {
+        self.shuffle::<0, 3, 2, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xwwx(self) -> Self {
+        self.shuffle::<0, 3, 3, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xwwy(self) -> Self {
+        self.shuffle::<0, 3, 3, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xwwz(self) -> Self {
+        self.shuffle::<0, 3, 3, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xwww(self) -> Self {
+        self.shuffle::<0, 3, 3, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yxxx(self) -> Self {
+        self.shuffle::<1, 0, 0, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yxxy(self) -> Self {
+        self.shuffle::<1, 0, 0, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yxxz(self) -> Self {
+        self.shuffle::<1, 0, 0, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yxxw(self) -> Self {
+        self.shuffle::<1, 0, 0, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yxyx(self) -> Self {
+        self.shuffle::<1, 0, 1, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yxyy(self) -> Self {
+        self.shuffle::<1, 0, 1, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yxyz(self) -> Self {
+        self.shuffle::<1, 0, 1, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yxyw(self) -> Self {
+        self.shuffle::<1, 0, 1, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yxzx(self) -> Self {
+        self.shuffle::<1, 0, 2, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yxzy(self) -> Self {
+        self.shuffle::<1, 0, 2, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yxzz(self) -> Self {
+        self.shuffle::<1, 0, 2, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yxzw(self) -> Self {
+        self.shuffle::<1, 0, 2, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yxwx(self) -> Self {
+        self.shuffle::<1, 0, 3, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yxwy(self) -> Self {
+        self.shuffle::<1, 0, 3, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yxwz(self) -> Self {
+        self.shuffle::<1, 0, 3, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yxww(self) -> Self {
+        self.shuffle::<1, 0, 3, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yyxx(self) -> Self {
+        self.shuffle::<1, 1, 0, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yyxy(self) -> Self {
+        self.shuffle::<1, 1, 0, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yyxz(self) -> Self {
+        self.shuffle::<1, 1, 0, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yyxw(self) -> Self {
+        self.shuffle::<1, 1, 0, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yyyx(self) -> Self {
+        self.shuffle::<1, 1, 1, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yyyy(self) -> Self {
+        self.shuffle::<1, 1, 1, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yyyz(self) -> Self {
+        self.shuffle::<1, 1, 1, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yyyw(self) -> Self {
+        self.shuffle::<1, 1, 1, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yyzx(self) -> Self {
+        self.shuffle::<1, 1, 2, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yyzy(self) -> Self {
+        self.shuffle::<1, 1, 2, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yyzz(self) -> Self {
+        self.shuffle::<1, 1, 2, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yyzw(self) -> Self {
+        self.shuffle::<1, 1, 2, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yywx(self) -> Self {
+        self.shuffle::<1, 1, 3, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yywy(self) -> Self {
+        self.shuffle::<1, 1, 3, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yywz(self) -> Self {
+        self.shuffle::<1, 1, 3, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yyww(self) -> Self {
+        self.shuffle::<1, 1, 3, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yzxx(self) -> Self {
+        self.shuffle::<1, 2, 0, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yzxy(self) -> Self {
+        self.shuffle::<1, 2, 0, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yzxz(self) -> Self {
+        self.shuffle::<1, 2, 0, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yzxw(self) -> Self {
+        self.shuffle::<1, 2, 0, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yzyx(self) -> Self {
+        self.shuffle::<1, 2, 1, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yzyy(self) -> Self {
+        self.shuffle::<1, 2, 1, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yzyz(self) -> Self {
+        self.shuffle::<1, 2, 1, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yzyw(self) -> Self {
+        self.shuffle::<1, 2, 1, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yzzx(self) -> Self {
+        self.shuffle::<1, 2, 2, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yzzy(self) -> Self {
+        self.shuffle::<1, 2, 2, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yzzz(self) -> Self {
+        self.shuffle::<1, 2, 2, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yzzw(self) -> Self {
+        self.shuffle::<1, 2, 2, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yzwx(self) -> Self {
+        self.shuffle::<1, 2, 3, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yzwy(self) -> Self {
+        self.shuffle::<1, 2, 3, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yzwz(self) -> Self {
+        self.shuffle::<1, 2, 3, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn yzww(self) -> Self {
+        self.shuffle::<1, 2, 3, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn ywxx(self) -> Self {
+        self.shuffle::<1, 3, 0, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn ywxy(self) -> Self {
+        self.shuffle::<1, 3, 0, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn ywxz(self) -> Self {
+        self.shuffle::<1, 3, 0, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn ywxw(self) -> Self {
+        self.shuffle::<1, 3, 0, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn ywyx(self) -> Self {
+        self.shuffle::<1, 3, 1, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn ywyy(self) -> Self {
+        self.shuffle::<1, 3, 1, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn ywyz(self) -> Self {
+        self.shuffle::<1, 3, 1, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn ywyw(self) -> Self {
+        self.shuffle::<1, 3, 1, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn ywzx(self) -> Self {
+        self.shuffle::<1, 3, 2, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn ywzy(self) -> Self {
+        self.shuffle::<1, 3, 2, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn ywzz(self) -> Self {
+        self.shuffle::<1, 3, 2, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn ywzw(self) -> Self {
+        self.shuffle::<1, 3, 2, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn ywwx(self) -> Self {
+        self.shuffle::<1, 3, 3, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn ywwy(self) -> Self {
+        self.shuffle::<1, 3, 3, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn ywwz(self) -> Self {
+        self.shuffle::<1, 3, 3, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn ywww(self) -> Self {
+        self.shuffle::<1, 3, 3, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zxxx(self) -> Self {
+        self.shuffle::<2, 0, 0, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zxxy(self) -> Self {
+        self.shuffle::<2, 0, 0, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zxxz(self) -> Self {
+        self.shuffle::<2, 0, 0, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zxxw(self) -> Self {
+        self.shuffle::<2, 0, 0, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zxyx(self) -> Self {
+        self.shuffle::<2, 0, 1, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zxyy(self) -> Self {
+        self.shuffle::<2, 0, 1, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zxyz(self) -> Self {
+        self.shuffle::<2, 0, 1, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zxyw(self) -> Self {
+        self.shuffle::<2, 0, 1, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zxzx(self) -> Self {
+        self.shuffle::<2, 0, 2, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zxzy(self) -> Self {
+        self.shuffle::<2, 0, 2, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zxzz(self) -> Self {
+        self.shuffle::<2, 0, 2, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zxzw(self) -> Self {
+        self.shuffle::<2, 0, 2, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zxwx(self) -> Self {
+        self.shuffle::<2, 0, 3, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zxwy(self) -> Self {
+        self.shuffle::<2, 0, 3, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zxwz(self) -> Self {
+        self.shuffle::<2, 0, 3, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zxww(self) -> Self {
+        self.shuffle::<2, 0, 3, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zyxx(self) -> Self {
+        self.shuffle::<2, 1, 0, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zyxy(self) -> Self {
+        self.shuffle::<2, 1, 0, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zyxz(self) -> Self {
+        self.shuffle::<2, 1, 0, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zyxw(self) -> Self {
+        self.shuffle::<2, 1, 0, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zyyx(self) -> Self {
+        self.shuffle::<2, 1, 1, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zyyy(self) -> Self {
+        self.shuffle::<2, 1, 1, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zyyz(self) -> Self {
+        self.shuffle::<2, 1, 1, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zyyw(self) -> Self {
+        self.shuffle::<2, 1, 1, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zyzx(self) -> Self {
+        self.shuffle::<2, 1, 2, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zyzy(self) -> Self {
+        self.shuffle::<2, 1, 2, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zyzz(self) -> Self {
+        self.shuffle::<2, 1, 2, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zyzw(self) -> Self {
+        self.shuffle::<2, 1, 2, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zywx(self) -> Self {
+        self.shuffle::<2, 1, 3, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zywy(self) -> Self {
+        self.shuffle::<2, 1, 3, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zywz(self) -> Self {
+        self.shuffle::<2, 1, 3, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zyww(self) -> Self {
+        self.shuffle::<2, 1, 3, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zzxx(self) -> Self {
+        self.shuffle::<2, 2, 0, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zzxy(self) -> Self {
+        self.shuffle::<2, 2, 0, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zzxz(self) -> Self {
+        self.shuffle::<2, 2, 0, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zzxw(self) -> Self {
+        self.shuffle::<2, 2, 0, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zzyx(self) -> Self {
+        self.shuffle::<2, 2, 1, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zzyy(self) -> Self {
+        self.shuffle::<2, 2, 1, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zzyz(self) -> Self {
+        self.shuffle::<2, 2, 1, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zzyw(self) -> Self {
+        self.shuffle::<2, 2, 1, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zzzx(self) -> Self {
+        self.shuffle::<2, 2, 2, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zzzy(self) -> Self {
+        self.shuffle::<2, 2, 2, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zzzz(self) -> Self {
+        self.shuffle::<2, 2, 2, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zzzw(self) -> Self {
+        self.shuffle::<2, 2, 2, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zzwx(self) -> Self {
+        self.shuffle::<2, 2, 3, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zzwy(self) -> Self {
+        self.shuffle::<2, 2, 3, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zzwz(self) -> Self {
+        self.shuffle::<2, 2, 3, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zzww(self) -> Self {
+        self.shuffle::<2, 2, 3, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zwxx(self) -> Self {
+        self.shuffle::<2, 3, 0, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zwxy(self) -> Self {
+        self.shuffle::<2, 3, 0, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zwxz(self) -> Self {
+        self.shuffle::<2, 3, 0, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zwxw(self) -> Self {
+        self.shuffle::<2, 3, 0, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zwyx(self) -> Self {
+        self.shuffle::<2, 3, 1, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zwyy(self) -> Self {
+        self.shuffle::<2, 3, 1, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zwyz(self) -> Self {
+        self.shuffle::<2, 3, 1, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zwyw(self) -> Self {
+        self.shuffle::<2, 3, 1, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zwzx(self) -> Self {
+        self.shuffle::<2, 3, 2, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zwzy(self) -> Self {
+        self.shuffle::<2, 3, 2, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zwzz(self) -> Self {
+        self.shuffle::<2, 3, 2, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zwzw(self) -> Self {
+        self.shuffle::<2, 3, 2, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zwwx(self) -> Self {
+        self.shuffle::<2, 3, 3, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zwwy(self) -> Self {
+        self.shuffle::<2, 3, 3, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zwwz(self) -> Self {
+        self.shuffle::<2, 3, 3, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn zwww(self) -> Self {
+        self.shuffle::<2, 3, 3, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wxxx(self) -> Self {
+        self.shuffle::<3, 0, 0, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wxxy(self) -> Self {
+        self.shuffle::<3, 0, 0, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wxxz(self) -> Self {
+        self.shuffle::<3, 0, 0, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wxxw(self) -> Self {
+        self.shuffle::<3, 0, 0, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wxyx(self) -> Self {
+        self.shuffle::<3, 0, 1, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wxyy(self) -> Self {
+        self.shuffle::<3, 0, 1, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wxyz(self) -> Self {
+        self.shuffle::<3, 0, 1, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wxyw(self) -> Self {
+        self.shuffle::<3, 0, 1, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wxzx(self) -> Self {
+        self.shuffle::<3, 0, 2, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wxzy(self) -> Self {
+        self.shuffle::<3, 0, 2, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wxzz(self) -> Self {
+        self.shuffle::<3, 0, 2, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wxzw(self) -> Self {
+        self.shuffle::<3, 0, 2, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wxwx(self) -> Self {
+        self.shuffle::<3, 0, 3, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wxwy(self) -> Self {
+        self.shuffle::<3, 0, 3, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wxwz(self) -> Self {
+        self.shuffle::<3, 0, 3, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wxww(self) -> Self {
+        self.shuffle::<3, 0, 3, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wyxx(self) -> Self {
+        self.shuffle::<3, 1, 0, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wyxy(self) -> Self {
+        self.shuffle::<3, 1, 0, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wyxz(self) -> Self {
+        self.shuffle::<3, 1, 0, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wyxw(self) -> Self {
+        self.shuffle::<3, 1, 0, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wyyx(self) -> Self {
+        self.shuffle::<3, 1, 1, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wyyy(self) -> Self {
+        self.shuffle::<3, 1, 1, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wyyz(self) -> Self {
+        self.shuffle::<3, 1, 1, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wyyw(self) -> Self {
+        self.shuffle::<3, 1, 1, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wyzx(self) -> Self {
+        self.shuffle::<3, 1, 2, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wyzy(self) -> Self {
+        self.shuffle::<3, 1, 2, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wyzz(self) -> Self {
+        self.shuffle::<3, 1, 2, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wyzw(self) -> Self {
+        self.shuffle::<3, 1, 2, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wywx(self) -> Self {
+        self.shuffle::<3, 1, 3, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wywy(self) -> Self {
+        self.shuffle::<3, 1, 3, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wywz(self) -> Self {
+        self.shuffle::<3, 1, 3, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wyww(self) -> Self {
+        self.shuffle::<3, 1, 3, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wzxx(self) -> Self {
+        self.shuffle::<3, 2, 0, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wzxy(self) -> Self {
+        self.shuffle::<3, 2, 0, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wzxz(self) -> Self {
+        self.shuffle::<3, 2, 0, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wzxw(self) -> Self {
+        self.shuffle::<3, 2, 0, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wzyx(self) -> Self {
+        self.shuffle::<3, 2, 1, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wzyy(self) -> Self {
+        self.shuffle::<3, 2, 1, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wzyz(self) -> Self {
+        self.shuffle::<3, 2, 1, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wzyw(self) -> Self {
+        self.shuffle::<3, 2, 1, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wzzx(self) -> Self {
+        self.shuffle::<3, 2, 2, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wzzy(self) -> Self {
+        self.shuffle::<3, 2, 2, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wzzz(self) -> Self {
+        self.shuffle::<3, 2, 2, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wzzw(self) -> Self {
+        self.shuffle::<3, 2, 2, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wzwx(self) -> Self {
+        self.shuffle::<3, 2, 3, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wzwy(self) -> Self {
+        self.shuffle::<3, 2, 3, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wzwz(self) -> Self {
+        self.shuffle::<3, 2, 3, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wzww(self) -> Self {
+        self.shuffle::<3, 2, 3, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wwxx(self) -> Self {
+        self.shuffle::<3, 3, 0, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wwxy(self) -> Self {
+        self.shuffle::<3, 3, 0, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wwxz(self) -> Self {
+        self.shuffle::<3, 3, 0, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wwxw(self) -> Self {
+        self.shuffle::<3, 3, 0, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wwyx(self) -> Self {
+        self.shuffle::<3, 3, 1, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wwyy(self) -> Self {
+        self.shuffle::<3, 3, 1, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wwyz(self) -> Self {
+        self.shuffle::<3, 3, 1, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wwyw(self) -> Self {
+        self.shuffle::<3, 3, 1, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wwzx(self) -> Self {
+        self.shuffle::<3, 3, 2, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wwzy(self) -> Self {
+        self.shuffle::<3, 3, 2, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wwzz(self) -> Self {
+        self.shuffle::<3, 3, 2, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wwzw(self) -> Self {
+        self.shuffle::<3, 3, 2, 3>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wwwx(self) -> Self {
+        self.shuffle::<3, 3, 3, 0>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wwwy(self) -> Self {
+        self.shuffle::<3, 3, 3, 1>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wwwz(self) -> Self {
+        self.shuffle::<3, 3, 3, 2>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn wwww(self) -> Self {
+        self.shuffle::<3, 3, 3, 3>()
+    }
+}